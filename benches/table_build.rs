@@ -55,5 +55,18 @@ table_bench!(
 
 table_bench!(big_table, { [0; 16] });
 
-criterion_group!(benches, small_table, big_table, small_table_stylish);
+table_bench!(big_table_short_text, { ["short"; 16] });
+
+table_bench!(big_table_long_text, {
+    ["This is a text which doesn't fit into a cell inline"; 16]
+});
+
+criterion_group!(
+    benches,
+    small_table,
+    big_table,
+    big_table_short_text,
+    big_table_long_text,
+    small_table_stylish
+);
 criterion_main!(benches);
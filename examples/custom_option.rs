@@ -0,0 +1,38 @@
+//! The example can be run by this command
+//! `cargo run --example custom_option`
+
+use tabled::{
+    ext,
+    grid::{AlignmentHorizontal, Grid, Settings},
+    Style, Table, TableOption,
+};
+
+/// A theme which centers a header and marks empty cells of a body.
+struct CompanyTheme;
+
+impl TableOption for CompanyTheme {
+    fn change(&mut self, grid: &mut Grid) {
+        Style::psql().change(grid);
+
+        let header_rows = grid.count_header_rows();
+        ext::for_each_cell(grid, &tabled::Full, |mut cell| {
+            if cell.position().0 < header_rows {
+                cell.update(Settings::new().alignment(AlignmentHorizontal::Center));
+            } else if cell.text().is_empty() {
+                cell.set_text("n/a");
+            }
+        });
+    }
+}
+
+fn main() {
+    let data = [
+        ("Debian", "2.6", ""),
+        ("Arch", "", "rolling"),
+        ("Fedora", "5.16", "35"),
+    ];
+
+    let table = Table::new(data).with(CompanyTheme);
+
+    println!("{}", table);
+}
@@ -0,0 +1,20 @@
+//! The example can be run by this command
+//! `cargo run --example terminal --features terminal`
+
+use tabled::{Constraint, Style, Table, Width};
+
+fn main() {
+    let data = [
+        [
+            "Hello World",
+            "An example of a long text which would be wrapped",
+        ],
+        ["Hello World", "if a terminal is narrower than the table"],
+    ];
+
+    let table = Table::new(data)
+        .with(Style::modern())
+        .with(Width::fit_terminal().column(0, Constraint::Max(13)));
+
+    println!("{}", table);
+}
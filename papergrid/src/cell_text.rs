@@ -0,0 +1,133 @@
+//! A storage type for cell contents.
+//!
+//! Most cells in a typical table hold a handful of characters,
+//! so [CellText] keeps short strings inline and only allocates for long ones.
+
+use std::{fmt, ops::Deref};
+
+/// An amount of bytes which can be stored without an allocation.
+///
+/// It's chosen so [CellText] takes the same space as a [String] on 64-bit targets.
+const INLINE_CAPACITY: usize = 22;
+
+/// A content of a cell.
+///
+/// Strings which fit into [INLINE_CAPACITY] bytes are stored inline,
+/// longer ones are kept on the heap.
+///
+/// Inline bytes are either empty or copied from a [str] by [CellText::inline],
+/// so they're always a valid UTF-8.
+#[derive(Clone)]
+pub(crate) enum CellText {
+    Inline {
+        len: u8,
+        bytes: [u8; INLINE_CAPACITY],
+    },
+    Heap(Box<str>),
+}
+
+impl CellText {
+    pub(crate) fn new() -> Self {
+        Self::Inline {
+            len: 0,
+            bytes: [0; INLINE_CAPACITY],
+        }
+    }
+
+    pub(crate) fn as_str(&self) -> &str {
+        match self {
+            // SAFETY: inline bytes are either empty or copied from a `&str` in `CellText::inline`
+            // and are never changed afterwards, so they're a valid UTF-8.
+            Self::Inline { len, bytes } => unsafe {
+                std::str::from_utf8_unchecked(&bytes[..*len as usize])
+            },
+            Self::Heap(text) => text,
+        }
+    }
+
+    fn inline(text: &str) -> Option<Self> {
+        if text.len() > INLINE_CAPACITY {
+            return None;
+        }
+
+        let mut bytes = [0; INLINE_CAPACITY];
+        bytes[..text.len()].copy_from_slice(text.as_bytes());
+
+        Some(Self::Inline {
+            len: text.len() as u8,
+            bytes,
+        })
+    }
+}
+
+impl Default for CellText {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Deref for CellText {
+    type Target = str;
+
+    fn deref(&self) -> &Self::Target {
+        self.as_str()
+    }
+}
+
+impl From<String> for CellText {
+    fn from(text: String) -> Self {
+        Self::inline(&text).unwrap_or_else(|| Self::Heap(text.into_boxed_str()))
+    }
+}
+
+impl From<&str> for CellText {
+    fn from(text: &str) -> Self {
+        Self::inline(text).unwrap_or_else(|| Self::Heap(Box::from(text)))
+    }
+}
+
+impl fmt::Debug for CellText {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(self.as_str(), f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    #[cfg(target_pointer_width = "64")]
+    fn cell_text_size_test() {
+        assert_eq!(
+            std::mem::size_of::<CellText>(),
+            std::mem::size_of::<String>()
+        );
+    }
+
+    #[test]
+    fn cell_text_storage_test() {
+        assert!(matches!(CellText::from(""), CellText::Inline { .. }));
+        assert!(matches!(
+            CellText::from("Hello 🎩"),
+            CellText::Inline { .. }
+        ));
+        assert!(matches!(
+            CellText::from("a".repeat(INLINE_CAPACITY)),
+            CellText::Inline { .. }
+        ));
+        assert!(matches!(
+            CellText::from("a".repeat(INLINE_CAPACITY + 1)),
+            CellText::Heap(..)
+        ));
+    }
+
+    #[test]
+    fn cell_text_content_test() {
+        for text in ["", "0", "Hello 🎩", &"Long text ".repeat(10)] {
+            assert_eq!(CellText::from(text).as_str(), text);
+            assert_eq!(CellText::from(text.to_owned()).as_str(), text);
+            assert_eq!(&*CellText::from(text).clone(), text);
+        }
+    }
+}
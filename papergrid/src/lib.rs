@@ -22,13 +22,21 @@
 //!     assert_eq!(expected, grid.to_string());
 //! ```
 
+mod cell_text;
+#[cfg(feature = "testing")]
+pub mod testing;
+
 use std::{
-    cmp::max,
-    collections::{BTreeSet, HashMap},
-    fmt::{self, Display},
+    borrow::Cow,
+    cmp::{max, min, Ordering},
+    collections::{BTreeMap, BTreeSet, HashMap},
+    fmt,
+    iter::FromIterator,
     ops::{Bound, RangeBounds},
 };
 
+use cell_text::CellText;
+
 pub const DEFAULT_CELL_STYLE: Border = Border {
     top: Some('-'),
     bottom: Some('-'),
@@ -45,18 +53,33 @@ const DEFAULT_SPLIT_BORDER_CHAR: char = ' ';
 const DEFAULT_SPLIT_INTERSECTION_CHAR: char = ' ';
 
 /// Grid provides a set of methods for building a text-based table
+#[derive(Clone)]
 pub struct Grid {
     size: (usize, usize),
-    cells: Vec<Vec<String>>,
+    cells: Vec<Vec<CellText>>,
     styles: HashMap<Entity, Style>,
     borders: Borders,
-    override_split_lines: HashMap<usize, String>,
+    override_split_lines: HashMap<usize, SplitLineOverride>,
+    border_overrides: Vec<(Entity, Border)>,
+    row_spacing: HashMap<usize, usize>,
+    column_min_widths: HashMap<usize, usize>,
+    origins: Vec<Vec<Option<(usize, usize)>>>,
+    inner_vertical_separator: Option<String>,
+    border_glyphs: HashMap<char, String>,
+    header_rows: usize,
+    tab_width: usize,
+    trim: bool,
+    center_rounding: CenterRounding,
+    fixed_widths: Option<Vec<usize>>,
+    hide_empty_rows: bool,
+    hide_empty_columns: bool,
 }
 
 impl Grid {
     /// The new method creates a grid instance with default styles.
     ///
-    /// The size of the grid can not be changed after the instance is created.
+    /// The size of the grid can be changed by [Grid::insert_row], [Grid::remove_row],
+    /// [Grid::insert_column] and [Grid::remove_column].
     ///
     /// # Example
     ///
@@ -75,15 +98,61 @@ impl Grid {
     ///     )
     /// ```
     pub fn new(rows: usize, columns: usize) -> Self {
+        Self::with_cells(vec![vec![CellText::new(); columns]; rows], columns)
+    }
+
+    /// Creates a grid out of a content of cells.
+    ///
+    /// The content is moved into the grid, so no cell is copied.
+    /// Rows shorter than `columns` are filled with empty cells and longer ones are truncated.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    ///     use papergrid::Grid;
+    ///     let cells = vec![vec![String::from("a"), String::from("b")], vec![String::from("c")]];
+    ///     let grid = Grid::from_cells(cells, 2);
+    ///     assert_eq!(grid.count_rows(), 2);
+    ///     assert_eq!(grid.get_cell_content(0, 1), "b");
+    ///     assert_eq!(grid.get_cell_content(1, 1), "");
+    /// ```
+    pub fn from_cells(cells: Vec<Vec<String>>, columns: usize) -> Self {
+        let cells = cells
+            .into_iter()
+            .map(|row| {
+                let mut row = row.into_iter().map(CellText::from).collect::<Vec<_>>();
+                row.resize_with(columns, CellText::new);
+                row
+            })
+            .collect();
+
+        Self::with_cells(cells, columns)
+    }
+
+    fn with_cells(cells: Vec<Vec<CellText>>, columns: usize) -> Self {
+        let rows = cells.len();
         let mut styles = HashMap::new();
         styles.insert(Entity::Global, Style::default());
 
         Grid {
             size: (rows, columns),
-            cells: vec![vec![String::new(); columns]; rows],
+            cells,
             styles,
             borders: Borders::new(rows, columns),
             override_split_lines: HashMap::new(),
+            border_overrides: Vec::new(),
+            row_spacing: HashMap::new(),
+            column_min_widths: HashMap::new(),
+            origins: vec![vec![None; columns]; rows],
+            inner_vertical_separator: None,
+            border_glyphs: HashMap::new(),
+            header_rows: 1,
+            tab_width: 4,
+            trim: true,
+            center_rounding: CenterRounding::default(),
+            fixed_widths: None,
+            hide_empty_rows: false,
+            hide_empty_columns: false,
         }
     }
 
@@ -115,8 +184,20 @@ impl Grid {
             self.set_text(entity, text);
         }
 
-        if let Some(indent) = settings.indent {
-            self.style_mut(entity).indent = indent;
+        if let Some(left) = settings.indent_left {
+            self.style_mut(entity).indent.left = left;
+        }
+
+        if let Some(right) = settings.indent_right {
+            self.style_mut(entity).indent.right = right;
+        }
+
+        if let Some(top) = settings.indent_top {
+            self.style_mut(entity).indent.top = top;
+        }
+
+        if let Some(bottom) = settings.indent_bottom {
+            self.style_mut(entity).indent.bottom = bottom;
         }
 
         if let Some(alignment_h) = settings.alignment_h {
@@ -131,7 +212,58 @@ impl Grid {
             self.style_mut(entity).span = span;
         }
 
+        if let Some(span) = settings.row_span {
+            self.style_mut(entity).row_span = span;
+        }
+
+        if let Some(alignment) = settings.span_alignment {
+            self.style_mut(entity).span_alignment = alignment;
+        }
+
+        if let Some(width) = settings.min_width {
+            self.style_mut(entity).min_width = width;
+        }
+
+        if let Some(height) = settings.min_height {
+            self.style_mut(entity).min_height = height;
+        }
+
+        if let Some(fill) = settings.fill {
+            self.style_mut(entity).fill = fill;
+        }
+
+        if let Some(pattern) = settings.fill_left {
+            self.style_mut(entity).fill_left = Some(pattern).filter(|p| !p.is_empty());
+        }
+
+        if let Some(pattern) = settings.fill_right {
+            self.style_mut(entity).fill_right = Some(pattern).filter(|p| !p.is_empty());
+        }
+
+        if let (Some(origin), Entity::Cell(row, column)) = (settings.origin, entity) {
+            self.origins[*row][*column] = Some(origin);
+        }
+
+        for tag in settings.tags {
+            let tags = &mut self.style_mut(entity).tags;
+            if !tags.contains(&tag) {
+                tags.push(tag);
+            }
+        }
+
+        for (key, value) in settings.attributes {
+            let attributes = &mut self.style_mut(entity).attributes;
+            match attributes.iter_mut().find(|(k, _)| *k == key) {
+                Some(attribute) => attribute.1 = value,
+                None => attributes.push((key, value)),
+            }
+        }
+
         if let Some(border) = settings.border {
+            if settings.border_override {
+                self.border_overrides.push((entity.clone(), border.clone()));
+            }
+
             let frame = self.frame_from_entity(entity);
             if settings.border_split_check {
                 self.add_split_lines_for_border(&frame, &border);
@@ -174,13 +306,57 @@ impl Grid {
     }
 
     fn is_vertical_present(&mut self, column: usize) -> bool {
-        self.borders.is_there_vertical(column)
+        self.has_vertical(column)
     }
 
     fn is_horizontal_present(&mut self, row: usize) -> bool {
+        self.has_horizontal(row)
+    }
+
+    /// Checks whether there's a horizontal split line before a given row.
+    ///
+    /// A line after the last row has an index equal to an amount of rows.
+    pub fn has_horizontal(&self, row: usize) -> bool {
         self.borders.is_there_horizontal(row)
     }
 
+    /// Checks whether there's a vertical split line before a given column.
+    ///
+    /// A line after the last column has an index equal to an amount of columns.
+    pub fn has_vertical(&self, column: usize) -> bool {
+        self.borders.is_there_vertical(column)
+    }
+
+    /// Returns all split lines of the grid.
+    ///
+    /// Horizontal lines go first, each kind of lines is ordered by an index.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    ///     use papergrid::{Grid, Split};
+    ///     let mut grid = Grid::new(2, 2);
+    ///     grid.add_horizontal_split(1);
+    ///     grid.add_vertical_split(0);
+    ///
+    ///     assert_eq!(
+    ///         grid.split_lines().collect::<Vec<_>>(),
+    ///         [Split::Horizontal(1), Split::Vertical(0)]
+    ///     );
+    /// ```
+    pub fn split_lines(&self) -> impl Iterator<Item = Split> + '_ {
+        self.borders
+            .horizontal
+            .keys()
+            .map(|&row| Split::Horizontal(row))
+            .chain(
+                self.borders
+                    .vertical
+                    .keys()
+                    .map(|&column| Split::Vertical(column)),
+            )
+    }
+
     pub fn add_grid_split(&mut self) {
         for row in 0..self.count_rows() + 1 {
             self.add_horizontal_split(row);
@@ -199,6 +375,298 @@ impl Grid {
         self.override_split_lines.clear();
     }
 
+    /// Sets an amount of blank lines which are added to a height of a row.
+    ///
+    /// The lines are placed according to a vertical alignment of cells,
+    /// the content of the cells is not changed.
+    pub fn set_row_spacing(&mut self, row: usize, lines: usize) {
+        self.row_spacing.insert(row, lines);
+    }
+
+    /// Returns an amount of blank lines which are added to a height of a row.
+    pub fn get_row_spacing(&self, row: usize) -> usize {
+        self.row_spacing.get(&row).copied().unwrap_or(0)
+    }
+
+    /// Sets a minimum width of a column.
+    ///
+    /// The width includes an indent of cells.
+    /// Cells which are spanned over a few columns are not affected.
+    pub fn set_column_min_width(&mut self, column: usize, width: usize) {
+        self.column_min_widths.insert(column, width);
+    }
+
+    /// Returns a minimum width of a column set by [Grid::set_column_min_width].
+    pub fn get_column_min_width(&self, column: usize) -> usize {
+        self.column_min_widths.get(&column).copied().unwrap_or(0)
+    }
+
+    /// Sets an amount of rows at the top of the grid which make a header.
+    ///
+    /// The grid renders them as any other rows,
+    /// it's a hint for whoever styles the grid.
+    /// By default a header is a first row.
+    pub fn set_header_rows(&mut self, count: usize) {
+        self.header_rows = count;
+    }
+
+    /// Sets an amount of spaces a tab character is replaced by.
+    ///
+    /// By default a tab is replaced by 4 spaces.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    ///     use papergrid::{Entity, Grid, Settings, DEFAULT_CELL_STYLE};
+    ///     let mut grid = Grid::new(1, 1);
+    ///     grid.set_cell_borders(DEFAULT_CELL_STYLE.clone());
+    ///     grid.set(&Entity::Cell(0, 0), Settings::new().text("a\tb"));
+    ///     grid.set_tab_width(2);
+    ///     assert_eq!(grid.to_string(), "+----+\n|a  b|\n+----+\n");
+    /// ```
+    pub fn set_tab_width(&mut self, width: usize) {
+        self.tab_width = width;
+    }
+
+    /// Returns an amount of spaces a tab character is replaced by.
+    pub fn get_tab_width(&self) -> usize {
+        self.tab_width
+    }
+
+    /// Sets whether leading and trailing whitespace of each line of a cell
+    /// is ignored when the line is aligned.
+    ///
+    /// By default lines are trimmed.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    ///     use papergrid::{Entity, Grid, Settings, DEFAULT_CELL_STYLE};
+    ///     let mut grid = Grid::new(2, 1);
+    ///     grid.set_cell_borders(DEFAULT_CELL_STYLE.clone());
+    ///     grid.set(&Entity::Cell(0, 0), Settings::new().text("  a"));
+    ///     grid.set(&Entity::Cell(1, 0), Settings::new().text("bbbb"));
+    ///     assert_eq!(grid.to_string(), "+----+\n|a   |\n+----+\n|bbbb|\n+----+\n");
+    ///
+    ///     grid.set_trim(false);
+    ///     assert_eq!(grid.to_string(), "+----+\n|  a |\n+----+\n|bbbb|\n+----+\n");
+    /// ```
+    pub fn set_trim(&mut self, trim: bool) {
+        self.trim = trim;
+    }
+
+    /// Returns whether lines of cells are trimmed before an alignment.
+    pub fn is_trim(&self) -> bool {
+        self.trim
+    }
+
+    /// Sets a side which gets an extra space of a centered line
+    /// when a free space can't be split evenly.
+    ///
+    /// By default it's [CenterRounding::Right].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    ///     use papergrid::{AlignmentHorizontal, CenterRounding, Entity, Grid, Settings};
+    ///     let mut grid = Grid::new(2, 1);
+    ///     grid.set(&Entity::Global, Settings::new().alignment(AlignmentHorizontal::Center));
+    ///     grid.set(&Entity::Cell(0, 0), Settings::new().text("ab\nc"));
+    ///     grid.set(&Entity::Cell(1, 0), Settings::new().text("d"));
+    ///     assert_eq!(grid.to_string(), "ab\nc \nd \n");
+    ///
+    ///     grid.set_center_rounding(CenterRounding::Left);
+    ///     assert_eq!(grid.to_string(), "ab\n c\n d\n");
+    ///
+    ///     grid.set_center_rounding(CenterRounding::Alternate);
+    ///     assert_eq!(grid.to_string(), "ab\n c\nd \n");
+    /// ```
+    pub fn set_center_rounding(&mut self, rounding: CenterRounding) {
+        self.center_rounding = rounding;
+    }
+
+    /// Returns a side which gets an extra space of a centered line.
+    pub fn get_center_rounding(&self) -> CenterRounding {
+        self.center_rounding
+    }
+
+    /// Sets widths of columns so contents are not measured to find them.
+    ///
+    /// A width includes an indent of a cell but not its borders.
+    /// A width of a spanned cell is a sum of widths of its columns and borders between them.
+    /// Lines which don't fit a cell are cut.
+    /// A column which has no width set, or a width smaller than an indent of its cells,
+    /// is made as wide as the indent.
+    ///
+    /// `None` turns the measurement back on.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    ///     use papergrid::{Grid, Entity, Settings, DEFAULT_CELL_STYLE};
+    ///     let mut grid = Grid::new(1, 2);
+    ///     grid.set_cell_borders(DEFAULT_CELL_STYLE.clone());
+    ///     grid.set(&Entity::Cell(0, 0), Settings::new().text("Hello"));
+    ///     grid.set(&Entity::Cell(0, 1), Settings::new().text("World"));
+    ///     grid.set_fixed_widths(Some(vec![3, 7]));
+    ///     assert_eq!(grid.to_string(), "+---+-------+\n|Hel|World  |\n+---+-------+\n");
+    /// ```
+    pub fn set_fixed_widths(&mut self, widths: Option<Vec<usize>>) {
+        self.fixed_widths = widths;
+    }
+
+    /// Returns widths of columns set by [Grid::set_fixed_widths].
+    pub fn get_fixed_widths(&self) -> Option<&[usize]> {
+        self.fixed_widths.as_deref()
+    }
+
+    /// Sets whether rows which have only empty cells are rendered.
+    ///
+    /// The rows are not removed,
+    /// so they are rendered again once they get a content or the flag is turned off.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    ///     use papergrid::{Entity, Grid, Settings, DEFAULT_CELL_STYLE};
+    ///     let mut grid = Grid::new(3, 1);
+    ///     grid.set_cell_borders(DEFAULT_CELL_STYLE.clone());
+    ///     grid.set(&Entity::Cell(0, 0), Settings::new().text("a"));
+    ///     grid.set(&Entity::Cell(2, 0), Settings::new().text("b"));
+    ///     grid.hide_empty_rows(true);
+    ///     assert_eq!(grid.to_string(), "+-+\n|a|\n+-+\n|b|\n+-+\n");
+    /// ```
+    pub fn hide_empty_rows(&mut self, hide: bool) {
+        self.hide_empty_rows = hide;
+    }
+
+    /// Sets whether columns which have only empty cells are rendered.
+    ///
+    /// See [Grid::hide_empty_rows].
+    pub fn hide_empty_columns(&mut self, hide: bool) {
+        self.hide_empty_columns = hide;
+    }
+
+    /// Returns whether rows with only empty cells are not rendered.
+    pub fn is_empty_rows_hidden(&self) -> bool {
+        self.hide_empty_rows
+    }
+
+    /// Returns whether columns with only empty cells are not rendered.
+    pub fn is_empty_columns_hidden(&self) -> bool {
+        self.hide_empty_columns
+    }
+
+    /// Returns an amount of rows at the top of the grid which make a header.
+    ///
+    /// It's never greater than an amount of rows.
+    pub fn count_header_rows(&self) -> usize {
+        std::cmp::min(self.header_rows, self.count_rows())
+    }
+
+    /// Sets a string which is rendered in place of inner vertical borders.
+    ///
+    /// The string may be wider than one character e.g. `" | "`.
+    /// On horizontal split lines whitespace characters of the string
+    /// are replaced by a line character and others by an intersection character.
+    ///
+    /// It affects only vertical borders which are present.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    ///     use papergrid::{Grid, Entity, Settings, DEFAULT_CELL_STYLE};
+    ///     let mut grid = Grid::new(1, 2);
+    ///     grid.set_cell_borders(DEFAULT_CELL_STYLE.clone());
+    ///     grid.set(&Entity::Cell(0, 0), Settings::new().text("a"));
+    ///     grid.set(&Entity::Cell(0, 1), Settings::new().text("b"));
+    ///     grid.set_inner_vertical_separator(" | ");
+    ///     assert_eq!(
+    ///          grid.to_string(),
+    ///          "+--+--+\n\
+    ///           |a | b|\n\
+    ///           +--+--+\n"
+    ///     )
+    /// ```
+    pub fn set_inner_vertical_separator<S: Into<String>>(&mut self, separator: S) {
+        self.inner_vertical_separator = Some(separator.into());
+    }
+
+    /// Returns a string set by [Grid::set_inner_vertical_separator].
+    pub fn get_inner_vertical_separator(&self) -> Option<&str> {
+        self.inner_vertical_separator.as_deref()
+    }
+
+    /// Removes a string set by [Grid::set_inner_vertical_separator].
+    pub fn clear_inner_vertical_separator(&mut self) {
+        self.inner_vertical_separator = None;
+    }
+
+    /// Copies settings which are not bound to cells, rows or columns from another grid.
+    ///
+    /// It's supposed to be used when a grid is rebuilt from cells of another one.
+    /// It copies an inner vertical separator and border glyphs,
+    /// as well as a tab width, a trim setting, a center rounding
+    /// and whether empty rows and columns are hidden.
+    pub fn copy_grid_settings(&mut self, other: &Grid) {
+        self.inner_vertical_separator = other.inner_vertical_separator.clone();
+        self.border_glyphs = other.border_glyphs.clone();
+        self.tab_width = other.tab_width;
+        self.trim = other.trim;
+        self.center_rounding = other.center_rounding;
+        self.hide_empty_rows = other.hide_empty_rows;
+        self.hide_empty_columns = other.hide_empty_columns;
+    }
+
+    /// Sets a string which is rendered in place of a border char.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    ///     use papergrid::{BorderGlyph, Grid, Entity, Settings, DEFAULT_CELL_STYLE};
+    ///     let mut grid = Grid::new(1, 1);
+    ///     grid.set_cell_borders(DEFAULT_CELL_STYLE.clone());
+    ///     grid.set(&Entity::Cell(0, 0), Settings::new().text("a"));
+    ///     grid.set_border_glyph(BorderGlyph::new('+', "*").unwrap());
+    ///     assert_eq!(grid.to_string(), "*-*\n|a|\n*-*\n");
+    /// ```
+    #[cfg(feature = "border-glyphs")]
+    pub fn set_border_glyph(&mut self, glyph: BorderGlyph) {
+        self.border_glyphs.insert(glyph.c, glyph.glyph);
+    }
+
+    /// Returns a string set for a border char by [Grid::set_border_glyph].
+    #[cfg(feature = "border-glyphs")]
+    pub fn get_border_glyph(&self, c: char) -> Option<&str> {
+        self.border_glyphs.get(&c).map(String::as_str)
+    }
+
+    /// Removes all strings set by [Grid::set_border_glyph].
+    #[cfg(feature = "border-glyphs")]
+    pub fn clear_border_glyphs(&mut self) {
+        self.border_glyphs.clear();
+    }
+
+    /// Applies all borders which were set as overrides once again.
+    ///
+    /// It may be used to keep custom borders after the whole grid was re-styled.
+    /// See [Settings::border_override].
+    pub fn restore_border_overrides(&mut self) {
+        let overrides = self.border_overrides.clone();
+        for (entity, border) in overrides {
+            let frame = self.frame_from_entity(&entity);
+            self.add_split_lines_for_border(&frame, &border);
+            self.set_border(&frame, border);
+        }
+    }
+
+    /// Forgets all borders which were set as overrides.
+    ///
+    /// It doesn't change the current borders.
+    pub fn clear_border_overrides(&mut self) {
+        self.border_overrides.clear();
+    }
+
     fn set_border(&mut self, frame: &EntityFrame, border: Border) {
         if let Some(top) = border.top {
             for column in frame.left_column..frame.right_column {
@@ -291,11 +759,20 @@ impl Grid {
         let content = &self.cells[row][column];
         let border = self.borders.get_border(row, column).unwrap();
 
-        Settings::default()
-            .text(content)
+        let settings = match self.origins[row][column] {
+            Some((origin_row, origin_column)) => Settings::new().origin(origin_row, origin_column),
+            None => Settings::new(),
+        };
+
+        settings
+            .text(content.as_str())
             .alignment(style.alignment_h)
             .vertical_alignment(style.alignment_v)
             .span(style.span)
+            .row_span(style.row_span)
+            .span_alignment(style.span_alignment)
+            .min_width(style.min_width)
+            .min_height(style.min_height)
             .indent(
                 style.indent.left,
                 style.indent.right,
@@ -303,9 +780,32 @@ impl Grid {
                 style.indent.bottom,
             )
             .border(border)
+            .fill(style.fill)
+            .fill_left(style.fill_left.clone().unwrap_or_default())
+            .fill_right(style.fill_right.clone().unwrap_or_default())
+            .attributes(style.attributes.clone())
+            .tags(style.tags.clone())
+    }
+
+    /// get_origin returns an original position of a cell set by [Settings::origin].
+    pub fn get_origin(&self, row: usize, column: usize) -> Option<(usize, usize)> {
+        self.origins[row][column]
+    }
+
+    /// get_tags returns a list of tags of a cell.
+    pub fn get_tags(&self, row: usize, column: usize) -> &[String] {
+        &self.style(&Entity::Cell(row, column)).tags
     }
 
-    pub fn get_border(&mut self, row: usize, column: usize) -> Border {
+    /// get_attributes returns a list of attributes of a cell.
+    ///
+    /// Attributes are not used on rendering.
+    /// They're a metadata which may be used by other renderers.
+    pub fn get_attributes(&self, row: usize, column: usize) -> &[(String, String)] {
+        &self.style(&Entity::Cell(row, column)).attributes
+    }
+
+    pub fn get_border(&self, row: usize, column: usize) -> Border {
         self.borders.get_border(row, column).unwrap()
     }
 
@@ -359,7 +859,7 @@ impl Grid {
     }
 
     pub fn set_text<S: Into<String>>(&mut self, entity: &Entity, text: S) {
-        let text = text.into();
+        let text = CellText::from(text.into());
         match *entity {
             Entity::Cell(row, column) => {
                 self.cells[row][column] = text;
@@ -423,50 +923,822 @@ impl Grid {
             }
         }
 
+        new_grid.copy_grid_settings(self);
+
         new_grid
     }
 
-    pub fn override_split_line(&mut self, row: usize, line: impl Into<String>) {
-        self.override_split_lines.insert(row, line.into());
-    }
+    /// Builds a grid out of given rows and columns.
+    ///
+    /// Settings of the rows and columns are kept,
+    /// spans are cut to the rows and columns which are kept.
+    /// A top and a bottom split lines are kept while others go along with rows.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    ///     use papergrid::{Grid, Entity, Settings, DEFAULT_CELL_STYLE};
+    ///     let mut grid = Grid::new(3, 2);
+    ///     grid.set_cell_borders(DEFAULT_CELL_STYLE.clone());
+    ///     grid.set(&Entity::Global, Settings::new().text("a"));
+    ///     grid.set(&Entity::Cell(0, 0), Settings::new().row_span(3).text("b"));
+    ///     grid.set_row_spacing(2, 1);
+    ///
+    ///     let grid = grid.extract_lines(&[0, 2], &[0, 1]);
+    ///     assert_eq!(grid.to_string(), "+-+-+\n|b|a|\n| +-+\n| |a|\n| | |\n+-+-+\n");
+    /// ```
+    pub fn extract_lines(&self, rows: &[usize], columns: &[usize]) -> Self {
+        let (count_rows, count_columns) = (self.count_rows(), self.count_columns());
+        let mut kept_rows = vec![false; count_rows];
+        rows.iter().for_each(|&row| kept_rows[row] = true);
+        let mut kept_columns = vec![false; count_columns];
+        columns
+            .iter()
+            .for_each(|&column| kept_columns[column] = true);
+
+        let count_kept = |kept: &[bool], start: usize, span: usize| {
+            let end = min(start + span, kept.len());
+            kept[start..end].iter().filter(|&&k| k).count()
+        };
 
-    fn add_split_lines_for_border(&mut self, frame: &EntityFrame, border: &Border) {
-        if border.left.is_some() && !self.is_vertical_present(frame.left_column) {
-            self.add_vertical_split(frame.left_column)
+        let mut grid = Grid::new(rows.len(), columns.len());
+        for (new_row, &row) in rows.iter().enumerate() {
+            for (new_column, &column) in columns.iter().enumerate() {
+                let style = self.style(&Entity::Cell(row, column));
+                let mut settings = self.get_settings(row, column).border_restriction(false);
+                if style.span > 1 {
+                    settings = settings.span(count_kept(&kept_columns, column, style.span));
+                }
+
+                if style.row_span > 1 {
+                    settings = settings.row_span(count_kept(&kept_rows, row, style.row_span));
+                }
+
+                grid.set(&Entity::Cell(new_row, new_column), settings);
+            }
         }
 
-        if border.right.is_some() && !self.is_vertical_present(frame.right_column) {
-            self.add_vertical_split(frame.right_column)
+        grid.copy_grid_settings(self);
+        grid.header_rows = count_kept(&kept_rows, 0, self.header_rows);
+        grid.fixed_widths = self.fixed_widths.as_ref().map(|widths| {
+            columns
+                .iter()
+                .filter_map(|&c| widths.get(c).copied())
+                .collect()
+        });
+
+        for (new_row, &row) in rows.iter().enumerate() {
+            if let Some(&spacing) = self.row_spacing.get(&row) {
+                grid.row_spacing.insert(new_row, spacing);
+            }
         }
 
-        if border.top.is_some() && !self.is_horizontal_present(frame.top_row) {
-            self.add_horizontal_split(frame.top_row)
+        for (new_column, &column) in columns.iter().enumerate() {
+            if let Some(&width) = self.column_min_widths.get(&column) {
+                grid.column_min_widths.insert(new_column, width);
+            }
         }
 
-        if border.bottom.is_some() && !self.is_horizontal_present(frame.bottom_row) {
-            self.add_horizontal_split(frame.bottom_row)
+        // a top and a bottom lines are kept while others go along with rows,
+        // the top line wins over a line of a first row which becomes the top one
+        let mut override_lines = self.override_split_lines.iter().collect::<Vec<_>>();
+        override_lines.sort_by_key(|(&line, _)| line);
+        for (&line, text) in override_lines {
+            let new_line = if line == 0 {
+                Some(0)
+            } else if line == count_rows {
+                Some(rows.len())
+            } else {
+                rows.iter().position(|&row| row == line)
+            };
+
+            if let Some(new_line) = new_line {
+                grid.override_split_lines
+                    .entry(new_line)
+                    .or_insert_with(|| text.clone());
+            }
         }
+
+        grid
     }
 
-    fn collect_cells(&self, count_rows: usize, count_columns: usize) -> Vec<Vec<Vec<&str>>> {
-        let mut rows = Vec::with_capacity(count_rows);
-        (0..count_rows).for_each(|row_index| {
-            let mut row = Vec::with_capacity(count_columns);
-            (0..count_columns).for_each(|column_index| {
-                let content = &self.cells[row_index][column_index];
-                // fixme: I guess it can be done in a different place?
-                let cell: Vec<_> = content.lines().collect();
-                row.push(cell);
-            });
+    /// Overrides a horizontal split line with a text starting from its left edge.
+    ///
+    /// The text replaces border characters but not contents of cells spanned through the line.
+    /// It's cut if it's longer than the line.
+    pub fn override_split_line(&mut self, row: usize, line: impl Into<String>) {
+        self.override_split_line_aligned(row, line, AlignmentHorizontal::Left);
+    }
 
-            rows.push(row);
-        });
+    /// Overrides a horizontal split line with a text aligned on the line.
+    ///
+    /// The text is placed by its display width,
+    /// a wide character which doesn't fit into the line is left out.
+    ///
+    /// ```rust
+    /// use papergrid::{AlignmentHorizontal, Entity, Grid, Settings, DEFAULT_CELL_STYLE};
+    ///
+    /// let mut grid = Grid::new(1, 2);
+    /// grid.set_cell_borders(DEFAULT_CELL_STYLE.clone());
+    /// grid.set(&Entity::Global, Settings::new().text("cell"));
+    /// grid.override_split_line_aligned(0, "表格", AlignmentHorizontal::Center);
+    ///
+    /// assert_eq!(
+    ///     grid.to_string(),
+    ///     "+--表格---+\n\
+    ///      |cell|cell|\n\
+    ///      +----+----+\n"
+    /// );
+    /// ```
+    pub fn override_split_line_aligned(
+        &mut self,
+        row: usize,
+        line: impl Into<String>,
+        alignment: AlignmentHorizontal,
+    ) {
+        let line = SplitLineOverride {
+            text: line.into(),
+            alignment,
+        };
 
-        rows
+        self.override_split_lines.insert(row, line);
     }
 
-    fn collect_styles(&self, count_rows: usize, count_columns: usize) -> Vec<Vec<Style>> {
-        let mut rows = Vec::with_capacity(count_rows);
+    /// Inserts an empty row before a given one.
+    ///
+    /// A row is added after the last one if the index is equal to an amount of rows.
+    /// Styles, split lines and other settings of rows are shifted
+    /// and a span going through the position is extended.
+    /// The new row has no borders, they may be set by [Settings::border].
+    ///
+    /// The method panics if the index is out of the grid.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    ///     use papergrid::{Grid, Entity, Settings, DEFAULT_CELL_STYLE};
+    ///     let mut grid = Grid::new(2, 1);
+    ///     grid.set_cell_borders(DEFAULT_CELL_STYLE.clone());
+    ///     grid.set(&Entity::Cell(0, 0), Settings::new().text("a"));
+    ///     grid.set(&Entity::Cell(1, 0), Settings::new().text("c"));
+    ///
+    ///     grid.insert_row(1);
+    ///     grid.set(
+    ///         &Entity::Cell(1, 0),
+    ///         Settings::new()
+    ///             .text("b")
+    ///             .border(DEFAULT_CELL_STYLE.clone())
+    ///             .border_restriction(false),
+    ///     );
+    ///
+    ///     assert_eq!(grid.to_string(), "+-+\n|a|\n+-+\n|b|\n+-+\n|c|\n+-+\n");
+    /// ```
+    pub fn insert_row(&mut self, row: usize) {
+        let count_rows = self.count_rows();
+        assert!(row <= count_rows, "row {} is out of the grid", row);
+
+        let count_columns = self.count_columns();
+        self.cells.insert(row, vec![CellText::new(); count_columns]);
+        self.origins.insert(row, vec![None; count_columns]);
+
+        for (entity, style) in self.styles.iter_mut() {
+            if let Entity::Cell(r, _) = *entity {
+                if r < row && r + style.row_span > row {
+                    style.row_span += 1;
+                }
+            }
+        }
+
+        let shift = |r: usize| Some(if r >= row { r + 1 } else { r });
+        self.styles = remap_entities(std::mem::take(&mut self.styles), shift, Some);
+        self.border_overrides = std::mem::take(&mut self.border_overrides)
+            .into_iter()
+            .filter_map(|(entity, border)| Some((map_entity(&entity, shift, Some)?, border)))
+            .collect();
+        self.row_spacing = remap_keys(std::mem::take(&mut self.row_spacing), shift);
+
+        // lines are kept in place, so the new row has no line below it
+        let missing_line = row + 1;
+        let shift_line = |line: usize| Some(if line >= missing_line { line + 1 } else { line });
+        self.override_split_lines =
+            remap_keys(std::mem::take(&mut self.override_split_lines), shift_line);
+        self.borders.insert_row(row, missing_line);
+
+        if row < self.header_rows {
+            self.header_rows += 1;
+        }
+
+        self.size.0 += 1;
+    }
+
+    /// Removes a row.
+    ///
+    /// Styles, split lines and other settings of rows are shifted
+    /// and a span going through the row is shortened.
+    /// A cell spanned from the row is moved to the next row it goes through.
+    /// A split line above the row is replaced by the one below it if there's any.
+    ///
+    /// The method panics if the index is out of the grid.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    ///     use papergrid::{Grid, Entity, Settings, DEFAULT_CELL_STYLE};
+    ///     let mut grid = Grid::new(2, 1);
+    ///     grid.set_cell_borders(DEFAULT_CELL_STYLE.clone());
+    ///     grid.set(&Entity::Cell(0, 0), Settings::new().text("a"));
+    ///     grid.set(&Entity::Cell(1, 0), Settings::new().text("b"));
+    ///
+    ///     grid.remove_row(0);
+    ///
+    ///     assert_eq!(grid.to_string(), "+-+\n|b|\n+-+\n");
+    /// ```
+    pub fn remove_row(&mut self, row: usize) {
+        let count_rows = self.count_rows();
+        assert!(row < count_rows, "row {} is out of the grid", row);
+
+        for column in 0..self.count_columns() {
+            let style = match self.styles.get_mut(&Entity::Cell(row, column)) {
+                Some(style) => style,
+                None => continue,
+            };
+
+            // a span may go beyond the grid so there may be no row to move it to
+            if style.row_span > 1 && row + 1 < count_rows {
+                let mut style = style.clone();
+                style.row_span -= 1;
+                self.styles.insert(Entity::Cell(row + 1, column), style);
+                self.cells[row + 1][column] = std::mem::take(&mut self.cells[row][column]);
+                self.origins[row + 1][column] = self.origins[row][column];
+            }
+        }
+
+        for (entity, style) in self.styles.iter_mut() {
+            if let Entity::Cell(r, _) = *entity {
+                if r < row && r + style.row_span > row {
+                    style.row_span -= 1;
+                }
+            }
+        }
+
+        self.cells.remove(row);
+        self.origins.remove(row);
+
+        let shift = |r: usize| match r.cmp(&row) {
+            Ordering::Less => Some(r),
+            Ordering::Equal => None,
+            Ordering::Greater => Some(r - 1),
+        };
+        self.styles = remap_entities(std::mem::take(&mut self.styles), shift, Some);
+        self.border_overrides = std::mem::take(&mut self.border_overrides)
+            .into_iter()
+            .filter_map(|(entity, border)| Some((map_entity(&entity, shift, Some)?, border)))
+            .collect();
+        self.row_spacing = remap_keys(std::mem::take(&mut self.row_spacing), shift);
+
+        // a top line is kept while others go along with rows
+        let removed_line = if row == 0 { 1 } else { row };
+        let shift_line = |line: usize| match line.cmp(&removed_line) {
+            Ordering::Less => Some(line),
+            Ordering::Equal => None,
+            Ordering::Greater => Some(line - 1),
+        };
+        self.override_split_lines =
+            remap_keys(std::mem::take(&mut self.override_split_lines), shift_line);
+        self.borders.remove_row(row);
+
+        if row < self.header_rows {
+            self.header_rows -= 1;
+        }
+
+        self.size.0 -= 1;
+    }
+
+    /// Inserts an empty column before a given one.
+    ///
+    /// A column is added after the last one if the index is equal to an amount of columns.
+    /// Styles, split lines and other settings of columns are shifted
+    /// and a span going through the position is extended.
+    /// The new column has no borders, they may be set by [Settings::border].
+    ///
+    /// The method panics if the index is out of the grid.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    ///     use papergrid::{Grid, Entity, Settings, DEFAULT_CELL_STYLE};
+    ///     let mut grid = Grid::new(1, 2);
+    ///     grid.set_cell_borders(DEFAULT_CELL_STYLE.clone());
+    ///     grid.set(&Entity::Cell(0, 0), Settings::new().text("a"));
+    ///     grid.set(&Entity::Cell(0, 1), Settings::new().text("c"));
+    ///
+    ///     grid.insert_column(1);
+    ///     grid.set(
+    ///         &Entity::Cell(0, 1),
+    ///         Settings::new()
+    ///             .text("b")
+    ///             .border(DEFAULT_CELL_STYLE.clone())
+    ///             .border_restriction(false),
+    ///     );
+    ///
+    ///     assert_eq!(grid.to_string(), "+-+-+-+\n|a|b|c|\n+-+-+-+\n");
+    /// ```
+    pub fn insert_column(&mut self, column: usize) {
+        let count_columns = self.count_columns();
+        assert!(
+            column <= count_columns,
+            "column {} is out of the grid",
+            column
+        );
+
+        for row in &mut self.cells {
+            row.insert(column, CellText::new());
+        }
+
+        for row in &mut self.origins {
+            row.insert(column, None);
+        }
+
+        for (entity, style) in self.styles.iter_mut() {
+            if let Entity::Cell(_, c) = *entity {
+                if c < column && c + style.span > column {
+                    style.span += 1;
+                }
+            }
+        }
+
+        let shift = |c: usize| Some(if c >= column { c + 1 } else { c });
+        self.styles = remap_entities(std::mem::take(&mut self.styles), Some, shift);
+        self.border_overrides = std::mem::take(&mut self.border_overrides)
+            .into_iter()
+            .filter_map(|(entity, border)| Some((map_entity(&entity, Some, shift)?, border)))
+            .collect();
+        self.column_min_widths = remap_keys(std::mem::take(&mut self.column_min_widths), shift);
+        if let Some(widths) = &mut self.fixed_widths {
+            if column <= widths.len() {
+                widths.insert(column, 0);
+            }
+        }
+
+        // lines are kept in place, so the new column has no line after it
+        let missing_line = column + 1;
+        self.borders.insert_column(column, missing_line);
+
+        self.size.1 += 1;
+    }
+
+    /// Removes a column.
+    ///
+    /// Styles, split lines and other settings of columns are shifted
+    /// and a span going through the column is shortened.
+    /// A cell spanned from the column is moved to the next column it goes through.
+    /// A split line before the column is replaced by the one after it if there's any.
+    ///
+    /// The method panics if the index is out of the grid.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    ///     use papergrid::{Grid, Entity, Settings, DEFAULT_CELL_STYLE};
+    ///     let mut grid = Grid::new(1, 2);
+    ///     grid.set_cell_borders(DEFAULT_CELL_STYLE.clone());
+    ///     grid.set(&Entity::Cell(0, 0), Settings::new().text("a"));
+    ///     grid.set(&Entity::Cell(0, 1), Settings::new().text("b"));
+    ///
+    ///     grid.remove_column(0);
+    ///
+    ///     assert_eq!(grid.to_string(), "+-+\n|b|\n+-+\n");
+    /// ```
+    pub fn remove_column(&mut self, column: usize) {
+        let count_columns = self.count_columns();
+        assert!(
+            column < count_columns,
+            "column {} is out of the grid",
+            column
+        );
+
+        for row in 0..self.count_rows() {
+            let style = match self.styles.get_mut(&Entity::Cell(row, column)) {
+                Some(style) => style,
+                None => continue,
+            };
+
+            // a span may go beyond the grid so there may be no column to move it to
+            if style.span > 1 && column + 1 < count_columns {
+                let mut style = style.clone();
+                style.span -= 1;
+                self.styles.insert(Entity::Cell(row, column + 1), style);
+                self.cells[row][column + 1] = std::mem::take(&mut self.cells[row][column]);
+                self.origins[row][column + 1] = self.origins[row][column];
+            }
+        }
+
+        for (entity, style) in self.styles.iter_mut() {
+            if let Entity::Cell(_, c) = *entity {
+                if c < column && c + style.span > column {
+                    style.span -= 1;
+                }
+            }
+        }
+
+        for row in &mut self.cells {
+            row.remove(column);
+        }
+
+        for row in &mut self.origins {
+            row.remove(column);
+        }
+
+        let shift = |c: usize| match c.cmp(&column) {
+            Ordering::Less => Some(c),
+            Ordering::Equal => None,
+            Ordering::Greater => Some(c - 1),
+        };
+        self.styles = remap_entities(std::mem::take(&mut self.styles), Some, shift);
+        self.border_overrides = std::mem::take(&mut self.border_overrides)
+            .into_iter()
+            .filter_map(|(entity, border)| Some((map_entity(&entity, Some, shift)?, border)))
+            .collect();
+        self.column_min_widths = remap_keys(std::mem::take(&mut self.column_min_widths), shift);
+        if let Some(widths) = &mut self.fixed_widths {
+            if column < widths.len() {
+                widths.remove(column);
+            }
+        }
+
+        self.borders.remove_column(column);
+
+        self.size.1 -= 1;
+    }
+
+    /// Visit walks through a grid layout and emits a [GridEvent] for each of its parts.
+    ///
+    /// It can be used to build an alternative backend
+    /// without re-implementing a layout logic.
+    /// Hidden empty rows and columns emit no events.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    ///     use papergrid::{Grid, GridEvent, Entity, Settings, DEFAULT_CELL_STYLE};
+    ///     let mut grid = Grid::new(1, 2);
+    ///     grid.set_cell_borders(DEFAULT_CELL_STYLE.clone());
+    ///     grid.set(&Entity::Cell(0, 0), Settings::new().text("Hello"));
+    ///     grid.set(&Entity::Cell(0, 1), Settings::new().text("World"));
+    ///
+    ///     let mut cells = Vec::new();
+    ///     grid.visit(&mut |event: GridEvent<'_>| {
+    ///         if let GridEvent::Cell { text, width, .. } = event {
+    ///             cells.push((text.to_owned(), width));
+    ///         }
+    ///     });
+    ///
+    ///     assert_eq!(cells, [("Hello".to_owned(), 5), ("World".to_owned(), 5)]);
+    /// ```
+    pub fn visit<V: GridVisitor>(&self, visitor: &mut V) {
+        let count_rows = self.count_rows();
+        self.prepare(|grid, prepared| {
+            // events are emitted with indexes of the grid, not of its visible part,
+            // so a line has an index of a visible row after it
+            let line_index = |line: usize| match line {
+                0 => 0,
+                line => prepared.rows.get(line).copied().unwrap_or(count_rows),
+            };
+
+            for row in 0..grid.count_rows() {
+                if row == 0 && grid.is_split_line_visible(row) {
+                    visitor.visit(GridEvent::SplitLine { row: 0 });
+                }
+
+                visitor.visit(GridEvent::BeginRow {
+                    row: prepared.rows[row],
+                    height: prepared.heights[row],
+                });
+
+                for column in 0..grid.count_columns() {
+                    let is_covered = prepared.origins[row][column].is_some();
+                    if is_covered || !is_cell_visible(&prepared.styles[row], column) {
+                        continue;
+                    }
+
+                    visitor.visit(GridEvent::Cell {
+                        row: prepared.rows[row],
+                        column: prepared.columns[column],
+                        text: grid.get_cell_content(row, column),
+                        width: prepared.widths[row][column],
+                        style: &prepared.styles[row][column],
+                    });
+                }
+
+                visitor.visit(GridEvent::EndRow {
+                    row: prepared.rows[row],
+                });
+
+                if grid.is_split_line_visible(row + 1) {
+                    visitor.visit(GridEvent::SplitLine {
+                        row: line_index(row + 1),
+                    });
+                }
+            }
+        });
+    }
+
+    /// Returns widths of columns on a rendered grid.
+    ///
+    /// A width includes an indent of a cell but not its borders.
+    /// A column which consists only of spanned cells or which is hidden has 0 width.
+    pub fn columns_widths(&self) -> Vec<usize> {
+        let count_rows = self.count_rows();
+        let count_columns = self.count_columns();
+        if count_rows == 0 || count_columns == 0 {
+            return Vec::new();
+        }
+
+        let mut columns_widths = vec![0; count_columns];
+        self.prepare(|grid, prepared| {
+            for column in 0..grid.count_columns() {
+                columns_widths[prepared.columns[column]] = (0..grid.count_rows())
+                    .filter(|&row| prepared.styles[row][column].span == 1)
+                    .map(|row| prepared.widths[row][column])
+                    .max()
+                    .unwrap_or(0);
+            }
+        });
+
+        columns_widths
+    }
+
+    /// Layout returns an area of each visible cell on a rendered grid.
+    ///
+    /// Coordinates are measured in terminal columns and lines
+    /// from a top left corner of the grid.
+    /// Areas include an indent of a cell but not its borders.
+    /// Cells of hidden empty rows and columns have no area.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    ///     use papergrid::{Grid, Entity, Settings, DEFAULT_CELL_STYLE};
+    ///     let mut grid = Grid::new(1, 2);
+    ///     grid.set_cell_borders(DEFAULT_CELL_STYLE.clone());
+    ///     grid.set(&Entity::Cell(0, 0), Settings::new().text("Hello"));
+    ///     grid.set(&Entity::Cell(0, 1), Settings::new().text("World"));
+    ///
+    ///     let layout = grid.layout();
+    ///     let cell = layout.iter().find(|cell| cell.contains(8, 1)).unwrap();
+    ///     assert_eq!((cell.row, cell.column), (0, 1));
+    /// ```
+    pub fn layout(&self) -> Vec<CellRect> {
+        let rects = self.prepare(|grid, prepared| {
+            let positions = rows_position(&prepared.heights, &prepared.split_lines);
+
+            let mut rects = Vec::new();
+            for row in 0..grid.count_rows() {
+                let mut x = 0;
+                for (column, border) in prepared.split_borders[row].iter().enumerate() {
+                    if !is_cell_visible(&prepared.styles[row], column) {
+                        continue;
+                    }
+
+                    x += connector_width(border);
+
+                    // a cell covered by a row span is a part of a cell above
+                    if prepared.origins[row][column].is_none() {
+                        let last_row = row + prepared.styles[row][column].row_span - 1;
+                        let height =
+                            positions[last_row] + prepared.heights[last_row] - positions[row];
+
+                        rects.push(CellRect {
+                            row: prepared.rows[row],
+                            column: prepared.columns[column],
+                            x,
+                            y: positions[row],
+                            width: prepared.widths[row][column],
+                            height,
+                        });
+                    }
+
+                    x += prepared.widths[row][column];
+                }
+            }
+
+            rects
+        });
+
+        rects.unwrap_or_default()
+    }
+
+    // measures a grid the way it's rendered, so with empty rows and columns hidden if set;
+    // the measured grid is passed to a given function along with the measurements
+    fn prepare<T>(&self, f: impl FnOnce(&Grid, Prepared<'_>) -> T) -> Option<T> {
+        match self.without_hidden_lines() {
+            Some((grid, rows, columns)) => grid.prepare_visible(rows, columns, f),
+            None => {
+                let rows = (0..self.count_rows()).collect();
+                let columns = (0..self.count_columns()).collect();
+                self.prepare_visible(rows, columns, f)
+            }
+        }
+    }
+
+    fn prepare_visible<T>(
+        &self,
+        rows: Vec<usize>,
+        columns: Vec<usize>,
+        f: impl FnOnce(&Grid, Prepared<'_>) -> T,
+    ) -> Option<T> {
+        let count_rows = self.count_rows();
+        let count_columns = self.count_columns();
+
+        // It may happen when all cells removed via `remove_row`, `remove_column` methods
+        if count_rows == 0 || count_columns == 0 {
+            return None;
+        }
+
+        let contents = self.collect_contents();
+        let mut cells = Self::collect_cells(&contents, count_rows, count_columns);
+        let mut styles = self.collect_styles(count_rows, count_columns);
+        let origins = prepare_row_spans(&mut cells, &mut styles, count_rows, count_columns);
+
+        let split_borders = (0..count_rows)
+            .map(|row| self.get_inner_split_line(row))
+            .collect::<Vec<_>>();
+        let split_lines = self.split_lines_visibility();
+
+        let heights = rows_height(
+            &cells,
+            &styles,
+            &self.row_spacing,
+            &split_lines,
+            count_rows,
+            count_columns,
+        );
+        let widths = self.cells_width(
+            &mut cells,
+            &mut styles,
+            &split_borders,
+            count_rows,
+            count_columns,
+        );
+
+        let prepared = Prepared {
+            rows,
+            columns,
+            cells,
+            styles,
+            origins,
+            split_borders,
+            split_lines,
+            heights,
+            widths,
+        };
+
+        Some(f(self, prepared))
+    }
+
+    fn is_split_line_visible(&self, row: usize) -> bool {
+        self.get_split_line(row).iter().any(|l| l.main.is_some())
+    }
+
+    fn split_lines_visibility(&self) -> Vec<bool> {
+        (0..=self.count_rows())
+            .map(|row| self.is_split_line_visible(row))
+            .collect()
+    }
+
+    fn add_split_lines_for_border(&mut self, frame: &EntityFrame, border: &Border) {
+        if border.left.is_some() && !self.is_vertical_present(frame.left_column) {
+            self.add_vertical_split(frame.left_column)
+        }
+
+        if border.right.is_some() && !self.is_vertical_present(frame.right_column) {
+            self.add_vertical_split(frame.right_column)
+        }
+
+        if border.top.is_some() && !self.is_horizontal_present(frame.top_row) {
+            self.add_horizontal_split(frame.top_row)
+        }
+
+        if border.bottom.is_some() && !self.is_horizontal_present(frame.bottom_row) {
+            self.add_horizontal_split(frame.bottom_row)
+        }
+    }
+
+    /// Returns contents of cells with tabs replaced by spaces.
+    // widths of cells are either measured or taken from fixed widths of columns
+    fn cells_width(
+        &self,
+        cells: &mut [Vec<Vec<&str>>],
+        styles: &mut [Vec<Style>],
+        borders: &[Vec<BorderLine>],
+        count_rows: usize,
+        count_columns: usize,
+    ) -> Vec<Vec<usize>> {
+        match &self.fixed_widths {
+            Some(fixed) => fixed_columns_width(cells, styles, borders, fixed, count_rows),
+            None => columns_width(
+                cells,
+                styles,
+                borders,
+                &self.column_min_widths,
+                count_rows,
+                count_columns,
+            ),
+        }
+    }
+
+    fn collect_contents(&self) -> Vec<Vec<Cow<'_, str>>> {
+        let tab = " ".repeat(self.tab_width);
+        self.cells
+            .iter()
+            .map(|row| {
+                row.iter()
+                    .map(|content| {
+                        if content.contains('\t') {
+                            Cow::Owned(content.replace('\t', &tab))
+                        } else {
+                            Cow::Borrowed(content.as_str())
+                        }
+                    })
+                    .collect()
+            })
+            .collect()
+    }
+
+    fn collect_cells<'a>(
+        contents: &'a [Vec<Cow<'a, str>>],
+        count_rows: usize,
+        count_columns: usize,
+    ) -> Vec<Vec<Vec<&'a str>>> {
+        let mut rows = Vec::with_capacity(count_rows);
+        (0..count_rows).for_each(|row_index| {
+            let mut row = Vec::with_capacity(count_columns);
+            (0..count_columns).for_each(|column_index| {
+                let content = &contents[row_index][column_index];
+                // fixme: I guess it can be done in a different place?
+                let cell: Vec<_> = content.lines().collect();
+                row.push(cell);
+            });
+
+            rows.push(row);
+        });
+
+        rows
+    }
+
+    // returns a copy of the grid without empty rows and columns in case they must be hidden
+    // together with indexes of rows and columns which are kept
+    fn without_hidden_lines(&self) -> Option<(Grid, Vec<usize>, Vec<usize>)> {
+        if !self.hide_empty_rows && !self.hide_empty_columns {
+            return None;
+        }
+
+        let (count_rows, count_columns) = (self.count_rows(), self.count_columns());
+        let styles = self.collect_styles(count_rows, count_columns);
+
+        // a spanned cell with a content fills all rows and columns it goes through
+        let mut filled_rows = vec![!self.hide_empty_rows; count_rows];
+        let mut filled_columns = vec![!self.hide_empty_columns; count_columns];
+        let mut covered = vec![vec![false; count_columns]; count_rows];
+        for row in 0..count_rows {
+            for column in 0..count_columns {
+                if covered[row][column] || !is_cell_visible(&styles[row], column) {
+                    continue;
+                }
+
+                let style = &styles[row][column];
+                let end_row = min(row + max(style.row_span, 1), count_rows);
+                let end_column = min(column + max(style.span, 1), count_columns);
+                for covered_row in &mut covered[row..end_row] {
+                    covered_row[column..end_column].fill(true);
+                }
+
+                if !self.cells[row][column].is_empty() {
+                    filled_rows[row..end_row].fill(true);
+                    filled_columns[column..end_column].fill(true);
+                }
+            }
+        }
+
+        if filled_rows.iter().all(|&f| f) && filled_columns.iter().all(|&f| f) {
+            return None;
+        }
+
+        let rows = (0..count_rows)
+            .filter(|&row| filled_rows[row])
+            .collect::<Vec<_>>();
+        let columns = (0..count_columns)
+            .filter(|&column| filled_columns[column])
+            .collect::<Vec<_>>();
+        let mut grid = self.extract_lines(&rows, &columns);
+        grid.hide_empty_rows = false;
+        grid.hide_empty_columns = false;
+
+        Some((grid, rows, columns))
+    }
+
+    fn collect_styles(&self, count_rows: usize, count_columns: usize) -> Vec<Vec<Style>> {
+        let mut rows = Vec::with_capacity(count_rows);
         (0..count_rows).for_each(|row_index| {
             let mut row = Vec::with_capacity(count_columns);
             (0..count_columns).for_each(|column_index| {
@@ -485,11 +1757,30 @@ impl Grid {
     }
 
     fn get_split_line(&self, index: usize) -> Vec<BorderLine> {
-        self.borders.get_row(index).unwrap()
+        let mut line = self.borders.get_row(index).unwrap();
+        if let Some(separator) = &self.inner_vertical_separator {
+            for border in line.iter_mut().skip(1) {
+                if let Some(intersection) = border.connector1 {
+                    let main = border.main.unwrap_or(DEFAULT_SPLIT_BORDER_CHAR);
+                    border.separator = Some(separator_split_line(separator, main, intersection));
+                }
+            }
+        }
+
+        line
     }
 
     fn get_inner_split_line(&self, index: usize) -> Vec<BorderLine> {
-        self.borders.get_inner_row(index).unwrap()
+        let mut line = self.borders.get_inner_row(index).unwrap();
+        if let Some(separator) = &self.inner_vertical_separator {
+            for border in line.iter_mut().skip(1) {
+                if border.connector1.is_some() {
+                    border.separator = Some(separator.clone());
+                }
+            }
+        }
+
+        line
     }
 }
 
@@ -596,11 +1887,109 @@ impl Border {
     }
 }
 
+/// BorderGlyph is a string which is rendered in place of a border char.
+///
+/// It allows to use graphemes which consist of a few code points
+/// or border pieces styled by ANSI escape sequences.
+/// A glyph must have the same width as the char it replaces.
+#[cfg(feature = "border-glyphs")]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BorderGlyph {
+    c: char,
+    glyph: String,
+}
+
+#[cfg(feature = "border-glyphs")]
+impl BorderGlyph {
+    /// Creates a glyph for a border char.
+    ///
+    /// ANSI escape sequences are not counted in a width only with a `color` feature on.
+    pub fn new<S: Into<String>>(c: char, glyph: S) -> Result<Self, BorderGlyphError> {
+        let glyph = glyph.into();
+        let width = string_width(&glyph);
+        let expected = char_width(c);
+        if width != expected {
+            return Err(BorderGlyphError { c, width, expected });
+        }
+
+        Ok(Self { c, glyph })
+    }
+
+    /// Returns a char which is replaced by the glyph.
+    pub fn char(&self) -> char {
+        self.c
+    }
+
+    /// Returns a string of the glyph.
+    pub fn glyph(&self) -> &str {
+        &self.glyph
+    }
+}
+
+/// BorderGlyphError is returned by [BorderGlyph::new] when a glyph has a different width than its char.
+#[cfg(feature = "border-glyphs")]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BorderGlyphError {
+    /// A border char.
+    pub c: char,
+    /// A width of the glyph.
+    pub width: usize,
+    /// A width of the char.
+    pub expected: usize,
+}
+
+#[cfg(feature = "border-glyphs")]
+impl fmt::Display for BorderGlyphError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "a glyph for {:?} has width {} but expected {}",
+            self.c, self.width, self.expected
+        )
+    }
+}
+
+#[cfg(feature = "border-glyphs")]
+impl std::error::Error for BorderGlyphError {}
+
 #[derive(Debug, Default, Clone)]
 struct BorderLine {
     main: Option<char>,
     connector1: Option<char>,
     connector2: Option<char>,
+    // a string which is rendered in place of connector1
+    separator: Option<String>,
+}
+
+fn connector_width(border: &BorderLine) -> usize {
+    match &border.separator {
+        Some(separator) => string_width(separator),
+        None => border.connector1.map_or(0, char_width),
+    }
+}
+
+fn separator_split_line(separator: &str, main: char, intersection: char) -> String {
+    let mut line = String::new();
+    for c in separator.chars() {
+        let width = char_width(c);
+        if c.is_whitespace() {
+            line.push_str(&main.to_string().repeat(width));
+        } else {
+            line.push(intersection);
+            line.push_str(&main.to_string().repeat(width.saturating_sub(1)));
+        }
+    }
+
+    line
+}
+
+/// Split represents a split line on the grid.
+#[derive(PartialEq, Eq, Debug, Hash, Clone, Copy, PartialOrd, Ord)]
+pub enum Split {
+    /// A horizontal line placed before a row with a given index.
+    Horizontal(usize),
+    /// A vertical line placed before a column with a given index.
+    Vertical(usize),
 }
 
 /// Entity a structure which represent a set of cells.
@@ -616,6 +2005,63 @@ pub enum Entity {
     Cell(usize, usize),
 }
 
+/// CellRect is an area of a cell on a rendered grid returned by [Grid::layout].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CellRect {
+    pub row: usize,
+    pub column: usize,
+    pub x: usize,
+    pub y: usize,
+    pub width: usize,
+    pub height: usize,
+}
+
+impl CellRect {
+    /// Checks whether a point is inside the area.
+    pub fn contains(&self, x: usize, y: usize) -> bool {
+        x >= self.x && x < self.x + self.width && y >= self.y && y < self.y + self.height
+    }
+}
+
+/// GridEvent is a part of a grid layout emitted by [Grid::visit].
+#[derive(Debug)]
+pub enum GridEvent<'a> {
+    /// A horizontal split line placed before a row with a given index.
+    ///
+    /// A line after the last row has an index equal to an amount of rows.
+    SplitLine { row: usize },
+    /// A beginning of a row.
+    BeginRow { row: usize, height: usize },
+    /// A visible cell of a row.
+    ///
+    /// Cells which are overlapped by a span of other cells are not emitted.
+    /// The width includes an indent and in case of a span the width of inner split lines.
+    Cell {
+        row: usize,
+        column: usize,
+        text: &'a str,
+        width: usize,
+        style: &'a Style,
+    },
+    /// An end of a row.
+    EndRow { row: usize },
+}
+
+/// GridVisitor is a consumer of [GridEvent]s.
+pub trait GridVisitor {
+    /// The function handles a next event of a grid layout.
+    fn visit(&mut self, event: GridEvent<'_>);
+}
+
+impl<F> GridVisitor for F
+where
+    F: FnMut(GridEvent<'_>),
+{
+    fn visit(&mut self, event: GridEvent<'_>) {
+        (self)(event)
+    }
+}
+
 #[derive(PartialEq, Eq, Debug, Hash, Clone)]
 struct EntityFrame {
     left_column: usize,
@@ -657,6 +2103,18 @@ pub struct Style {
     pub alignment_h: AlignmentHorizontal,
     pub alignment_v: AlignmentVertical,
     pub span: usize,
+    pub row_span: usize,
+    /// A region a content of a cell spanned over a few rows is vertically aligned in.
+    pub span_alignment: SpanAlignment,
+    pub min_width: usize,
+    pub min_height: usize,
+    pub fill: char,
+    /// A pattern which fills a space to the left of a content instead of [Style::fill].
+    pub fill_left: Option<String>,
+    /// A pattern which fills a space to the right of a content instead of [Style::fill].
+    pub fill_right: Option<String>,
+    pub attributes: Vec<(String, String)>,
+    pub tags: Vec<String>,
 }
 
 impl Default for Style {
@@ -671,6 +2129,15 @@ impl Default for Style {
                 top: 0,
             },
             span: 1,
+            row_span: 1,
+            span_alignment: SpanAlignment::Merged,
+            min_width: 0,
+            min_height: 0,
+            fill: ' ',
+            fill_left: None,
+            fill_right: None,
+            attributes: Vec::new(),
+            tags: Vec::new(),
         }
     }
 }
@@ -692,37 +2159,68 @@ pub enum AlignmentHorizontal {
 }
 
 impl AlignmentHorizontal {
-    fn align(&self, f: &mut std::fmt::Formatter<'_>, text: &str, width: usize) -> fmt::Result {
+    fn align(
+        &self,
+        f: &mut dyn fmt::Write,
+        text: &str,
+        width: usize,
+        fill: char,
+        extra_left: bool,
+    ) -> fmt::Result {
         // it's important step
         // we are ignoring trailing spaces which allows us to do alignment with more space
         // example: tests::grid_2x2_alignment_test
         let text = text.trim();
         let text_width = string_width(text);
-        let diff = width - text_width;
+        let (left, right) = self.spaces(width - text_width, extra_left);
+
+        repeat_char(f, fill, left)?;
+        f.write_str(text)?;
+        repeat_char(f, fill, right)
+    }
+
+    // splits a free space of a cell into a left and a right part
+    fn spaces(&self, diff: usize, extra_left: bool) -> (usize, usize) {
         match self {
-            AlignmentHorizontal::Left => {
-                write!(f, "{text}{: <1$}", "", diff, text = text)
-            }
-            AlignmentHorizontal::Right => {
-                write!(f, "{: <1$}{text}", "", diff, text = text)
-            }
-            AlignmentHorizontal::Center => {
-                let left = diff / 2;
-                let right = diff - left;
-                write!(
-                    f,
-                    "{: <left$}{text}{: <right$}",
-                    "",
-                    "",
-                    left = left,
-                    right = right,
-                    text = text
-                )
-            }
+            AlignmentHorizontal::Left => (0, diff),
+            AlignmentHorizontal::Right => (diff, 0),
+            AlignmentHorizontal::Center if extra_left => (diff - diff / 2, diff / 2),
+            AlignmentHorizontal::Center => (diff / 2, diff - diff / 2),
+        }
+    }
+}
+
+/// CenterRounding sets a side which gets an extra space of a centered line
+/// when its free space is odd.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum CenterRounding {
+    /// The extra space is put on the right.
+    #[default]
+    Right,
+    /// The extra space is put on the left.
+    Left,
+    /// The extra space is put on the right on even lines of a cell
+    /// and on the left on odd ones.
+    Alternate,
+}
+
+impl CenterRounding {
+    fn is_extra_left(&self, line_index: usize) -> bool {
+        match self {
+            CenterRounding::Right => false,
+            CenterRounding::Left => true,
+            CenterRounding::Alternate => line_index % 2 == 1,
         }
     }
 }
 
+/// Settings which are used to render a line of a cell.
+#[derive(Debug, Clone, Copy)]
+struct LineFormat {
+    trim: bool,
+    center_rounding: CenterRounding,
+}
+
 /// AlignmentVertical represents an vertical aligment of a cell content.
 #[derive(Debug, Clone, Copy)]
 pub enum AlignmentVertical {
@@ -741,16 +2239,42 @@ impl AlignmentVertical {
     }
 }
 
+/// SpanAlignment represents a region in which a content of a cell spanned over a few rows
+/// is vertically aligned.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SpanAlignment {
+    /// The content is aligned across all the rows the cell is spanned over.
+    Merged,
+    /// The content is aligned within the first row the cell is spanned over.
+    ///
+    /// A content which doesn't fit the row goes on the next ones.
+    FirstRow,
+}
+
 /// Settings represent setting of a particular cell
 #[derive(Debug, Clone, Default)]
 pub struct Settings {
     text: Option<String>,
-    indent: Option<Indent>,
+    indent_left: Option<usize>,
+    indent_right: Option<usize>,
+    indent_top: Option<usize>,
+    indent_bottom: Option<usize>,
     alignment_h: Option<AlignmentHorizontal>,
     alignment_v: Option<AlignmentVertical>,
     span: Option<usize>,
+    row_span: Option<usize>,
+    span_alignment: Option<SpanAlignment>,
+    min_width: Option<usize>,
+    min_height: Option<usize>,
+    fill: Option<char>,
+    fill_left: Option<String>,
+    fill_right: Option<String>,
+    origin: Option<(usize, usize)>,
     border: Option<Border>,
     border_split_check: bool,
+    border_override: bool,
+    attributes: Vec<(String, String)>,
+    tags: Vec<String>,
 }
 
 impl Settings {
@@ -766,13 +2290,34 @@ impl Settings {
     }
 
     /// Indent method sets indent for a cell
-    pub fn indent(mut self, left: usize, right: usize, top: usize, bottom: usize) -> Self {
-        self.indent = Some(Indent {
-            top,
-            bottom,
-            left,
-            right,
-        });
+    pub fn indent(self, left: usize, right: usize, top: usize, bottom: usize) -> Self {
+        self.indent_left(left)
+            .indent_right(right)
+            .indent_top(top)
+            .indent_bottom(bottom)
+    }
+
+    /// Sets a left indent for a cell keeping other sides unchanged.
+    pub fn indent_left(mut self, left: usize) -> Self {
+        self.indent_left = Some(left);
+        self
+    }
+
+    /// Sets a right indent for a cell keeping other sides unchanged.
+    pub fn indent_right(mut self, right: usize) -> Self {
+        self.indent_right = Some(right);
+        self
+    }
+
+    /// Sets a top indent for a cell keeping other sides unchanged.
+    pub fn indent_top(mut self, top: usize) -> Self {
+        self.indent_top = Some(top);
+        self
+    }
+
+    /// Sets a bottom indent for a cell keeping other sides unchanged.
+    pub fn indent_bottom(mut self, bottom: usize) -> Self {
+        self.indent_bottom = Some(bottom);
         self
     }
 
@@ -794,6 +2339,80 @@ impl Settings {
         self
     }
 
+    /// Set an amount of rows a cell is spanned over.
+    ///
+    /// Cells below it are not rendered.
+    /// A span which goes beyond the last row is cut.
+    pub fn row_span(mut self, span: usize) -> Self {
+        self.row_span = Some(span);
+        self
+    }
+
+    /// Set a region in which a content of a cell spanned over a few rows is vertically aligned.
+    ///
+    /// By default it's [SpanAlignment::Merged].
+    pub fn span_alignment(mut self, alignment: SpanAlignment) -> Self {
+        self.span_alignment = Some(alignment);
+        self
+    }
+
+    /// Set a minimum width of a cell.
+    ///
+    /// The width includes an indent of the cell.
+    /// A width of a spanned cell includes borders between the columns it's spanned over.
+    pub fn min_width(mut self, width: usize) -> Self {
+        self.min_width = Some(width);
+        self
+    }
+
+    /// Set a minimum height of a cell.
+    ///
+    /// The height includes an indent of the cell.
+    /// A height of a cell spanned over a few rows includes borders between the rows.
+    pub fn min_height(mut self, height: usize) -> Self {
+        self.min_height = Some(height);
+        self
+    }
+
+    /// Fill method sets a character which is used instead of spaces
+    /// in an indent and an alignment space of a cell.
+    pub fn fill(mut self, c: char) -> Self {
+        self.fill = Some(c);
+        self
+    }
+
+    /// Fill left method sets a pattern which fills a space to the left of a content,
+    /// an indent included.
+    ///
+    /// The pattern is repeated starting from the left side of a cell,
+    /// so patterns of cells in a column are aligned to each other.
+    /// An empty pattern resets it so [Settings::fill] is used.
+    pub fn fill_left<S: Into<String>>(mut self, pattern: S) -> Self {
+        self.fill_left = Some(pattern.into());
+        self
+    }
+
+    /// Fill right method sets a pattern which fills a space to the right of a content,
+    /// an indent included.
+    ///
+    /// The pattern is repeated starting from the left side of a cell,
+    /// so patterns of cells in a column are aligned to each other.
+    /// An empty pattern resets it so [Settings::fill] is used.
+    pub fn fill_right<S: Into<String>>(mut self, pattern: S) -> Self {
+        self.fill_right = Some(pattern.into());
+        self
+    }
+
+    /// Origin method sets an original position of a cell.
+    ///
+    /// The position is kept by [Grid::get_settings],
+    /// so a cell can be tracked after a grid was rebuilt with cells moved.
+    /// It's ignored for entities other than [Entity::Cell].
+    pub fn origin(mut self, row: usize, column: usize) -> Self {
+        self.origin = Some((row, column));
+        self
+    }
+
     /// Set the settings's border.
     ///
     /// The border setting is in a restrictive manner, by default.
@@ -813,39 +2432,244 @@ impl Settings {
         self.border_split_check = !strict;
         self
     }
+
+    /// Set the border as an override.
+    ///
+    /// An override is remembered by a [Grid] so it can be re-applied
+    /// by [Grid::restore_border_overrides] after the borders were reset.
+    pub fn border_override(mut self, is_override: bool) -> Self {
+        self.border_override = is_override;
+        self
+    }
+
+    /// Attribute method sets a key-value metadata for a cell.
+    ///
+    /// Attributes are ignored by [Grid] rendering.
+    pub fn attribute<K: Into<String>, V: Into<String>>(mut self, key: K, value: V) -> Self {
+        self.attributes.push((key.into(), value.into()));
+        self
+    }
+
+    fn attributes(mut self, attributes: Vec<(String, String)>) -> Self {
+        self.attributes = attributes;
+        self
+    }
+
+    /// Tag method marks a cell by a name so it can be found later by [Grid::get_tags].
+    ///
+    /// Tags are ignored by [Grid] rendering.
+    pub fn tag<S: Into<String>>(mut self, tag: S) -> Self {
+        self.tags.push(tag.into());
+        self
+    }
+
+    fn tags(mut self, tags: Vec<String>) -> Self {
+        self.tags = tags;
+        self
+    }
+}
+
+/// Shows a structure of a grid rather than a rendered table:
+/// its size, cells, set styles, spans and configured split lines.
+impl std::fmt::Debug for Grid {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut styles = self.styles.iter().collect::<Vec<_>>();
+        styles.sort_by_key(|(entity, _)| entity_order(entity));
+
+        let spans = (0..self.count_rows())
+            .flat_map(|row| (0..self.count_columns()).map(move |column| (row, column)))
+            .filter_map(|(row, column)| {
+                let style = self.style(&Entity::Cell(row, column));
+                if style.span != 1 || style.row_span != 1 {
+                    Some(((row, column), (style.span, style.row_span)))
+                } else {
+                    None
+                }
+            })
+            .collect::<BTreeMap<_, _>>();
+
+        let mut debug = f.debug_struct("Grid");
+        debug
+            .field("size", &self.size)
+            .field("header_rows", &self.header_rows)
+            .field("cells", &self.cells)
+            .field("styles", &DebugMap(styles))
+            .field("spans", &spans)
+            .field("split_lines", &self.split_lines().collect::<Vec<_>>())
+            .field(
+                "override_split_lines",
+                &self.override_split_lines.iter().collect::<BTreeMap<_, _>>(),
+            )
+            .field(
+                "row_spacing",
+                &self.row_spacing.iter().collect::<BTreeMap<_, _>>(),
+            )
+            .field(
+                "column_min_widths",
+                &self.column_min_widths.iter().collect::<BTreeMap<_, _>>(),
+            )
+            .field("inner_vertical_separator", &self.inner_vertical_separator)
+            .field("tab_width", &self.tab_width)
+            .field("trim", &self.trim)
+            .field("center_rounding", &self.center_rounding)
+            .field("fixed_widths", &self.fixed_widths)
+            .field("hide_empty_rows", &self.hide_empty_rows)
+            .field("hide_empty_columns", &self.hide_empty_columns);
+
+        #[cfg(feature = "border-glyphs")]
+        debug.field(
+            "border_glyphs",
+            &self.border_glyphs.iter().collect::<BTreeMap<_, _>>(),
+        );
+
+        debug.finish()
+    }
+}
+
+struct DebugMap<K, V>(Vec<(K, V)>);
+
+impl<K: std::fmt::Debug, V: std::fmt::Debug> std::fmt::Debug for DebugMap<K, V> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_map()
+            .entries(self.0.iter().map(|(k, v)| (k, v)))
+            .finish()
+    }
+}
+
+fn entity_order(entity: &Entity) -> (usize, usize, usize) {
+    match *entity {
+        Entity::Global => (0, 0, 0),
+        Entity::Column(column) => (1, 0, column),
+        Entity::Row(row) => (2, row, 0),
+        Entity::Cell(row, column) => (3, row, column),
+    }
 }
 
 impl std::fmt::Display for Grid {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        let count_rows = self.count_rows();
-        let count_columns = self.count_columns();
+        self.build_into(f)
+    }
+}
 
-        // It may happen when all cells removed via `remove_row`, `remove_column` methods
-        if count_rows == 0 || count_columns == 0 {
-            return Ok(());
-        }
+impl Grid {
+    /// Renders the grid into a writer line by line,
+    /// so the whole table is not allocated as a single string.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    ///     use papergrid::{Entity, Grid, Settings, DEFAULT_CELL_STYLE};
+    ///     let mut grid = Grid::new(1, 1);
+    ///     grid.set_cell_borders(DEFAULT_CELL_STYLE.clone());
+    ///     grid.set(&Entity::Cell(0, 0), Settings::new().text("a"));
+    ///
+    ///     let mut buf = String::new();
+    ///     grid.build_into(&mut buf).unwrap();
+    ///
+    ///     assert_eq!(buf, "+-+\n|a|\n+-+\n");
+    /// ```
+    pub fn build_into<W: fmt::Write>(&self, f: &mut W) -> fmt::Result {
+        self.build_rows(f, None)
+    }
 
-        let mut cells = self.collect_cells(count_rows, count_columns);
-        let mut styles = self.collect_styles(count_rows, count_columns);
+    /// Renders lines of a single row without split lines around it.
+    ///
+    /// The lines are the same as the ones of the row in the whole grid,
+    /// so rows can be printed one by one e.g. to paginate or to diff a table.
+    /// A row which is hidden as empty is rendered as an empty string.
+    ///
+    /// The method panics if incorrect row index is given.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    ///     use papergrid::{Entity, Grid, Settings, DEFAULT_CELL_STYLE};
+    ///     let mut grid = Grid::new(2, 2);
+    ///     grid.set_cell_borders(DEFAULT_CELL_STYLE.clone());
+    ///     grid.set(&Entity::Cell(0, 0), Settings::new().text("a"));
+    ///     grid.set(&Entity::Cell(1, 0), Settings::new().text("b\nc"));
+    ///     grid.set(&Entity::Cell(1, 1), Settings::new().text("dd"));
+    ///
+    ///     assert_eq!(grid.row_to_string(0), "|a|  |\n");
+    ///     assert_eq!(grid.row_to_string(1), "|b|dd|\n|c|  |\n");
+    /// ```
+    pub fn row_to_string(&self, row: usize) -> String {
+        assert!(row < self.count_rows(), "row {} is out of the grid", row);
 
-        let split_borders = (0..count_rows)
-            .map(|row| self.get_inner_split_line(row))
-            .collect::<Vec<_>>();
+        let mut buf = String::new();
+        self.build_rows(&mut buf, Some(row))
+            .expect("writing to a string can't fail");
+        buf
+    }
 
-        let row_heights = rows_height(&cells, &styles, count_rows, count_columns);
-        let widths = columns_width(
-            &mut cells,
-            &mut styles,
-            &split_borders,
-            count_rows,
-            count_columns,
-        );
+    // renders all rows with split lines or only lines of a given row
+    fn build_rows(&self, f: &mut dyn fmt::Write, only_row: Option<usize>) -> fmt::Result {
+        let result = self.prepare(|grid, prepared| {
+            let only_row = match only_row {
+                Some(row) => match prepared.rows.iter().position(|&kept| kept == row) {
+                    Some(row) => Some(row),
+                    // the row is hidden
+                    None => return Ok(()),
+                },
+                None => None,
+            };
 
-        let normal_widths = normalized_width(&widths, &styles, count_rows, count_columns);
+            grid.build_prepared(f, prepared, only_row)
+        });
 
-        for row in 0..count_rows {
+        result.unwrap_or(Ok(()))
+    }
+
+    fn build_prepared(
+        &self,
+        f: &mut dyn fmt::Write,
+        prepared: Prepared<'_>,
+        only_row: Option<usize>,
+    ) -> fmt::Result {
+        let count_rows = self.count_rows();
+        let count_columns = self.count_columns();
+        let Prepared {
+            cells,
+            styles,
+            origins,
+            split_lines,
+            heights: row_heights,
+            widths,
+            ..
+        } = prepared;
+
+        let row_spans = RowSpans {
+            cells: &cells,
+            styles: &styles,
+            widths: &widths,
+            heights: &row_heights,
+            positions: rows_position(&row_heights, &split_lines),
+            origins,
+        };
+
+        let separator_width = self
+            .inner_vertical_separator
+            .as_deref()
+            .map_or(1, string_width);
+        let normal_widths =
+            normalized_width(&widths, &styles, separator_width, count_rows, count_columns);
+
+        // a width of the first line which all other lines are checked against
+        #[cfg(feature = "width-assertions")]
+        let mut table_width = None;
+
+        // widths and heights are calculated for the whole grid,
+        // so a single row looks the same as it does in the grid
+        let rows = match only_row {
+            Some(row) => row..row + 1,
+            None => 0..count_rows,
+        };
+
+        for row in rows {
             let inner_border = self.get_inner_split_line(row);
-            let top_border = if row == 0 {
+            let top_border = if only_row.is_some() {
+                None
+            } else if row == 0 {
                 Some((
                     self.get_split_line(row),
                     self.override_split_lines.get(&row),
@@ -853,42 +2677,129 @@ impl std::fmt::Display for Grid {
             } else {
                 None
             };
-            let bottom_border = Some((
-                self.get_split_line(row + 1),
-                self.override_split_lines.get(&(row + 1)),
-            ));
-
-            build_row(
-                f,
-                &cells[row],
-                &styles[row],
-                &widths[row],
-                &normal_widths,
-                row_heights[row],
-                inner_border,
-                top_border,
-                bottom_border,
-            )?;
+            let bottom_border = if only_row.is_some() {
+                None
+            } else {
+                Some((
+                    self.get_split_line(row + 1),
+                    self.override_split_lines.get(&(row + 1)),
+                ))
+            };
+            let spanned_cells = row_spans.row(row);
+            let bottom_spanned_cells = row_spans.line(row + 1);
+
+            let render_row = |f: &mut dyn fmt::Write| {
+                build_row(
+                    f,
+                    &cells[row],
+                    &styles[row],
+                    &widths[row],
+                    &normal_widths,
+                    row_heights[row],
+                    inner_border.clone(),
+                    top_border.clone(),
+                    bottom_border.clone(),
+                    &spanned_cells,
+                    &bottom_spanned_cells,
+                    &self.border_glyphs,
+                    LineFormat {
+                        trim: self.trim,
+                        center_rounding: self.center_rounding,
+                    },
+                )
+            };
+
+            #[cfg(not(feature = "width-assertions"))]
+            render_row(f)?;
+
+            #[cfg(feature = "width-assertions")]
+            {
+                let mut text = String::new();
+                render_row(&mut text)?;
+                let expected_width = *table_width.get_or_insert_with(|| {
+                    text.lines().next().map(string_width).unwrap_or_default()
+                });
+
+                assert_lines_width(
+                    &text,
+                    expected_width,
+                    row,
+                    &cells[row],
+                    &styles[row],
+                    &widths[row],
+                );
+
+                f.write_str(&text)?;
+            }
         }
 
         Ok(())
     }
 }
 
+/// Verifies that all lines of a rendered row have an expected width.
+///
+/// It panics pointing to a cell which is wider than its column if there's one.
+#[cfg(feature = "width-assertions")]
+fn assert_lines_width(
+    text: &str,
+    expected_width: usize,
+    row: usize,
+    cells: &[Vec<&str>],
+    styles: &[Style],
+    widths: &[usize],
+) {
+    let line = text
+        .lines()
+        .map(string_width)
+        .enumerate()
+        .find(|&(_, width)| width != expected_width);
+
+    if let Some((line, width)) = line {
+        let cell = (0..cells.len())
+            .filter(|&column| is_cell_visible(styles, column))
+            .find(|&column| cell_width(&cells[column], &styles[column]) > widths[column]);
+
+        match cell {
+            Some(column) => panic!(
+                "papergrid: line {} of row {} has width {} but expected {}; cell ({}, {}) doesn't fit its width {}",
+                line, row, width, expected_width, row, column, widths[column]
+            ),
+            None => panic!(
+                "papergrid: line {} of row {} has width {} but expected {}",
+                line, row, width, expected_width
+            ),
+        }
+    }
+}
+
 #[allow(clippy::too_many_arguments)]
 fn build_row(
-    f: &mut std::fmt::Formatter<'_>,
+    f: &mut dyn fmt::Write,
     cell_contents: &[Vec<&str>],
     cell_styles: &[Style],
     cell_widths: &[usize],
     normal_widths: &[usize],
     height: usize,
     inner_border: Vec<BorderLine>,
-    top_border: Option<(Vec<BorderLine>, Option<&String>)>,
-    bottom_border: Option<(Vec<BorderLine>, Option<&String>)>,
+    top_border: Option<(Vec<BorderLine>, Option<&SplitLineOverride>)>,
+    bottom_border: Option<(Vec<BorderLine>, Option<&SplitLineOverride>)>,
+    spanned_cells: &[Option<RowSpanCell<'_>>],
+    bottom_spanned_cells: &[Option<RowSpanCell<'_>>],
+    glyphs: &HashMap<char, String>,
+    format: LineFormat,
 ) -> fmt::Result {
     if let Some((top_border, override_border)) = top_border {
-        build_split_line(f, normal_widths, &top_border, override_border)?;
+        build_split_line(
+            f,
+            normal_widths,
+            &top_border,
+            override_border,
+            glyphs,
+            &[],
+            &[],
+            format,
+        )?;
     }
 
     build_row_internals(
@@ -898,119 +2809,215 @@ fn build_row(
         cell_widths,
         height,
         &inner_border,
+        spanned_cells,
+        glyphs,
+        format,
     )?;
 
     if let Some((bottom_border, override_border)) = bottom_border {
-        build_split_line(f, normal_widths, &bottom_border, override_border)?;
+        build_split_line(
+            f,
+            normal_widths,
+            &bottom_border,
+            override_border,
+            glyphs,
+            bottom_spanned_cells,
+            &inner_border,
+            format,
+        )?;
     }
 
     Ok(())
 }
 
+#[allow(clippy::too_many_arguments)]
 fn build_row_internals(
-    f: &mut std::fmt::Formatter<'_>,
+    f: &mut dyn fmt::Write,
     row: &[Vec<&str>],
     row_styles: &[Style],
     widths: &[usize],
     height: usize,
     border: &[BorderLine],
+    spanned_cells: &[Option<RowSpanCell<'_>>],
+    glyphs: &HashMap<char, String>,
+    format: LineFormat,
 ) -> fmt::Result {
     for line_index in 0..height {
-        build_line(f, border, row_styles, row.len(), |f, column| {
-            build_row_internal_line(
-                f,
-                line_index,
-                &row[column],
-                &row_styles[column],
-                widths[column],
-                height,
-            )
-        })?;
+        build_line(
+            f,
+            border,
+            row_styles,
+            row.len(),
+            glyphs,
+            |f, column| match spanned_cells.get(column).copied().flatten() {
+                Some(span) => build_row_internal_line(
+                    f,
+                    span.offset + line_index,
+                    span.cell,
+                    span.style,
+                    span.width,
+                    span.height,
+                    format,
+                ),
+                None => build_row_internal_line(
+                    f,
+                    line_index,
+                    &row[column],
+                    &row_styles[column],
+                    widths[column],
+                    height,
+                    format,
+                ),
+            },
+        )?;
     }
 
     Ok(())
 }
 
 fn build_row_internal_line(
-    f: &mut std::fmt::Formatter<'_>,
+    f: &mut dyn fmt::Write,
     line_index: usize,
     cell: &[&str],
     style: &Style,
     width: usize,
     height: usize,
+    format: LineFormat,
 ) -> fmt::Result {
     let top_indent = top_indent(cell, style, height);
     if top_indent > line_index {
-        return empty_line(f, width);
+        return fill(f, style.fill_left.as_deref(), style.fill, 0, width);
     }
 
     let cell_line_index = line_index - top_indent;
     let cell_has_this_line = cell.len() > cell_line_index;
     // happen when other cells have bigger height
     if !cell_has_this_line {
-        return empty_line(f, width);
+        return fill(f, style.fill_left.as_deref(), style.fill, 0, width);
     }
 
     let line_text = cell[cell_line_index];
-    line(
-        f,
-        line_text,
-        width,
-        style.indent.left,
-        style.indent.right,
-        style.alignment_h,
-    )
+    let extra_left = format.center_rounding.is_extra_left(cell_line_index);
+    line(f, line_text, width, style, format.trim, extra_left)
 }
 
 fn top_indent(cell: &[&str], style: &Style, height: usize) -> usize {
     let height = height - style.indent.top;
-    let content_height = cell_height(cell, style) - style.indent.top - style.indent.bottom;
-    let indent = style.alignment_v.top_ident(height, content_height);
+    let indent = style.alignment_v.top_ident(height, cell.len());
     indent + style.indent.top
 }
 
-fn empty_line(f: &mut std::fmt::Formatter<'_>, n: usize) -> fmt::Result {
-    write!(f, "{:1$}", "", n)
+fn repeat_char(f: &mut dyn fmt::Write, c: char, n: usize) -> fmt::Result {
+    (0..n).try_for_each(|_| f.write_char(c))
 }
 
-fn repeat_char(f: &mut std::fmt::Formatter<'_>, c: char, n: usize) -> fmt::Result {
-    if n > 0 {
-        write!(f, "{:1$}", c, n)
-    } else {
-        Ok(())
+/// Fills a space of a cell with a pattern or a char if there's no pattern.
+///
+/// The pattern is repeated from the start of the cell,
+/// so `offset` is a position of the space in the cell.
+/// A wide char of the pattern which doesn't fit the space is replaced by spaces.
+fn fill(
+    f: &mut dyn fmt::Write,
+    pattern: Option<&str>,
+    c: char,
+    offset: usize,
+    n: usize,
+) -> fmt::Result {
+    let pattern = match pattern {
+        Some(pattern) if string_width(pattern) > 0 => pattern,
+        _ => return repeat_char(f, c, n),
+    };
+
+    let pattern_width = string_width(pattern);
+    let end = offset + n;
+    let mut position = offset;
+    while position < end {
+        let mut pattern_position = 0;
+        let found = pattern.chars().find(|&c| {
+            let start = pattern_position;
+            pattern_position += char_width(c);
+            start == position % pattern_width && char_width(c) > 0
+        });
+
+        match found {
+            Some(c) if position + char_width(c) <= end => {
+                f.write_char(c)?;
+                position += char_width(c);
+            }
+            _ => {
+                f.write_char(' ')?;
+                position += 1;
+            }
+        }
     }
+
+    Ok(())
 }
 
 fn line(
-    f: &mut std::fmt::Formatter<'_>,
+    f: &mut dyn fmt::Write,
     text: &str,
     width: usize,
-    left_indent: usize,
-    right_indent: usize,
-    alignment: AlignmentHorizontal,
+    style: &Style,
+    trim: bool,
+    extra_left: bool,
 ) -> fmt::Result {
-    repeat_char(f, ' ', left_indent)?;
-    alignment.align(f, text, width - left_indent - right_indent)?;
-    repeat_char(f, ' ', right_indent)?;
+    let (left_indent, right_indent) = (style.indent.left, style.indent.right);
+    if style.fill_left.is_some() || style.fill_right.is_some() || !trim {
+        let text = if trim { text.trim() } else { text };
+        let text_width = string_width(text);
+        let diff = width.saturating_sub(left_indent + right_indent + text_width);
+        let (left, right) = style.alignment_h.spaces(diff, extra_left);
+        let left = left_indent + left;
+        let right = right + right_indent;
+
+        fill(f, style.fill_left.as_deref(), style.fill, 0, left)?;
+        f.write_str(text)?;
+        return fill(
+            f,
+            style.fill_right.as_deref(),
+            style.fill,
+            left + text_width,
+            right,
+        );
+    }
+
+    repeat_char(f, style.fill, left_indent)?;
+    style.alignment_h.align(
+        f,
+        text,
+        width.saturating_sub(left_indent + right_indent),
+        style.fill,
+        extra_left,
+    )?;
+    repeat_char(f, style.fill, right_indent)?;
     Ok(())
 }
 
-fn build_line<F: FnMut(&mut std::fmt::Formatter<'_>, usize) -> fmt::Result>(
-    f: &mut std::fmt::Formatter<'_>,
+fn build_line<F: FnMut(&mut dyn fmt::Write, usize) -> fmt::Result>(
+    f: &mut dyn fmt::Write,
     borders: &[BorderLine],
     row_styles: &[Style],
     length: usize,
+    glyphs: &HashMap<char, String>,
     mut writer: F,
 ) -> fmt::Result {
     for (i, border) in borders.iter().enumerate() {
         if is_cell_visible(row_styles, i) {
-            write_option(f, border.connector1)?;
+            match (&border.separator, border.connector1) {
+                (Some(separator), _) => f.write_str(separator)?,
+                (None, Some(c)) => write_border_char(f, c, glyphs)?,
+                (None, None) => {}
+            }
+
             writer(f, i)?;
         }
 
         let is_last_cell = i + 1 == length;
         if is_last_cell {
-            write_option(f, border.connector2)?;
+            if let Some(c) = border.connector2 {
+                write_border_char(f, c, glyphs)?;
+            }
         }
     }
 
@@ -1019,66 +3026,122 @@ fn build_line<F: FnMut(&mut std::fmt::Formatter<'_>, usize) -> fmt::Result>(
     Ok(())
 }
 
+// Cells spanned over a few rows are rendered instead of a line where they go through it.
+// Such cells are separated by vertical borders of a row above the line.
+#[allow(clippy::too_many_arguments)]
 fn build_split_line(
-    f: &mut std::fmt::Formatter<'_>,
+    f: &mut dyn fmt::Write,
     widths: &[usize],
     borders: &[BorderLine],
-    override_str: Option<&String>,
+    override_line: Option<&SplitLineOverride>,
+    glyphs: &HashMap<char, String>,
+    spanned_cells: &[Option<RowSpanCell<'_>>],
+    verticals: &[BorderLine],
+    format: LineFormat,
 ) -> fmt::Result {
     let theres_no_border = borders.iter().all(|l| l.main.is_none());
     if theres_no_border || borders.is_empty() {
         return Ok(());
     }
 
-    let mut override_str = override_str.map(|s| s.to_owned());
+    let mut pieces = Vec::new();
+    // the left edge is considered to be spanned
+    let mut is_prev_spanned = true;
+    let mut skip = 0;
     for (i, border) in borders.iter().enumerate().take(widths.len()) {
-        if let Some(left_connector) = border.connector1 {
-            let connector = override_str
-                .as_mut()
-                .and_then(|s| {
-                    s.chars().next().map(|c| {
-                        let _ = s.drain(..c.len_utf8());
-                        c
-                    })
-                })
-                .unwrap_or(left_connector);
-            write!(f, "{}", connector)?
-        }
-
-        if let Some(main) = border.main {
-            let mut width = widths[i];
-            if let Some(s) = override_str.as_mut() {
-                while !s.is_empty() && width > 0 {
-                    match s.chars().next() {
-                        Some(c) => {
-                            write!(f, "{}", c)?;
-                            width -= 1;
-                            let _ = s.drain(..c.len_utf8());
-                        }
-                        None => break,
+        let is_last_cell = i + 1 == widths.len();
+
+        if skip > 0 {
+            skip -= 1;
+        } else {
+            let spanned_cell = spanned_cells.get(i).copied().flatten();
+            let connector = match (is_prev_spanned, spanned_cell, verticals.get(i)) {
+                (true, Some(_), Some(vertical)) => vertical,
+                _ => border,
+            };
+
+            if let Some(separator) = &connector.separator {
+                pieces.extend(separator.chars().map(SplitPiece::Border));
+            } else if let Some(c) = connector.connector1 {
+                pieces.push(SplitPiece::Border(c));
+            }
+
+            match spanned_cell {
+                Some(span) => {
+                    let mut text = String::new();
+                    build_row_internal_line(
+                        &mut text,
+                        span.offset,
+                        span.cell,
+                        span.style,
+                        span.width,
+                        span.height,
+                        format,
+                    )?;
+
+                    pieces.push(SplitPiece::Cell(text, span.width));
+
+                    skip = max(span.style.span, 1) - 1;
+                    is_prev_spanned = true;
+                }
+                None => {
+                    if let Some(main) = border.main {
+                        pieces.resize(pieces.len() + widths[i], SplitPiece::Border(main));
                     }
+
+                    is_prev_spanned = false;
                 }
             }
+        }
 
-            while width > 0 {
-                write!(f, "{}", main)?;
-                width -= 1;
+        if is_last_cell {
+            let right_connector = match (is_prev_spanned, verticals.get(i)) {
+                (true, Some(vertical)) => vertical.connector2,
+                _ => border.connector2,
+            };
+
+            if let Some(right_connector) = right_connector {
+                pieces.push(SplitPiece::Border(right_connector));
             }
         }
+    }
 
-        let is_last_cell = i + 1 == widths.len();
-        if is_last_cell {
-            if let Some(right_connector) = border.connector2 {
-                let connector = override_str
-                    .as_mut()
-                    .and_then(|s| {
-                        s.chars().next().map(|c| {
-                            let _ = s.drain(..c.len_utf8());
-                            c
-                        })
-                    })
-                    .unwrap_or(right_connector);
-                write!(f, "{}", connector)?
+    let texts = match override_line {
+        Some(line) => line.layout(&pieces),
+        None => Vec::new(),
+    };
+
+    // a number of border chars which are covered by a previous wide char
+    let mut covered = 0;
+    let mut position = 0;
+    for (i, piece) in pieces.iter().enumerate() {
+        match piece {
+            SplitPiece::Cell(text, width) => {
+                f.write_str(text)?;
+                position += width;
+            }
+            SplitPiece::Border(c) => {
+                let text = texts.get(position).copied().flatten();
+                let fits = |width: usize| {
+                    pieces[i..].len() >= width
+                        && pieces[i..i + width]
+                            .iter()
+                            .all(|piece| matches!(piece, SplitPiece::Border(_)))
+                };
+
+                if covered > 0 {
+                    covered -= 1;
+                } else {
+                    match text {
+                        Some((text, width)) if fits(width) => {
+                            f.write_str(text)?;
+                            covered = width - 1;
+                        }
+                        _ => write_border_char(f, *c, glyphs)?,
+                    }
+                }
+
+                position += 1;
             }
         }
     }
@@ -1088,10 +3151,76 @@ fn build_split_line(
     Ok(())
 }
 
-fn write_option<D: Display>(f: &mut std::fmt::Formatter<'_>, text: Option<D>) -> fmt::Result {
-    match text {
-        Some(text) => write!(f, "{}", text),
-        None => Ok(()),
+#[derive(Debug, Clone)]
+enum SplitPiece {
+    Border(char),
+    Cell(String, usize),
+}
+
+/// A text which is rendered in place of a horizontal split line.
+#[derive(Debug, Clone)]
+struct SplitLineOverride {
+    text: String,
+    alignment: AlignmentHorizontal,
+}
+
+impl SplitLineOverride {
+    // returns a part of the text and its width for each position of a line where it starts
+    fn layout(&self, pieces: &[SplitPiece]) -> Vec<Option<(&str, usize)>> {
+        let length = pieces
+            .iter()
+            .map(|piece| match piece {
+                SplitPiece::Border(_) => 1,
+                SplitPiece::Cell(_, width) => *width,
+            })
+            .sum();
+
+        let free_space = length - min(length, string_width(&self.text));
+        let mut position = match self.alignment {
+            AlignmentHorizontal::Left => 0,
+            AlignmentHorizontal::Center => free_space / 2,
+            AlignmentHorizontal::Right => free_space,
+        };
+
+        let mut texts = vec![None; length];
+        // zero width chars are kept together with a char before them
+        let mut chars = self.text.char_indices().peekable();
+        while let Some((start, c)) = chars.next() {
+            let mut end = start + c.len_utf8();
+            while let Some(&(i, c)) = chars.peek() {
+                if char_width(c) > 0 {
+                    break;
+                }
+
+                end = i + c.len_utf8();
+                chars.next();
+            }
+
+            let width = char_width(c);
+            if width == 0 {
+                continue;
+            }
+
+            if position >= length {
+                break;
+            }
+
+            texts[position] = Some((&self.text[start..end], width));
+            position += width;
+        }
+
+        texts
+    }
+}
+
+fn write_border_char(
+    f: &mut dyn fmt::Write,
+    c: char,
+    glyphs: &HashMap<char, String>,
+) -> fmt::Result {
+    match glyphs.get(&c) {
+        Some(glyph) => f.write_str(glyph),
+        None => f.write_char(c),
     }
 }
 
@@ -1107,6 +3236,10 @@ pub fn string_width(text: &str) -> usize {
     real_string_width(s)
 }
 
+fn char_width(c: char) -> usize {
+    unicode_width::UnicodeWidthChar::width(c).unwrap_or(0)
+}
+
 fn real_string_width(text: &str) -> usize {
     text.lines()
         .map(unicode_width::UnicodeWidthStr::width)
@@ -1118,6 +3251,7 @@ fn columns_width(
     cells: &mut [Vec<Vec<&str>>],
     styles: &mut [Vec<Style>],
     borders: &[Vec<BorderLine>],
+    min_widths: &HashMap<usize, usize>,
     count_rows: usize,
     count_columns: usize,
 ) -> Vec<Vec<usize>> {
@@ -1127,7 +3261,13 @@ fn columns_width(
             let cell = &cells[row][column];
             let style = &styles[row][column];
             if is_cell_visible(&styles[row], column) {
-                widths[row][column] = cell_width(cell, style);
+                widths[row][column] = max(cell_width(cell, style), style.min_width);
+
+                if style.span == 1 {
+                    if let Some(&min_width) = min_widths.get(&column) {
+                        widths[row][column] = max(widths[row][column], min_width);
+                    }
+                }
             } else {
                 widths[row][column] = 0;
                 styles[row][column].span = 0;
@@ -1159,6 +3299,103 @@ fn columns_width(
     widths
 }
 
+// widths of cells are built from widths of columns and lines which don't fit are cut
+fn fixed_columns_width(
+    cells: &mut [Vec<Vec<&str>>],
+    styles: &mut [Vec<Style>],
+    borders: &[Vec<BorderLine>],
+    fixed: &[usize],
+    count_rows: usize,
+) -> Vec<Vec<usize>> {
+    let columns = fixed_columns_min_indent(styles, borders, fixed, count_rows);
+    let column_width = |column: usize| columns[column];
+
+    let mut widths = vec![Vec::new(); count_rows];
+    for row in 0..count_rows {
+        let count_columns = styles[row].len();
+        let mut row_widths = vec![0; count_columns];
+        for (column, width) in row_widths.iter_mut().enumerate() {
+            if !is_cell_visible(&styles[row], column) {
+                styles[row][column].span = 0;
+                continue;
+            }
+
+            let style = &styles[row][column];
+            let end = std::cmp::min(column + style.span, count_columns);
+            *width = (column..end).map(column_width).sum::<usize>()
+                + (column + 1..end)
+                    .map(|i| connector_width(&borders[row][i]))
+                    .sum::<usize>();
+
+            let content_width = width.saturating_sub(style.indent.left + style.indent.right);
+            for line in cells[row][column].iter_mut() {
+                *line = cut_str(line, content_width);
+            }
+        }
+
+        widths[row] = row_widths;
+    }
+
+    widths
+}
+
+// widths of columns are raised so every cell fits at least its indent
+fn fixed_columns_min_indent(
+    styles: &[Vec<Style>],
+    borders: &[Vec<BorderLine>],
+    fixed: &[usize],
+    count_rows: usize,
+) -> Vec<usize> {
+    let count_columns = styles.iter().map(Vec::len).max().unwrap_or(0);
+    let mut widths = (0..count_columns)
+        .map(|column| fixed.get(column).copied().unwrap_or(0))
+        .collect::<Vec<_>>();
+
+    let visible_cells = || {
+        (0..count_rows).flat_map(move |row| {
+            (0..styles[row].len())
+                .filter(move |&column| is_cell_visible(&styles[row], column))
+                .map(move |column| (row, column, &styles[row][column]))
+        })
+    };
+
+    for (_, column, style) in visible_cells().filter(|(_, _, style)| style.span <= 1) {
+        let indent = style.indent.left + style.indent.right;
+        widths[column] = std::cmp::max(widths[column], indent);
+    }
+
+    for (row, column, style) in visible_cells().filter(|(_, _, style)| style.span > 1) {
+        let end = std::cmp::min(column + style.span, styles[row].len());
+        let width = widths[column..end].iter().sum::<usize>()
+            + (column + 1..end)
+                .map(|i| connector_width(&borders[row][i]))
+                .sum::<usize>();
+        let indent = style.indent.left + style.indent.right;
+        if width < indent {
+            widths[end - 1] += indent - width;
+        }
+    }
+
+    widths
+}
+
+// cuts a line so it fits a width, a wide char which doesn't fit is dropped
+fn cut_str(text: &str, width: usize) -> &str {
+    if string_width(text) <= width {
+        return text;
+    }
+
+    let mut text_width = 0;
+    for (i, c) in text.char_indices() {
+        text_width += char_width(c);
+        if text_width > width {
+            return &text[..i];
+        }
+    }
+
+    text
+}
+
 fn adjust_width(
     widths: &mut [Vec<usize>],
     styles: &[Vec<Style>],
@@ -1352,14 +3589,12 @@ fn row_width(
         (column_start..column_end)
             .filter(|&i| is_cell_visible(styles, i))
             .filter(|&i| is_cell_in_scope(styles, i, column_end))
-            .filter(|&i| {
-                if i == column_start {
-                    false
-                } else {
-                    borders[i].connector1.is_some()
-                }
+            .filter(|&i| i != column_start)
+            .map(|i| match &borders[i].separator {
+                Some(separator) => string_width(separator),
+                None => borders[i].connector1.map_or(0, |_| 1),
             })
-            .count()
+            .sum()
     };
 
     width + border_count
@@ -1389,6 +3624,8 @@ fn cell_width(cell: &[&str], style: &Style) -> usize {
 fn rows_height(
     cells: &[Vec<Vec<&str>>],
     styles: &[Vec<Style>],
+    row_spacing: &HashMap<usize, usize>,
+    split_lines: &[bool],
     count_rows: usize,
     count_columns: usize,
 ) -> Vec<usize> {
@@ -1408,21 +3645,206 @@ fn rows_height(
         (0..count_columns).for_each(|column_index| {
             let cell = &cells[row_index][column_index];
             let style = &styles[row_index][column_index];
+            // cells spanned over a few rows are handled once the rows are measured
+            if style.row_span > 1 {
+                return;
+            }
+
             row_heights[row_index] = max(row_heights[row_index], cell_height(cell, style));
         });
     });
 
+    for (&row, &spacing) in row_spacing {
+        if let Some(height) = row_heights.get_mut(row) {
+            *height += spacing;
+        }
+    }
+
+    // the last row of a span is increased if a spanned cell doesn't fit the rows
+    (0..count_rows).for_each(|row| {
+        (0..count_columns)
+            .filter(|&column| styles[row][column].row_span > 1)
+            .filter(|&column| is_cell_visible(&styles[row], column))
+            .for_each(|column| {
+                let end_row = row + styles[row][column].row_span;
+                let lines = (row + 1..end_row).filter(|&r| split_lines[r]).count();
+                let height = row_heights[row..end_row].iter().sum::<usize>() + lines;
+
+                let cell_height = cell_height(&cells[row][column], &styles[row][column]);
+                if cell_height > height {
+                    row_heights[end_row - 1] += cell_height - height;
+                }
+            });
+    });
+
     row_heights
 }
 
 fn cell_height(cell: &[&str], style: &Style) -> usize {
     let content_height = cell.len();
-    content_height + style.indent.top + style.indent.bottom
+    max(
+        content_height + style.indent.top + style.indent.bottom,
+        style.min_height,
+    )
+}
+
+// Cells covered by a span of a cell above are replaced by empty cells
+// which have the same style as the spanned cell,
+// so widths of columns are calculated as if there were no row spans.
+//
+// It returns an origin row of a span for each covered cell.
+fn prepare_row_spans(
+    cells: &mut [Vec<Vec<&str>>],
+    styles: &mut [Vec<Style>],
+    count_rows: usize,
+    count_columns: usize,
+) -> Vec<Vec<Option<usize>>> {
+    let mut origins = vec![vec![None; count_columns]; count_rows];
+    for row in 0..count_rows {
+        for column in 0..count_columns {
+            let is_covered = origins[row][column].is_some();
+            if is_covered || !is_cell_visible(&styles[row], column) {
+                continue;
+            }
+
+            let span = min(max(styles[row][column].row_span, 1), count_rows - row);
+            styles[row][column].row_span = span;
+
+            let end_column = min(column + max(styles[row][column].span, 1), count_columns);
+            for covered_row in row + 1..row + span {
+                let mut style = styles[row][column].clone();
+                style.row_span = 1;
+                style.indent.top = 0;
+                style.indent.bottom = 0;
+                style.min_height = 0;
+
+                for covered_column in column..end_column {
+                    cells[covered_row][covered_column] = Vec::new();
+                    styles[covered_row][covered_column].row_span = 1;
+                    origins[covered_row][covered_column] = Some(row);
+                }
+
+                styles[covered_row][column] = style;
+            }
+        }
+    }
+
+    origins
+}
+
+// a vertical position of the first line of each row
+fn rows_position(heights: &[usize], split_lines: &[bool]) -> Vec<usize> {
+    let mut positions = Vec::with_capacity(heights.len());
+    let mut y = 0;
+    for (row, height) in heights.iter().enumerate() {
+        if split_lines[row] {
+            y += 1;
+        }
+
+        positions.push(y);
+        y += height;
+    }
+
+    positions
+}
+
+// a part of a cell spanned over a few rows which is rendered on a row or a split line
+#[derive(Debug, Clone, Copy)]
+struct RowSpanCell<'a> {
+    cell: &'a [&'a str],
+    style: &'a Style,
+    width: usize,
+    height: usize,
+    // a line of the cell rendered first
+    offset: usize,
+}
+
+// measurements of a grid which are used to render it
+struct Prepared<'a> {
+    // indexes of rows and columns of an original grid in case some are hidden
+    rows: Vec<usize>,
+    columns: Vec<usize>,
+    cells: Vec<Vec<Vec<&'a str>>>,
+    styles: Vec<Vec<Style>>,
+    origins: Vec<Vec<Option<usize>>>,
+    split_borders: Vec<Vec<BorderLine>>,
+    split_lines: Vec<bool>,
+    heights: Vec<usize>,
+    widths: Vec<Vec<usize>>,
+}
+
+struct RowSpans<'a> {
+    cells: &'a [Vec<Vec<&'a str>>],
+    styles: &'a [Vec<Style>],
+    widths: &'a [Vec<usize>],
+    heights: &'a [usize],
+    positions: Vec<usize>,
+    origins: Vec<Vec<Option<usize>>>,
+}
+
+impl<'a> RowSpans<'a> {
+    // parts of spanned cells on a row
+    fn row(&self, row: usize) -> Vec<Option<RowSpanCell<'a>>> {
+        (0..self.origins[row].len())
+            .map(|column| {
+                if !is_cell_visible(&self.styles[row], column) {
+                    return None;
+                }
+
+                let origin = match self.origins[row][column] {
+                    Some(origin) => origin,
+                    None if self.styles[row][column].row_span > 1 => row,
+                    None => return None,
+                };
+
+                Some(self.cell(origin, column, self.positions[row]))
+            })
+            .collect()
+    }
+
+    // parts of spanned cells on a split line before a row
+    fn line(&self, row: usize) -> Vec<Option<RowSpanCell<'a>>> {
+        if row == 0 || row >= self.origins.len() {
+            return Vec::new();
+        }
+
+        (0..self.origins[row].len())
+            .map(|column| {
+                if !is_cell_visible(&self.styles[row], column) {
+                    return None;
+                }
+
+                self.origins[row][column]
+                    .map(|origin| self.cell(origin, column, self.positions[row] - 1))
+            })
+            .collect()
+    }
+
+    fn cell(&self, origin: usize, column: usize, y: usize) -> RowSpanCell<'a> {
+        let style = &self.styles[origin][column];
+        let last_row = origin + style.row_span - 1;
+        let mut height = self.positions[last_row] + self.heights[last_row] - self.positions[origin];
+        if style.span_alignment == SpanAlignment::FirstRow {
+            let cell = &self.cells[origin][column];
+            let cell_height = cell.len() + style.indent.top + style.indent.bottom;
+            let first_row_height = std::cmp::max(self.heights[origin], cell_height);
+            height = std::cmp::min(height, first_row_height);
+        }
+
+        RowSpanCell {
+            cell: &self.cells[origin][column],
+            style,
+            width: self.widths[origin][column],
+            height,
+            offset: y - self.positions[origin],
+        }
+    }
 }
 
 fn normalized_width(
     widths: &[Vec<usize>],
     styles: &[Vec<Style>],
+    separator_width: usize,
     count_rows: usize,
     count_columns: usize,
 ) -> Vec<usize> {
@@ -1440,7 +3862,7 @@ fn normalized_width(
 
         if let Some(row) = min_spanned_row {
             let span = styles[row][col].span;
-            let mut width = widths[row][col] - (span - 1);
+            let mut width = widths[row][col] - (span - 1) * separator_width;
 
             for col in (col..col + span).cycle() {
                 if width == 0 {
@@ -1458,11 +3880,12 @@ fn normalized_width(
     v
 }
 
-#[derive(Debug)]
+// ordered maps are used to keep a placement of intersections deterministic
+#[derive(Debug, Clone)]
 struct Borders {
-    vertical: HashMap<CellIndex, Line>,
-    horizontal: HashMap<CellIndex, Line>,
-    intersections: HashMap<GridPosition, char>,
+    vertical: BTreeMap<CellIndex, Line>,
+    horizontal: BTreeMap<CellIndex, Line>,
+    intersections: BTreeMap<GridPosition, char>,
     count_columns: usize,
     count_rows: usize,
 }
@@ -1477,9 +3900,9 @@ type Line = Vec<char>;
 impl Borders {
     fn new(count_rows: usize, count_columns: usize) -> Self {
         Self {
-            vertical: HashMap::new(),
-            horizontal: HashMap::new(),
-            intersections: HashMap::new(),
+            vertical: BTreeMap::new(),
+            horizontal: BTreeMap::new(),
+            intersections: BTreeMap::new(),
             count_columns,
             count_rows,
         }
@@ -1500,6 +3923,7 @@ impl Borders {
                 main: Some(self.get_horizontal_char(row, column).unwrap()),
                 connector1: None,
                 connector2: None,
+                separator: None,
             };
 
             line.push(border);
@@ -1528,7 +3952,7 @@ impl Borders {
 
             if border.connector1.is_some() {
                 if let Some(last) = last_index {
-                    let mut last: &mut BorderLine = &mut line[last];
+                    let last: &mut BorderLine = &mut line[last];
                     last.connector2 = border.connector1;
                 }
             }
@@ -1625,6 +4049,84 @@ impl Borders {
         self.horizontal.len() + 1
     }
 
+    // a new row has no borders of its own
+    fn insert_row(&mut self, row: usize, missing_line: usize) {
+        for line in self.vertical.values_mut() {
+            line.insert(row, DEFAULT_SPLIT_BORDER_CHAR);
+        }
+
+        let shift = |line: usize| Some(if line >= missing_line { line + 1 } else { line });
+        self.horizontal = remap_keys(std::mem::take(&mut self.horizontal), shift);
+        self.intersections = remap_keys(std::mem::take(&mut self.intersections), |(r, c)| {
+            Some((shift(r)?, c))
+        });
+
+        self.count_rows += 1;
+    }
+
+    // a line below the row replaces a line above it in case it's present
+    fn remove_row(&mut self, row: usize) {
+        for line in self.vertical.values_mut() {
+            line.remove(row);
+        }
+
+        let removed_line = if self.is_there_horizontal(row + 1) {
+            row
+        } else {
+            row + 1
+        };
+        let shift = |line: usize| match line.cmp(&removed_line) {
+            Ordering::Less => Some(line),
+            Ordering::Equal => None,
+            Ordering::Greater => Some(line - 1),
+        };
+        self.horizontal = remap_keys(std::mem::take(&mut self.horizontal), shift);
+        self.intersections = remap_keys(std::mem::take(&mut self.intersections), |(r, c)| {
+            Some((shift(r)?, c))
+        });
+
+        self.count_rows -= 1;
+    }
+
+    // a new column has no borders of its own
+    fn insert_column(&mut self, column: usize, missing_line: usize) {
+        for line in self.horizontal.values_mut() {
+            line.insert(column, DEFAULT_SPLIT_BORDER_CHAR);
+        }
+
+        let shift = |line: usize| Some(if line >= missing_line { line + 1 } else { line });
+        self.vertical = remap_keys(std::mem::take(&mut self.vertical), shift);
+        self.intersections = remap_keys(std::mem::take(&mut self.intersections), |(r, c)| {
+            Some((r, shift(c)?))
+        });
+
+        self.count_columns += 1;
+    }
+
+    // a line after the column replaces a line before it in case it's present
+    fn remove_column(&mut self, column: usize) {
+        for line in self.horizontal.values_mut() {
+            line.remove(column);
+        }
+
+        let removed_line = if self.is_there_vertical(column + 1) {
+            column
+        } else {
+            column + 1
+        };
+        let shift = |line: usize| match line.cmp(&removed_line) {
+            Ordering::Less => Some(line),
+            Ordering::Equal => None,
+            Ordering::Greater => Some(line - 1),
+        };
+        self.vertical = remap_keys(std::mem::take(&mut self.vertical), shift);
+        self.intersections = remap_keys(std::mem::take(&mut self.intersections), |(r, c)| {
+            Some((r, shift(c)?))
+        });
+
+        self.count_columns -= 1;
+    }
+
     fn clear(&mut self) {
         self.horizontal.clear();
         self.vertical.clear();
@@ -1736,6 +4238,41 @@ enum BorderError {
     NotEnoughIntersections,
 }
 
+// keys which are mapped to nothing are dropped
+fn remap_keys<K, V, M, F>(map: M, f: F) -> M
+where
+    M: IntoIterator<Item = (K, V)> + FromIterator<(K, V)>,
+    F: Fn(K) -> Option<K>,
+{
+    map.into_iter()
+        .filter_map(|(key, value)| Some((f(key)?, value)))
+        .collect()
+}
+
+fn remap_entities<R, C>(styles: HashMap<Entity, Style>, row: R, column: C) -> HashMap<Entity, Style>
+where
+    R: Fn(usize) -> Option<usize>,
+    C: Fn(usize) -> Option<usize>,
+{
+    styles
+        .into_iter()
+        .filter_map(|(entity, style)| Some((map_entity(&entity, &row, &column)?, style)))
+        .collect()
+}
+
+fn map_entity<R, C>(entity: &Entity, row: R, column: C) -> Option<Entity>
+where
+    R: Fn(usize) -> Option<usize>,
+    C: Fn(usize) -> Option<usize>,
+{
+    match *entity {
+        Entity::Global => Some(Entity::Global),
+        Entity::Column(c) => Some(Entity::Column(column(c)?)),
+        Entity::Row(r) => Some(Entity::Row(row(r)?)),
+        Entity::Cell(r, c) => Some(Entity::Cell(row(r)?, column(c)?)),
+    }
+}
+
 fn entity_frame(entity: &Entity, count_rows: usize, count_columns: usize) -> EntityFrame {
     match entity {
         Entity::Global => EntityFrame::new(0, count_columns, 0, count_rows),
@@ -1782,7 +4319,15 @@ mod tests {
 
         impl fmt::Display for F<'_> {
             fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-                self.1.align(f, self.0, self.2)
+                self.1.align(f, self.0, self.2, ' ', false)
+            }
+        }
+
+        impl F<'_> {
+            fn with_extra_left(&self) -> String {
+                let mut text = String::new();
+                self.1.align(&mut text, self.0, self.2, ' ', true).unwrap();
+                text
             }
         }
 
@@ -1791,6 +4336,14 @@ mod tests {
         assert_eq!(F("AAA", AlignmentHorizontal::Center, 4).to_string(), "AAA ");
         assert_eq!(F("🎩", AlignmentHorizontal::Center, 4).to_string(), " 🎩 ");
         assert_eq!(F("🎩", AlignmentHorizontal::Center, 3).to_string(), "🎩 ");
+        assert_eq!(
+            F("AAA", AlignmentHorizontal::Center, 4).with_extra_left(),
+            " AAA"
+        );
+        assert_eq!(
+            F("AAA", AlignmentHorizontal::Center, 5).with_extra_left(),
+            " AAA "
+        );
         #[cfg(feature = "color")]
         {
             use colored::Colorize;
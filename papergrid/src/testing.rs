@@ -0,0 +1,222 @@
+//! The module contains generators of arbitrary grids and checkers of layout invariants.
+//!
+//! It's supposed to be used for property testing.
+//!
+//! # Example
+//!
+//! ```rust
+//!     use papergrid::testing::{check_lines_width, ArbitraryGrid};
+//!     use quickcheck::{Gen, Arbitrary};
+//!
+//!     let grid = ArbitraryGrid::arbitrary(&mut Gen::new(10)).without_spans();
+//!     assert!(check_lines_width(&grid.build().to_string()).is_ok());
+//! ```
+
+use std::fmt;
+
+use quickcheck::{Arbitrary, Gen};
+
+use crate::{
+    AlignmentHorizontal, AlignmentVertical, Border, Entity, Grid, Settings, DEFAULT_CELL_STYLE,
+};
+
+const TEXTS: &[&str] = &[
+    "",
+    "0",
+    "Hello World",
+    "multi\nline",
+    "\n",
+    "some\n\ntext\n",
+    "👍",
+    "Rust 💕",
+    "中文",
+    "   ",
+    "ℕ∈ℤ",
+];
+
+/// ArbitraryGrid is a description of a grid which can be generated by [quickcheck].
+///
+/// The amount of rows and columns is limited by a size of [Gen].
+/// Use [ArbitraryGrid::build] to get a [Grid].
+#[derive(Debug, Clone)]
+pub struct ArbitraryGrid {
+    pub count_rows: usize,
+    pub count_columns: usize,
+    pub cells: Vec<Vec<ArbitraryCell>>,
+    pub borders: bool,
+}
+
+/// ArbitraryCell is a description of a cell of [ArbitraryGrid].
+#[derive(Debug, Clone)]
+pub struct ArbitraryCell {
+    pub text: String,
+    pub span: usize,
+    pub indent: (usize, usize, usize, usize),
+    pub alignment_h: AlignmentHorizontal,
+    pub alignment_v: AlignmentVertical,
+}
+
+impl ArbitraryGrid {
+    /// Builds a [Grid] from the description.
+    pub fn build(&self) -> Grid {
+        let mut grid = Grid::new(self.count_rows, self.count_columns);
+        if self.borders {
+            grid.set_cell_borders(DEFAULT_CELL_STYLE.clone());
+        } else {
+            grid.set_cell_borders(Border::default());
+        }
+
+        for (row, cells) in self.cells.iter().enumerate() {
+            for (column, cell) in cells.iter().enumerate() {
+                let (left, right, top, bottom) = cell.indent;
+                grid.set(
+                    &Entity::Cell(row, column),
+                    Settings::new()
+                        .text(&cell.text)
+                        .span(cell.span)
+                        .indent(left, right, top, bottom)
+                        .alignment(cell.alignment_h)
+                        .vertical_alignment(cell.alignment_v),
+                );
+            }
+        }
+
+        grid
+    }
+
+    /// Removes spans of all cells.
+    pub fn without_spans(mut self) -> Self {
+        for cell in self.cells.iter_mut().flatten() {
+            cell.span = 1;
+        }
+
+        self
+    }
+}
+
+impl Arbitrary for ArbitraryGrid {
+    fn arbitrary(g: &mut Gen) -> Self {
+        let size = std::cmp::max(g.size(), 1);
+        let count_rows = usize::arbitrary(g) % size;
+        let count_columns = usize::arbitrary(g) % size;
+
+        let cells = (0..count_rows)
+            .map(|_| {
+                (0..count_columns)
+                    .map(|column| ArbitraryCell::generate(g, count_columns - column))
+                    .collect()
+            })
+            .collect();
+
+        Self {
+            count_rows,
+            count_columns,
+            cells,
+            borders: bool::arbitrary(g),
+        }
+    }
+
+    fn shrink(&self) -> Box<dyn Iterator<Item = Self>> {
+        let mut shrinks = Vec::new();
+
+        if self.count_rows > 0 {
+            let mut grid = self.clone();
+            grid.count_rows -= 1;
+            grid.cells.pop();
+            shrinks.push(grid);
+        }
+
+        if self.count_columns > 0 {
+            let mut grid = self.clone();
+            grid.count_columns -= 1;
+            for cells in &mut grid.cells {
+                cells.pop();
+                for (column, cell) in cells.iter_mut().enumerate() {
+                    cell.span = std::cmp::min(cell.span, grid.count_columns - column);
+                }
+            }
+
+            shrinks.push(grid);
+        }
+
+        Box::new(shrinks.into_iter())
+    }
+}
+
+impl ArbitraryCell {
+    fn generate(g: &mut Gen, max_span: usize) -> Self {
+        // most of the cells are not spanned as it's a common case
+        let span = if u8::arbitrary(g) % 4 == 0 {
+            1 + usize::arbitrary(g) % max_span
+        } else {
+            1
+        };
+
+        let mut indent = || usize::arbitrary(g) % 3;
+        let indent = (indent(), indent(), indent(), indent());
+
+        let alignment_h = *g
+            .choose(&[
+                AlignmentHorizontal::Left,
+                AlignmentHorizontal::Center,
+                AlignmentHorizontal::Right,
+            ])
+            .unwrap();
+        let alignment_v = *g
+            .choose(&[
+                AlignmentVertical::Top,
+                AlignmentVertical::Center,
+                AlignmentVertical::Bottom,
+            ])
+            .unwrap();
+
+        Self {
+            text: g.choose(TEXTS).unwrap().to_string(),
+            span,
+            indent,
+            alignment_h,
+            alignment_v,
+        }
+    }
+}
+
+/// LinesWidthError is returned by [check_lines_width] when lines have different widths.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LinesWidthError {
+    /// An index of the first line which width is different from the width of the first line.
+    pub line: usize,
+    /// A width of the line.
+    pub width: usize,
+    /// A width of the first line.
+    pub expected: usize,
+}
+
+impl fmt::Display for LinesWidthError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "line {} has width {} but expected {}",
+            self.line, self.width, self.expected
+        )
+    }
+}
+
+impl std::error::Error for LinesWidthError {}
+
+/// Checks that every line of a rendered grid has the same display width.
+pub fn check_lines_width(text: &str) -> Result<(), LinesWidthError> {
+    let mut lines = text.lines().map(crate::string_width).enumerate();
+    let expected = match lines.next() {
+        Some((_, width)) => width,
+        None => return Ok(()),
+    };
+
+    match lines.find(|&(_, width)| width != expected) {
+        Some((line, width)) => Err(LinesWidthError {
+            line,
+            width,
+            expected,
+        }),
+        None => Ok(()),
+    }
+}
@@ -0,0 +1,18 @@
+use papergrid::{Entity, Grid, Settings};
+
+#[test]
+fn debug_shows_structure() {
+    let mut grid = Grid::new(2, 2);
+    grid.set(&Entity::Cell(0, 0), Settings::new().text("a").span(2));
+    grid.set_row_spacing(1, 2);
+    grid.add_horizontal_split(1);
+
+    let debug = format!("{:?}", grid);
+
+    assert!(debug
+        .starts_with("Grid { size: (2, 2), header_rows: 1, cells: [[\"a\", \"\"], [\"\", \"\"]]"));
+    assert!(debug.contains("spans: {(0, 0): (2, 1)}"));
+    assert!(debug.contains("split_lines: [Horizontal(1)]"));
+    assert!(debug.contains("row_spacing: {1: 2}"));
+    assert!(!debug.contains("+"));
+}
@@ -0,0 +1,134 @@
+use papergrid::{Entity, Grid, GridEvent, Settings, DEFAULT_CELL_STYLE};
+
+#[test]
+fn hide_empty_rows_and_columns() {
+    let mut grid = Grid::new(3, 3);
+    grid.set_cell_borders(DEFAULT_CELL_STYLE.clone());
+    grid.set(&Entity::Cell(0, 0), Settings::new().text("0-0"));
+    grid.set(&Entity::Cell(0, 2), Settings::new().text("0-2"));
+    grid.set(&Entity::Cell(2, 0), Settings::new().text("2-0"));
+
+    grid.hide_empty_rows(true);
+    grid.hide_empty_columns(true);
+
+    let expected = concat!(
+        "+---+---+\n",
+        "|0-0|0-2|\n",
+        "+---+---+\n",
+        "|2-0|   |\n",
+        "+---+---+\n",
+    );
+
+    assert_eq!(expected, grid.to_string());
+
+    grid.hide_empty_rows(false);
+
+    let expected = concat!(
+        "+---+---+\n",
+        "|0-0|0-2|\n",
+        "+---+---+\n",
+        "|   |   |\n",
+        "+---+---+\n",
+        "|2-0|   |\n",
+        "+---+---+\n",
+    );
+
+    assert_eq!(expected, grid.to_string());
+    assert_eq!(grid.count_rows(), 3);
+    assert_eq!(grid.count_columns(), 3);
+}
+
+#[test]
+fn hide_empty_columns_under_span() {
+    let mut grid = Grid::new(2, 3);
+    grid.set_cell_borders(DEFAULT_CELL_STYLE.clone());
+    grid.set(&Entity::Cell(0, 0), Settings::new().text("spanned").span(2));
+    grid.set(&Entity::Cell(1, 0), Settings::new().text("1-0"));
+    grid.set(&Entity::Cell(1, 2), Settings::new().text("1-2"));
+
+    // a column is filled by a spanned cell
+    grid.hide_empty_columns(true);
+
+    let expected = concat!(
+        "+-----+-+---+\n",
+        "|spanned|   |\n",
+        "+-----+-+---+\n",
+        "|1-0  | |1-2|\n",
+        "+-----+-+---+\n",
+    );
+
+    assert_eq!(expected, grid.to_string());
+
+    grid.set(&Entity::Cell(0, 0), Settings::new().text(""));
+
+    let expected = concat!(
+        "+---+---+\n",
+        "|   |   |\n",
+        "+---+---+\n",
+        "|1-0|1-2|\n",
+        "+---+---+\n",
+    );
+
+    assert_eq!(expected, grid.to_string());
+}
+
+#[test]
+fn hide_empty_rows_keeps_top_override() {
+    let mut grid = Grid::new(3, 1);
+    grid.set_cell_borders(DEFAULT_CELL_STYLE.clone());
+    grid.set(&Entity::Cell(1, 0), Settings::new().text("1-0"));
+    grid.override_split_line(0, "T");
+    grid.override_split_line(1, "X");
+
+    grid.hide_empty_rows(true);
+
+    let expected = concat!("T---+\n", "|1-0|\n", "+---+\n",);
+
+    assert_eq!(expected, grid.to_string());
+}
+
+#[test]
+fn hide_empty_rows_and_columns_layout() {
+    let mut grid = Grid::new(3, 3);
+    grid.set_cell_borders(DEFAULT_CELL_STYLE.clone());
+    grid.set(&Entity::Cell(0, 0), Settings::new().text("0-0"));
+    grid.set(&Entity::Cell(0, 2), Settings::new().text("0-2"));
+    grid.set(&Entity::Cell(2, 0), Settings::new().text("2-0"));
+
+    grid.hide_empty_rows(true);
+    grid.hide_empty_columns(true);
+
+    // +---+---+
+    // |0-0|0-2|
+    // +---+---+
+    // |2-0|   |
+    // +---+---+
+    let layout = grid
+        .layout()
+        .into_iter()
+        .map(|cell| ((cell.row, cell.column), (cell.x, cell.y, cell.width)))
+        .collect::<Vec<_>>();
+
+    assert_eq!(
+        vec![
+            ((0, 0), (1, 1, 3)),
+            ((0, 2), (5, 1, 3)),
+            ((2, 0), (1, 3, 3)),
+            ((2, 2), (5, 3, 3)),
+        ],
+        layout
+    );
+    assert_eq!(vec![3, 0, 3], grid.columns_widths());
+
+    let mut events = Vec::new();
+    grid.visit(&mut |event: GridEvent<'_>| match event {
+        GridEvent::Cell { row, column, .. } => events.push(format!("cell {} {}", row, column)),
+        GridEvent::SplitLine { row } => events.push(format!("line {}", row)),
+        _ => (),
+    });
+
+    assert_eq!(
+        vec!["line 0", "cell 0 0", "cell 0 2", "line 2", "cell 2 0", "cell 2 2", "line 3",],
+        events
+    );
+}
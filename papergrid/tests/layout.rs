@@ -0,0 +1,99 @@
+use papergrid::{CellRect, Entity, Grid, Settings, DEFAULT_CELL_STYLE};
+
+#[test]
+fn layout_2x2() {
+    let mut grid = Grid::new(2, 2);
+    grid.set_cell_borders(DEFAULT_CELL_STYLE.clone());
+    grid.set(&Entity::Cell(0, 0), Settings::new().text("0-0").span(2));
+    grid.set(&Entity::Cell(1, 0), Settings::new().text("1-0\n1-0"));
+    grid.set(&Entity::Cell(1, 1), Settings::new().text("1-11"));
+
+    // +--------+
+    // |0-0     |
+    // +---+----+
+    // |1-0|1-11|
+    // |1-0|    |
+    // +---+----+
+    assert_eq!(
+        grid.layout(),
+        [
+            CellRect {
+                row: 0,
+                column: 0,
+                x: 1,
+                y: 1,
+                width: 8,
+                height: 1
+            },
+            CellRect {
+                row: 1,
+                column: 0,
+                x: 1,
+                y: 3,
+                width: 3,
+                height: 2
+            },
+            CellRect {
+                row: 1,
+                column: 1,
+                x: 5,
+                y: 3,
+                width: 4,
+                height: 2
+            },
+        ]
+    );
+}
+
+#[test]
+fn layout_without_borders() {
+    let mut grid = Grid::new(2, 2);
+    grid.set(
+        &Entity::Global,
+        Settings::new().text("a").indent(1, 1, 0, 0),
+    );
+
+    let layout = grid.layout();
+    let cell = layout.iter().find(|cell| cell.contains(4, 1)).unwrap();
+
+    assert_eq!((cell.row, cell.column, cell.x, cell.y), (1, 1, 3, 1));
+    assert!(layout.iter().all(|cell| !cell.contains(6, 0)));
+}
+
+#[test]
+fn layout_span_over_rows() {
+    let mut grid = Grid::new(3, 2);
+    grid.set_cell_borders(DEFAULT_CELL_STYLE.clone());
+    grid.set(&Entity::Global, Settings::new().text("x"));
+    grid.set(&Entity::Cell(0, 0), Settings::new().text("0-0").row_span(2));
+
+    // +---+-+
+    // |0-0|x|
+    // |   +-+
+    // |   |x|
+    // +---+-+
+    // |x  |x|
+    // +---+-+
+    let layout = grid.layout();
+
+    assert_eq!(layout.len(), 5);
+    assert_eq!(
+        layout[0],
+        CellRect {
+            row: 0,
+            column: 0,
+            x: 1,
+            y: 1,
+            width: 3,
+            height: 3
+        }
+    );
+    assert!(layout.iter().all(|cell| (cell.row, cell.column) != (1, 0)));
+    assert_eq!(
+        layout
+            .iter()
+            .find(|cell| cell.contains(2, 3))
+            .map(|cell| (cell.row, cell.column)),
+        Some((0, 0))
+    );
+}
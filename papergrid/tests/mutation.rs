@@ -0,0 +1,259 @@
+use papergrid::{Entity, Grid, Settings, DEFAULT_CELL_STYLE};
+
+#[test]
+fn insert_row_test() {
+    let mut grid = new_grid::<2, 2>();
+
+    grid.insert_row(1);
+    for column in 0..2 {
+        grid.set(
+            &Entity::Cell(1, column),
+            Settings::new()
+                .text("new")
+                .border(DEFAULT_CELL_STYLE.clone())
+                .border_restriction(false),
+        );
+    }
+
+    assert_eq!(grid.count_rows(), 3);
+    assert_eq!(
+        grid.to_string(),
+        "+---+---+\n\
+         |0-0|0-1|\n\
+         +---+---+\n\
+         |new|new|\n\
+         +---+---+\n\
+         |1-0|1-1|\n\
+         +---+---+\n"
+    )
+}
+
+#[test]
+fn insert_row_without_borders_test() {
+    let mut grid = new_grid::<2, 2>();
+
+    grid.insert_row(2);
+
+    assert_eq!(
+        grid.to_string(),
+        "+---+---+\n\
+         |0-0|0-1|\n\
+         +---+---+\n\
+         |1-0|1-1|\n\
+         +---+---+\n\
+         \x20\x20\x20\x20\x20\x20\x20\x20\x20\n"
+    )
+}
+
+#[test]
+fn insert_row_extends_row_span_test() {
+    let mut grid = new_grid::<2, 2>();
+    grid.set(&Entity::Cell(0, 0), Settings::new().row_span(2));
+
+    grid.insert_row(1);
+
+    assert_eq!(grid.style(&Entity::Cell(0, 0)).row_span, 3);
+    assert_eq!(grid.get_cell_content(2, 1), "1-1");
+}
+
+#[test]
+fn insert_row_shifts_header_test() {
+    let mut grid = new_grid::<2, 2>();
+    grid.set_header_rows(1);
+    grid.set_row_spacing(1, 2);
+
+    grid.insert_row(0);
+
+    assert_eq!(grid.count_header_rows(), 2);
+    assert_eq!(grid.get_row_spacing(1), 0);
+    assert_eq!(grid.get_row_spacing(2), 2);
+
+    grid.insert_row(3);
+
+    assert_eq!(grid.count_header_rows(), 2);
+}
+
+#[test]
+fn remove_row_test() {
+    let mut grid = new_grid::<3, 2>();
+
+    grid.remove_row(1);
+
+    assert_eq!(grid.count_rows(), 2);
+    assert_eq!(
+        grid.to_string(),
+        "+---+---+\n\
+         |0-0|0-1|\n\
+         +---+---+\n\
+         |2-0|2-1|\n\
+         +---+---+\n"
+    )
+}
+
+#[test]
+fn remove_row_keeps_line_below_test() {
+    let mut grid = new_grid::<3, 1>();
+    grid.set(
+        &Entity::Row(1),
+        Settings::new().border(papergrid::Border::full(
+            '=', '=', '|', '|', '#', '#', '#', '#',
+        )),
+    );
+
+    grid.remove_row(0);
+
+    assert_eq!(
+        grid.to_string(),
+        "#===#\n\
+         |1-0|\n\
+         #===#\n\
+         |2-0|\n\
+         +---+\n"
+    )
+}
+
+#[test]
+fn remove_row_under_row_span_test() {
+    let mut grid = new_grid::<3, 2>();
+    grid.set(&Entity::Cell(0, 0), Settings::new().row_span(3));
+
+    grid.remove_row(1);
+
+    assert_eq!(grid.style(&Entity::Cell(0, 0)).row_span, 2);
+    assert_eq!(
+        grid.to_string(),
+        "+---+---+\n\
+         |0-0|0-1|\n\
+         |   +---+\n\
+         |   |2-1|\n\
+         +---+---+\n"
+    )
+}
+
+#[test]
+fn remove_row_spanned_from_test() {
+    let mut grid = new_grid::<3, 2>();
+    grid.set(&Entity::Cell(0, 0), Settings::new().row_span(2));
+
+    grid.remove_row(0);
+
+    assert_eq!(grid.style(&Entity::Cell(0, 0)).row_span, 1);
+    assert_eq!(grid.get_cell_content(0, 0), "0-0");
+    assert_eq!(grid.get_cell_content(0, 1), "1-1");
+}
+
+#[test]
+fn insert_column_test() {
+    let mut grid = new_grid::<2, 2>();
+
+    grid.insert_column(0);
+    for row in 0..2 {
+        grid.set(
+            &Entity::Cell(row, 0),
+            Settings::new()
+                .text("new")
+                .border(DEFAULT_CELL_STYLE.clone())
+                .border_restriction(false),
+        );
+    }
+
+    assert_eq!(grid.count_columns(), 3);
+    assert_eq!(
+        grid.to_string(),
+        "+---+---+---+\n\
+         |new|0-0|0-1|\n\
+         +---+---+---+\n\
+         |new|1-0|1-1|\n\
+         +---+---+---+\n"
+    )
+}
+
+#[test]
+fn insert_column_extends_span_test() {
+    let mut grid = new_grid::<2, 3>();
+    grid.set(&Entity::Cell(0, 0), Settings::new().span(3));
+    grid.set_column_min_width(1, 5);
+
+    grid.insert_column(1);
+
+    assert_eq!(grid.style(&Entity::Cell(0, 0)).span, 4);
+    assert_eq!(grid.get_column_min_width(1), 0);
+    assert_eq!(grid.get_column_min_width(2), 5);
+}
+
+#[test]
+fn remove_column_test() {
+    let mut grid = new_grid::<2, 3>();
+
+    grid.remove_column(2);
+
+    assert_eq!(grid.count_columns(), 2);
+    assert_eq!(
+        grid.to_string(),
+        "+---+---+\n\
+         |0-0|0-1|\n\
+         +---+---+\n\
+         |1-0|1-1|\n\
+         +---+---+\n"
+    )
+}
+
+#[test]
+fn remove_column_under_span_test() {
+    let mut grid = new_grid::<2, 3>();
+    grid.set(&Entity::Cell(0, 0), Settings::new().span(3));
+
+    grid.remove_column(1);
+
+    assert_eq!(
+        grid.to_string(),
+        "+---+---+\n\
+         |0-0    |\n\
+         +---+---+\n\
+         |1-0|1-2|\n\
+         +---+---+\n"
+    )
+}
+
+#[test]
+fn remove_column_spanned_from_test() {
+    let mut grid = new_grid::<2, 3>();
+    grid.set(&Entity::Cell(0, 0), Settings::new().span(3));
+
+    grid.remove_column(0);
+
+    assert_eq!(
+        grid.to_string(),
+        "+---+---+\n\
+         |0-0    |\n\
+         +---+---+\n\
+         |1-1|1-2|\n\
+         +---+---+\n"
+    )
+}
+
+#[test]
+fn remove_all_columns_test() {
+    let mut grid = new_grid::<2, 2>();
+
+    grid.remove_column(1);
+    grid.remove_column(0);
+
+    assert_eq!(grid.count_columns(), 0);
+    assert_eq!(grid.to_string(), "");
+}
+
+fn new_grid<const N_ROWS: usize, const N_COLUMNS: usize>() -> Grid {
+    let mut grid = Grid::new(N_ROWS, N_COLUMNS);
+
+    for row in 0..N_ROWS {
+        for column in 0..N_COLUMNS {
+            let text = format!("{}-{}", row, column);
+            grid.set(&Entity::Cell(row, column), Settings::new().text(text));
+        }
+    }
+
+    grid.set_cell_borders(DEFAULT_CELL_STYLE.clone());
+
+    grid
+}
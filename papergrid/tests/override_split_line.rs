@@ -1,4 +1,4 @@
-use papergrid::{Entity, Grid, Settings, DEFAULT_CELL_STYLE};
+use papergrid::{AlignmentHorizontal, Entity, Grid, Settings, DEFAULT_CELL_STYLE};
 
 #[test]
 fn override_top_test() {
@@ -163,3 +163,64 @@ fn override_bottom_test() {
 
     assert_eq!(expected, grid.to_string());
 }
+
+#[test]
+fn override_with_wide_chars_test() {
+    let mut grid = Grid::new(2, 2);
+    grid.set_cell_borders(DEFAULT_CELL_STYLE.clone());
+    grid.set(&Entity::Cell(0, 0), Settings::new().text("0-0"));
+    grid.set(&Entity::Cell(0, 1), Settings::new().text("0-1"));
+    grid.set(&Entity::Cell(1, 0), Settings::new().text("1-0"));
+    grid.set(&Entity::Cell(1, 1), Settings::new().text("1-1"));
+
+    grid.override_split_line(0, "表格");
+
+    let expected = concat!(
+        "表格+---+\n",
+        "|0-0|0-1|\n",
+        "+---+---+\n",
+        "|1-0|1-1|\n",
+        "+---+---+\n",
+    );
+
+    assert_eq!(expected, grid.to_string());
+
+    // a wide char which doesn't fit is left out
+    grid.override_split_line(0, "TT表格表格");
+
+    let expected = concat!(
+        "TT表格表+\n",
+        "|0-0|0-1|\n",
+        "+---+---+\n",
+        "|1-0|1-1|\n",
+        "+---+---+\n",
+    );
+
+    assert_eq!(expected, grid.to_string());
+}
+
+#[test]
+fn override_aligned_test() {
+    let mut grid = Grid::new(1, 2);
+    grid.set_cell_borders(DEFAULT_CELL_STYLE.clone());
+    grid.set(&Entity::Cell(0, 0), Settings::new().text("0-0"));
+    grid.set(&Entity::Cell(0, 1), Settings::new().text("0-1"));
+
+    grid.override_split_line_aligned(0, "表", AlignmentHorizontal::Center);
+
+    let expected = concat!("+--表---+\n", "|0-0|0-1|\n", "+---+---+\n",);
+
+    assert_eq!(expected, grid.to_string());
+
+    grid.override_split_line_aligned(1, "Tab表", AlignmentHorizontal::Right);
+
+    let expected = concat!("+--表---+\n", "|0-0|0-1|\n", "+---Tab表\n",);
+
+    assert_eq!(expected, grid.to_string());
+
+    grid.override_split_line_aligned(0, "Table LONGER THEN LINE", AlignmentHorizontal::Right);
+
+    let expected = concat!("Table LON\n", "|0-0|0-1|\n", "+---Tab表\n",);
+
+    assert_eq!(expected, grid.to_string());
+}
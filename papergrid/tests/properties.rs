@@ -0,0 +1,29 @@
+#![cfg(feature = "testing")]
+
+use papergrid::testing::{check_lines_width, ArbitraryGrid};
+use quickcheck::{Gen, QuickCheck, TestResult};
+
+#[test]
+fn lines_have_equal_width() {
+    // spans are not covered as a layout of narrow spanned cells is known to be broken
+    fn property(grid: ArbitraryGrid) -> TestResult {
+        let table = grid.without_spans().build().to_string();
+        match check_lines_width(&table) {
+            Ok(()) => TestResult::passed(),
+            Err(err) => TestResult::error(format!("{}\n{}", err, table)),
+        }
+    }
+
+    QuickCheck::new()
+        .rng(Gen::new(10))
+        .tests(500)
+        .quickcheck(property as fn(ArbitraryGrid) -> TestResult);
+}
+
+#[test]
+fn check_lines_width_reports_a_line() {
+    let err = check_lines_width("+--+\n|ab|\n|abc|\n").unwrap_err();
+    assert_eq!(err.line, 2);
+    assert_eq!(err.width, 5);
+    assert_eq!(err.expected, 4);
+}
@@ -11,7 +11,8 @@
 // copies or substantial portions of the Software.
 
 use papergrid::{
-    AlignmentHorizontal, AlignmentVertical, Entity, Grid, Settings, DEFAULT_CELL_STYLE,
+    AlignmentHorizontal, AlignmentVertical, Border, Entity, Grid, Settings, SpanAlignment,
+    DEFAULT_CELL_STYLE,
 };
 
 #[test]
@@ -744,3 +745,280 @@ fn hieroglyph_multiline_handling() {
          +----+--+\n"
     )
 }
+
+#[test]
+fn render_doesnt_depend_on_split_lines_order() {
+    let build = |reversed: bool| {
+        let mut grid = Grid::new(5, 5);
+        let mut indexes = (0..6).collect::<Vec<_>>();
+        if reversed {
+            indexes.reverse();
+        }
+
+        for &i in &indexes {
+            grid.add_horizontal_split(i);
+        }
+
+        for &i in &indexes {
+            grid.add_vertical_split(i);
+        }
+
+        for row in 0..5 {
+            for column in 0..5 {
+                let c = char::from(b'a' + (row * 5 + column) as u8);
+                grid.set(
+                    &Entity::Cell(row, column),
+                    Settings::new()
+                        .text(c.to_string())
+                        .border(Border::full('-', '-', '|', '|', c, c, c, c)),
+                );
+            }
+        }
+
+        grid.to_string()
+    };
+
+    let expected = build(false);
+    for _ in 0..10 {
+        assert_eq!(build(false), expected);
+        assert_eq!(build(true), expected);
+    }
+}
+
+#[test]
+fn render_column_min_width() {
+    let mut grid = Grid::new(2, 2);
+    grid.set_cell_borders(DEFAULT_CELL_STYLE.clone());
+
+    grid.set(&Entity::Cell(0, 0), Settings::new().text("0-0"));
+    grid.set(&Entity::Cell(0, 1), Settings::new().text("0-1"));
+    grid.set(&Entity::Cell(1, 0), Settings::new().text("1-0"));
+    grid.set(&Entity::Cell(1, 1), Settings::new().text("1-1"));
+    grid.set_column_min_width(1, 5);
+
+    let expected = concat!(
+        "+---+-----+\n",
+        "|0-0|0-1  |\n",
+        "+---+-----+\n",
+        "|1-0|1-1  |\n",
+        "+---+-----+\n",
+    );
+
+    assert_eq!(expected, grid.to_string());
+    assert_eq!(grid.get_column_min_width(1), 5);
+    assert_eq!(grid.get_column_min_width(0), 0);
+}
+
+#[test]
+fn columns_widths() {
+    let mut grid = Grid::new(2, 2);
+    grid.set_cell_borders(DEFAULT_CELL_STYLE.clone());
+
+    grid.set(&Entity::Cell(0, 0), Settings::new().text("0-0"));
+    grid.set(&Entity::Cell(0, 1), Settings::new().text("0-1"));
+    grid.set(&Entity::Cell(1, 0), Settings::new().text("1-0000"));
+    grid.set(
+        &Entity::Cell(1, 1),
+        Settings::new().text("1-1").indent(1, 1, 0, 0),
+    );
+
+    assert_eq!(grid.columns_widths(), vec![6, 5]);
+}
+
+#[test]
+fn render_fill() {
+    let mut grid = Grid::new(1, 2);
+    grid.set_cell_borders(DEFAULT_CELL_STYLE.clone());
+
+    grid.set(
+        &Entity::Cell(0, 0),
+        Settings::new()
+            .text("0-0")
+            .indent(1, 1, 0, 1)
+            .alignment(AlignmentHorizontal::Center)
+            .fill('.'),
+    );
+    grid.set(&Entity::Cell(0, 1), Settings::new().text("0-1"));
+
+    let expected = concat!(
+        "+-----+---+\n",
+        "|.0-0.|0-1|\n",
+        "|.....|   |\n",
+        "+-----+---+\n",
+    );
+
+    assert_eq!(expected, grid.to_string());
+}
+
+#[test]
+fn render_inner_vertical_separator() {
+    let mut grid = Grid::new(2, 3);
+    grid.set_cell_borders(DEFAULT_CELL_STYLE.clone());
+
+    grid.set(&Entity::Cell(0, 0), Settings::new().text("0-0"));
+    grid.set(&Entity::Cell(0, 1), Settings::new().text("0-1"));
+    grid.set(&Entity::Cell(0, 2), Settings::new().text("0-2"));
+    grid.set(&Entity::Cell(1, 0), Settings::new().text("1-0").span(2));
+    grid.set(&Entity::Cell(1, 2), Settings::new().text("1-2"));
+    grid.set_inner_vertical_separator(" :: ");
+
+    let expected = concat!(
+        "+----++-----++----+\n",
+        "|0-0 :: 0-1 :: 0-2|\n",
+        "+----++-----++----+\n",
+        "|1-0        :: 1-2|\n",
+        "+----++-----++----+\n",
+    );
+
+    assert_eq!(expected, grid.to_string());
+    assert_eq!(grid.get_inner_vertical_separator(), Some(" :: "));
+}
+
+#[test]
+fn render_span_over_rows() {
+    let mut grid = Grid::new(3, 2);
+    grid.set_cell_borders(DEFAULT_CELL_STYLE.clone());
+
+    grid.set(&Entity::Cell(0, 0), Settings::new().text("0-0").row_span(2));
+    grid.set(&Entity::Cell(0, 1), Settings::new().text("0-1"));
+    grid.set(&Entity::Cell(1, 0), Settings::new().text("1-0"));
+    grid.set(&Entity::Cell(1, 1), Settings::new().text("1-1"));
+    grid.set(&Entity::Cell(2, 0), Settings::new().text("2-0"));
+    grid.set(&Entity::Cell(2, 1), Settings::new().text("2-1"));
+
+    let expected = concat!(
+        "+---+---+\n",
+        "|0-0|0-1|\n",
+        "|   +---+\n",
+        "|   |1-1|\n",
+        "+---+---+\n",
+        "|2-0|2-1|\n",
+        "+---+---+\n",
+    );
+
+    assert_eq!(expected, grid.to_string());
+}
+
+#[test]
+fn render_span_over_rows_increases_last_row() {
+    let mut grid = Grid::new(3, 2);
+    grid.set_cell_borders(DEFAULT_CELL_STYLE.clone());
+
+    grid.set(&Entity::Global, Settings::new().text("x"));
+    grid.set(
+        &Entity::Cell(0, 1),
+        Settings::new().text("0\n1\n2\n3\n4").row_span(2),
+    );
+
+    let expected = concat!(
+        "+-+-+\n", "|x|0|\n", "+-+1|\n", "|x|2|\n", "| |3|\n", "| |4|\n", "+-+-+\n", "|x|x|\n",
+        "+-+-+\n",
+    );
+
+    assert_eq!(expected, grid.to_string());
+}
+
+#[test]
+fn render_span_over_rows_and_columns() {
+    let mut grid = Grid::new(3, 3);
+    grid.set_cell_borders(DEFAULT_CELL_STYLE.clone());
+
+    grid.set(&Entity::Global, Settings::new().text("x"));
+    grid.set(
+        &Entity::Cell(1, 1),
+        Settings::new()
+            .text("1-1")
+            .span(2)
+            .row_span(2)
+            .alignment(AlignmentHorizontal::Center)
+            .vertical_alignment(AlignmentVertical::Center),
+    );
+
+    let expected = concat!(
+        "+-+-+-+\n",
+        "|x|x|x|\n",
+        "+-+-+-+\n",
+        "|x|   |\n",
+        "+-+1-1|\n",
+        "|x|   |\n",
+        "+-+-+-+\n",
+    );
+
+    assert_eq!(expected, grid.to_string());
+}
+
+#[test]
+fn render_span_over_rows_is_cut_by_last_row() {
+    let mut grid = Grid::new(2, 2);
+    grid.set_cell_borders(DEFAULT_CELL_STYLE.clone());
+
+    grid.set(&Entity::Global, Settings::new().text("x"));
+    grid.set(&Entity::Cell(0, 1), Settings::new().text("y").row_span(10));
+
+    let expected = concat!("+-+-+\n", "|x|y|\n", "+-+ |\n", "|x| |\n", "+-+-+\n",);
+
+    assert_eq!(expected, grid.to_string());
+}
+
+#[test]
+fn build_into_appends_to_writer() {
+    let mut grid = Grid::new(1, 2);
+    grid.set_cell_borders(DEFAULT_CELL_STYLE.clone());
+    grid.set(&Entity::Cell(0, 0), Settings::new().text("hello").span(2));
+
+    let mut buf = String::from("> ");
+    grid.build_into(&mut buf).unwrap();
+
+    assert_eq!(buf, format!("> {}", grid));
+}
+
+#[test]
+fn render_row_span_first_row_alignment() {
+    let mut grid = Grid::new(3, 2);
+    grid.set_cell_borders(DEFAULT_CELL_STYLE.clone());
+
+    grid.set(
+        &Entity::Cell(0, 0),
+        Settings::new()
+            .text("0-0")
+            .row_span(3)
+            .vertical_alignment(AlignmentVertical::Center)
+            .span_alignment(SpanAlignment::FirstRow),
+    );
+    grid.set(&Entity::Cell(0, 1), Settings::new().text("0-1\n0-1\n0-1"));
+    grid.set(&Entity::Cell(1, 1), Settings::new().text("1-1"));
+    grid.set(&Entity::Cell(2, 1), Settings::new().text("2-1"));
+
+    let expected = concat!(
+        "+---+---+\n",
+        "|   |0-1|\n",
+        "|0-0|0-1|\n",
+        "|   |0-1|\n",
+        "|   +---+\n",
+        "|   |1-1|\n",
+        "|   +---+\n",
+        "|   |2-1|\n",
+        "+---+---+\n",
+    );
+
+    assert_eq!(expected, grid.to_string());
+
+    grid.set(
+        &Entity::Cell(0, 0),
+        Settings::new().span_alignment(SpanAlignment::Merged),
+    );
+
+    let expected = concat!(
+        "+---+---+\n",
+        "|   |0-1|\n",
+        "|   |0-1|\n",
+        "|   |0-1|\n",
+        "|0-0+---+\n",
+        "|   |1-1|\n",
+        "|   +---+\n",
+        "|   |2-1|\n",
+        "+---+---+\n",
+    );
+
+    assert_eq!(expected, grid.to_string());
+}
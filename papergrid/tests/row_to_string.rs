@@ -0,0 +1,64 @@
+use papergrid::{Entity, Grid, Settings, DEFAULT_CELL_STYLE};
+
+#[test]
+fn row_to_string_test() {
+    let grid = new_grid::<3, 2>();
+
+    assert_eq!(grid.row_to_string(0), "|0-0|0-1|\n");
+    assert_eq!(grid.row_to_string(2), "|2-0|2-1|\n");
+}
+
+#[test]
+fn row_to_string_uses_widths_of_grid_test() {
+    let mut grid = new_grid::<2, 2>();
+    grid.set(&Entity::Cell(1, 1), Settings::new().text("long\ntext"));
+
+    assert_eq!(grid.row_to_string(0), "|0-0|0-1 |\n");
+    assert_eq!(grid.row_to_string(1), "|1-0|long|\n|   |text|\n");
+}
+
+#[test]
+fn rows_are_the_same_as_in_grid_test() {
+    let mut grid = new_grid::<3, 3>();
+    grid.set(&Entity::Cell(0, 0), Settings::new().span(2));
+    grid.set(
+        &Entity::Cell(1, 2),
+        Settings::new().row_span(2).text("a\nb\nc"),
+    );
+
+    let table = grid.to_string();
+    for row in 0..grid.count_rows() {
+        let text = grid.row_to_string(row);
+        assert!(table.contains(&text), "{:?} isn't in\n{}", text, table);
+    }
+}
+
+#[test]
+fn row_to_string_of_hidden_row_test() {
+    let mut grid = new_grid::<3, 2>();
+    grid.set(&Entity::Row(1), Settings::new().text(""));
+    grid.hide_empty_rows(true);
+
+    assert_eq!(grid.row_to_string(1), "");
+    assert_eq!(grid.row_to_string(2), "|2-0|2-1|\n");
+}
+
+#[test]
+#[should_panic]
+fn row_to_string_out_of_grid_test() {
+    new_grid::<2, 2>().row_to_string(2);
+}
+
+fn new_grid<const N_ROWS: usize, const N_COLUMNS: usize>() -> Grid {
+    let mut grid = Grid::new(N_ROWS, N_COLUMNS);
+    grid.set_cell_borders(DEFAULT_CELL_STYLE.clone());
+
+    for row in 0..N_ROWS {
+        for column in 0..N_COLUMNS {
+            let text = format!("{}-{}", row, column);
+            grid.set(&Entity::Cell(row, column), Settings::new().text(text));
+        }
+    }
+
+    grid
+}
@@ -1,4 +1,4 @@
-use papergrid::{Entity, Grid, Settings, DEFAULT_CELL_STYLE};
+use papergrid::{AlignmentHorizontal, Entity, Grid, Settings, DEFAULT_CELL_STYLE};
 
 #[test]
 fn set_global_text_2x2() {
@@ -15,3 +15,227 @@ fn set_global_text_2x2() {
          +---+---+\n"
     )
 }
+
+#[test]
+fn attributes_are_ignored_by_rendering() {
+    let mut grid = Grid::new(1, 2);
+    grid.set(&Entity::Global, Settings::new().text("asd"));
+    grid.set(
+        &Entity::Column(0),
+        Settings::new().attribute("title", "column"),
+    );
+    grid.set(
+        &Entity::Cell(0, 0),
+        Settings::new().attribute("class", "cell"),
+    );
+    grid.set_cell_borders(DEFAULT_CELL_STYLE.clone());
+
+    assert_eq!(
+        grid.get_attributes(0, 0),
+        [
+            ("title".to_owned(), "column".to_owned()),
+            ("class".to_owned(), "cell".to_owned())
+        ]
+    );
+    assert!(grid.get_attributes(0, 1).is_empty());
+    assert_eq!(
+        grid.to_string(),
+        "+---+---+\n\
+         |asd|asd|\n\
+         +---+---+\n"
+    )
+}
+
+#[test]
+fn tags_are_kept_in_settings() {
+    let mut grid = Grid::new(1, 1);
+    grid.set(
+        &Entity::Cell(0, 0),
+        Settings::new().tag("total").tag("sum").tag("total"),
+    );
+
+    assert_eq!(grid.get_tags(0, 0), ["total", "sum"]);
+
+    let mut other = Grid::new(1, 1);
+    other.set(&Entity::Cell(0, 0), grid.get_settings(0, 0));
+
+    assert_eq!(other.get_tags(0, 0), ["total", "sum"]);
+}
+
+#[test]
+fn origin_is_kept_in_settings() {
+    let mut grid = Grid::new(2, 1);
+    grid.set(&Entity::Cell(1, 0), Settings::new().origin(5, 3));
+
+    assert_eq!(grid.get_origin(0, 0), None);
+    assert_eq!(grid.get_origin(1, 0), Some((5, 3)));
+
+    let extracted = grid.extract(1.., ..);
+    assert_eq!(extracted.get_origin(0, 0), Some((5, 3)));
+}
+
+#[test]
+fn min_width() {
+    let mut grid = Grid::new(2, 2);
+    grid.set_cell_borders(DEFAULT_CELL_STYLE.clone());
+    grid.set(&Entity::Global, Settings::new().text("x"));
+    grid.set(
+        &Entity::Cell(0, 0),
+        Settings::new().indent(1, 1, 0, 0).min_width(5),
+    );
+    grid.set(&Entity::Cell(1, 0), Settings::new().span(2).min_width(7));
+
+    let expected = concat!(
+        "+-----+-+\n",
+        "| x   |x|\n",
+        "+-----+-+\n",
+        "|x      |\n",
+        "+-----+-+\n",
+    );
+
+    assert_eq!(expected, grid.to_string());
+    assert_eq!(grid.style(&Entity::Cell(0, 0)).min_width, 5);
+}
+
+#[test]
+fn min_height() {
+    let mut grid = Grid::new(2, 2);
+    grid.set_cell_borders(DEFAULT_CELL_STYLE.clone());
+    grid.set(&Entity::Global, Settings::new().text("x"));
+    grid.set(
+        &Entity::Cell(0, 0),
+        Settings::new().indent(0, 0, 1, 0).min_height(3),
+    );
+
+    let expected =
+        concat!("+-+-+\n", "| |x|\n", "|x| |\n", "| | |\n", "+-+-+\n", "|x|x|\n", "+-+-+\n",);
+
+    assert_eq!(expected, grid.to_string());
+    assert_eq!(grid.style(&Entity::Cell(0, 0)).min_height, 3);
+}
+
+#[test]
+fn fill_pattern_is_aligned_in_column() {
+    let mut grid = Grid::new(2, 2);
+    grid.set_cell_borders(DEFAULT_CELL_STYLE.clone());
+    grid.set(&Entity::Cell(0, 0), Settings::new().text("Name"));
+    grid.set(&Entity::Cell(1, 0), Settings::new().text("Version"));
+    grid.set(&Entity::Cell(0, 1), Settings::new().text("tabled"));
+    grid.set(&Entity::Cell(1, 1), Settings::new().text("0.5"));
+    grid.set(
+        &Entity::Column(0),
+        Settings::new().min_width(12).fill_right(" ."),
+    );
+    grid.set(
+        &Entity::Column(1),
+        Settings::new()
+            .alignment(AlignmentHorizontal::Right)
+            .indent(0, 1, 0, 0)
+            .fill_left("-"),
+    );
+
+    assert_eq!(
+        grid.to_string(),
+        "+------------+-------+\n\
+         |Name . . . .|tabled |\n\
+         +------------+-------+\n\
+         |Version. . .|---0.5 |\n\
+         +------------+-------+\n"
+    );
+}
+
+#[test]
+fn fill_pattern_with_wide_chars() {
+    let mut grid = Grid::new(1, 1);
+    grid.set_cell_borders(DEFAULT_CELL_STYLE.clone());
+    grid.set(
+        &Entity::Global,
+        Settings::new().text("a").min_width(6).fill_right("🎩"),
+    );
+
+    assert_eq!(grid.to_string(), "+------+\n|a 🎩🎩|\n+------+\n");
+}
+
+#[test]
+fn tab_width_is_counted_in_width() {
+    let mut grid = Grid::new(2, 1);
+    grid.set_cell_borders(DEFAULT_CELL_STYLE.clone());
+    grid.set(&Entity::Cell(0, 0), Settings::new().text("\ta"));
+    grid.set(&Entity::Cell(1, 0), Settings::new().text("b"));
+
+    assert_eq!(
+        grid.to_string(),
+        "+-----+\n\
+         |a    |\n\
+         +-----+\n\
+         |b    |\n\
+         +-----+\n"
+    );
+
+    grid.set_trim(false);
+
+    assert_eq!(
+        grid.to_string(),
+        "+-----+\n\
+         |    a|\n\
+         +-----+\n\
+         |b    |\n\
+         +-----+\n"
+    );
+
+    grid.set_tab_width(0);
+
+    assert_eq!(grid.to_string(), "+-+\n|a|\n+-+\n|b|\n+-+\n");
+}
+
+#[test]
+fn fixed_widths() {
+    let mut grid = Grid::new(2, 2);
+    grid.set_cell_borders(DEFAULT_CELL_STYLE.clone());
+    grid.set(
+        &Entity::Cell(0, 0),
+        Settings::new().text("a long header").span(2),
+    );
+    grid.set(
+        &Entity::Cell(1, 0),
+        Settings::new().text("古古古\nab").indent(1, 0, 0, 0),
+    );
+    grid.set(&Entity::Cell(1, 1), Settings::new().text("1"));
+    grid.set_fixed_widths(Some(vec![4, 3]));
+
+    assert_eq!(
+        grid.to_string(),
+        "+----+---+\n\
+         |a long h|\n\
+         +----+---+\n\
+         | 古 |1  |\n\
+         | ab |   |\n\
+         +----+---+\n"
+    );
+    assert_eq!(grid.columns_widths(), vec![4, 3]);
+
+    grid.set_fixed_widths(None);
+
+    assert_eq!(grid.columns_widths(), vec![9, 3]);
+}
+
+#[test]
+fn fixed_widths_below_indent() {
+    let mut grid = Grid::new(2, 2);
+    grid.set_cell_borders(DEFAULT_CELL_STYLE.clone());
+    grid.set(&Entity::Global, Settings::new().indent(1, 1, 0, 0));
+    grid.set(&Entity::Cell(0, 0), Settings::new().text("header").span(2));
+    grid.set(&Entity::Cell(1, 0), Settings::new().text("a"));
+    grid.set(&Entity::Cell(1, 1), Settings::new().text("b"));
+    grid.set_fixed_widths(Some(vec![1]));
+
+    assert_eq!(
+        grid.to_string(),
+        "+--+--+\n\
+         | hea |\n\
+         +--+--+\n\
+         |  |  |\n\
+         +--+--+\n"
+    );
+    assert_eq!(grid.columns_widths(), vec![2, 2]);
+}
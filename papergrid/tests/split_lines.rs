@@ -0,0 +1,50 @@
+use papergrid::{Border, Entity, Grid, Settings, Split, DEFAULT_CELL_STYLE};
+
+#[test]
+fn split_lines_of_empty_grid() {
+    let grid = Grid::new(2, 2);
+
+    assert!(!grid.has_horizontal(0));
+    assert!(!grid.has_vertical(0));
+    assert_eq!(grid.split_lines().count(), 0);
+}
+
+#[test]
+fn split_lines_of_full_grid() {
+    let mut grid = Grid::new(1, 2);
+    grid.set_cell_borders(DEFAULT_CELL_STYLE.clone());
+
+    assert!(grid.has_horizontal(0));
+    assert!(grid.has_horizontal(1));
+    assert!(!grid.has_horizontal(2));
+    assert!(grid.has_vertical(2));
+    assert_eq!(
+        grid.split_lines().collect::<Vec<_>>(),
+        [
+            Split::Horizontal(0),
+            Split::Horizontal(1),
+            Split::Vertical(0),
+            Split::Vertical(1),
+            Split::Vertical(2),
+        ]
+    );
+
+    grid.clear_split_grid();
+    assert_eq!(grid.split_lines().count(), 0);
+}
+
+#[test]
+fn split_lines_created_by_border() {
+    let mut grid = Grid::new(2, 2);
+    grid.set(
+        &Entity::Cell(1, 1),
+        Settings::new()
+            .border(Border::default().top('-').left('|'))
+            .border_restriction(false),
+    );
+
+    assert_eq!(
+        grid.split_lines().collect::<Vec<_>>(),
+        [Split::Horizontal(1), Split::Vertical(1)]
+    );
+}
@@ -271,3 +271,39 @@ fn grid_2x2_custom_border_test() {
          \u{0020}*** *** \n"
     )
 }
+
+#[test]
+fn grid_border_override_is_restored() {
+    let mut grid = Grid::new(1, 2);
+    grid.set_cell_borders(DEFAULT_CELL_STYLE.clone());
+    grid.set(&Entity::Global, Settings::new().text("asd"));
+    grid.set(
+        &Entity::Cell(0, 1),
+        Settings::new()
+            .border(Border::default().top('*'))
+            .border_override(true),
+    );
+
+    grid.clear_split_grid();
+    grid.set_cell_borders(DEFAULT_CELL_STYLE.clone());
+    grid.restore_border_overrides();
+
+    assert_eq!(
+        grid.to_string(),
+        "+---+***+\n\
+         |asd|asd|\n\
+         +---+---+\n"
+    );
+
+    grid.clear_border_overrides();
+    grid.clear_split_grid();
+    grid.set_cell_borders(DEFAULT_CELL_STYLE.clone());
+    grid.restore_border_overrides();
+
+    assert_eq!(
+        grid.to_string(),
+        "+---+---+\n\
+         |asd|asd|\n\
+         +---+---+\n"
+    );
+}
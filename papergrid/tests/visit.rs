@@ -0,0 +1,100 @@
+use papergrid::{Border, Entity, Grid, GridEvent, Settings, DEFAULT_CELL_STYLE};
+
+fn events(grid: &Grid) -> Vec<String> {
+    let mut events = Vec::new();
+    grid.visit(&mut |event: GridEvent<'_>| {
+        let event = match event {
+            GridEvent::SplitLine { row } => format!("split {}", row),
+            GridEvent::BeginRow { row, height } => format!("begin {} {}", row, height),
+            GridEvent::Cell {
+                row,
+                column,
+                text,
+                width,
+                style,
+            } => format!(
+                "cell {} {} {:?} {} {}",
+                row, column, text, width, style.span
+            ),
+            GridEvent::EndRow { row } => format!("end {}", row),
+        };
+
+        events.push(event);
+    });
+
+    events
+}
+
+#[test]
+fn visit_2x2() {
+    let mut grid = Grid::new(2, 2);
+    grid.set_cell_borders(DEFAULT_CELL_STYLE.clone());
+    grid.set(&Entity::Cell(0, 0), Settings::new().text("0-0"));
+    grid.set(&Entity::Cell(0, 1), Settings::new().text("0-1"));
+    grid.set(&Entity::Cell(1, 0), Settings::new().text("1-0\n1-0"));
+    grid.set(&Entity::Cell(1, 1), Settings::new().text("1-1111"));
+
+    assert_eq!(
+        events(&grid),
+        [
+            "split 0",
+            "begin 0 1",
+            "cell 0 0 \"0-0\" 3 1",
+            "cell 0 1 \"0-1\" 6 1",
+            "end 0",
+            "split 1",
+            "begin 1 2",
+            "cell 1 0 \"1-0\\n1-0\" 3 1",
+            "cell 1 1 \"1-1111\" 6 1",
+            "end 1",
+            "split 2",
+        ]
+    );
+}
+
+#[test]
+fn visit_with_span() {
+    let mut grid = Grid::new(2, 2);
+    grid.set_cell_borders(DEFAULT_CELL_STYLE.clone());
+    grid.set(&Entity::Cell(0, 0), Settings::new().text("0-0").span(2));
+    grid.set(&Entity::Cell(1, 0), Settings::new().text("1-0"));
+    grid.set(&Entity::Cell(1, 1), Settings::new().text("1-1"));
+
+    assert_eq!(
+        events(&grid),
+        [
+            "split 0",
+            "begin 0 1",
+            "cell 0 0 \"0-0\" 7 2",
+            "end 0",
+            "split 1",
+            "begin 1 1",
+            "cell 1 0 \"1-0\" 3 1",
+            "cell 1 1 \"1-1\" 3 1",
+            "end 1",
+            "split 2",
+        ]
+    );
+}
+
+#[test]
+fn visit_without_split_lines() {
+    let mut grid = Grid::new(1, 2);
+    grid.set(
+        &Entity::Global,
+        Settings::new()
+            .text("a")
+            .border(Border::default().left('|'))
+            .border_restriction(false),
+    );
+
+    assert_eq!(
+        events(&grid),
+        [
+            "begin 0 1",
+            "cell 0 0 \"a\" 1 1",
+            "cell 0 1 \"a\" 1 1",
+            "end 0"
+        ]
+    );
+}
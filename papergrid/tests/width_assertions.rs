@@ -0,0 +1,28 @@
+#![cfg(feature = "width-assertions")]
+
+use papergrid::{Border, Entity, Grid, Settings, DEFAULT_CELL_STYLE};
+
+#[test]
+fn lines_of_equal_width_pass() {
+    let mut grid = Grid::new(2, 2);
+    grid.set_cell_borders(DEFAULT_CELL_STYLE.clone());
+    grid.set(&Entity::Cell(0, 0), Settings::new().text("0-0").span(2));
+    grid.set(&Entity::Cell(1, 0), Settings::new().text("中文"));
+    grid.set(&Entity::Cell(1, 1), Settings::new().text("👍\nline"));
+
+    let _ = grid.to_string();
+}
+
+#[test]
+#[should_panic(expected = "papergrid: line")]
+fn lines_of_different_width_panic() {
+    let mut grid = Grid::new(2, 3);
+    grid.set_cell_borders(Border::default());
+    grid.set(&Entity::Cell(0, 0), Settings::new().text("multi\nline"));
+    grid.set(&Entity::Cell(0, 1), Settings::new().text("").span(2));
+    grid.set(&Entity::Cell(0, 2), Settings::new().text("👍"));
+    grid.set(&Entity::Cell(1, 0), Settings::new().text("Rust 💕").span(2));
+    grid.set(&Entity::Cell(1, 2), Settings::new().text("Hello World"));
+
+    let _ = grid.to_string();
+}
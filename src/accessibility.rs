@@ -0,0 +1,143 @@
+#[allow(unused)]
+use crate::Table;
+use crate::TableOption;
+use papergrid::{Border, Entity, Grid, Settings};
+
+/// Accessible makes a [Table] readable in any environment.
+///
+/// It removes ANSI escape sequences from cells
+/// and replaces box-drawing characters in borders by ASCII ones.
+///
+/// Using [Accessible::from_env] it can be turned on only in case
+/// a [`NO_COLOR`](https://no-color.org/) environment variable is set.
+///
+/// ```
+/// use tabled::{Accessible, Style, Table};
+///
+/// let table = Table::new(&["\u{1b}[31mHello\u{1b}[0m"])
+///     .with(Style::modern())
+///     .with(Accessible::new());
+///
+/// assert_eq!(
+///     table.to_string(),
+///     "+-------+\n\
+///      | &str  |\n\
+///      +-------+\n\
+///      | Hello |\n\
+///      +-------+\n"
+/// );
+/// ```
+#[derive(Debug)]
+pub struct Accessible {
+    enabled: bool,
+}
+
+impl Accessible {
+    /// Creates an [Accessible] which is always applied.
+    pub fn new() -> Self {
+        Self { enabled: true }
+    }
+
+    /// Creates an [Accessible] which is applied only
+    /// in case a `NO_COLOR` environment variable is present and not empty.
+    pub fn from_env() -> Self {
+        let enabled = std::env::var_os("NO_COLOR").is_some_and(|value| !value.is_empty());
+        Self { enabled }
+    }
+}
+
+impl Default for Accessible {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl TableOption for Accessible {
+    fn change(&mut self, grid: &mut Grid) {
+        if !self.enabled {
+            return;
+        }
+
+        for row in 0..grid.count_rows() {
+            for column in 0..grid.count_columns() {
+                let content = grid.get_cell_content(row, column);
+                if content.contains('\u{1b}') {
+                    let content = strip_ansi(content);
+                    grid.set(&Entity::Cell(row, column), Settings::new().text(content));
+                }
+
+                let border = ascii_border(grid.get_border(row, column));
+                grid.set(
+                    &Entity::Cell(row, column),
+                    Settings::new().border(border).border_restriction(false),
+                );
+            }
+        }
+    }
+}
+
+fn ascii_border(border: Border) -> Border {
+    Border {
+        top: border.top.map(ascii_char),
+        bottom: border.bottom.map(ascii_char),
+        left: border.left.map(ascii_char),
+        right: border.right.map(ascii_char),
+        left_top_corner: border.left_top_corner.map(ascii_char),
+        right_top_corner: border.right_top_corner.map(ascii_char),
+        left_bottom_corner: border.left_bottom_corner.map(ascii_char),
+        right_bottom_corner: border.right_bottom_corner.map(ascii_char),
+    }
+}
+
+/// Converts a box-drawing character to its ASCII analog.
+fn ascii_char(c: char) -> char {
+    match c {
+        '─' | '━' | '═' | '┄' | '┅' | '┈' | '┉' | '╌' | '╍' | '╴' | '╶' | '╸' | '╺' | '╼' | '╾' => {
+            '-'
+        }
+        '│' | '┃' | '║' | '┆' | '┇' | '┊' | '┋' | '╎' | '╏' | '╵' | '╷' | '╹' | '╻' | '╽' | '╿' => {
+            '|'
+        }
+        '\u{2500}'..='\u{257F}' => '+',
+        c => c,
+    }
+}
+
+/// Removes CSI and OSC escape sequences from a string.
+fn strip_ansi(s: &str) -> String {
+    let mut buf = String::with_capacity(s.len());
+    let mut chars = s.chars().peekable();
+    while let Some(c) = chars.next() {
+        if c != '\u{1b}' {
+            buf.push(c);
+            continue;
+        }
+
+        match chars.next() {
+            // CSI sequence ends with a char in a range '@'..='~'
+            Some('[') => {
+                for c in chars.by_ref() {
+                    if ('@'..='~').contains(&c) {
+                        break;
+                    }
+                }
+            }
+            // OSC sequence ends with BEL or ST
+            Some(']') => {
+                while let Some(c) = chars.next() {
+                    if c == '\u{7}' {
+                        break;
+                    }
+
+                    if c == '\u{1b}' && chars.peek() == Some(&'\\') {
+                        chars.next();
+                        break;
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+
+    buf
+}
@@ -1,7 +1,7 @@
-use crate::CellOption;
+use crate::{CellOption, TableOption};
 use papergrid::{Entity, Grid, Settings};
 
-pub use papergrid::{AlignmentHorizontal, AlignmentVertical};
+pub use papergrid::{AlignmentHorizontal, AlignmentVertical, CenterRounding};
 
 /// Alignment represent a horizontal and vertical alignemt setting for any cell on a [crate::Table].
 ///
@@ -10,10 +10,14 @@ pub use papergrid::{AlignmentHorizontal, AlignmentVertical};
 ///   # let data: Vec<&'static str> = Vec::new();
 ///     let table = Table::new(&data).with(Modify::new(Row(..1)).with(Alignment::center_horizontal()));
 /// ```
-#[derive(Debug)]
+///
+/// A horizontal alignment doesn't reset a vertical one and vice versa.
+/// Both can be set at once by combined presets e.g. [Alignment::top_left].
+#[derive(Debug, Clone, Copy)]
 pub enum Alignment {
     Horizontal(AlignmentHorizontal),
     Vertical(AlignmentVertical),
+    Both(AlignmentHorizontal, AlignmentVertical),
 }
 
 impl Alignment {
@@ -47,6 +51,51 @@ impl Alignment {
         Self::horizontal(AlignmentHorizontal::Center)
     }
 
+    /// Top_left constructs an alignment to [AlignmentVertical::Top] and [AlignmentHorizontal::Left]
+    pub fn top_left() -> Self {
+        Self::both(AlignmentHorizontal::Left, AlignmentVertical::Top)
+    }
+
+    /// Top_center constructs an alignment to [AlignmentVertical::Top] and [AlignmentHorizontal::Center]
+    pub fn top_center() -> Self {
+        Self::both(AlignmentHorizontal::Center, AlignmentVertical::Top)
+    }
+
+    /// Top_right constructs an alignment to [AlignmentVertical::Top] and [AlignmentHorizontal::Right]
+    pub fn top_right() -> Self {
+        Self::both(AlignmentHorizontal::Right, AlignmentVertical::Top)
+    }
+
+    /// Center_left constructs an alignment to [AlignmentVertical::Center] and [AlignmentHorizontal::Left]
+    pub fn center_left() -> Self {
+        Self::both(AlignmentHorizontal::Left, AlignmentVertical::Center)
+    }
+
+    /// Center constructs an alignment to [AlignmentVertical::Center] and [AlignmentHorizontal::Center]
+    pub fn center() -> Self {
+        Self::both(AlignmentHorizontal::Center, AlignmentVertical::Center)
+    }
+
+    /// Center_right constructs an alignment to [AlignmentVertical::Center] and [AlignmentHorizontal::Right]
+    pub fn center_right() -> Self {
+        Self::both(AlignmentHorizontal::Right, AlignmentVertical::Center)
+    }
+
+    /// Bottom_left constructs an alignment to [AlignmentVertical::Bottom] and [AlignmentHorizontal::Left]
+    pub fn bottom_left() -> Self {
+        Self::both(AlignmentHorizontal::Left, AlignmentVertical::Bottom)
+    }
+
+    /// Bottom_center constructs an alignment to [AlignmentVertical::Bottom] and [AlignmentHorizontal::Center]
+    pub fn bottom_center() -> Self {
+        Self::both(AlignmentHorizontal::Center, AlignmentVertical::Bottom)
+    }
+
+    /// Bottom_right constructs an alignment to [AlignmentVertical::Bottom] and [AlignmentHorizontal::Right]
+    pub fn bottom_right() -> Self {
+        Self::both(AlignmentHorizontal::Right, AlignmentVertical::Bottom)
+    }
+
     /// Returns an alignment with the given horizontal alignment.
     fn horizontal(alignment: AlignmentHorizontal) -> Self {
         Self::Horizontal(alignment)
@@ -56,6 +105,11 @@ impl Alignment {
     fn vertical(alignment: AlignmentVertical) -> Self {
         Self::Vertical(alignment)
     }
+
+    /// Returns an alignment with the given horizontal and vertical alignments.
+    fn both(horizontal: AlignmentHorizontal, vertical: AlignmentVertical) -> Self {
+        Self::Both(horizontal, vertical)
+    }
 }
 
 impl CellOption for Alignment {
@@ -63,8 +117,29 @@ impl CellOption for Alignment {
         let settings = match &self {
             Self::Horizontal(a) => Settings::new().alignment(*a),
             Self::Vertical(a) => Settings::new().vertical_alignment(*a),
+            Self::Both(h, v) => Settings::new().alignment(*h).vertical_alignment(*v),
         };
 
         grid.set(&Entity::Cell(row, column), settings);
     }
 }
+
+/// A [CenterRounding] sets a side of a centered cell which gets an extra space
+/// when it can't be split evenly.
+///
+/// It may be needed to match an output of other tools.
+///
+/// ```rust
+///   # use tabled::{Alignment, CenterRounding, Full, Modify, Style, Table};
+///     let table = Table::new(["ab", "c"])
+///         .with(Style::blank())
+///         .with(Modify::new(Full).with(Alignment::center_horizontal()))
+///         .with(CenterRounding::Left);
+///
+///     assert_eq!(table.to_string(), " &str \n  ab  \n   c  \n");
+/// ```
+impl TableOption for CenterRounding {
+    fn change(&mut self, grid: &mut Grid) {
+        grid.set_center_rounding(*self);
+    }
+}
@@ -1,11 +1,18 @@
 //! Builder module provides a [Builder] type which helps building
 //! a [Table] dynamically.
 
-use std::{fmt::Display, iter::FromIterator};
+use std::{
+    any::Any,
+    cmp::max,
+    collections::{BTreeSet, HashMap},
+    fmt::Display,
+    iter::FromIterator,
+    sync::Arc,
+};
 
 use papergrid::{AlignmentHorizontal, Entity, Grid, Settings};
 
-use crate::{Style, Table};
+use crate::{Alignment, CellOption, Error, Style, Table, Tabled};
 
 /// Builder creates a [Table] from dynamic data set.
 ///
@@ -40,12 +47,18 @@ use crate::{Style, Table};
 pub struct Builder {
     /// A header row.
     headers: Option<Vec<String>>,
+    /// Header rows beneath the main header, e.g. units of columns.
+    sub_headers: Vec<Vec<String>>,
     /// A list of rows.
     rows: Vec<Vec<String>>,
     /// A number of columns.
     size: usize,
     /// A content of cells which are created in case rows has different length.
     empty_cell_text: Option<String>,
+    /// Alignments of columns.
+    alignments: HashMap<usize, Alignment>,
+    /// Metadata of rows by their indexes.
+    metas: HashMap<usize, Arc<dyn Any + Send + Sync>>,
 }
 
 impl Builder {
@@ -54,6 +67,383 @@ impl Builder {
         Self::default()
     }
 
+    /// Creates a [Builder] from a list of maps.
+    ///
+    /// A header is a union of keys of all maps sorted alphabetically.
+    /// A value which is missing in a map is left empty.
+    ///
+    /// ```rust
+    /// use std::collections::HashMap;
+    /// use tabled::builder::Builder;
+    ///
+    /// let records = vec![
+    ///     HashMap::from([("name".to_owned(), "Debian".to_owned())]),
+    ///     HashMap::from([("released".to_owned(), "2005".to_owned())]),
+    /// ];
+    ///
+    /// let table = Builder::from_maps(records).build();
+    ///
+    /// assert_eq!(
+    ///     table.to_string(),
+    ///     "+--------+----------+\n\
+    ///      |  name  | released |\n\
+    ///      +--------+----------+\n\
+    ///      | Debian |          |\n\
+    ///      +--------+----------+\n\
+    ///      |        |   2005   |\n\
+    ///      +--------+----------+\n"
+    /// );
+    /// ```
+    pub fn from_maps<I>(maps: I) -> Self
+    where
+        I: IntoIterator<Item = HashMap<String, String>>,
+    {
+        let mut maps: Vec<HashMap<String, String>> = maps.into_iter().collect();
+        let header: BTreeSet<String> = maps.iter().flat_map(|map| map.keys().cloned()).collect();
+
+        let mut builder = Self::default();
+        for map in &mut maps {
+            let row = header
+                .iter()
+                .map(|key| map.remove(key).unwrap_or_default())
+                .collect::<Vec<_>>();
+            builder = builder.add_row(row);
+        }
+
+        builder.set_header(header)
+    }
+
+    /// Creates a [Builder] from a CSV data.
+    ///
+    /// A first record is considered a header.
+    /// Use [CsvOptions] to change a delimiter, quoting or a header detection.
+    ///
+    /// It's available only with a `csv` feature on.
+    ///
+    /// ```rust
+    /// use tabled::builder::Builder;
+    ///
+    /// let data = "name,released\nDebian,1993\n\"Arch, Linux\",2002\n";
+    /// let table = Builder::from_csv(data.as_bytes()).unwrap().build();
+    ///
+    /// assert_eq!(
+    ///     table.to_string(),
+    ///     "+-------------+----------+\n\
+    ///      |    name     | released |\n\
+    ///      +-------------+----------+\n\
+    ///      |   Debian    |   1993   |\n\
+    ///      +-------------+----------+\n\
+    ///      | Arch, Linux |   2002   |\n\
+    ///      +-------------+----------+\n"
+    /// );
+    /// ```
+    #[cfg(feature = "csv")]
+    pub fn from_csv<R: std::io::Read>(reader: R) -> Result<Self, Error> {
+        CsvOptions::new().read(reader)
+    }
+
+    /// Creates a [Builder] from a TSV data, which is a CSV data separated by tabs.
+    ///
+    /// A first record is considered a header.
+    ///
+    /// It's available only with a `csv` feature on.
+    #[cfg(feature = "csv")]
+    pub fn from_tsv<R: std::io::Read>(reader: R) -> Result<Self, Error> {
+        CsvOptions::new().delimiter(b'\t').read(reader)
+    }
+
+    /// Creates a [Builder] from a TOML value.
+    ///
+    /// An array of tables is shown as records with a union of keys as a header,
+    /// a table is shown as pairs of keys and values, and any other value is shown as a list.
+    /// Nested values are written in TOML.
+    ///
+    /// It's available only with a `toml` feature on.
+    ///
+    /// ```rust
+    /// use tabled::builder::Builder;
+    ///
+    /// let value: toml::Value = toml::from_str(r#"
+    ///     [[server]]
+    ///     name = "alpha"
+    ///     port = 8080
+    ///
+    ///     [[server]]
+    ///     name = "beta"
+    /// "#).unwrap();
+    ///
+    /// let table = Builder::from_toml(&value["server"]).build();
+    ///
+    /// assert_eq!(
+    ///     table.to_string(),
+    ///     "+-------+------+\n\
+    ///      | name  | port |\n\
+    ///      +-------+------+\n\
+    ///      | alpha | 8080 |\n\
+    ///      +-------+------+\n\
+    ///      | beta  |      |\n\
+    ///      +-------+------+\n"
+    /// );
+    /// ```
+    #[cfg(feature = "toml")]
+    pub fn from_toml(value: &toml::Value) -> Self {
+        fn to_string(value: &toml::Value) -> String {
+            match value {
+                toml::Value::String(s) => s.clone(),
+                value => value.to_string().trim_end().to_owned(),
+            }
+        }
+
+        fn to_document(value: &toml::Value) -> Document {
+            match value {
+                toml::Value::Array(values) if values.iter().all(|v| v.is_table()) => {
+                    let records = values
+                        .iter()
+                        .filter_map(|value| value.as_table())
+                        .map(|table| {
+                            table
+                                .iter()
+                                .map(|(key, value)| (key.clone(), to_string(value)))
+                                .collect()
+                        })
+                        .collect();
+
+                    Document::Records(records)
+                }
+                toml::Value::Array(values) => {
+                    Document::List(values.iter().map(to_string).collect())
+                }
+                toml::Value::Table(table) => Document::Map(
+                    table
+                        .iter()
+                        .map(|(key, value)| (key.clone(), to_string(value)))
+                        .collect(),
+                ),
+                value => Document::List(vec![to_string(value)]),
+            }
+        }
+
+        from_document(to_document(value))
+    }
+
+    /// Creates a [Builder] from a YAML value.
+    ///
+    /// A sequence of mappings is shown as records with a union of keys as a header,
+    /// a mapping is shown as pairs of keys and values, and any other value is shown as a list.
+    /// Nested values are written in YAML.
+    ///
+    /// It's available only with a `serde_yaml` feature on.
+    ///
+    /// ```rust
+    /// use tabled::builder::Builder;
+    ///
+    /// let value: serde_yaml::Value = serde_yaml::from_str("
+    ///     name: tabled
+    ///     version: 0.5.0
+    /// ").unwrap();
+    ///
+    /// let table = Builder::from_yaml(&value).build();
+    ///
+    /// assert_eq!(
+    ///     table.to_string(),
+    ///     "+---------+--------+\n\
+    ///      |   key   | value  |\n\
+    ///      +---------+--------+\n\
+    ///      |  name   | tabled |\n\
+    ///      +---------+--------+\n\
+    ///      | version | 0.5.0  |\n\
+    ///      +---------+--------+\n"
+    /// );
+    /// ```
+    #[cfg(feature = "serde_yaml")]
+    pub fn from_yaml(value: &serde_yaml::Value) -> Self {
+        use serde_yaml::Value;
+
+        fn to_string(value: &Value) -> String {
+            match value {
+                Value::Null => String::new(),
+                Value::Bool(b) => b.to_string(),
+                Value::Number(n) => n.to_string(),
+                Value::String(s) => s.clone(),
+                value => serde_yaml::to_string(value)
+                    .map(|s| s.trim_start_matches("---\n").trim_end().to_owned())
+                    .unwrap_or_default(),
+            }
+        }
+
+        fn to_pairs(mapping: &serde_yaml::Mapping) -> Vec<(String, String)> {
+            mapping
+                .iter()
+                .map(|(key, value)| (to_string(key), to_string(value)))
+                .collect()
+        }
+
+        fn to_document(value: &Value) -> Document {
+            match value {
+                Value::Sequence(values) if values.iter().all(|v| v.is_mapping()) => {
+                    let records = values
+                        .iter()
+                        .filter_map(|value| value.as_mapping())
+                        .map(to_pairs)
+                        .collect();
+
+                    Document::Records(records)
+                }
+                Value::Sequence(values) => Document::List(values.iter().map(to_string).collect()),
+                Value::Mapping(mapping) => Document::Map(to_pairs(mapping)),
+                value => Document::List(vec![to_string(value)]),
+            }
+        }
+
+        from_document(to_document(value))
+    }
+
+    /// Creates a [Builder] from an Arrow record batch.
+    ///
+    /// Names of fields make a header, and only the first `max_rows` rows are taken.
+    /// Numeric columns are aligned to the right and string ones to the left.
+    /// Null values are left empty.
+    ///
+    /// It's available only with an `arrow` feature on.
+    ///
+    /// ```rust
+    /// use std::sync::Arc;
+    ///
+    /// use arrow_array::{ArrayRef, Int32Array, RecordBatch, StringArray};
+    /// use tabled::builder::Builder;
+    ///
+    /// let batch = RecordBatch::try_from_iter([
+    ///     ("name", Arc::new(StringArray::from(vec!["Rust", "Go", "C"])) as ArrayRef),
+    ///     ("year", Arc::new(Int32Array::from(vec![Some(2010), None, Some(1972)])) as ArrayRef),
+    /// ])
+    /// .unwrap();
+    ///
+    /// let table = Builder::from_record_batch(&batch, 2).unwrap().build();
+    ///
+    /// assert_eq!(
+    ///     table.to_string(),
+    ///     "+------+------+\n\
+    ///      | name | year |\n\
+    ///      +------+------+\n\
+    ///      | Rust | 2010 |\n\
+    ///      +------+------+\n\
+    ///      | Go   |      |\n\
+    ///      +------+------+\n"
+    /// );
+    /// ```
+    #[cfg(feature = "arrow")]
+    pub fn from_record_batch(
+        batch: &arrow_array::RecordBatch,
+        max_rows: usize,
+    ) -> Result<Self, Error> {
+        use arrow_cast::display::{ArrayFormatter, FormatOptions};
+        use arrow_schema::DataType;
+
+        let options = FormatOptions::default().with_null("");
+        let formatters = batch
+            .columns()
+            .iter()
+            .map(|column| ArrayFormatter::try_new(column.as_ref(), &options))
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|err| Error::Arrow(err.to_string()))?;
+
+        let schema = batch.schema();
+        let mut builder = Self::new().set_header(schema.fields().iter().map(|field| field.name()));
+        for row in 0..std::cmp::min(batch.num_rows(), max_rows) {
+            let values = formatters
+                .iter()
+                .map(|formatter| formatter.value(row).to_string())
+                .collect::<Vec<_>>();
+            builder = builder.add_row(values);
+        }
+
+        for (column, field) in schema.fields().iter().enumerate() {
+            let data_type = field.data_type();
+            if data_type.is_numeric() {
+                builder = builder.column_alignment(column, Alignment::right());
+            } else if matches!(data_type, DataType::Utf8 | DataType::LargeUtf8) {
+                builder = builder.column_alignment(column, Alignment::left());
+            }
+        }
+
+        Ok(builder)
+    }
+
+    /// Creates a [Builder] from rows of an SQLite query.
+    ///
+    /// Column names of the query are used as a header.
+    /// NULL values are replaced by a `null` placeholder,
+    /// and blobs are shown as hex literals like `x'0a0b'`.
+    ///
+    /// It's available only with a `rusqlite` feature on.
+    ///
+    /// ```rust
+    /// use rusqlite::Connection;
+    /// use tabled::builder::Builder;
+    ///
+    /// let conn = Connection::open_in_memory().unwrap();
+    /// conn.execute_batch(
+    ///     "CREATE TABLE langs (name TEXT, year INTEGER);
+    ///      INSERT INTO langs VALUES ('Rust', 2010), ('Go', NULL);",
+    /// )
+    /// .unwrap();
+    ///
+    /// let mut stmt = conn.prepare("SELECT name, year FROM langs").unwrap();
+    /// let table = Builder::from_sqlite_rows(stmt.query([]).unwrap(), "NULL")
+    ///     .unwrap()
+    ///     .build();
+    ///
+    /// assert_eq!(
+    ///     table.to_string(),
+    ///     "+------+------+\n\
+    ///      | name | year |\n\
+    ///      +------+------+\n\
+    ///      | Rust | 2010 |\n\
+    ///      +------+------+\n\
+    ///      |  Go  | NULL |\n\
+    ///      +------+------+\n"
+    /// );
+    /// ```
+    #[cfg(feature = "rusqlite")]
+    pub fn from_sqlite_rows(mut rows: rusqlite::Rows<'_>, null: &str) -> Result<Self, Error> {
+        use rusqlite::types::ValueRef;
+
+        let header = rows
+            .as_ref()
+            .map(|stmt| stmt.column_names())
+            .unwrap_or_default();
+        let count_columns = header.len();
+        let mut builder = Self::new().set_header(header);
+
+        while let Some(row) = rows.next().map_err(|err| Error::Sqlite(err.to_string()))? {
+            let mut values = Vec::with_capacity(count_columns);
+            for column in 0..count_columns {
+                let value = row
+                    .get_ref(column)
+                    .map_err(|err| Error::Sqlite(err.to_string()))?;
+                let value = match value {
+                    ValueRef::Null => null.to_owned(),
+                    ValueRef::Integer(i) => i.to_string(),
+                    ValueRef::Real(f) => f.to_string(),
+                    ValueRef::Text(text) => String::from_utf8_lossy(text).into_owned(),
+                    ValueRef::Blob(blob) => {
+                        let hex = blob
+                            .iter()
+                            .map(|b| format!("{:02x}", b))
+                            .collect::<String>();
+                        format!("x'{}'", hex)
+                    }
+                };
+
+                values.push(value);
+            }
+
+            builder = builder.add_row(values);
+        }
+
+        Ok(builder)
+    }
+
     /// Sets a [Table] header.
     ///
     /// If not set a first row will be considered a header.
@@ -76,6 +466,49 @@ impl Builder {
         self
     }
 
+    /// Adds a header row to a [Table].
+    ///
+    /// The first header row is the one set by [Self::set_header],
+    /// others are put beneath it.
+    /// All of them make a header region of the table, so a header line of a [Style]
+    /// is drawn after the last one and options like [crate::Disable] treat them as a header.
+    ///
+    /// ```rust
+    /// use tabled::{builder::Builder, Style};
+    ///
+    /// let table = Builder::default()
+    ///     .add_header_row(["distance", "time"])
+    ///     .add_header_row(["km", "h"])
+    ///     .add_row(["100", "1.5"])
+    ///     .build()
+    ///     .with(Style::psql());
+    ///
+    /// assert_eq!(
+    ///     table.to_string(),
+    ///     concat!(
+    ///         " distance | time \n",
+    ///         "    km    |  h   \n",
+    ///         "----------+------\n",
+    ///         "   100    | 1.5  \n",
+    ///     )
+    /// );
+    /// ```
+    pub fn add_header_row<R, T>(mut self, row: R) -> Self
+    where
+        R: IntoIterator<Item = T>,
+        T: Display,
+    {
+        if self.headers.is_none() {
+            return self.set_header(row);
+        }
+
+        let row: Vec<String> = row.into_iter().map(|t| t.to_string()).collect();
+        self.update_size(row.len());
+        self.sub_headers.push(row);
+
+        self
+    }
+
     /// Adds a row to a [Table].
     ///
     /// If [Self::header] is not set the row will be considered a header.
@@ -98,6 +531,110 @@ impl Builder {
         self
     }
 
+    /// Adds a row with a metadata attached to it.
+    ///
+    /// The metadata is not rendered,
+    /// it can be retrieved by [Table::meta] even after rows were sorted or shifted.
+    ///
+    /// ```rust
+    /// use tabled::builder::Builder;
+    ///
+    /// struct Status {
+    ///     failed: bool,
+    /// }
+    ///
+    /// let table = Builder::default()
+    ///     .set_header(["job"])
+    ///     .add_row_with_meta(["build"], Status { failed: false })
+    ///     .add_row_with_meta(["test"], Status { failed: true })
+    ///     .build();
+    ///
+    /// let failed = (0..table.shape().0)
+    ///     .filter(|&row| table.meta::<Status>(row).map_or(false, |status| status.failed))
+    ///     .collect::<Vec<_>>();
+    ///
+    /// assert_eq!(failed, [2]);
+    /// ```
+    pub fn add_row_with_meta<R, T, M>(mut self, row: R, meta: M) -> Self
+    where
+        R: IntoIterator<Item = T>,
+        T: Display,
+        M: Any + Send + Sync,
+    {
+        self.metas.insert(self.rows.len(), Arc::new(meta));
+        self.add_row(row)
+    }
+
+    /// Adds a row to a [Table] which is made of fields of a [Tabled] value.
+    ///
+    /// ```rust
+    /// use tabled::{builder::Builder, Table};
+    ///
+    /// let table = Table::new(["Hello"]);
+    /// let table = Builder::from(table).add_record("World").build();
+    ///
+    /// assert_eq!(
+    ///     table.to_string(),
+    ///     "+-------+\n\
+    ///      | &str  |\n\
+    ///      +-------+\n\
+    ///      | Hello |\n\
+    ///      +-------+\n\
+    ///      | World |\n\
+    ///      +-------+\n"
+    /// );
+    /// ```
+    pub fn add_record<T: Tabled>(self, record: T) -> Self {
+        self.add_row(record.fields())
+    }
+
+    /// Removes a row from a [Table].
+    ///
+    /// An index doesn't include a header set by [Self::set_header].
+    /// Nothing is changed if there's no row with such index.
+    ///
+    /// ```rust
+    /// use tabled::builder::Builder;
+    /// let table = Builder::default()
+    ///     .set_header(["i"])
+    ///     .add_row(["0"])
+    ///     .add_row(["1"])
+    ///     .remove_record(0)
+    ///     .build();
+    ///
+    /// assert_eq!(
+    ///     table.to_string(),
+    ///     "+---+\n\
+    ///      | i |\n\
+    ///      +---+\n\
+    ///      | 1 |\n\
+    ///      +---+\n"
+    /// );
+    /// ```
+    pub fn remove_record(mut self, index: usize) -> Self {
+        if index < self.rows.len() {
+            self.rows.remove(index);
+
+            self.metas.remove(&index);
+            self.metas = std::mem::take(&mut self.metas)
+                .into_iter()
+                .map(|(i, meta)| if i > index { (i - 1, meta) } else { (i, meta) })
+                .collect();
+
+            let header_size = self.headers.as_ref().map_or(0, |header| header.len());
+            let rows_size = self
+                .sub_headers
+                .iter()
+                .chain(&self.rows)
+                .map(|row| row.len())
+                .max()
+                .unwrap_or(0);
+            self.size = max(header_size, rows_size);
+        }
+
+        self
+    }
+
     /// Sets a content of cells which are created in case rows has different length.
     ///
     ///
@@ -114,6 +651,78 @@ impl Builder {
         self
     }
 
+    /// Sets an alignment of a column.
+    ///
+    /// It's applied to all cells of the column including a header.
+    ///
+    /// ```rust
+    /// use tabled::{builder::Builder, Alignment};
+    /// let table = Builder::default()
+    ///     .set_header(["name", "value"])
+    ///     .add_row(["weight", "0.443"])
+    ///     .column_alignment(1, Alignment::right())
+    ///     .build();
+    ///
+    /// assert_eq!(
+    ///     table.to_string(),
+    ///     "+--------+-------+\n\
+    ///      |  name  | value |\n\
+    ///      +--------+-------+\n\
+    ///      | weight | 0.443 |\n\
+    ///      +--------+-------+\n"
+    /// );
+    /// ```
+    pub fn column_alignment(mut self, column: usize, alignment: Alignment) -> Self {
+        self.alignments.insert(column, alignment);
+        self
+    }
+
+    /// Checks that a header matches the one of a [Tabled] type.
+    ///
+    /// If a header is not set a first row is checked.
+    /// Both names and an amount of columns must be equal.
+    ///
+    /// ```rust
+    /// use tabled::{builder::Builder, Error, Tabled};
+    ///
+    /// #[derive(Tabled)]
+    /// struct Measure {
+    ///     name: &'static str,
+    ///     value: f32,
+    /// }
+    ///
+    /// let builder = Builder::default()
+    ///     .set_header(["name", "value"])
+    ///     .expect_header::<Measure>();
+    /// assert!(builder.is_ok());
+    ///
+    /// let builder = Builder::default()
+    ///     .set_header(["name"])
+    ///     .expect_header::<Measure>();
+    /// assert_eq!(
+    ///     builder.unwrap_err(),
+    ///     Error::HeaderMismatch {
+    ///         expected: vec!["name".to_owned(), "value".to_owned()],
+    ///         found: vec!["name".to_owned()],
+    ///     }
+    /// );
+    /// ```
+    pub fn expect_header<T: Tabled>(self) -> Result<Self, Error> {
+        let expected = T::headers();
+        let found = self
+            .headers
+            .as_ref()
+            .or_else(|| self.rows.first())
+            .cloned()
+            .unwrap_or_default();
+
+        if found != expected {
+            return Err(Error::HeaderMismatch { expected, found });
+        }
+
+        Ok(self)
+    }
+
     /// Build creates a [Table] instance.
     ///
     /// ```rust
@@ -131,14 +740,26 @@ impl Builder {
                 }
             }
 
-            for row in self.rows.iter_mut() {
+            for row in self.sub_headers.iter_mut().chain(self.rows.iter_mut()) {
                 if self.size > row.len() {
                     append_vec(row, self.size - row.len(), empty_cell_text.clone());
                 }
             }
         }
 
-        build_table(self.headers, self.rows, self.size)
+        let mut header = self.headers.map(|header| vec![header]).unwrap_or_default();
+        header.extend(self.sub_headers);
+
+        // metadata is bound to original rows of a grid
+        let header_rows = header.len();
+        let mut table = build_table(header, self.rows, self.size, self.alignments);
+        table.metas = self
+            .metas
+            .into_iter()
+            .map(|(row, meta)| (row + header_rows, meta))
+            .collect();
+
+        table
     }
 
     fn update_size(&mut self, size: usize) {
@@ -148,6 +769,188 @@ impl Builder {
     }
 }
 
+/// CsvOptions reads a CSV data into a [Builder] using given settings.
+///
+/// It's available only with a `csv` feature on.
+///
+/// ```rust
+/// use tabled::builder::CsvOptions;
+///
+/// let data = "Debian;'1993'\nArch;'2002'\n";
+/// let table = CsvOptions::new()
+///     .delimiter(b';')
+///     .quote(b'\'')
+///     .has_header(false)
+///     .read(data.as_bytes())
+///     .unwrap()
+///     .build();
+///
+/// assert_eq!(
+///     table.to_string(),
+///     "+--------+------+\n\
+///      | Debian | 1993 |\n\
+///      +--------+------+\n\
+///      |  Arch  | 2002 |\n\
+///      +--------+------+\n"
+/// );
+/// ```
+#[cfg(feature = "csv")]
+#[derive(Debug, Clone)]
+pub struct CsvOptions {
+    delimiter: u8,
+    quote: u8,
+    quoting: bool,
+    // `None` means a header is detected by a content
+    has_header: Option<bool>,
+}
+
+#[cfg(feature = "csv")]
+impl CsvOptions {
+    /// Creates [CsvOptions] for a comma separated data with a header
+    /// where fields may be quoted by `"`.
+    pub fn new() -> Self {
+        Self {
+            delimiter: b',',
+            quote: b'"',
+            quoting: true,
+            has_header: Some(true),
+        }
+    }
+
+    /// Sets a delimiter of fields.
+    pub fn delimiter(mut self, delimiter: u8) -> Self {
+        self.delimiter = delimiter;
+        self
+    }
+
+    /// Sets a quote character.
+    pub fn quote(mut self, quote: u8) -> Self {
+        self.quote = quote;
+        self
+    }
+
+    /// Sets whether quotes are handled.
+    ///
+    /// If turned off quote characters are kept as a part of fields.
+    pub fn quoting(mut self, quoting: bool) -> Self {
+        self.quoting = quoting;
+        self
+    }
+
+    /// Sets whether a first record is a header.
+    ///
+    /// If there's no header, a table is built without one.
+    pub fn has_header(mut self, has_header: bool) -> Self {
+        self.has_header = Some(has_header);
+        self
+    }
+
+    /// Makes a first record be considered a header only if it looks like one.
+    ///
+    /// A column votes for a header if all other records have numbers in it
+    /// while the first one doesn't, or if all other records have values of the same length
+    /// while the first one has a different one; a column votes against it otherwise.
+    ///
+    /// ```rust
+    /// use tabled::builder::CsvOptions;
+    ///
+    /// let data = "name,released\nDebian,1993\nArch,2002\n";
+    /// let table = CsvOptions::new().detect_header().read(data.as_bytes()).unwrap().build();
+    /// assert_eq!(table.shape(), (3, 2));
+    ///
+    /// let data = "Debian,1993\nArch,2002\n";
+    /// let table = CsvOptions::new().detect_header().read(data.as_bytes()).unwrap().build();
+    /// assert_eq!(table.to_string(), "+--------+------+\n| Debian | 1993 |\n+--------+------+\n|  Arch  | 2002 |\n+--------+------+\n");
+    /// ```
+    pub fn detect_header(mut self) -> Self {
+        self.has_header = None;
+        self
+    }
+
+    /// Reads a CSV data into a [Builder].
+    ///
+    /// Records may have a different number of fields,
+    /// missing cells are filled the same way as for [Builder::add_row].
+    pub fn read<R: std::io::Read>(&self, reader: R) -> Result<Builder, Error> {
+        let mut reader = csv::ReaderBuilder::new()
+            .delimiter(self.delimiter)
+            .quote(self.quote)
+            .quoting(self.quoting)
+            .has_headers(false)
+            .flexible(true)
+            .from_reader(reader);
+
+        let records = reader
+            .records()
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|err| Error::Csv(err.to_string()))?;
+
+        let has_header = match self.has_header {
+            Some(has_header) => has_header,
+            None => looks_like_header(&records),
+        };
+
+        let mut builder = Builder::new();
+        let mut records = records.iter();
+        if has_header {
+            if let Some(header) = records.next() {
+                builder = builder.set_header(header.iter());
+            }
+        }
+
+        for record in records {
+            builder = builder.add_row(record.iter());
+        }
+
+        Ok(builder)
+    }
+}
+
+// a first record is a header if it differs from the rest by types or lengths of values
+#[cfg(feature = "csv")]
+fn looks_like_header(records: &[csv::StringRecord]) -> bool {
+    let (header, rows) = match records.split_first() {
+        Some((header, rows)) if !rows.is_empty() => (header, rows),
+        _ => return false,
+    };
+
+    let is_number = |value: &str| value.trim().parse::<f64>().is_ok();
+
+    let mut votes = 0;
+    for (column, name) in header.iter().enumerate() {
+        let values = rows
+            .iter()
+            .filter_map(|row| row.get(column))
+            .collect::<Vec<_>>();
+        if values.is_empty() {
+            continue;
+        }
+
+        if values.iter().all(|value| is_number(value)) {
+            votes += if is_number(name) { -1 } else { 1 };
+            continue;
+        }
+
+        let length = values[0].chars().count();
+        if values.iter().all(|value| value.chars().count() == length) {
+            votes += if name.chars().count() == length {
+                -1
+            } else {
+                1
+            };
+        }
+    }
+
+    votes > 0
+}
+
+#[cfg(feature = "csv")]
+impl Default for CsvOptions {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl<R, V> FromIterator<R> for Builder
 where
     R: IntoIterator<Item = V>,
@@ -174,6 +977,32 @@ where
     }
 }
 
+/// A [Builder] is created from a content of [Table] cells.
+///
+/// Header rows of the table are kept as a header, by default it's a first row.
+/// Styles and other settings of the table are not kept.
+impl From<Table> for Builder {
+    fn from(table: Table) -> Self {
+        let grid = &table.grid;
+        let mut rows = (0..grid.count_rows()).map(|row| {
+            (0..grid.count_columns())
+                .map(|column| grid.get_cell_content(row, column).to_owned())
+                .collect::<Vec<_>>()
+        });
+
+        let mut builder = Self::default();
+        for header in rows.by_ref().take(grid.count_header_rows()) {
+            builder = builder.add_header_row(header);
+        }
+
+        rows.fold(builder, |builder, row| builder.add_row(row))
+    }
+}
+
+/// A [Builder] is created from rows which are moved into it as they are.
+///
+/// Unlike [FromIterator] a content is not converted by [Display],
+/// so it's preferable for big data sets as no cell is copied on the way to a [Table].
 impl From<Vec<Vec<String>>> for Builder {
     fn from(rows: Vec<Vec<String>>) -> Self {
         let max_row_length = rows.iter().map(|row| row.len()).max().unwrap_or(0);
@@ -185,48 +1014,95 @@ impl From<Vec<Vec<String>>> for Builder {
     }
 }
 
-/// Building [Table] from ordinary data.
-fn build_table(header: Option<Vec<String>>, rows: Vec<Vec<String>>, count_columns: usize) -> Table {
-    let grid = build_grid(header, rows, count_columns);
-    create_table_from_grid(grid)
+/// A shape of a document which is shown as a table.
+#[cfg(any(feature = "toml", feature = "serde_yaml"))]
+enum Document {
+    Records(Vec<Vec<(String, String)>>),
+    Map(Vec<(String, String)>),
+    List(Vec<String>),
 }
 
-/// Building [Grid] from ordinary data.
-fn build_grid(header: Option<Vec<String>>, rows: Vec<Vec<String>>, count_columns: usize) -> Grid {
-    let mut count_rows = rows.len();
+#[cfg(any(feature = "toml", feature = "serde_yaml"))]
+fn from_document(document: Document) -> Builder {
+    match document {
+        Document::Records(records) => {
+            // keys are kept in order of their first appearance
+            let mut header: Vec<String> = Vec::new();
+            for (key, _) in records.iter().flatten() {
+                if !header.contains(key) {
+                    header.push(key.clone());
+                }
+            }
 
-    if header.is_some() {
-        count_rows += 1;
+            let mut builder = Builder::new().set_header(header.iter());
+            for mut record in records {
+                let row = header.iter().map(|key| {
+                    record
+                        .iter()
+                        .position(|(k, _)| k == key)
+                        .map(|i| record.swap_remove(i).1)
+                        .unwrap_or_default()
+                });
+                builder = builder.add_row(row.collect::<Vec<_>>());
+            }
+
+            builder
+        }
+        Document::Map(pairs) => pairs.into_iter().fold(
+            Builder::new().set_header(["key", "value"]),
+            |builder, (key, value)| builder.add_row([key, value]),
+        ),
+        Document::List(values) => values
+            .into_iter()
+            .fold(Builder::new().set_header(["value"]), |builder, value| {
+                builder.add_row([value])
+            }),
     }
+}
 
-    let mut grid = Grid::new(count_rows, count_columns);
+/// Building [Table] from ordinary data.
+fn build_table(
+    header: Vec<Vec<String>>,
+    rows: Vec<Vec<String>>,
+    count_columns: usize,
+    alignments: HashMap<usize, Alignment>,
+) -> Table {
+    let grid = build_grid(header, rows, count_columns);
+    create_table_from_grid(grid, alignments)
+}
 
-    let mut row = 0;
-    if let Some(headers) = header {
-        for (i, text) in headers.into_iter().enumerate() {
-            grid.set(&Entity::Cell(0, i), Settings::new().text(text));
-        }
+/// Building [Grid] from ordinary data.
+///
+/// Cells are moved into the grid so a content is never copied.
+fn build_grid(header: Vec<Vec<String>>, rows: Vec<Vec<String>>, count_columns: usize) -> Grid {
+    let header_rows = header.len();
+    let count_rows = header_rows + rows.len();
 
-        row = 1;
-    }
+    let mut cells = header;
+    cells.reserve(rows.len());
+    // don't show off a empty data array
+    cells.extend(rows.into_iter().filter(|fields| !fields.is_empty()));
+    cells.resize_with(count_rows, Vec::new);
 
-    for fields in rows.into_iter() {
-        // don't show off a empty data array
-        if fields.is_empty() {
-            continue;
-        }
+    let mut grid = Grid::from_cells(cells, count_columns);
 
-        for (column, field) in fields.into_iter().enumerate() {
-            grid.set(&Entity::Cell(row, column), Settings::new().text(field));
-        }
+    // without a header a first row is considered a header
+    grid.set_header_rows(std::cmp::max(header_rows, 1));
 
-        row += 1;
+    // original positions are remembered so cells can be found after a table is restructured
+    for row in 0..grid.count_rows() {
+        for column in 0..grid.count_columns() {
+            grid.set(
+                &Entity::Cell(row, column),
+                Settings::new().origin(row, column),
+            );
+        }
     }
 
     grid
 }
 
-fn create_table_from_grid(mut grid: Grid) -> Table {
+fn create_table_from_grid(mut grid: Grid, alignments: HashMap<usize, Alignment>) -> Table {
     // it's crusial to set a global setting rather than a setting for an each cell
     // as it will be hard to override that since how Grid::style method works
     grid.set(
@@ -236,7 +1112,22 @@ fn create_table_from_grid(mut grid: Grid) -> Table {
             .alignment(AlignmentHorizontal::Center),
     );
 
-    let table = Table { grid };
+    // column alignments are set after the global setting so they are not overridden by it
+    for (column, mut alignment) in alignments {
+        if column >= grid.count_columns() {
+            continue;
+        }
+
+        for row in 0..grid.count_rows() {
+            alignment.change_cell(&mut grid, row, column);
+        }
+    }
+
+    let table = Table {
+        grid,
+        metas: HashMap::new(),
+        errors: Vec::new(),
+    };
     table.with(Style::ascii())
 }
 
@@ -0,0 +1,301 @@
+use std::collections::HashMap;
+
+use crate::{
+    color_support::{ansi256_to_rgb, rgb_to_ansi256, rgb_to_basic},
+    CellOption, ColorSupport,
+};
+use papergrid::{Entity, Grid, Settings};
+
+/// Color represents a foreground color of a cell.
+///
+/// It supports a basic 16 colors palette, 256 colors palette and a truecolor.
+/// A color which is not supported by a terminal is downgraded
+/// to the closest one from a supported palette (see [ColorSupport::detect]).
+///
+/// ```rust,no_run
+///   # use tabled::{Color, Column, Table, Modify};
+///   # let data: Vec<&'static str> = Vec::new();
+///     let table = Table::new(&data)
+///         .with(Modify::new(Column(..1)).with(Color::rgb(12, 200, 90)))
+///         .with(Modify::new(Column(1..)).with(Color::fixed(208)));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Color {
+    /// A color from a basic 16 colors palette.
+    ///
+    /// Indexes from 8 to 15 are bright versions of the first 8 colors,
+    /// a bigger index is taken modulo 16.
+    Basic(u8),
+    /// A color from a 256 colors palette.
+    Fixed(u8),
+    /// A truecolor.
+    Rgb(u8, u8, u8),
+}
+
+impl Color {
+    pub const BLACK: Self = Self::Basic(0);
+    pub const RED: Self = Self::Basic(1);
+    pub const GREEN: Self = Self::Basic(2);
+    pub const YELLOW: Self = Self::Basic(3);
+    pub const BLUE: Self = Self::Basic(4);
+    pub const MAGENTA: Self = Self::Basic(5);
+    pub const CYAN: Self = Self::Basic(6);
+    pub const WHITE: Self = Self::Basic(7);
+
+    /// Creates a color from a basic 16 colors palette.
+    ///
+    /// The index is taken modulo 16.
+    pub const fn basic(index: u8) -> Self {
+        Self::Basic(index % 16)
+    }
+
+    /// Creates a color from a 256 colors palette.
+    pub const fn fixed(index: u8) -> Self {
+        Self::Fixed(index)
+    }
+
+    /// Creates a truecolor.
+    pub const fn rgb(r: u8, g: u8, b: u8) -> Self {
+        Self::Rgb(r, g, b)
+    }
+
+    /// Converts the color to the closest one which is supported.
+    ///
+    /// The color is left as it is for [ColorSupport::None],
+    /// use [Colors::downgrade] to drop it.
+    pub fn downgrade(self, support: ColorSupport) -> Self {
+        match (self, support) {
+            (Self::Rgb(r, g, b), ColorSupport::Ansi256) => Self::Fixed(rgb_to_ansi256(r, g, b)),
+            (Self::Rgb(r, g, b), ColorSupport::Basic) => Self::Basic(rgb_to_basic(r, g, b)),
+            (Self::Fixed(index), ColorSupport::Basic) => {
+                let (r, g, b) = ansi256_to_rgb(index);
+                Self::Basic(rgb_to_basic(r, g, b))
+            }
+            (color, _) => color,
+        }
+    }
+
+    /// Returns an RGB representation of the color.
+    ///
+    /// Basic colors are converted according to a xterm palette.
+    pub fn to_rgb(self) -> (u8, u8, u8) {
+        match self {
+            Self::Basic(index) => ansi256_to_rgb(index % 16),
+            Self::Fixed(index) => ansi256_to_rgb(index),
+            Self::Rgb(r, g, b) => (r, g, b),
+        }
+    }
+
+    /// Colorize wraps each line of a text in escape sequences of the color.
+    pub fn colorize(self, text: &str) -> String {
+        Colors::new().fg(self).colorize(text)
+    }
+
+    /// Creates [Colors] with the color as a foreground one.
+    ///
+    /// ```rust,no_run
+    ///   # use tabled::{Color, Row, Table, Modify};
+    ///   # let data: Vec<&'static str> = Vec::new();
+    ///     let table = Table::new(&data)
+    ///         .with(Modify::new(Row(..1)).with(Color::fg(Color::RED).bg(Color::BLACK)));
+    /// ```
+    pub fn fg(self) -> Colors {
+        Colors::new().fg(self)
+    }
+
+    /// Creates [Colors] with the color as a background one.
+    pub fn bg(self) -> Colors {
+        Colors::new().bg(self)
+    }
+
+    fn escape_sequence(self, is_background: bool) -> String {
+        let (basic, bright, extended) = if is_background {
+            (40, 100, 48)
+        } else {
+            (30, 90, 38)
+        };
+
+        match self {
+            Self::Basic(index) => match index % 16 {
+                index if index < 8 => format!("\u{1b}[{}m", basic + index),
+                index => format!("\u{1b}[{}m", bright + index - 8),
+            },
+            Self::Fixed(index) => format!("\u{1b}[{};5;{}m", extended, index),
+            Self::Rgb(r, g, b) => format!("\u{1b}[{};2;{};{};{}m", extended, r, g, b),
+        }
+    }
+}
+
+/// Colors represents a foreground and a background color of a cell.
+///
+/// Each line of a content is wrapped in escape sequences,
+/// which are not counted in a width of a cell, so an alignment is kept.
+/// A background is applied to a content but not to an indent or an alignment of a cell.
+///
+/// ```rust,no_run
+///   # use tabled::{Color, Colors, Row, Table, Modify};
+///   # let data: Vec<&'static str> = Vec::new();
+///     let table = Table::new(&data)
+///         .with(Modify::new(Row(..1)).with(Colors::new().fg(Color::WHITE).bg(Color::BLUE)));
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct Colors {
+    fg: Option<Color>,
+    bg: Option<Color>,
+}
+
+impl Colors {
+    /// Creates [Colors] which don't change a cell.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets a foreground color.
+    pub fn fg(mut self, color: Color) -> Self {
+        self.fg = Some(color);
+        self
+    }
+
+    /// Sets a background color.
+    pub fn bg(mut self, color: Color) -> Self {
+        self.bg = Some(color);
+        self
+    }
+
+    /// Converts the colors to the closest ones which are supported.
+    ///
+    /// Both colors are dropped in case colors are not supported.
+    pub fn downgrade(self, support: ColorSupport) -> Self {
+        if support == ColorSupport::None {
+            return Self::new();
+        }
+
+        Self {
+            fg: self.fg.map(|color| color.downgrade(support)),
+            bg: self.bg.map(|color| color.downgrade(support)),
+        }
+    }
+
+    /// Colorize wraps each line of a text in escape sequences of the colors.
+    pub fn colorize(self, text: &str) -> String {
+        let mut prefix = String::new();
+        let mut suffix = String::new();
+        if let Some(color) = self.fg {
+            prefix.push_str(&color.escape_sequence(false));
+            suffix.push_str("\u{1b}[39m");
+        }
+
+        if let Some(color) = self.bg {
+            prefix.push_str(&color.escape_sequence(true));
+            suffix.push_str("\u{1b}[49m");
+        }
+
+        if prefix.is_empty() {
+            return text.to_owned();
+        }
+
+        text.lines()
+            .map(|line| format!("{}{}{}", prefix, line, suffix))
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
+
+impl CellOption for Colors {
+    fn change_cell(&mut self, grid: &mut Grid, row: usize, column: usize) {
+        let colors = self.downgrade(ColorSupport::detect());
+        let content = colors.colorize(grid.get_cell_content(row, column));
+        grid.set(&Entity::Cell(row, column), Settings::new().text(content))
+    }
+}
+
+impl CellOption for Color {
+    fn change_cell(&mut self, grid: &mut Grid, row: usize, column: usize) {
+        self.fg().change_cell(grid, row, column)
+    }
+}
+
+/// Gradient colors numeric cells proportionally between 2 colors.
+///
+/// A scale is computed from min and max values of a column.
+/// Cells which are not numbers (e.g. a header) are left untouched.
+///
+/// ```rust,no_run
+///   # use tabled::{Color, Column, Gradient, Table, Modify};
+///   # let data: Vec<(&'static str, f64)> = Vec::new();
+///     let table = Table::new(&data)
+///         .with(Modify::new(Column(1..)).with(Gradient::new(Color::GREEN, Color::RED)));
+/// ```
+#[derive(Debug)]
+pub struct Gradient {
+    min_color: Color,
+    max_color: Color,
+    scales: HashMap<usize, Option<(f64, f64)>>,
+}
+
+impl Gradient {
+    /// Creates a [Gradient] from a color of a minimum value to a color of a maximum value.
+    pub fn new(min_color: Color, max_color: Color) -> Self {
+        Self {
+            min_color,
+            max_color,
+            scales: HashMap::new(),
+        }
+    }
+}
+
+impl CellOption for Gradient {
+    fn change_cell(&mut self, grid: &mut Grid, row: usize, column: usize) {
+        // the scale must be computed before any cell of the column is colored
+        let scale = *self
+            .scales
+            .entry(column)
+            .or_insert_with(|| column_scale(grid, column));
+
+        let (min, max) = match scale {
+            Some(scale) => scale,
+            None => return,
+        };
+
+        let value = match parse_number(grid.get_cell_content(row, column)) {
+            Some(value) => value,
+            None => return,
+        };
+
+        let ratio = if max > min {
+            (value - min) / (max - min)
+        } else {
+            0.0
+        };
+
+        let (r1, g1, b1) = self.min_color.to_rgb();
+        let (r2, g2, b2) = self.max_color.to_rgb();
+        let color = Color::rgb(
+            interpolate(r1, r2, ratio),
+            interpolate(g1, g2, ratio),
+            interpolate(b1, b2, ratio),
+        );
+
+        color.fg().change_cell(grid, row, column)
+    }
+}
+
+fn column_scale(grid: &Grid, column: usize) -> Option<(f64, f64)> {
+    (0..grid.count_rows())
+        .filter_map(|row| parse_number(grid.get_cell_content(row, column)))
+        .fold(None, |scale, value| match scale {
+            Some((min, max)) => Some((f64::min(min, value), f64::max(max, value))),
+            None => Some((value, value)),
+        })
+}
+
+fn parse_number(text: &str) -> Option<f64> {
+    text.trim()
+        .parse::<f64>()
+        .ok()
+        .filter(|value| value.is_finite())
+}
+
+fn interpolate(from: u8, to: u8, ratio: f64) -> u8 {
+    (from as f64 + (to as f64 - from as f64) * ratio).round() as u8
+}
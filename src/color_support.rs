@@ -0,0 +1,294 @@
+use std::borrow::Cow;
+
+use crate::TableOption;
+use papergrid::{Entity, Grid, Settings};
+
+/// ColorSupport represents a palette supported by a terminal.
+///
+/// Being used as an option it converts ANSI colors of a content to the closest ones
+/// from the palette, so a table which was colored for a truecolor terminal
+/// looks right in a basic one.
+/// [ColorSupport::None] removes all escape sequences, which is handy for dumb terminals and logs.
+///
+/// ```rust
+/// use tabled::{ColorSupport, Table};
+///
+/// let data = ["\u{1b}[38;2;255;0;0mred\u{1b}[39m"];
+///
+/// let table = Table::new(&data).with(ColorSupport::Basic);
+/// assert!(table.to_string().contains("\u{1b}[91mred\u{1b}[39m"));
+///
+/// let table = Table::new(&data).with(ColorSupport::None);
+/// assert!(table.to_string().contains("| red  |"));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum ColorSupport {
+    /// Colors are not supported.
+    None,
+    /// A basic 16 colors palette.
+    Basic,
+    /// A 256 colors palette.
+    Ansi256,
+    /// A truecolor.
+    TrueColor,
+}
+
+impl ColorSupport {
+    /// Detects a palette supported by a terminal.
+    ///
+    /// It relies on `NO_COLOR`, `COLORTERM` and `TERM` environment variables.
+    /// On Windows a Windows Terminal is recognized by `WT_SESSION`,
+    /// and a legacy console is considered to support a basic palette.
+    pub fn detect() -> Self {
+        let is_set = |name: &str| std::env::var_os(name).is_some_and(|value| !value.is_empty());
+
+        if is_set("NO_COLOR") {
+            return Self::None;
+        }
+
+        let colorterm = std::env::var("COLORTERM").unwrap_or_default();
+        if colorterm == "truecolor" || colorterm == "24bit" {
+            return Self::TrueColor;
+        }
+
+        if cfg!(windows) && is_set("WT_SESSION") {
+            return Self::TrueColor;
+        }
+
+        let term = std::env::var("TERM").unwrap_or_default();
+        if term == "dumb" {
+            return Self::None;
+        }
+
+        if term.contains("256color") {
+            return Self::Ansi256;
+        }
+
+        Self::Basic
+    }
+
+    /// Detects a palette like [ColorSupport::detect]
+    /// but returns [ColorSupport::None] in case a standard output is not a terminal,
+    /// e.g. when it's redirected to a file.
+    pub fn detect_stdout() -> Self {
+        use std::io::IsTerminal;
+
+        if std::io::stdout().is_terminal() {
+            Self::detect()
+        } else {
+            Self::None
+        }
+    }
+}
+
+impl TableOption for ColorSupport {
+    fn change(&mut self, grid: &mut Grid) {
+        for row in 0..grid.count_rows() {
+            for column in 0..grid.count_columns() {
+                let content = grid.get_cell_content(row, column);
+                if let Cow::Owned(content) = downgrade_sequences(content, *self) {
+                    grid.set(&Entity::Cell(row, column), Settings::new().text(content));
+                }
+            }
+        }
+    }
+}
+
+// rewrites SGR color sequences to fit the palette;
+// in case colors are not supported all escape sequences are removed
+fn downgrade_sequences(text: &str, support: ColorSupport) -> Cow<'_, str> {
+    if support == ColorSupport::TrueColor || !text.contains('\u{1b}') {
+        return Cow::Borrowed(text);
+    }
+
+    let mut buf = String::with_capacity(text.len());
+    let mut rest = text;
+    while let Some(pos) = rest.find('\u{1b}') {
+        buf.push_str(&rest[..pos]);
+        rest = &rest[pos..];
+
+        let length = sequence_length(rest);
+        let sequence = &rest[..length];
+        rest = &rest[length..];
+
+        if support == ColorSupport::None {
+            continue;
+        }
+
+        match sequence
+            .strip_prefix("\u{1b}[")
+            .and_then(|s| s.strip_suffix('m'))
+        {
+            Some(params) => {
+                let params = downgrade_params(params, support);
+                // an empty list would reset all attributes so the sequence is dropped
+                if !params.is_empty() {
+                    buf.push_str("\u{1b}[");
+                    buf.push_str(&params);
+                    buf.push('m');
+                }
+            }
+            None => buf.push_str(sequence),
+        }
+    }
+
+    buf.push_str(rest);
+
+    Cow::Owned(buf)
+}
+
+// returns a length in bytes of an escape sequence at the beginning of the text
+fn sequence_length(text: &str) -> usize {
+    let bytes = text.as_bytes();
+    match bytes.get(1) {
+        // CSI ends with a byte in a 0x40..=0x7E range
+        Some(b'[') => bytes[2..]
+            .iter()
+            .position(|b| (0x40..=0x7E).contains(b))
+            .map_or(bytes.len(), |i| i + 3),
+        // OSC (e.g. a hyperlink) ends with BEL or ST
+        Some(b']') => {
+            let mut i = 2;
+            while i < bytes.len() {
+                match bytes[i] {
+                    0x07 => return i + 1,
+                    0x1b if bytes.get(i + 1) == Some(&b'\\') => return i + 2,
+                    _ => i += 1,
+                }
+            }
+
+            bytes.len()
+        }
+        Some(_) => 1 + text[1..].chars().next().map_or(0, char::len_utf8),
+        None => 1,
+    }
+}
+
+fn downgrade_params(params: &str, support: ColorSupport) -> String {
+    let params = params.split(';').collect::<Vec<_>>();
+    let mut result = Vec::with_capacity(params.len());
+
+    let mut i = 0;
+    while i < params.len() {
+        let param = params[i];
+        let color = match param {
+            "38" | "48" | "58" => parse_extended_color(&params[i + 1..]),
+            _ => None,
+        };
+
+        let (color, length) = match color {
+            Some(color) => color,
+            None => {
+                result.push(param.to_owned());
+                i += 1;
+                continue;
+            }
+        };
+
+        match (support, color) {
+            (ColorSupport::Ansi256, ExtendedColor::Fixed(_)) => {
+                result.push(params[i..=i + length].join(";"))
+            }
+            (ColorSupport::Ansi256, ExtendedColor::Rgb(r, g, b)) => {
+                result.push(format!("{};5;{}", param, rgb_to_ansi256(r, g, b)))
+            }
+            // an underline color has no basic equivalent
+            _ if param == "58" => {}
+            _ => {
+                let (r, g, b) = match color {
+                    ExtendedColor::Fixed(index) => ansi256_to_rgb(index),
+                    ExtendedColor::Rgb(r, g, b) => (r, g, b),
+                };
+
+                let index = rgb_to_basic(r, g, b);
+                let code = if index < 8 { 30 + index } else { 82 + index };
+                let code = if param == "48" { code + 10 } else { code };
+                result.push(code.to_string());
+            }
+        }
+
+        i += length + 1;
+    }
+
+    result.join(";")
+}
+
+#[derive(Clone, Copy)]
+enum ExtendedColor {
+    Fixed(u8),
+    Rgb(u8, u8, u8),
+}
+
+// parses `5;n` or `2;r;g;b` returning a color and a number of used params
+fn parse_extended_color(params: &[&str]) -> Option<(ExtendedColor, usize)> {
+    match params {
+        ["5", n, ..] => Some((ExtendedColor::Fixed(n.parse().ok()?), 2)),
+        ["2", r, g, b, ..] => {
+            let color = ExtendedColor::Rgb(r.parse().ok()?, g.parse().ok()?, b.parse().ok()?);
+            Some((color, 4))
+        }
+        _ => None,
+    }
+}
+
+pub(crate) fn rgb_to_ansi256(r: u8, g: u8, b: u8) -> u8 {
+    if r == g && g == b {
+        return match r {
+            0..=7 => 16,
+            249..=255 => 231,
+            _ => 232 + (((r as u16 - 8) * 24 + 123) / 247) as u8,
+        };
+    }
+
+    let scale = |c: u8| ((c as u16 * 5 + 127) / 255) as u8;
+    16 + 36 * scale(r) + 6 * scale(g) + scale(b)
+}
+
+pub(crate) fn ansi256_to_rgb(index: u8) -> (u8, u8, u8) {
+    const BASIC: [(u8, u8, u8); 16] = [
+        (0, 0, 0),
+        (205, 0, 0),
+        (0, 205, 0),
+        (205, 205, 0),
+        (0, 0, 238),
+        (205, 0, 205),
+        (0, 205, 205),
+        (229, 229, 229),
+        (127, 127, 127),
+        (255, 0, 0),
+        (0, 255, 0),
+        (255, 255, 0),
+        (92, 92, 255),
+        (255, 0, 255),
+        (0, 255, 255),
+        (255, 255, 255),
+    ];
+
+    match index {
+        0..=15 => BASIC[index as usize],
+        16..=231 => {
+            let index = index - 16;
+            let level = |c: u8| if c == 0 { 0 } else { 55 + c * 40 };
+            (level(index / 36), level(index / 6 % 6), level(index % 6))
+        }
+        _ => {
+            let level = 8 + (index - 232) * 10;
+            (level, level, level)
+        }
+    }
+}
+
+pub(crate) fn rgb_to_basic(r: u8, g: u8, b: u8) -> u8 {
+    let value = (r.max(g).max(b) as u16 * 2 + 127) / 255;
+    if value == 0 {
+        return 0;
+    }
+
+    let bit = |c: u8| (c >= 128) as u8;
+    let index = (bit(b) << 2) | (bit(g) << 1) | bit(r);
+    if value == 2 {
+        index + 8
+    } else {
+        index
+    }
+}
@@ -1,12 +1,14 @@
 use crate::Table;
-use crate::TableOption;
-use papergrid::{Entity, Grid};
+use crate::{panel::plain_cell, Alignment, TableOption};
+use papergrid::{AlignmentHorizontal, AlignmentVertical, Border, Entity, Grid, Settings};
 use std::cmp;
 
 /// Concat concatenate tables along a particular axis [Horizontal | Vertical].
 /// It doesn't do any key or column comparisions like SQL's join does.
 ///
 /// When the tables has different sizes, empty cells will be created by default.
+/// A smaller table is placed at the top left corner by default,
+/// which can be changed by [Concat::alignment].
 ///
 /// [Concat] in horizontal mode has simmilar behaiviour to tuples `(a, b)`.
 /// But it behaives on tables rather than on an actuall data.
@@ -18,10 +20,57 @@ use std::cmp;
 ///
 /// let table3 = table1.with(Concat::horizontal(table2));
 /// ```
+///
+/// By default each table keeps its own style.
+/// Use [Concat::style] to make borders of a resulting table consistent.
 pub struct Concat {
     table: Table,
     mode: ConcatMode,
     default_cell: String,
+    alignment: Alignment,
+    style: Option<ConcatStyle>,
+}
+
+/// ConcatStyle defines a style of a table produced by [Concat].
+///
+/// The left table is the one [Concat] is applied to
+/// and the right one is the one which is passed to [Concat].
+/// In a vertical mode the left table is placed on top.
+///
+/// ```
+/// use tabled::{Concat, ConcatStyle, Style, TableIteratorExt};
+/// let table1 = [0, 1].table().with(Style::psql());
+/// let table2 = ["A", "B"].table().with(Style::ascii());
+///
+/// let table3 = table1.with(Concat::vertical(table2).style(ConcatStyle::KeepLeft));
+///
+/// assert_eq!(
+///     table3.to_string(),
+///     concat!(
+///         " i32  \n",
+///         "------\n",
+///         "  0   \n",
+///         "  1   \n",
+///         " &str \n",
+///         "  A   \n",
+///         "  B   \n",
+///     )
+/// );
+/// ```
+pub enum ConcatStyle {
+    /// Borders of the left table are spread over the resulting table.
+    KeepLeft,
+    /// Borders of the right table are spread over the resulting table.
+    KeepRight,
+    /// A style is applied to the resulting table.
+    Restyle(Box<dyn TableOption>),
+}
+
+impl ConcatStyle {
+    /// Creates a [ConcatStyle::Restyle] from a style e.g. [crate::Style::ascii].
+    pub fn restyle(style: impl TableOption + 'static) -> Self {
+        Self::Restyle(Box::new(style))
+    }
 }
 enum ConcatMode {
     Vertical,
@@ -34,6 +83,8 @@ impl Concat {
             table,
             mode,
             default_cell: String::new(),
+            alignment: Alignment::top_left(),
+            style: None,
         }
     }
     /// Concatenate 2 tables horizontally (along axis=0)
@@ -51,92 +102,291 @@ impl Concat {
         self.default_cell = cell.into();
         self
     }
+
+    /// Sets a position of a smaller table.
+    ///
+    /// A horizontal alignment is used by [Concat::vertical] to place a narrower table
+    /// and a vertical one is used by [Concat::horizontal] to place a shorter table.
+    ///
+    /// ```
+    /// use tabled::{Alignment, Concat, Style, TableIteratorExt};
+    /// let table1 = [0, 1, 2].table().with(Style::psql());
+    /// let table2 = ["A"].table().with(Style::psql());
+    ///
+    /// let table3 = table1.with(Concat::horizontal(table2).alignment(Alignment::bottom()));
+    ///
+    /// assert_eq!(
+    ///     table3.to_string(),
+    ///     concat!(
+    ///         " i32       \n",
+    ///         "-----      \n",
+    ///         "  0        \n",
+    ///         "  1   &str \n",
+    ///         "     ------\n",
+    ///         "  2    A   \n",
+    ///     )
+    /// );
+    /// ```
+    pub fn alignment(mut self, alignment: Alignment) -> Self {
+        self.alignment = alignment;
+        self
+    }
+
+    /// Sets a style of a resulting table.
+    pub fn style(mut self, style: ConcatStyle) -> Self {
+        self.style = Some(style);
+        self
+    }
+
+    // an amount of columns which are added before a narrower table
+    fn horizontal_offset(&self, free_columns: usize) -> usize {
+        let alignment = match self.alignment {
+            Alignment::Horizontal(alignment) | Alignment::Both(alignment, _) => alignment,
+            Alignment::Vertical(_) => AlignmentHorizontal::Left,
+        };
+
+        match alignment {
+            AlignmentHorizontal::Left => 0,
+            AlignmentHorizontal::Center => free_columns / 2,
+            AlignmentHorizontal::Right => free_columns,
+        }
+    }
+
+    // an amount of rows which are added before a shorter table
+    fn vertical_offset(&self, free_rows: usize) -> usize {
+        let alignment = match self.alignment {
+            Alignment::Vertical(alignment) | Alignment::Both(_, alignment) => alignment,
+            Alignment::Horizontal(_) => AlignmentVertical::Top,
+        };
+
+        match alignment {
+            AlignmentVertical::Top => 0,
+            AlignmentVertical::Center => free_rows / 2,
+            AlignmentVertical::Bottom => free_rows,
+        }
+    }
+
+    fn restyle(&mut self, left: Option<&mut Grid>, grid: &mut Grid) {
+        match (&mut self.style, left) {
+            (Some(ConcatStyle::KeepLeft), Some(left)) => spread_borders(left, grid),
+            (Some(ConcatStyle::KeepRight), _) => spread_borders(&mut self.table.grid, grid),
+            (Some(ConcatStyle::Restyle(style)), _) => style.change(grid),
+            _ => (),
+        }
+    }
 }
 
 impl TableOption for Concat {
     fn change(&mut self, other: &mut Grid) {
-        match &mut self.mode {
+        // borders of the left table are spread as they were before it's extended
+        let mut left = match self.style {
+            Some(ConcatStyle::KeepLeft) => Some(other.clone()),
+            _ => None,
+        };
+
+        let grid = &self.table.grid;
+        let (count_rows, count_columns) = (other.count_rows(), other.count_columns());
+        match self.mode {
             ConcatMode::Vertical => {
-                let new_row_size = self.table.grid.count_rows() + other.count_rows();
-                let new_column_size =
-                    cmp::max(self.table.grid.count_columns(), other.count_columns());
-                let mut new_grid = Grid::new(new_row_size, new_column_size);
+                let width = cmp::max(count_columns, grid.count_columns());
+                let left_offset = self.horizontal_offset(width - count_columns);
+                let right_offset = self.horizontal_offset(width - grid.count_columns());
 
-                for row in 0..other.count_rows() {
-                    for column in 0..other.count_columns() {
-                        let settings = other.get_settings(row, column);
-                        new_grid.set(
-                            &Entity::Cell(row, column),
-                            settings.border_restriction(false),
-                        );
-                    }
+                for _ in 0..left_offset {
+                    other.insert_column(0);
+                }
+
+                for column in other.count_columns()..width {
+                    other.insert_column(column);
                 }
 
-                for row in 0..self.table.grid.count_rows() {
-                    for column in 0..self.table.grid.count_columns() {
-                        let settings = self.table.grid.get_settings(row, column);
-                        new_grid.set(
-                            &Entity::Cell(other.count_rows() + row, column),
+                for row in 0..grid.count_rows() {
+                    other.insert_row(count_rows + row);
+                    for column in 0..grid.count_columns() {
+                        let settings = grid.get_settings(row, column);
+                        other.set(
+                            &Entity::Cell(count_rows + row, right_offset + column),
                             settings.border_restriction(false),
                         );
                     }
                 }
 
-                for row in 0..new_grid.count_rows() {
-                    for column in 0..new_grid.count_columns() {
-                        if (column >= other.count_columns() && row < other.count_rows())
-                            || (column >= self.table.grid.count_columns()
-                                && row > other.count_rows())
-                        {
-                            let settings =
-                                new_grid.get_settings(row, column).text(&self.default_cell);
-                            new_grid.set(&Entity::Cell(row, column), settings);
+                let added_columns = (0..grid.count_columns())
+                    .map(|column| (right_offset + column, column))
+                    .filter(|&(target, _)| {
+                        target < left_offset || target >= left_offset + count_columns
+                    });
+                keep_fixed_widths(other, grid, added_columns);
+
+                for row in 0..other.count_rows() {
+                    let filled_columns = if row < count_rows {
+                        left_offset..left_offset + count_columns
+                    } else {
+                        right_offset..right_offset + grid.count_columns()
+                    };
+
+                    for column in 0..width {
+                        if !filled_columns.contains(&column) {
+                            let settings = plain_cell().text(&self.default_cell);
+                            other.set(&Entity::Cell(row, column), settings);
                         }
                     }
                 }
-
-                *other = new_grid;
             }
             ConcatMode::Horizontal => {
-                let new_row_size = cmp::max(self.table.grid.count_rows(), other.count_rows());
-                let new_column_size = self.table.grid.count_columns() + other.count_columns();
-                let mut new_grid = Grid::new(new_row_size, new_column_size);
+                let height = cmp::max(count_rows, grid.count_rows());
+                let left_offset = self.vertical_offset(height - count_rows);
+                let right_offset = self.vertical_offset(height - grid.count_rows());
 
-                for column in 0..other.count_columns() {
-                    for row in 0..other.count_rows() {
-                        let settings = other.get_settings(row, column);
-                        new_grid.set(
-                            &Entity::Cell(row, column),
-                            settings.border_restriction(false),
-                        );
-                    }
+                for _ in 0..left_offset {
+                    other.insert_row(0);
                 }
 
-                for column in 0..self.table.grid.count_columns() {
-                    for row in 0..self.table.grid.count_rows() {
-                        let settings = self.table.grid.get_settings(row, column);
-                        new_grid.set(
-                            &Entity::Cell(row, column + other.count_columns()),
+                for row in other.count_rows()..height {
+                    other.insert_row(row);
+                }
+
+                for column in 0..grid.count_columns() {
+                    other.insert_column(count_columns + column);
+                    for row in 0..grid.count_rows() {
+                        let settings = grid.get_settings(row, column);
+                        other.set(
+                            &Entity::Cell(right_offset + row, count_columns + column),
                             settings.border_restriction(false),
                         );
                     }
                 }
 
-                for row in 0..new_grid.count_rows() {
-                    for column in 0..new_grid.count_columns() {
-                        if (row >= other.count_rows() && column < other.count_columns())
-                            || (row >= self.table.grid.count_rows()
-                                && column > other.count_columns())
-                        {
-                            let settings =
-                                new_grid.get_settings(row, column).text(&self.default_cell);
-                            new_grid.set(&Entity::Cell(row, column), settings);
+                let added_columns =
+                    (0..grid.count_columns()).map(|column| (count_columns + column, column));
+                keep_fixed_widths(other, grid, added_columns);
+
+                for column in 0..other.count_columns() {
+                    let filled_rows = if column < count_columns {
+                        left_offset..left_offset + count_rows
+                    } else {
+                        right_offset..right_offset + grid.count_rows()
+                    };
+
+                    for row in 0..height {
+                        if !filled_rows.contains(&row) {
+                            let settings = plain_cell().text(&self.default_cell);
+                            other.set(&Entity::Cell(row, column), settings);
                         }
                     }
                 }
-
-                *other = new_grid;
             }
         }
+
+        self.restyle(left.as_mut(), other);
+    }
+}
+
+// columns added to a table with a fixed layout take widths of the columns they are copied from
+fn keep_fixed_widths(other: &mut Grid, grid: &Grid, columns: impl Iterator<Item = (usize, usize)>) {
+    let mut fixed = match other.get_fixed_widths() {
+        Some(widths) => widths.to_vec(),
+        None => return,
+    };
+
+    let widths = grid.columns_widths();
+    for (target, column) in columns {
+        if target < fixed.len() {
+            fixed[target] = widths[column];
+        }
+    }
+
+    other.set_fixed_widths(Some(fixed));
+}
+
+fn spread_borders(source: &mut Grid, grid: &mut Grid) {
+    if source.count_rows() == 0 || source.count_columns() == 0 {
+        return;
+    }
+
+    let count_rows = grid.count_rows();
+    let count_columns = grid.count_columns();
+    let source_rows = source.count_rows();
+    let source_columns = source.count_columns();
+    let map_row_line = |line| map_line(line, count_rows, source_rows);
+    let map_column_line = |line| map_line(line, count_columns, source_columns);
+
+    let mut borders = Vec::new();
+    for row in 0..count_rows {
+        for column in 0..count_columns {
+            let (top, bottom) = (map_row_line(row), map_row_line(row + 1));
+            let (left, right) = (map_column_line(column), map_column_line(column + 1));
+            let source_row = cmp::min(row, source_rows - 1);
+            let source_column = cmp::min(column, source_columns - 1);
+
+            let border = Border {
+                top: horizontal_char(source, top, source_column),
+                bottom: horizontal_char(source, bottom, source_column),
+                left: vertical_char(source, source_row, left),
+                right: vertical_char(source, source_row, right),
+                left_top_corner: intersection_char(source, top, left),
+                right_top_corner: intersection_char(source, top, right),
+                left_bottom_corner: intersection_char(source, bottom, left),
+                right_bottom_corner: intersection_char(source, bottom, right),
+            };
+
+            borders.push((row, column, border));
+        }
+    }
+
+    grid.clear_split_grid();
+    grid.clear_overide_split_lines();
+
+    for (row, column, border) in borders {
+        grid.set(
+            &Entity::Cell(row, column),
+            Settings::new().border(border).border_restriction(false),
+        );
+    }
+}
+
+fn map_line(line: usize, count: usize, source_count: usize) -> usize {
+    if line == 0 {
+        0
+    } else if line == count {
+        source_count
+    } else {
+        cmp::min(line, cmp::max(source_count - 1, 1))
+    }
+}
+
+fn horizontal_char(grid: &mut Grid, line: usize, column: usize) -> Option<char> {
+    if line < grid.count_rows() {
+        grid.get_border(line, column).top
+    } else {
+        grid.get_border(line - 1, column).bottom
+    }
+}
+
+fn vertical_char(grid: &mut Grid, row: usize, line: usize) -> Option<char> {
+    if line < grid.count_columns() {
+        grid.get_border(row, line).left
+    } else {
+        grid.get_border(row, line - 1).right
+    }
+}
+
+fn intersection_char(grid: &mut Grid, row_line: usize, column_line: usize) -> Option<char> {
+    let is_bottom = row_line == grid.count_rows();
+    let is_right = column_line == grid.count_columns();
+    let border = grid.get_border(
+        if is_bottom { row_line - 1 } else { row_line },
+        if is_right {
+            column_line - 1
+        } else {
+            column_line
+        },
+    );
+
+    match (is_bottom, is_right) {
+        (false, false) => border.left_top_corner,
+        (false, true) => border.right_top_corner,
+        (true, false) => border.left_bottom_corner,
+        (true, true) => border.right_bottom_corner,
     }
 }
@@ -1,8 +1,8 @@
 #[allow(unused)]
 use crate::Table;
-use crate::{bounds_to_usize, TableOption};
-use papergrid::{Entity, Grid};
-use std::ops::RangeBounds;
+use crate::{bounds_to_usize, ext::column_name, TableOption};
+use papergrid::Grid;
+use std::ops::{RangeBounds, RangeFull};
 
 /// Disable removes particular rows/columns from a [Table].
 ///
@@ -28,52 +28,133 @@ impl<R: RangeBounds<usize>> TableOption for Disable<R> {
                 let (x, y) =
                     bounds_to_usize(range.start_bound(), range.end_bound(), grid.count_columns());
 
-                let removal_size = y - x;
-                let new_column_size = grid.count_columns() - removal_size;
-                let mut new_grid = Grid::new(grid.count_rows(), new_column_size);
-
-                for row in 0..grid.count_rows() {
-                    let mut new_column_index = 0;
-                    for column in 0..grid.count_columns() {
-                        let is_column_deleted = column >= x && column < y;
-                        if is_column_deleted {
-                            continue;
-                        }
-
-                        let cell_settings =
-                            grid.get_settings(row, column).border_restriction(false);
-                        new_grid.set(&Entity::Cell(row, new_column_index), cell_settings);
-                        new_column_index += 1;
-                    }
-                }
-
-                *grid = new_grid;
+                remove_columns(grid, |column| column >= x && column < y);
             }
             Self::Row(range) => {
                 let (x, y) =
                     bounds_to_usize(range.start_bound(), range.end_bound(), grid.count_rows());
 
-                let removal_size = y - x;
-                let new_row_size = grid.count_rows() - removal_size;
-                let mut new_grid = Grid::new(new_row_size, grid.count_columns());
+                for row in (x..y).rev() {
+                    grid.remove_row(row);
+                }
 
-                for column in 0..grid.count_columns() {
-                    let mut new_row_index = 0;
-                    for row in 0..grid.count_rows() {
-                        let is_row_deleted = row >= x && row < y;
-                        if is_row_deleted {
-                            continue;
-                        }
+                // removed header rows make the header shorter,
+                // but as usual a first row is considered a header anyway
+                let header_rows = grid.count_header_rows();
+                grid.set_header_rows(std::cmp::max(header_rows, 1));
+            }
+        }
+    }
+}
 
-                        let cell_settings =
-                            grid.get_settings(row, column).border_restriction(false);
-                        new_grid.set(&Entity::Cell(new_row_index, column), cell_settings);
-                        new_row_index += 1;
-                    }
-                }
+impl Disable<RangeFull> {
+    /// Creates an option which removes columns by their header names.
+    ///
+    /// Names are looked up in the last row of a header, like [crate::ext::column_name] does.
+    /// All columns with any of the names are removed, and names which are not found are ignored.
+    ///
+    /// ```rust
+    /// use tabled::{Disable, Style, Table};
+    ///
+    /// let table = Table::new([("Debian", 1993, "apt")])
+    ///     .with(Disable::column_by_name(&["i32"]))
+    ///     .with(Style::psql());
+    ///
+    /// assert_eq!(
+    ///     table.to_string(),
+    ///     concat!(
+    ///         "  &str  | &str \n",
+    ///         "--------+------\n",
+    ///         " Debian | apt  \n",
+    ///     )
+    /// );
+    /// ```
+    pub fn column_by_name<I, S>(names: I) -> DisableByName
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        DisableByName::Names(
+            names
+                .into_iter()
+                .map(|name| name.as_ref().to_owned())
+                .collect(),
+        )
+    }
+
+    /// Creates an option which removes columns from a `start` one to an `end` one inclusively,
+    /// where columns are found by their header names.
+    ///
+    /// The first column with a given name is used.
+    /// Nothing is removed if any of the names is not found or `end` goes before `start`.
+    ///
+    /// ```rust
+    /// use tabled::{builder::Builder, Disable, Style};
+    ///
+    /// let table = Builder::default()
+    ///     .set_header(["a", "b", "c", "d"])
+    ///     .add_row(["1", "2", "3", "4"])
+    ///     .build()
+    ///     .with(Disable::column_range_by_name("b", "c"))
+    ///     .with(Style::psql());
+    ///
+    /// assert_eq!(
+    ///     table.to_string(),
+    ///     concat!(
+    ///         " a | d \n",
+    ///         "---+---\n",
+    ///         " 1 | 4 \n",
+    ///     )
+    /// );
+    /// ```
+    pub fn column_range_by_name<S: AsRef<str>>(start: S, end: S) -> DisableByName {
+        DisableByName::Range(start.as_ref().to_owned(), end.as_ref().to_owned())
+    }
+}
+
+/// DisableByName removes columns of a [Table] found by their header names.
+///
+/// It's created by [Disable::column_by_name] and [Disable::column_range_by_name].
+#[derive(Debug)]
+pub enum DisableByName {
+    /// Columns with any of the names.
+    Names(Vec<String>),
+    /// Columns between two names inclusively.
+    Range(String, String),
+}
+
+impl TableOption for DisableByName {
+    fn change(&mut self, grid: &mut Grid) {
+        let names = (0..grid.count_columns())
+            .map(|column| column_name(grid, column).to_owned())
+            .collect::<Vec<_>>();
 
-                *grid = new_grid;
+        let deleted: Vec<bool> = match self {
+            Self::Names(list) => names.iter().map(|name| list.contains(name)).collect(),
+            Self::Range(start, end) => {
+                let start = names.iter().position(|name| name == start);
+                let end = names.iter().position(|name| name == end);
+                match (start, end) {
+                    (Some(x), Some(y)) => (0..names.len()).map(|c| c >= x && c <= y).collect(),
+                    _ => vec![false; names.len()],
+                }
             }
+        };
+
+        // a grid is left untouched if no column is matched
+        if deleted.contains(&true) {
+            remove_columns(grid, |column| deleted[column]);
+        }
+    }
+}
+
+fn remove_columns<F>(grid: &mut Grid, is_deleted: F)
+where
+    F: Fn(usize) -> bool,
+{
+    for column in (0..grid.count_columns()).rev() {
+        if is_deleted(column) {
+            grid.remove_column(column);
         }
     }
 }
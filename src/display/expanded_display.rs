@@ -9,11 +9,13 @@ use crate::Tabled;
 /// Because of that `colors` may not be rendered.
 pub struct ExpandedDisplay {
     format_record_splitter: Option<fn(usize) -> String>,
-    format_value: Option<Box<dyn Fn(&str) -> String>>,
+    format_value: Option<ValueFormatter>,
     fields: Vec<String>,
     records: Vec<Vec<String>>,
 }
 
+type ValueFormatter = Box<dyn Fn(&str) -> String>;
+
 impl ExpandedDisplay {
     /// Creates a new instance of ExpandedDisplay
     pub fn new<T: Tabled>(iter: impl IntoIterator<Item = T>) -> Self {
@@ -161,14 +163,11 @@ fn write_header_template(
             let part1 = (max_field_width + 1) - default_template_length;
             let part2 = rest_to_print - part1 - 1;
 
-            template.extend(
-                std::iter::repeat('-')
-                    .take(part1)
-                    .chain(std::iter::once('+'))
-                    .chain(std::iter::repeat('-').take(part2)),
-            );
+            template.push_str(&"-".repeat(part1));
+            template.push('+');
+            template.push_str(&"-".repeat(part2));
         } else {
-            template.extend(std::iter::repeat('-').take(rest_to_print));
+            template.push_str(&"-".repeat(rest_to_print));
         }
     }
 
@@ -196,9 +195,9 @@ fn write_record_line(
 }
 
 fn truncate(s: &str, max: usize) -> String {
-    crate::width::strip(s, max)
+    crate::width::strip(s, max).into_owned()
 }
 
 fn wrap(s: &str, max: usize) -> String {
-    crate::width::split(s, max)
+    crate::width::split(s, max).into_owned()
 }
@@ -0,0 +1,114 @@
+use std::fmt;
+
+use crate::Table;
+
+/// LogDisplay shows a [Table] in a way convenient for log records and `tracing` events.
+///
+/// By default each line of a rendered table is prefixed,
+/// so a table can be distinguished from other records of a log.
+/// In a single line mode each row after a header is shown as `header=value` pairs
+/// separated by spaces, and rows are separated by `; `.
+/// Values which contain spaces, quotes, `=` or new lines are quoted.
+///
+/// ## Example
+///
+/// ```
+/// use tabled::{display::LogDisplay, Style, Table};
+///
+/// let table = Table::new([("Rust", 2010), ("Hello World", 1972)]).with(Style::psql());
+///
+/// assert_eq!(
+///     LogDisplay::new(&table).prefix("> ").to_string(),
+///     concat!(
+///         ">     &str     | i32  \n",
+///         "> -------------+------\n",
+///         ">     Rust     | 2010 \n",
+///         ">  Hello World | 1972 \n",
+///     )
+/// );
+///
+/// assert_eq!(
+///     LogDisplay::new(&table).single_line().to_string(),
+///     "&str=Rust i32=2010; &str=\"Hello World\" i32=1972",
+/// );
+/// ```
+pub struct LogDisplay<'a> {
+    table: &'a Table,
+    prefix: String,
+    single_line: bool,
+}
+
+impl<'a> LogDisplay<'a> {
+    /// Creates a new instance of LogDisplay.
+    pub fn new(table: &'a Table) -> Self {
+        Self {
+            table,
+            prefix: String::new(),
+            single_line: false,
+        }
+    }
+
+    /// Sets a prefix of each line.
+    ///
+    /// In a single line mode the prefix is put once before the line.
+    pub fn prefix(&mut self, prefix: impl Into<String>) -> &mut Self {
+        self.prefix = prefix.into();
+        self
+    }
+
+    /// Collapses a table into a single line of `header=value` pairs.
+    pub fn single_line(&mut self) -> &mut Self {
+        self.single_line = true;
+        self
+    }
+}
+
+impl fmt::Display for LogDisplay<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if !self.single_line {
+            for line in self.table.to_string().lines() {
+                writeln!(f, "{}{}", self.prefix, line)?;
+            }
+
+            return Ok(());
+        }
+
+        let grid = &self.table.grid;
+        let header_rows = grid.count_header_rows();
+        let keys = (0..grid.count_columns())
+            .map(|column| match header_rows {
+                0 => column.to_string(),
+                _ => quote(grid.get_cell_content(0, column)),
+            })
+            .collect::<Vec<_>>();
+
+        f.write_str(&self.prefix)?;
+        for row in header_rows..grid.count_rows() {
+            if row > header_rows {
+                f.write_str("; ")?;
+            }
+
+            for (column, key) in keys.iter().enumerate() {
+                if column > 0 {
+                    f.write_str(" ")?;
+                }
+
+                let value = quote(grid.get_cell_content(row, column));
+                write!(f, "{}={}", key, value)?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+fn quote(s: &str) -> String {
+    let need_quotes = s.is_empty()
+        || s.chars()
+            .any(|c| c.is_whitespace() || c == '"' || c == '=' || c == ';');
+    if need_quotes {
+        format!("{:?}", s)
+    } else {
+        s.to_owned()
+    }
+}
@@ -1,4 +1,8 @@
 /// A module which contains a different Views for a [crate::Table].
 mod expanded_display;
+mod log_display;
+mod progress_display;
 
 pub use expanded_display::*;
+pub use log_display::*;
+pub use progress_display::*;
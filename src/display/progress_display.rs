@@ -0,0 +1,138 @@
+use std::fmt::Display;
+
+use papergrid::{string_width, Border, Entity, Grid, Settings};
+
+use crate::Table;
+
+/// ProgressDisplay renders single rows of a [Table] keeping widths of its columns,
+/// so a row can be updated in place like a progress bar.
+///
+/// A header is rendered once by [ProgressDisplay::header],
+/// and each update is rendered by [ProgressDisplay::row]
+/// without a trailing new line and horizontal borders,
+/// so it can be printed over the previous one after a carriage return.
+///
+/// A row takes a style of the last row of the table.
+/// A content which doesn't fit a column is truncated, so a line never grows.
+/// Use [crate::MinWidth] or [crate::UseWidths] on the table to reserve a space for values.
+///
+/// ## Example
+///
+/// ```
+/// use tabled::{display::ProgressDisplay, Style, Table};
+///
+/// let table = Table::new([("file.txt", "100%")]).with(Style::psql());
+/// let progress = ProgressDisplay::new(&table);
+///
+/// assert_eq!(progress.header(), "   &str   | &str \n----------+------\n");
+/// assert_eq!(progress.row(["data.csv", "5%"]), " data.csv |  5%  ");
+/// assert_eq!(progress.row(["very_long_name.csv", "15%"]), " very_lon | 15%  ");
+///
+/// // print!("\r{}", progress.row(["data.csv", "5%"]));
+/// ```
+#[derive(Debug, Clone)]
+pub struct ProgressDisplay {
+    header: String,
+    cells: Vec<Settings>,
+    widths: Vec<usize>,
+    content_widths: Vec<usize>,
+    grid: Grid,
+}
+
+impl ProgressDisplay {
+    /// Creates a new instance of ProgressDisplay from a template table.
+    pub fn new(table: &Table) -> Self {
+        let grid = &table.grid;
+        let count_columns = grid.count_columns();
+        let header_rows = grid.count_header_rows();
+        let widths = grid.columns_widths();
+        let header = if header_rows > 0 {
+            let mut header = grid.extract(..header_rows, ..);
+            for (column, &width) in widths.iter().enumerate() {
+                header.set_column_min_width(column, width);
+            }
+
+            header.to_string()
+        } else {
+            String::new()
+        };
+
+        let row = grid.count_rows().saturating_sub(1);
+        let mut cells = Vec::with_capacity(count_columns);
+        let mut content_widths = Vec::with_capacity(count_columns);
+        for (column, &width) in widths.iter().enumerate() {
+            let border = grid.get_border(row, column);
+            let border = Border {
+                left: border.left,
+                right: border.right,
+                ..Default::default()
+            };
+
+            let indent = &grid.style(&Entity::Cell(row, column)).indent;
+            content_widths.push(width.saturating_sub(indent.left + indent.right));
+
+            let settings = grid
+                .get_settings(row, column)
+                .span(1)
+                .border(border)
+                .border_restriction(false);
+            cells.push(settings);
+        }
+
+        let mut template = Grid::new(1, count_columns);
+        template.copy_grid_settings(grid);
+
+        Self {
+            header,
+            cells,
+            widths,
+            content_widths,
+            grid: template,
+        }
+    }
+
+    /// Renders header rows of the table with a line underneath them.
+    pub fn header(&self) -> String {
+        self.header.clone()
+    }
+
+    /// Renders a row without a trailing new line.
+    pub fn row<I, T>(&self, fields: I) -> String
+    where
+        I: IntoIterator<Item = T>,
+        T: Display,
+    {
+        let mut fields = fields.into_iter();
+        let mut grid = self.grid.clone();
+        for (column, settings) in self.cells.iter().enumerate() {
+            let text = fields
+                .next()
+                .map(|field| field.to_string())
+                .unwrap_or_default();
+            let text = fit_width(&text, self.content_widths[column]);
+
+            grid.set(&Entity::Cell(0, column), settings.clone().text(text));
+            grid.set_column_min_width(column, self.widths[column]);
+        }
+
+        let mut row = grid.to_string();
+        if row.ends_with('\n') {
+            row.pop();
+        }
+
+        row
+    }
+}
+
+fn fit_width(text: &str, width: usize) -> String {
+    text.lines()
+        .map(|line| {
+            if string_width(line) > width {
+                crate::width::strip(line, width).into_owned()
+            } else {
+                line.to_owned()
+            }
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
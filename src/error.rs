@@ -0,0 +1,77 @@
+use std::fmt;
+
+/// Error represents a misconfiguration of a [crate::Table] found by [crate::Table::try_build]
+/// or a failure to build a table from data.
+///
+/// New variants may be added with new features, so it's marked as `non_exhaustive`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum Error {
+    /// A span of a cell goes beyond the last column.
+    SpanOutOfRange {
+        row: usize,
+        column: usize,
+        span: usize,
+    },
+    /// A span of a cell goes beyond the last row.
+    RowSpanOutOfRange {
+        row: usize,
+        column: usize,
+        span: usize,
+    },
+    /// A cell targeted by [crate::Modify] is out of a table.
+    OutOfRange { row: usize, column: usize },
+    /// A header of a [crate::builder::Builder] doesn't match the one which was expected.
+    HeaderMismatch {
+        expected: Vec<String>,
+        found: Vec<String>,
+    },
+    /// A CSV data can't be read by a [crate::builder::Builder].
+    ///
+    /// It's available only with a `csv` feature on.
+    #[cfg(feature = "csv")]
+    Csv(String),
+    /// An Arrow data can't be formatted by a [crate::builder::Builder].
+    ///
+    /// It's available only with an `arrow` feature on.
+    #[cfg(feature = "arrow")]
+    Arrow(String),
+    /// SQLite rows can't be read by a [crate::builder::Builder].
+    ///
+    /// It's available only with a `rusqlite` feature on.
+    #[cfg(feature = "rusqlite")]
+    Sqlite(String),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::SpanOutOfRange { row, column, span } => write!(
+                f,
+                "a span {} of a cell ({}, {}) goes beyond the last column",
+                span, row, column
+            ),
+            Self::RowSpanOutOfRange { row, column, span } => write!(
+                f,
+                "a row span {} of a cell ({}, {}) goes beyond the last row",
+                span, row, column
+            ),
+            Self::OutOfRange { row, column } => {
+                write!(f, "a cell ({}, {}) is out of a table", row, column)
+            }
+            Self::HeaderMismatch { expected, found } => write!(
+                f,
+                "a header {:?} doesn't match an expected header {:?}",
+                found, expected
+            ),
+            #[cfg(feature = "csv")]
+            Self::Csv(message) => write!(f, "a CSV data can't be read: {}", message),
+            #[cfg(feature = "arrow")]
+            Self::Arrow(message) => write!(f, "an Arrow data can't be formatted: {}", message),
+            #[cfg(feature = "rusqlite")]
+            Self::Sqlite(message) => write!(f, "SQLite rows can't be read: {}", message),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
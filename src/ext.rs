@@ -0,0 +1,200 @@
+//! This module contains helpers for writing custom options.
+//!
+//! They cover common needs of an option so it doesn't have to deal with a [Grid] directly:
+//! locating cells of an [Object], reading and changing their settings,
+//! and rebuilding a grid of a different shape.
+//!
+//! See [crate::grid] for types of the grid itself.
+//!
+//! # Example
+//!
+//! ```rust
+//! use tabled::{ext, Column, Table};
+//!
+//! // a company theme which marks empty cells
+//! let theme = ext::option(|grid| {
+//!     ext::for_each_cell(grid, &Column(1..), |mut cell| {
+//!         if cell.text().is_empty() {
+//!             cell.set_text("-");
+//!         }
+//!     })
+//! });
+//!
+//! let table = Table::new([("Debian", ""), ("Arch", "rolling")]).with(theme);
+//!
+//! assert_eq!(
+//!     table.to_string(),
+//!     "+--------+---------+\n\
+//!      |  &str  |  &str   |\n\
+//!      +--------+---------+\n\
+//!      | Debian |    -    |\n\
+//!      +--------+---------+\n\
+//!      |  Arch  | rolling |\n\
+//!      +--------+---------+\n"
+//! );
+//! ```
+
+use papergrid::{Entity, Grid, Settings};
+
+use crate::{Object, TableOption};
+
+/// Returns cells of an [Object] which exist on a grid.
+pub fn cells<O: Object>(grid: &Grid, target: &O) -> Vec<(usize, usize)> {
+    let (count_rows, count_columns) = (grid.count_rows(), grid.count_columns());
+    target
+        .grid_cells(grid)
+        .into_iter()
+        .filter(|&(row, column)| row < count_rows && column < count_columns)
+        .collect()
+}
+
+/// Returns a name of a column, which is a content of its cell in the last row of a header.
+///
+/// A cell spanned over the column gives it its name.
+/// An empty string is returned if there's no header.
+///
+/// ```rust
+/// use tabled::{ext, Table};
+///
+/// let table = Table::new([("Debian", 2)]).with(ext::option(|grid| {
+///     assert_eq!(ext::column_name(grid, 1), "i32");
+/// }));
+/// ```
+pub fn column_name(grid: &Grid, column: usize) -> &str {
+    let header_rows = grid.count_header_rows();
+    if header_rows == 0 || column >= grid.count_columns() {
+        return "";
+    }
+
+    let row = header_rows - 1;
+    let origin = (0..column)
+        .find(|&origin| grid.style(&Entity::Cell(row, origin)).span > column - origin)
+        .unwrap_or(column);
+
+    grid.get_cell_content(row, origin)
+}
+
+/// Calls a function for each cell of an [Object].
+pub fn for_each_cell<O, F>(grid: &mut Grid, target: &O, mut f: F)
+where
+    O: Object,
+    F: FnMut(CellMut<'_>),
+{
+    for (row, column) in cells(grid, target) {
+        f(CellMut { grid, row, column });
+    }
+}
+
+/// CellMut is a handle of a cell which is passed to [for_each_cell].
+#[derive(Debug)]
+pub struct CellMut<'a> {
+    grid: &'a mut Grid,
+    row: usize,
+    column: usize,
+}
+
+impl CellMut<'_> {
+    /// Returns a position of the cell.
+    pub fn position(&self) -> (usize, usize) {
+        (self.row, self.column)
+    }
+
+    /// Returns a content of the cell.
+    pub fn text(&self) -> &str {
+        self.grid.get_cell_content(self.row, self.column)
+    }
+
+    /// Sets a content of the cell.
+    pub fn set_text<S: Into<String>>(&mut self, text: S) {
+        self.update(Settings::new().text(text));
+    }
+
+    /// Returns effective settings of the cell,
+    /// which take into account settings of its row, column and a whole grid.
+    pub fn settings(&self) -> Settings {
+        self.grid.get_settings(self.row, self.column)
+    }
+
+    /// Changes settings of the cell.
+    ///
+    /// Only settings which are set are changed.
+    pub fn update(&mut self, settings: Settings) {
+        self.grid
+            .set(&Entity::Cell(self.row, self.column), settings);
+    }
+
+    /// Returns a grid the cell belongs to.
+    pub fn grid(&self) -> &Grid {
+        self.grid
+    }
+}
+
+/// Creates a [TableOption] from a function.
+pub fn option<F>(f: F) -> FnOption<F>
+where
+    F: FnMut(&mut Grid),
+{
+    FnOption(f)
+}
+
+/// FnOption is a [TableOption] made of a function, see [option].
+#[derive(Debug)]
+pub struct FnOption<F>(F);
+
+impl<F> TableOption for FnOption<F>
+where
+    F: FnMut(&mut Grid),
+{
+    fn change(&mut self, grid: &mut Grid) {
+        (self.0)(grid)
+    }
+}
+
+/// Rebuilds a grid with a different number of rows and columns.
+///
+/// A function maps a position on a new grid to a position on the old one,
+/// a cell gets all settings of the old one including borders.
+/// Cells which are not mapped are left empty.
+/// Settings of borders and a number of header rows are kept.
+///
+/// ```rust
+/// use tabled::{ext, Table};
+///
+/// // duplicates each row of a body
+/// let option = ext::option(|grid| {
+///     let count_rows = grid.count_rows() * 2 - 1;
+///     let count_columns = grid.count_columns();
+///     ext::reshape(grid, (count_rows, count_columns), |row, column| {
+///         let row = if row == 0 { 0 } else { (row + 1) / 2 };
+///         Some((row, column))
+///     });
+/// });
+///
+/// let table = Table::new(["a", "b"]).with(option);
+///
+/// assert_eq!(table.shape(), (5, 1));
+/// ```
+pub fn reshape<F>(grid: &mut Grid, (count_rows, count_columns): (usize, usize), f: F)
+where
+    F: Fn(usize, usize) -> Option<(usize, usize)>,
+{
+    let mut new_grid = Grid::new(count_rows, count_columns);
+    for row in 0..count_rows {
+        for column in 0..count_columns {
+            let origin = f(row, column).filter(|&(origin_row, origin_column)| {
+                origin_row < grid.count_rows() && origin_column < grid.count_columns()
+            });
+
+            if let Some((origin_row, origin_column)) = origin {
+                let settings = grid
+                    .get_settings(origin_row, origin_column)
+                    .border_restriction(false);
+                new_grid.set(&Entity::Cell(row, column), settings);
+            }
+        }
+    }
+
+    new_grid.copy_grid_settings(grid);
+    new_grid.set_header_rows(grid.count_header_rows());
+    *grid = new_grid;
+}
@@ -1,4 +1,4 @@
-use crate::CellOption;
+use crate::{ext::column_name, CellOption};
 use papergrid::{Entity, Grid, Settings};
 
 /// Formatting of particular cells on a [Grid].
@@ -170,3 +170,196 @@ impl<F: FnMut(&str, usize, usize) -> String> CellOption for FormatWithIndex<F> {
         grid.set(&Entity::Cell(row, column), Settings::new().text(content))
     }
 }
+
+/// FormatWithName is like a [Format].
+/// But it also provides a name of a column,
+/// which is a content of its cell in the last row of a header.
+///
+/// Names are taken before any cell is changed,
+/// so a header can be formatted along with other cells.
+///
+/// # Example
+///
+/// ```
+/// use tabled::{FormatWithName, Modify, Row, Style, Table, Tabled};
+///
+/// #[derive(Tabled)]
+/// struct Job {
+///     name: &'static str,
+///     status: &'static str,
+/// }
+///
+/// let data = vec![
+///     Job { name: "build", status: "ok" },
+///     Job { name: "deploy", status: "failed" },
+/// ];
+///
+/// let table = Table::new(&data)
+///     .with(Style::psql())
+///     .with(Modify::new(Row(1..)).with(FormatWithName::new(|name, value| {
+///         if name == "status" {
+///             value.to_uppercase()
+///         } else {
+///             value.to_owned()
+///         }
+///     })))
+///     .to_string();
+///
+/// assert_eq!(
+///     table,
+///     concat!(
+///         "  name  | status \n",
+///         "--------+--------\n",
+///         " build  |   OK   \n",
+///         " deploy | FAILED \n",
+///     )
+/// );
+/// ```
+pub struct FormatWithName<F: FnMut(&str, &str) -> String> {
+    f: F,
+    // names are taken before any cell is changed so formatting a header doesn't affect them
+    names: Option<Vec<String>>,
+}
+
+impl<F: FnMut(&str, &str) -> String> FormatWithName<F> {
+    /// Creates a formatting by a function of a column name and a content of a cell.
+    pub fn new(f: F) -> Self {
+        Self { f, names: None }
+    }
+}
+
+impl<F: FnMut(&str, &str) -> String> CellOption for FormatWithName<F> {
+    fn change_cell(&mut self, grid: &mut Grid, row: usize, column: usize) {
+        let names = self.names.get_or_insert_with(|| {
+            (0..grid.count_columns())
+                .map(|column| column_name(grid, column).to_owned())
+                .collect()
+        });
+
+        let name = names.get(column).map_or("", String::as_str);
+        let content = grid.get_cell_content(row, column);
+        let content = (self.f)(name, content);
+        grid.set(&Entity::Cell(row, column), Settings::new().text(content))
+    }
+}
+
+/// Newlines sets how line breaks inside a cell are handled.
+///
+/// It's useful for compact single line tables of messages,
+/// where a multiline content would change a height of a row.
+///
+/// # Example
+///
+/// ```
+/// use tabled::{Full, Modify, Newlines, Style, Table};
+///
+/// let data = ["first\nsecond"];
+/// let table = Table::new(&data)
+///     .with(Style::psql())
+///     .with(Modify::new(Full).with(Newlines::Escape))
+///     .to_string();
+///
+/// assert_eq!(
+///     table,
+///     concat!(
+///         "     &str      \n",
+///         "---------------\n",
+///         " first\\nsecond \n",
+///     )
+/// );
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Newlines {
+    /// Line breaks are shown as `\n` and carriage returns as `\r`,
+    /// so a content is rendered on a single line.
+    Escape,
+    /// Escaped `\n` and `\r\n` sequences are rendered as line breaks.
+    Render,
+}
+
+impl CellOption for Newlines {
+    fn change_cell(&mut self, grid: &mut Grid, row: usize, column: usize) {
+        let content = grid.get_cell_content(row, column);
+        let content = match self {
+            Newlines::Escape => content.replace('\r', "\\r").replace('\n', "\\n"),
+            Newlines::Render => content.replace("\\r\\n", "\n").replace("\\n", "\n"),
+        };
+
+        grid.set(&Entity::Cell(row, column), Settings::new().text(content))
+    }
+}
+
+/// ControlChars makes non-printable characters of a cell visible,
+/// so a raw content is shown exactly without breaking a layout.
+///
+/// A line break `\n` is kept as is, [Newlines] can be used to escape it.
+///
+/// # Example
+///
+/// ```
+/// use tabled::{ControlChars, Modify, Row, Style, Table};
+///
+/// let data = ["a\tb\0"];
+///
+/// let table = Table::new(&data)
+///     .with(Style::psql())
+///     .with(Modify::new(Row(1..)).with(ControlChars::Pictures))
+///     .to_string();
+///
+/// assert_eq!(table, " &str \n------\n a␉b␀ \n");
+///
+/// let table = Table::new(&data)
+///     .with(Style::psql())
+///     .with(Modify::new(Row(1..)).with(ControlChars::Escape))
+///     .to_string();
+///
+/// assert_eq!(table, "    &str    \n------------\n a\\x09b\\x00 \n");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ControlChars {
+    /// Control characters are replaced by unicode control pictures like `␉` and `␀`.
+    ///
+    /// Characters which have no picture are escaped.
+    Pictures,
+    /// Control characters are replaced by `\xNN` escapes.
+    Escape,
+}
+
+impl CellOption for ControlChars {
+    fn change_cell(&mut self, grid: &mut Grid, row: usize, column: usize) {
+        let content = grid.get_cell_content(row, column);
+        if !content.chars().any(is_visualized_control) {
+            return;
+        }
+
+        let mut text = String::with_capacity(content.len());
+        for c in content.chars() {
+            if !is_visualized_control(c) {
+                text.push(c);
+                continue;
+            }
+
+            match self.picture(c) {
+                Some(picture) => text.push(picture),
+                None => text.push_str(&format!("\\x{:02X}", c as u32)),
+            }
+        }
+
+        grid.set(&Entity::Cell(row, column), Settings::new().text(text))
+    }
+}
+
+impl ControlChars {
+    fn picture(&self, c: char) -> Option<char> {
+        match (self, c as u32) {
+            (ControlChars::Escape, _) => None,
+            (ControlChars::Pictures, code @ 0x00..=0x1F) => char::from_u32(0x2400 + code),
+            (ControlChars::Pictures, 0x7F) => Some('␡'),
+            (ControlChars::Pictures, _) => None,
+        }
+    }
+}
+
+fn is_visualized_control(c: char) -> bool {
+    c.is_control() && c != '\n'
+}
@@ -0,0 +1,44 @@
+//! This module contains types of an underlying grid which are needed to write custom options.
+//!
+//! A [crate::TableOption] and a [crate::CellOption] get a [Grid] to change.
+//! The types below are the part of the grid which is supported by `tabled`,
+//! they follow semantic versioning of `tabled` rather than of `papergrid`,
+//! so it's recommended to use them instead of depending on `papergrid` directly.
+//!
+//! # Example
+//!
+//! ```rust
+//! use tabled::{
+//!     grid::{Entity, Grid, Settings},
+//!     Table, TableOption,
+//! };
+//!
+//! /// Numbers rows of a body.
+//! struct Enumerate;
+//!
+//! impl TableOption for Enumerate {
+//!     fn change(&mut self, grid: &mut Grid) {
+//!         for row in 1..grid.count_rows() {
+//!             let text = format!("{}. {}", row, grid.get_cell_content(row, 0));
+//!             grid.set(&Entity::Cell(row, 0), Settings::new().text(text));
+//!         }
+//!     }
+//! }
+//!
+//! let table = Table::new(["Hello", "World"]).with(Enumerate);
+//!
+//! assert_eq!(
+//!     table.to_string(),
+//!     "+----------+\n\
+//!      |   &str   |\n\
+//!      +----------+\n\
+//!      | 1. Hello |\n\
+//!      +----------+\n\
+//!      | 2. World |\n\
+//!      +----------+\n"
+//! );
+//! ```
+
+pub use papergrid::{
+    AlignmentHorizontal, AlignmentVertical, Border, CenterRounding, Entity, Grid, Indent, Settings,
+};
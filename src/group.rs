@@ -0,0 +1,109 @@
+#[allow(unused)]
+use crate::Table;
+use crate::{bounds_to_usize, TableOption};
+use papergrid::{AlignmentHorizontal, Entity, Grid, Settings};
+use std::ops::{Bound, RangeBounds};
+
+/// Group adds a header row with names of groups of columns,
+/// each name is centered over its columns.
+///
+/// Groups of a row are set by [Group::and],
+/// each [Group] adds one more row at the top so groups can be nested.
+/// Group rows become a part of a header.
+///
+/// A group is kept over its columns when some of them are removed by [crate::Disable]
+/// or reordered by [crate::SelectColumns], its span is updated accordingly.
+///
+/// ```rust
+/// use tabled::{Disable, Group, Style, Table};
+///
+/// let data = [("Rust", 2010, 2015), ("Go", 2009, 2012)];
+/// let table = Table::new(&data)
+///     .with(Group::new("released", 1..3))
+///     .with(Disable::Column(2..3))
+///     .with(Style::psql());
+///
+/// assert_eq!(
+///     table.to_string(),
+///     concat!(
+///         "      | released \n",
+///         " &str |   i32    \n",
+///         "------+----------\n",
+///         " Rust |   2010   \n",
+///         "  Go  |   2009   \n",
+///     )
+/// );
+/// ```
+#[derive(Debug)]
+pub struct Group {
+    groups: Vec<(String, Bound<usize>, Bound<usize>)>,
+}
+
+impl Group {
+    /// Creates a group of columns with a given name.
+    pub fn new<S, R>(name: S, columns: R) -> Self
+    where
+        S: Into<String>,
+        R: RangeBounds<usize>,
+    {
+        Self { groups: Vec::new() }.and(name, columns)
+    }
+
+    /// Adds one more group to the same row.
+    pub fn and<S, R>(mut self, name: S, columns: R) -> Self
+    where
+        S: Into<String>,
+        R: RangeBounds<usize>,
+    {
+        self.groups.push((
+            name.into(),
+            columns.start_bound().cloned(),
+            columns.end_bound().cloned(),
+        ));
+        self
+    }
+}
+
+impl TableOption for Group {
+    fn change(&mut self, grid: &mut Grid) {
+        let count_columns = grid.count_columns();
+        let mut new_grid = Grid::new(grid.count_rows() + 1, count_columns);
+        for row in 0..grid.count_rows() {
+            for column in 0..count_columns {
+                let cell_settings = grid.get_settings(row, column).border_restriction(false);
+                new_grid.set(&Entity::Cell(row + 1, column), cell_settings);
+            }
+        }
+
+        // a group row is indented as the first row
+        if grid.count_rows() > 0 {
+            for column in 0..count_columns {
+                let indent = &grid.style(&Entity::Cell(0, column)).indent;
+                new_grid.set(
+                    &Entity::Cell(0, column),
+                    Settings::new().indent(indent.left, indent.right, indent.top, indent.bottom),
+                );
+            }
+        }
+
+        for (name, start, end) in &self.groups {
+            let (start, end) = bounds_to_usize(start.as_ref(), end.as_ref(), count_columns);
+            let end = end.min(count_columns);
+            if start >= end {
+                continue;
+            }
+
+            new_grid.set(
+                &Entity::Cell(0, start),
+                Settings::new()
+                    .text(name.clone())
+                    .span(end - start)
+                    .alignment(AlignmentHorizontal::Center),
+            );
+        }
+
+        new_grid.copy_grid_settings(grid);
+        new_grid.set_header_rows(grid.count_header_rows() + 1);
+        *grid = new_grid;
+    }
+}
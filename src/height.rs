@@ -0,0 +1,334 @@
+//! This module contains objects which can be used to limit a cell to a given height
+//! or to make it higher, as well as to limit a height of a whole table.
+
+use crate::{panel::header_like_settings, table::build_page, CellOption, TableOption};
+use papergrid::{Entity, Grid, Settings};
+
+/// MaxHeight cuts lines of a cell if their amount exceeds a given height.
+/// Otherwise keeps the content of a cell untouched.
+///
+/// ## Example
+///
+/// ```
+/// use tabled::{Ellipsis, Full, MaxHeight, Modify, Style, Table};
+///
+/// let table = Table::new(&["Hello\nWorld\n!"])
+///     .with(Style::psql())
+///     .with(Modify::new(Full).with(MaxHeight::new(2).ellipsis(Ellipsis::Line("...".into()))));
+///
+/// assert_eq!(table.to_string(), " &str  \n-------\n Hello \n  ...  \n");
+/// ```
+#[derive(Debug)]
+pub struct MaxHeight {
+    height: usize,
+    ellipsis: Ellipsis,
+    strategy: HeightStrategy,
+}
+
+/// Ellipsis defines how a cut of lines by [MaxHeight] is shown.
+///
+/// It's applied to the last visible line if lines are cut at the bottom,
+/// and to the first visible one if lines are cut at the top.
+#[derive(Debug, Clone)]
+pub enum Ellipsis {
+    /// The cut is not shown.
+    None,
+    /// The last visible line is replaced by a given one.
+    Line(String),
+    /// A given string is appended to the last visible line.
+    Suffix(String),
+}
+
+/// HeightStrategy defines which lines are kept by [MaxHeight].
+///
+/// ## Example
+///
+/// ```
+/// use tabled::{Full, HeightStrategy, MaxHeight, Modify, Style, Table};
+///
+/// let table = Table::new(&["1\n2\n3\n4"])
+///     .with(Style::psql())
+///     .with(Modify::new(Full).with(MaxHeight::new(2).strategy(HeightStrategy::Scroll(1))));
+///
+/// assert_eq!(table.to_string(), " &str \n------\n  2   \n  3   \n");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HeightStrategy {
+    /// First lines are kept.
+    ClipBottom,
+    /// Last lines are kept.
+    ClipTop,
+    /// Lines starting from a given one are kept.
+    ///
+    /// The offset is limited so a cell is always filled.
+    Scroll(usize),
+    /// Blank lines are removed first starting from the bottom,
+    /// then first lines are kept.
+    Squeeze,
+}
+
+impl MaxHeight {
+    /// Creates a [MaxHeight] object.
+    pub fn new(height: usize) -> Self {
+        Self {
+            height,
+            ellipsis: Ellipsis::None,
+            strategy: HeightStrategy::ClipBottom,
+        }
+    }
+
+    /// Sets an [Ellipsis] which is used in case lines are cut.
+    pub fn ellipsis(mut self, ellipsis: Ellipsis) -> Self {
+        self.ellipsis = ellipsis;
+        self
+    }
+
+    /// Sets a [HeightStrategy] which defines lines which are kept.
+    ///
+    /// By default it's [HeightStrategy::ClipBottom].
+    pub fn strategy(mut self, strategy: HeightStrategy) -> Self {
+        self.strategy = strategy;
+        self
+    }
+}
+
+impl CellOption for MaxHeight {
+    fn change_cell(&mut self, grid: &mut Grid, row: usize, column: usize) {
+        let content = grid.get_cell_content(row, column);
+        let mut lines = content.lines().collect::<Vec<_>>();
+        if lines.len() <= self.height {
+            return;
+        }
+
+        if self.strategy == HeightStrategy::Squeeze {
+            squeeze_lines(&mut lines, self.height);
+        }
+
+        let count_lines = lines.len();
+        let height = std::cmp::min(self.height, count_lines);
+        let start = match self.strategy {
+            HeightStrategy::ClipBottom | HeightStrategy::Squeeze => 0,
+            HeightStrategy::ClipTop => count_lines - height,
+            HeightStrategy::Scroll(offset) => std::cmp::min(offset, count_lines - height),
+        };
+        let end = start + height;
+
+        let mut lines = lines[start..end]
+            .iter()
+            .map(|line| line.to_string())
+            .collect::<Vec<_>>();
+
+        if end < count_lines {
+            if let Some(last) = lines.last_mut() {
+                apply_ellipsis(last, &self.ellipsis);
+            }
+        }
+
+        if start > 0 {
+            if let Some(first) = lines.first_mut() {
+                apply_ellipsis(first, &self.ellipsis);
+            }
+        }
+
+        grid.set(
+            &Entity::Cell(row, column),
+            Settings::new().text(lines.join("\n")),
+        )
+    }
+}
+
+/// Removes blank lines starting from the bottom until lines fit the height.
+fn squeeze_lines(lines: &mut Vec<&str>, height: usize) {
+    let mut i = lines.len();
+    while lines.len() > height && i > 0 {
+        i -= 1;
+        if lines[i].trim().is_empty() {
+            lines.remove(i);
+        }
+    }
+}
+
+fn apply_ellipsis(line: &mut String, ellipsis: &Ellipsis) {
+    match ellipsis {
+        Ellipsis::None => {}
+        Ellipsis::Line(ellipsis) => *line = ellipsis.to_owned(),
+        Ellipsis::Suffix(suffix) => line.push_str(suffix),
+    }
+}
+
+/// MinHeight sets a minimum height of a cell.
+///
+/// The height includes an [crate::Indent] of the cell.
+/// It doesn't change a content of a cell, empty lines are added according to a vertical alignment.
+///
+/// ## Example
+///
+/// ```
+/// use tabled::{Alignment, Full, MinHeight, Modify, Style, Table};
+///
+/// let table = Table::new(&["Hello"])
+///     .with(Style::psql())
+///     .with(Modify::new(Full).with(MinHeight::new(3)))
+///     .with(Modify::new(Full).with(Alignment::center_vertical()));
+///
+/// assert_eq!(
+///     table.to_string(),
+///     concat!(
+///         "       \n",
+///         " &str  \n",
+///         "       \n",
+///         "-------\n",
+///         "       \n",
+///         " Hello \n",
+///         "       \n",
+///     )
+/// );
+/// ```
+#[derive(Debug)]
+pub struct MinHeight {
+    height: usize,
+}
+
+impl MinHeight {
+    /// Creates a [MinHeight] object.
+    pub fn new(height: usize) -> Self {
+        Self { height }
+    }
+}
+
+impl CellOption for MinHeight {
+    fn change_cell(&mut self, grid: &mut Grid, row: usize, column: usize) {
+        grid.set(
+            &Entity::Cell(row, column),
+            Settings::new().min_height(self.height),
+        );
+    }
+}
+
+/// Height is a constructor of options which change a height of a whole [crate::Table].
+///
+/// ## Example
+///
+/// ```
+/// use tabled::{Height, Style, Table};
+///
+/// let table = Table::new(1..=10)
+///     .with(Style::psql())
+///     .with(Height::limit(5));
+///
+/// assert_eq!(
+///     table.to_string(),
+///     concat!(
+///         "      i32      \n",
+///         "---------------\n",
+///         "       1       \n",
+///         "       2       \n",
+///         " … 8 more rows \n",
+///     )
+/// );
+/// ```
+pub struct Height;
+
+impl Height {
+    /// Returns a [HeightLimit] object.
+    pub fn limit(height: usize) -> HeightLimit {
+        HeightLimit::new(height)
+    }
+}
+
+/// HeightLimit limits an amount of lines of a rendered [crate::Table].
+///
+/// A header is kept, as well as as many rows as fit the height,
+/// borders and a trailing `… N more rows` line included.
+/// A table which fits the height is left untouched.
+///
+/// If the height is too small for a header and the line, the header is dropped,
+/// and if the line alone doesn't fit either, nothing is left.
+#[derive(Debug, Clone)]
+pub struct HeightLimit {
+    height: usize,
+}
+
+impl HeightLimit {
+    fn new(height: usize) -> Self {
+        Self { height }
+    }
+}
+
+impl TableOption for HeightLimit {
+    fn change(&mut self, grid: &mut Grid) {
+        // each row takes at least a line so there's no need to render a big table
+        if grid.count_rows() <= self.height && count_lines(grid) <= self.height {
+            return;
+        }
+
+        let header_rows = std::cmp::min(grid.count_header_rows(), grid.count_rows());
+        let count_rows = grid.count_rows() - header_rows;
+        if count_rows == 0 {
+            return;
+        }
+
+        let mut page = elide_rows(grid, header_rows, header_rows, 0, count_rows);
+        if count_lines(&page) > self.height {
+            // a header is dropped and then the line itself if they don't fit
+            page = elide_rows(grid, header_rows, 0, 0, count_rows);
+            if count_lines(&page) > self.height {
+                page = build_page(grid, 0, header_rows..header_rows);
+            }
+
+            *grid = page;
+            return;
+        }
+
+        for shown in 1..count_rows {
+            let next = elide_rows(grid, header_rows, header_rows, shown, count_rows);
+            if count_lines(&next) > self.height {
+                break;
+            }
+
+            page = next;
+        }
+
+        *grid = page;
+    }
+}
+
+// keeps a given amount of header rows and body rows adding a line with an amount of hidden rows
+fn elide_rows(
+    grid: &Grid,
+    header_rows: usize,
+    kept_header_rows: usize,
+    shown: usize,
+    count_rows: usize,
+) -> Grid {
+    // the next hidden row is taken so the line has the borders of a row
+    let next_row = header_rows + shown;
+    let mut page = build_page(grid, kept_header_rows, header_rows..next_row + 1);
+    let row = kept_header_rows + shown;
+
+    let hidden = count_rows - shown;
+    let message = if hidden == 1 {
+        String::from("… 1 more row")
+    } else {
+        format!("… {} more rows", hidden)
+    };
+
+    // cells covered by the message must not affect a height of the line
+    for column in 1..page.count_columns() {
+        page.set(&Entity::Cell(row, column), Settings::new().text(""));
+    }
+
+    page.set(
+        &Entity::Cell(row, 0),
+        header_like_settings(grid)
+            .text(message)
+            .span(page.count_columns())
+            .row_span(1),
+    );
+
+    page
+}
+
+fn count_lines(grid: &Grid) -> usize {
+    grid.to_string().lines().count()
+}
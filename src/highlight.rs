@@ -1,6 +1,6 @@
 #[allow(unused)]
 use crate::Table;
-use crate::TableOption;
+use crate::{Object, TableOption};
 use papergrid::{Entity, Grid, Settings};
 
 pub use papergrid::Border;
@@ -49,11 +49,207 @@ impl Highlight {
         )
     }
 
+    /// Fills an indent and an alignment space of cells with a character.
+    ///
+    /// ```rust
+    ///   # use tabled::{Column, Highlight, Style, Table};
+    ///     let table = Table::new(&["Hello", "World!"])
+    ///         .with(Style::psql())
+    ///         .with(Highlight::fill(Column(..), '.'));
+    ///
+    ///     assert_eq!(
+    ///         table.to_string(),
+    ///         concat!(
+    ///             "..&str..\n",
+    ///             "--------\n",
+    ///             ".Hello..\n",
+    ///             ".World!.\n",
+    ///         )
+    ///     );
+    /// ```
+    pub fn fill<O: Object>(object: O, c: char) -> HighlightFill<O> {
+        HighlightFill { object, c }
+    }
+
+    /// Draws a single frame around a region of cells of an [Object].
+    ///
+    /// Unlike highlighting cells one by one only an outer boundary of the region is drawn,
+    /// so a region may have any shape.
+    ///
+    /// ```rust
+    ///   # use tabled::{Border, Cell, Column, Highlight, Object, Row, Style, Table};
+    ///     let data = [["A", "B", "C"], ["D", "E", "F"], ["G", "H", "I"]];
+    ///     let table = Table::new(&data)
+    ///         .with(Style::ascii())
+    ///         .with(Highlight::object(
+    ///             Row(1..2).and(Cell(2, 0)),
+    ///             Border::full('*', '*', '*', '*', '*', '*', '*', '*'),
+    ///         ));
+    ///
+    ///     assert_eq!(
+    ///         table.to_string(),
+    ///         concat!(
+    ///             "+---+---+---+\n",
+    ///             "| 0 | 1 | 2 |\n",
+    ///             "*************\n",
+    ///             "* A | B | C *\n",
+    ///             "*---*********\n",
+    ///             "* D * E | F |\n",
+    ///             "*****---+---+\n",
+    ///             "| G | H | I |\n",
+    ///             "+---+---+---+\n",
+    ///         )
+    ///     );
+    /// ```
+    pub fn object<O: Object>(object: O, border: Border) -> HighlightObject<O> {
+        HighlightObject { object, border }
+    }
+
     fn new(target: Target, border: Border) -> Self {
         Self { target, border }
     }
 }
 
+/// HighlightFill fills an indent and an alignment space of cells.
+///
+/// It's created by [Highlight::fill].
+#[derive(Debug)]
+pub struct HighlightFill<O> {
+    object: O,
+    c: char,
+}
+
+impl<O: Object> TableOption for HighlightFill<O> {
+    fn change(&mut self, grid: &mut Grid) {
+        let cells = self.object.grid_cells(grid);
+        for (row, column) in cells {
+            grid.set(&Entity::Cell(row, column), Settings::new().fill(self.c));
+        }
+    }
+}
+
+/// HighlightObject draws a frame around a region of cells.
+///
+/// It's created by [Highlight::object].
+#[derive(Debug)]
+pub struct HighlightObject<O> {
+    object: O,
+    border: Border,
+}
+
+impl<O: Object> TableOption for HighlightObject<O> {
+    fn change(&mut self, grid: &mut Grid) {
+        let (count_rows, count_columns) = (grid.count_rows(), grid.count_columns());
+        let mut region = vec![vec![false; count_columns]; count_rows];
+        for (row, column) in self.object.grid_cells(grid) {
+            if row < count_rows && column < count_columns {
+                region[row][column] = true;
+            }
+        }
+
+        let contains = |row: Option<usize>, column: Option<usize>| match (row, column) {
+            (Some(row), Some(column)) => region
+                .get(row)
+                .and_then(|cells| cells.get(column))
+                .copied()
+                .unwrap_or(false),
+            _ => false,
+        };
+
+        let mut borders = vec![vec![Border::default(); count_columns]; count_rows];
+        for row in 0..count_rows {
+            for column in 0..count_columns {
+                if !region[row][column] {
+                    continue;
+                }
+
+                let border = &mut borders[row][column];
+                if !contains(row.checked_sub(1), Some(column)) {
+                    border.top = self.border.top;
+                }
+
+                if !contains(Some(row + 1), Some(column)) {
+                    border.bottom = self.border.bottom;
+                }
+
+                if !contains(Some(row), column.checked_sub(1)) {
+                    border.left = self.border.left;
+                }
+
+                if !contains(Some(row), Some(column + 1)) {
+                    border.right = self.border.right;
+                }
+            }
+        }
+
+        // each intersection is set by one of the cells of the region around it
+        for row in 0..=count_rows {
+            for column in 0..=count_columns {
+                let around = [
+                    contains(row.checked_sub(1), column.checked_sub(1)),
+                    contains(row.checked_sub(1), Some(column)),
+                    contains(Some(row), column.checked_sub(1)),
+                    contains(Some(row), Some(column)),
+                ];
+
+                let c = match self.intersection(around) {
+                    Some(c) => c,
+                    None => continue,
+                };
+
+                let border = match around {
+                    [_, _, _, true] => &mut borders[row][column].left_top_corner,
+                    [_, _, true, _] => &mut borders[row][column - 1].right_top_corner,
+                    [_, true, _, _] => &mut borders[row - 1][column].left_bottom_corner,
+                    _ => &mut borders[row - 1][column - 1].right_bottom_corner,
+                };
+
+                *border = Some(c);
+            }
+        }
+
+        for (row, borders) in borders.into_iter().enumerate() {
+            for (column, border) in borders.into_iter().enumerate() {
+                if region[row][column] {
+                    let settings = Settings::default()
+                        .border(border)
+                        .border_restriction(false)
+                        .border_override(true);
+                    grid.set(&Entity::Cell(row, column), settings);
+                }
+            }
+        }
+    }
+}
+
+impl<O> HighlightObject<O> {
+    // returns a char of an intersection by cells around it
+    // which are ordered as top left, top right, bottom left and bottom right
+    fn intersection(&self, around: [bool; 4]) -> Option<char> {
+        let border = &self.border;
+        match around {
+            [false, false, false, false] | [true, true, true, true] => None,
+            // a line goes through the intersection
+            [false, false, true, true] => border.top,
+            [true, true, false, false] => border.bottom,
+            [false, true, false, true] => border.left,
+            [true, false, true, false] => border.right,
+            // an outer corner
+            [false, false, false, true] => border.left_top_corner,
+            [false, false, true, false] => border.right_top_corner,
+            [false, true, false, false] => border.left_bottom_corner,
+            [true, false, false, false] => border.right_bottom_corner,
+            // an inner corner
+            [false, true, true, true] => border.right_bottom_corner,
+            [true, false, true, true] => border.left_bottom_corner,
+            [true, true, false, true] => border.right_top_corner,
+            [true, true, true, false] => border.left_top_corner,
+            // regions which touch by a corner
+            [false, true, true, false] | [true, false, false, true] => border.left_top_corner,
+        }
+    }
+}
+
 pub enum Target {
     Cell { row: usize, column: usize },
     Row { from: usize, to: usize },
@@ -67,20 +263,23 @@ impl TableOption for Highlight {
             Target::Cell { row, column } => {
                 let settings = Settings::default()
                     .border(self.border.clone())
-                    .border_restriction(false);
+                    .border_restriction(false)
+                    .border_override(true);
                 grid.set(&Entity::Cell(row, column), settings);
             }
             Target::Frame => {
                 let settings = Settings::default()
                     .border(self.border.clone())
-                    .border_restriction(false);
+                    .border_restriction(false)
+                    .border_override(true);
                 grid.set(&Entity::Global, settings);
             }
             Target::Row { from, to } => {
                 if to == from + 1 {
                     let settings = Settings::default()
                         .border(self.border.clone())
-                        .border_restriction(false);
+                        .border_restriction(false)
+                        .border_override(true);
                     grid.set(&Entity::Row(from), settings);
                 } else {
                     for row in from..to {
@@ -97,7 +296,10 @@ impl TableOption for Highlight {
                             border.bottom = None;
                         }
 
-                        let settings = Settings::default().border(border).border_restriction(false);
+                        let settings = Settings::default()
+                            .border(border)
+                            .border_restriction(false)
+                            .border_override(true);
                         grid.set(&Entity::Row(row), settings);
                     }
                 }
@@ -106,7 +308,8 @@ impl TableOption for Highlight {
                 if to == from + 1 {
                     let settings = Settings::default()
                         .border(self.border.clone())
-                        .border_restriction(false);
+                        .border_restriction(false)
+                        .border_override(true);
                     grid.set(&Entity::Column(from), settings);
                 } else {
                     for column in from..to {
@@ -123,7 +326,10 @@ impl TableOption for Highlight {
                             border.right = None;
                         }
 
-                        let settings = Settings::default().border(border).border_restriction(false);
+                        let settings = Settings::default()
+                            .border(border)
+                            .border_restriction(false)
+                            .border_override(true);
                         grid.set(&Entity::Column(column), settings);
                     }
                 }
@@ -131,3 +337,25 @@ impl TableOption for Highlight {
         }
     }
 }
+
+/// ClearHighlight forgets all borders set by [Highlight].
+///
+/// By default a [Highlight] is kept even when a [crate::Style] is changed.
+/// After [ClearHighlight] the next style change overrides the highlighted borders.
+///
+/// ```rust,no_run
+///   # use tabled::{Border, ClearHighlight, Highlight, Style, Table};
+///   # let data: Vec<&'static str> = Vec::new();
+///     let table = Table::new(&data)
+///         .with(Highlight::frame(Border::full('*', '*', '*', '*', '*', '*', '*', '*')))
+///         .with(ClearHighlight)
+///         .with(Style::ascii());
+/// ```
+#[derive(Debug)]
+pub struct ClearHighlight;
+
+impl TableOption for ClearHighlight {
+    fn change(&mut self, grid: &mut Grid) {
+        grid.clear_border_overrides();
+    }
+}
@@ -0,0 +1,275 @@
+//! This module contains an [Html] renderer which exports a [Table] as an HTML `<table>`.
+
+use std::fmt::Write;
+
+use papergrid::{AlignmentHorizontal, AlignmentVertical, Entity, Grid};
+
+use crate::Table;
+
+/// Html renders a [Table] as an HTML `<table>`.
+///
+/// Header rows of the table are rendered in a `<thead>`, by default it's a first row.
+/// Spans of cells are exported as `colspan` and `rowspan` attributes,
+/// and lines of a multiline cell are separated by `<br>`.
+///
+/// # Example
+///
+/// ```rust
+/// use tabled::{html::Html, Table};
+///
+/// let table = Table::new([("Rust", "<2010>")]);
+/// let html = Html::new().render(&table);
+///
+/// assert_eq!(
+///     html,
+///     concat!(
+///         "<table>\n",
+///         "  <thead>\n",
+///         "    <tr>",
+///         "<th style=\"text-align: center; vertical-align: top\">&amp;str</th>",
+///         "<th style=\"text-align: center; vertical-align: top\">&amp;str</th>",
+///         "</tr>\n",
+///         "  </thead>\n",
+///         "  <tbody>\n",
+///         "    <tr>",
+///         "<td style=\"text-align: center; vertical-align: top\">Rust</td>",
+///         "<td style=\"text-align: center; vertical-align: top\">&lt;2010&gt;</td>",
+///         "</tr>\n",
+///         "  </tbody>\n",
+///         "</table>\n",
+///     )
+/// );
+/// ```
+#[derive(Debug, Clone)]
+pub struct Html {
+    escape: bool,
+    alignment: HtmlAlignment,
+}
+
+/// HtmlAlignment sets how an alignment of cells is exported.
+#[derive(Debug, Clone)]
+pub enum HtmlAlignment {
+    /// An alignment is set by a `style` attribute.
+    ///
+    /// E.g. `style="text-align: left; vertical-align: top"`.
+    Inline,
+    /// An alignment is set by a `class` attribute with classes made of a prefix and an alignment.
+    ///
+    /// E.g. `class="tabled-left tabled-top"` for a `tabled-` prefix.
+    Class(String),
+    /// An alignment is not exported.
+    None,
+}
+
+impl Html {
+    /// Creates an [Html] renderer which escapes a content and sets an alignment inline.
+    pub fn new() -> Self {
+        Self {
+            escape: true,
+            alignment: HtmlAlignment::Inline,
+        }
+    }
+
+    /// Sets whether a content of cells is escaped.
+    ///
+    /// It's on by default.
+    /// It may be turned off if cells contain HTML markup.
+    pub fn escape(mut self, escape: bool) -> Self {
+        self.escape = escape;
+        self
+    }
+
+    /// Sets how an alignment of cells is exported.
+    ///
+    /// ```rust
+    /// use tabled::{html::{Html, HtmlAlignment}, Table};
+    ///
+    /// let table = Table::new(["<b>Hello</b>"]);
+    /// let html = Html::new()
+    ///     .escape(false)
+    ///     .alignment(HtmlAlignment::Class("tabled-".to_owned()))
+    ///     .render(&table);
+    ///
+    /// assert_eq!(
+    ///     html,
+    ///     concat!(
+    ///         "<table>\n",
+    ///         "  <thead>\n",
+    ///         "    <tr><th class=\"tabled-center tabled-top\">&str</th></tr>\n",
+    ///         "  </thead>\n",
+    ///         "  <tbody>\n",
+    ///         "    <tr><td class=\"tabled-center tabled-top\"><b>Hello</b></td></tr>\n",
+    ///         "  </tbody>\n",
+    ///         "</table>\n",
+    ///     )
+    /// );
+    /// ```
+    pub fn alignment(mut self, alignment: HtmlAlignment) -> Self {
+        self.alignment = alignment;
+        self
+    }
+
+    /// Renders a [Table] as an HTML `<table>`.
+    pub fn render(&self, table: &Table) -> String {
+        let mut html = String::new();
+        self.render_grid(&mut html, &table.grid)
+            .expect("writing to a string can't fail");
+
+        html
+    }
+
+    fn render_grid(&self, f: &mut String, grid: &Grid) -> std::fmt::Result {
+        let count_rows = grid.count_rows();
+        let count_columns = grid.count_columns();
+
+        writeln!(f, "<table>")?;
+
+        let header_rows = grid.count_header_rows();
+        let spans = cell_spans(grid);
+        for row in 0..count_rows {
+            let (section, tag) = if row < header_rows {
+                ("thead", "th")
+            } else {
+                ("tbody", "td")
+            };
+
+            if row == 0 || row == header_rows {
+                writeln!(f, "  <{}>", section)?;
+            }
+
+            write!(f, "    <tr>")?;
+            for column in 0..count_columns {
+                let (column_span, row_span) = match spans[row][column] {
+                    Some(spans) => spans,
+                    None => continue,
+                };
+
+                write!(f, "<{}", tag)?;
+
+                if column_span > 1 {
+                    write!(f, " colspan=\"{}\"", column_span)?;
+                }
+
+                if row_span > 1 {
+                    write!(f, " rowspan=\"{}\"", row_span)?;
+                }
+
+                let style = grid.style(&Entity::Cell(row, column));
+                self.render_alignment(f, &style.alignment_h, &style.alignment_v)?;
+
+                write!(f, ">")?;
+                self.render_content(f, grid.get_cell_content(row, column))?;
+                write!(f, "</{}>", tag)?;
+            }
+            writeln!(f, "</tr>")?;
+
+            if row + 1 == header_rows || row + 1 == count_rows {
+                writeln!(f, "  </{}>", section)?;
+            }
+        }
+
+        writeln!(f, "</table>")?;
+
+        Ok(())
+    }
+
+    fn render_alignment(
+        &self,
+        f: &mut String,
+        horizontal: &AlignmentHorizontal,
+        vertical: &AlignmentVertical,
+    ) -> std::fmt::Result {
+        let horizontal = match horizontal {
+            AlignmentHorizontal::Left => "left",
+            AlignmentHorizontal::Center => "center",
+            AlignmentHorizontal::Right => "right",
+        };
+
+        let vertical = match vertical {
+            AlignmentVertical::Top => "top",
+            AlignmentVertical::Center => "middle",
+            AlignmentVertical::Bottom => "bottom",
+        };
+
+        match &self.alignment {
+            HtmlAlignment::Inline => write!(
+                f,
+                " style=\"text-align: {}; vertical-align: {}\"",
+                horizontal, vertical
+            ),
+            HtmlAlignment::Class(prefix) => write!(
+                f,
+                " class=\"{}{} {}{}\"",
+                prefix, horizontal, prefix, vertical
+            ),
+            HtmlAlignment::None => Ok(()),
+        }
+    }
+
+    fn render_content(&self, f: &mut String, content: &str) -> std::fmt::Result {
+        for (i, line) in content.lines().enumerate() {
+            if i > 0 {
+                write!(f, "<br>")?;
+            }
+
+            if self.escape {
+                escape(f, line)?;
+            } else {
+                write!(f, "{}", line)?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl Default for Html {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Returns a column and a row span of each cell,
+/// or [None] if a cell is covered by a span of another cell.
+pub(crate) fn cell_spans(grid: &Grid) -> Vec<Vec<Option<(usize, usize)>>> {
+    let count_rows = grid.count_rows();
+    let count_columns = grid.count_columns();
+
+    let mut spans = vec![vec![None; count_columns]; count_rows];
+    let mut covered = vec![vec![false; count_columns]; count_rows];
+    for row in 0..count_rows {
+        for column in 0..count_columns {
+            let style = grid.style(&Entity::Cell(row, column));
+            if covered[row][column] || style.span == 0 {
+                continue;
+            }
+
+            let column_span = style.span.min(count_columns - column);
+            let row_span = style.row_span.max(1).min(count_rows - row);
+            for covered_row in covered.iter_mut().skip(row).take(row_span) {
+                for is_covered in covered_row.iter_mut().skip(column).take(column_span) {
+                    *is_covered = true;
+                }
+            }
+
+            spans[row][column] = Some((column_span, row_span));
+        }
+    }
+
+    spans
+}
+
+fn escape(f: &mut String, text: &str) -> std::fmt::Result {
+    for c in text.chars() {
+        match c {
+            '&' => write!(f, "&amp;")?,
+            '<' => write!(f, "&lt;")?,
+            '>' => write!(f, "&gt;")?,
+            '"' => write!(f, "&quot;")?,
+            '\'' => write!(f, "&#39;")?,
+            c => f.write_char(c)?,
+        }
+    }
+
+    Ok(())
+}
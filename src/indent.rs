@@ -1,4 +1,6 @@
-use crate::CellOption;
+#[allow(unused)]
+use crate::Table;
+use crate::{CellOption, TableOption};
 use papergrid::{Entity, Grid, Settings};
 
 /// Indent is responsible for a left/right/top/bottom indent of particular cells.
@@ -8,21 +10,167 @@ use papergrid::{Entity, Grid, Settings};
 ///   # let data: Vec<&'static str> = Vec::new();
 ///     let table = Table::new(&data).with(Modify::new(Row(..1)).with(Indent::new(0, 0, 1, 1)));
 /// ```
-#[derive(Debug)]
-pub struct Indent(usize, usize, usize, usize);
+///
+/// An indent can be set only for some sides keeping others unchanged.
+///
+/// ```rust,no_run
+///   # use tabled::{Full, Indent, Row, Table, Modify};
+///   # let data: Vec<&'static str> = Vec::new();
+///     let table = Table::new(&data)
+///         .with(Modify::new(Full).with(Indent::default().left(2)))
+///         .with(Modify::new(Row(..1)).with(Indent::default().top(1)));
+/// ```
+#[derive(Debug, Default)]
+pub struct Indent {
+    left: Option<usize>,
+    right: Option<usize>,
+    top: Option<usize>,
+    bottom: Option<usize>,
+}
 
 impl Indent {
     /// Construct's an Indent object.
     pub fn new(left: usize, right: usize, top: usize, bottom: usize) -> Self {
-        Self(left, right, top, bottom)
+        Self::default()
+            .left(left)
+            .right(right)
+            .top(top)
+            .bottom(bottom)
+    }
+
+    /// Sets a left indent.
+    pub fn left(mut self, size: usize) -> Self {
+        self.left = Some(size);
+        self
+    }
+
+    /// Sets a right indent.
+    pub fn right(mut self, size: usize) -> Self {
+        self.right = Some(size);
+        self
+    }
+
+    /// Sets a top indent.
+    pub fn top(mut self, size: usize) -> Self {
+        self.top = Some(size);
+        self
+    }
+
+    /// Sets a bottom indent.
+    pub fn bottom(mut self, size: usize) -> Self {
+        self.bottom = Some(size);
+        self
     }
 }
 
 impl CellOption for Indent {
     fn change_cell(&mut self, grid: &mut Grid, row: usize, column: usize) {
-        grid.set(
-            &Entity::Cell(row, column),
-            Settings::new().indent(self.0, self.1, self.2, self.3),
-        )
+        let mut settings = Settings::new();
+        if let Some(left) = self.left {
+            settings = settings.indent_left(left);
+        }
+
+        if let Some(right) = self.right {
+            settings = settings.indent_right(right);
+        }
+
+        if let Some(top) = self.top {
+            settings = settings.indent_top(top);
+        }
+
+        if let Some(bottom) = self.bottom {
+            settings = settings.indent_bottom(bottom);
+        }
+
+        grid.set(&Entity::Cell(row, column), settings)
+    }
+}
+
+/// Fill sets patterns which fill a space around a content of particular cells,
+/// an indent and an alignment space included.
+///
+/// A pattern is repeated from the left side of a cell,
+/// so patterns in a column are aligned to each other, which is handy for dotted leaders.
+///
+/// ```rust
+/// use tabled::{Column, Fill, Modify, MinWidth, Alignment, Style, Table};
+///
+/// let table = Table::new([("Name", "tabled"), ("Version", "0.5")])
+///     .with(Style::blank())
+///     .with(
+///         Modify::new(Column(..1))
+///             .with(Alignment::left())
+///             .with(MinWidth::new(12))
+///             .with(Fill::default().right(" .")),
+///     );
+///
+/// assert_eq!(
+///     table.to_string(),
+///     concat!(
+///         " &str. . . .   &str  \n",
+///         " Name. . . .  tabled \n",
+///         " Version . .   0.5   \n",
+///     )
+/// );
+/// ```
+#[derive(Debug, Default)]
+pub struct Fill {
+    left: Option<String>,
+    right: Option<String>,
+}
+
+impl Fill {
+    /// Construct's a Fill object with the same pattern on both sides.
+    pub fn new<S: Into<String>>(pattern: S) -> Self {
+        let pattern = pattern.into();
+        Self::default().left(pattern.clone()).right(pattern)
+    }
+
+    /// Sets a pattern to the left of a content.
+    pub fn left<S: Into<String>>(mut self, pattern: S) -> Self {
+        self.left = Some(pattern.into());
+        self
+    }
+
+    /// Sets a pattern to the right of a content.
+    pub fn right<S: Into<String>>(mut self, pattern: S) -> Self {
+        self.right = Some(pattern.into());
+        self
+    }
+}
+
+impl CellOption for Fill {
+    fn change_cell(&mut self, grid: &mut Grid, row: usize, column: usize) {
+        let mut settings = Settings::new();
+        if let Some(left) = &self.left {
+            settings = settings.fill_left(left.clone());
+        }
+
+        if let Some(right) = &self.right {
+            settings = settings.fill_right(right.clone());
+        }
+
+        grid.set(&Entity::Cell(row, column), settings)
+    }
+}
+
+/// LineSpacing adds blank lines to each row of a [Table] except a header.
+///
+/// A content of cells is not changed,
+/// so the lines are placed according to a vertical alignment.
+///
+/// ```rust,no_run
+///   # use tabled::{LineSpacing, Table};
+///   # let data: Vec<&'static str> = Vec::new();
+///     let table = Table::new(&data).with(LineSpacing(1));
+/// ```
+#[derive(Debug)]
+pub struct LineSpacing(pub usize);
+
+impl TableOption for LineSpacing {
+    fn change(&mut self, grid: &mut Grid) {
+        for row in 1..grid.count_rows() {
+            grid.set_row_spacing(row, self.0);
+        }
     }
 }
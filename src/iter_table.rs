@@ -0,0 +1,264 @@
+//! This module contains an [IterTable] which renders rows of an iterator lazily.
+
+use std::{io, iter::FromIterator};
+
+use papergrid::{Border, Entity, Grid, Settings};
+
+use crate::{builder::Builder, table::build_page, width::split, TableOption, Tabled, UseWidths};
+
+/// IterTable renders rows of an iterator one by one writing them to an [io::Write],
+/// so a table of any length can be printed without keeping its rows in memory.
+///
+/// Widths of columns are fixed.
+/// They are taken from a sample of first rows or set by [IterTable::widths].
+/// A content which doesn't fit a column is wrapped.
+///
+/// Options are applied to a table built out of the sample,
+/// which is used as a template of a header and rows.
+///
+/// ## Example
+///
+/// ```
+/// use tabled::{IterTable, Style};
+///
+/// let mut output = Vec::new();
+/// IterTable::new((1..=3).map(|i| (i, i * 100)))
+///     .sample(2)
+///     .with(Style::psql())
+///     .write_to(&mut output)
+///     .unwrap();
+///
+/// assert_eq!(
+///     String::from_utf8(output).unwrap(),
+///     concat!(
+///         " i32 | i32 \n",
+///         "-----+-----\n",
+///         "  1  | 100 \n",
+///         "  2  | 200 \n",
+///         "  3  | 300 \n",
+///     )
+/// );
+/// ```
+pub struct IterTable<I> {
+    iter: I,
+    sample: usize,
+    widths: Option<Vec<usize>>,
+    options: Vec<Box<dyn TableOption>>,
+}
+
+impl<I, T> IterTable<I>
+where
+    I: Iterator<Item = T>,
+    T: Tabled,
+{
+    /// Creates a new [IterTable] from an iterator of records.
+    ///
+    /// By default first 100 rows are used as a sample.
+    pub fn new<It>(iter: It) -> Self
+    where
+        It: IntoIterator<IntoIter = I>,
+    {
+        Self {
+            iter: iter.into_iter(),
+            sample: 100,
+            widths: None,
+            options: Vec::new(),
+        }
+    }
+
+    /// Sets a number of first rows which are used to determine widths of columns.
+    pub fn sample(mut self, count: usize) -> Self {
+        self.sample = count;
+        self
+    }
+
+    /// Sets widths of columns instead of taking them from a sample.
+    pub fn widths(mut self, widths: Vec<usize>) -> Self {
+        self.widths = Some(widths);
+        self
+    }
+
+    /// Adds an option which is applied to a template table, e.g. a [crate::Style].
+    pub fn with<O>(mut self, option: O) -> Self
+    where
+        O: TableOption + 'static,
+    {
+        self.options.push(Box::new(option));
+        self
+    }
+
+    /// Renders the table writing it row by row.
+    pub fn write_to<W: io::Write>(self, mut writer: W) -> io::Result<()> {
+        let Self {
+            iter,
+            sample,
+            widths,
+            mut options,
+        } = self;
+
+        let mut iter = iter.map(|record| record.fields()).peekable();
+        let mut sample_rows = Vec::new();
+        while sample_rows.len() < sample {
+            match iter.next() {
+                Some(row) => sample_rows.push(row),
+                None => break,
+            }
+        }
+
+        let mut template = build_template::<T>(&sample_rows);
+        for option in &mut options {
+            option.change(&mut template);
+        }
+
+        if let Some(widths) = widths {
+            UseWidths(widths).change(&mut template);
+        }
+
+        let widths = template.columns_widths();
+        let header_rows = template.count_header_rows();
+
+        let mut rows = sample_rows.into_iter().chain(iter).peekable();
+        let mut header = if rows.peek().is_some() {
+            template.extract(..header_rows, ..)
+        } else {
+            build_page(&template, header_rows, header_rows..header_rows)
+        };
+        set_widths(&mut header, &widths);
+        write!(writer, "{}", header)?;
+
+        let row_template = RowTemplate::new(&template, header_rows);
+        let mut is_first = true;
+        while let Some(row) = rows.next() {
+            let is_last = rows.peek().is_none();
+            let mut grid = row_template.build(row, is_first, is_last);
+            set_widths(&mut grid, &widths);
+            write!(writer, "{}", grid)?;
+
+            is_first = false;
+        }
+
+        Ok(())
+    }
+}
+
+fn build_template<T: Tabled>(rows: &[Vec<String>]) -> Grid {
+    // at least 2 rows are used so borders between rows are known
+    let count_empty_rows = 2_usize.saturating_sub(rows.len());
+    let empty_rows = vec![vec![String::new(); T::LENGTH]; count_empty_rows];
+
+    let mut builder = Builder::from_iter(rows.iter().cloned().chain(empty_rows));
+    builder = builder.set_header(T::headers());
+    for (column, alignment) in T::alignments().into_iter().enumerate() {
+        if let Some(alignment) = alignment {
+            builder = builder.column_alignment(column, alignment);
+        }
+    }
+
+    builder.build().grid
+}
+
+fn set_widths(grid: &mut Grid, widths: &[usize]) {
+    for (column, &width) in widths.iter().enumerate() {
+        grid.set_column_min_width(column, width);
+    }
+}
+
+/// Settings of a data row taken from a template table.
+struct RowTemplate {
+    cells: Vec<Settings>,
+    content_widths: Vec<usize>,
+    // left and right borders of cells
+    sides: Vec<Border>,
+    // a line between data rows
+    tops: Vec<Border>,
+    // a bottom line of the table
+    bottoms: Vec<Border>,
+    grid: Grid,
+}
+
+impl RowTemplate {
+    fn new(template: &Grid, header_rows: usize) -> Self {
+        let count_columns = template.count_columns();
+        let last_row = template.count_rows() - 1;
+        let widths = template.columns_widths();
+
+        let mut row_template = Self {
+            cells: Vec::with_capacity(count_columns),
+            content_widths: Vec::with_capacity(count_columns),
+            sides: Vec::with_capacity(count_columns),
+            tops: Vec::with_capacity(count_columns),
+            bottoms: Vec::with_capacity(count_columns),
+            grid: Grid::new(1, count_columns),
+        };
+
+        for (column, &width) in widths.iter().enumerate() {
+            let border = template.get_border(last_row, column);
+            row_template.tops.push(Border {
+                top: border.top,
+                left_top_corner: border.left_top_corner,
+                right_top_corner: border.right_top_corner,
+                ..Default::default()
+            });
+            row_template.bottoms.push(Border {
+                bottom: border.bottom,
+                left_bottom_corner: border.left_bottom_corner,
+                right_bottom_corner: border.right_bottom_corner,
+                ..Default::default()
+            });
+
+            let style = template.style(&Entity::Cell(header_rows, column));
+            let indent = style.indent.left + style.indent.right;
+            // a width must be at least 1 to be able to split a content
+            let content_width = std::cmp::max(width.saturating_sub(indent), 1);
+            row_template.content_widths.push(content_width);
+
+            row_template.sides.push(Border {
+                left: border.left,
+                right: border.right,
+                ..Default::default()
+            });
+
+            let settings = template
+                .get_settings(header_rows, column)
+                .span(1)
+                .border_restriction(false);
+            row_template.cells.push(settings);
+        }
+
+        row_template.grid.copy_grid_settings(template);
+        row_template
+    }
+
+    fn build(&self, row: Vec<String>, is_first: bool, is_last: bool) -> Grid {
+        let mut grid = self.grid.clone();
+        let mut fields = row.into_iter();
+        for (column, settings) in self.cells.iter().enumerate() {
+            let text = fields.next().unwrap_or_default();
+            let text = split(&text, self.content_widths[column])
+                .trim_end_matches('\n')
+                .to_owned();
+
+            let mut border = self.sides[column].clone();
+            if !is_first {
+                let top = &self.tops[column];
+                border.top = top.top;
+                border.left_top_corner = top.left_top_corner;
+                border.right_top_corner = top.right_top_corner;
+            }
+
+            if is_last {
+                let bottom = &self.bottoms[column];
+                border.bottom = bottom.bottom;
+                border.left_bottom_corner = bottom.left_bottom_corner;
+                border.right_bottom_corner = bottom.right_bottom_corner;
+            }
+
+            grid.set(
+                &Entity::Cell(0, column),
+                settings.clone().text(text).border(border),
+            );
+        }
+
+        grid
+    }
+}
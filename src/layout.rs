@@ -0,0 +1,185 @@
+#[allow(unused)]
+use crate::Table;
+use crate::{
+    width::{split, split_keeping_words},
+    TableOption,
+};
+use papergrid::{Entity, Grid, Settings};
+
+pub use papergrid::CellRect;
+
+/// TableLayout is a set of areas of cells on a rendered [Table].
+///
+/// It can be used by TUI applications to find a cell by a mouse position.
+///
+/// ```
+/// use tabled::Table;
+///
+/// let table = Table::new(&["Hello", "World"]);
+/// let layout = table.layout();
+///
+/// // +-------+
+/// // | &str  |
+/// // +-------+
+/// // | Hello |
+/// // +-------+
+/// // | World |
+/// // +-------+
+/// assert_eq!(layout.cell_at(3, 5), Some((2, 0)));
+/// assert_eq!(layout.cell_at(0, 5), None);
+/// ```
+#[derive(Debug, Clone)]
+pub struct TableLayout {
+    cells: Vec<CellRect>,
+}
+
+impl TableLayout {
+    pub(crate) fn new(cells: Vec<CellRect>) -> Self {
+        Self { cells }
+    }
+
+    /// Returns areas of all visible cells.
+    ///
+    /// Cells which are hidden by a span are not included.
+    pub fn cells(&self) -> &[CellRect] {
+        &self.cells
+    }
+
+    /// Returns an area of a cell.
+    pub fn cell(&self, row: usize, column: usize) -> Option<&CellRect> {
+        self.cells
+            .iter()
+            .find(|cell| cell.row == row && cell.column == column)
+    }
+
+    /// Returns a (row, column) of a cell which is located at a given point.
+    ///
+    /// `x` is a terminal column and `y` is a line of a rendered table.
+    /// Borders don't belong to any cell.
+    pub fn cell_at(&self, x: usize, y: usize) -> Option<(usize, usize)> {
+        self.cells
+            .iter()
+            .find(|cell| cell.contains(x, y))
+            .map(|cell| (cell.row, cell.column))
+    }
+}
+
+/// Layout is a constructor of layout modes of a [Table].
+///
+/// ## Example
+///
+/// ```
+/// use tabled::{Layout, Style, Table};
+///
+/// let table = Table::new(&["Hello World"])
+///     .with(Style::ascii())
+///     .with(Layout::fixed(vec![7]));
+///
+/// assert_eq!(
+///     table.to_string(),
+///     "+-------+\n\
+///      | &str  |\n\
+///      +-------+\n\
+///      | Hello |\n\
+///      +-------+\n"
+/// );
+/// ```
+pub struct Layout;
+
+impl Layout {
+    /// Returns a [FixedLayout] object.
+    pub fn fixed(widths: Vec<usize>) -> FixedLayout {
+        FixedLayout::new(widths)
+    }
+}
+
+/// FixedLayout sets widths of columns so a content of a [Table] is not measured to find them.
+///
+/// It's useful for repeated rendering of tables with the same columns.
+/// A width includes an indent but not borders.
+/// A column which has no width set keeps the width of its content,
+/// and a column with a width smaller than an indent is made as wide as the indent.
+/// A content which doesn't fit a column is cut, or wrapped if [FixedLayout::wrap] is set.
+#[derive(Debug, Clone)]
+pub struct FixedLayout {
+    widths: Vec<usize>,
+    wrap: bool,
+    keep_words: bool,
+}
+
+impl FixedLayout {
+    fn new(widths: Vec<usize>) -> Self {
+        Self {
+            widths,
+            wrap: false,
+            keep_words: false,
+        }
+    }
+
+    /// Wraps a content which doesn't fit a column instead of cutting it.
+    ///
+    /// Cells which are spanned over a few columns are cut anyhow.
+    pub fn wrap(mut self) -> Self {
+        self.wrap = true;
+        self
+    }
+
+    /// Wraps a content preserving words if possible.
+    pub fn keep_words(mut self) -> Self {
+        self.wrap = true;
+        self.keep_words = true;
+        self
+    }
+}
+
+impl TableOption for FixedLayout {
+    fn change(&mut self, grid: &mut Grid) {
+        if self.wrap {
+            for (column, &width) in self.widths.iter().enumerate().take(grid.count_columns()) {
+                for row in 0..grid.count_rows() {
+                    wrap_cell(grid, row, column, width, self.keep_words);
+                }
+            }
+        }
+
+        let mut widths = self.widths.clone();
+        if widths.len() < grid.count_columns() {
+            // columns which have no width set keep the width of their content
+            let natural = grid.columns_widths();
+            widths.extend_from_slice(&natural[widths.len()..]);
+        }
+
+        grid.set_fixed_widths(Some(widths));
+    }
+}
+
+fn wrap_cell(grid: &mut Grid, row: usize, column: usize, width: usize, keep_words: bool) {
+    let style = grid.style(&Entity::Cell(row, column));
+    if style.span != 1 {
+        return;
+    }
+
+    // a width must be at least 1 to be able to split a content
+    let width = std::cmp::max(
+        width.saturating_sub(style.indent.left + style.indent.right),
+        1,
+    );
+    let content = grid.get_cell_content(row, column);
+    let wrapped = content
+        .lines()
+        .map(|line| {
+            let wrapped = if keep_words {
+                split_keeping_words(line, width)
+            } else {
+                split(line, width)
+            };
+
+            wrapped.trim_end_matches('\n').to_owned()
+        })
+        .collect::<Vec<_>>()
+        .join("\n");
+
+    if wrapped != content {
+        grid.set(&Entity::Cell(row, column), Settings::new().text(wrapped));
+    }
+}
@@ -153,28 +153,55 @@
 
 use std::fmt;
 
+mod accessibility;
 mod alignment;
+#[cfg(feature = "color")]
+mod color;
+mod color_support;
 mod concat;
 mod disable;
+mod error;
 mod formating;
+mod group;
+mod height;
 mod highlight;
 mod indent;
+mod iter_table;
+mod layout;
+mod markdown;
+mod mask;
+mod merge;
+mod metadata;
 mod object;
 mod panel;
 mod rotate;
+mod select;
+mod sort;
 mod span;
 mod table;
 mod width;
 
 pub mod builder;
 pub mod display;
+pub mod ext;
+pub mod grid;
+pub mod html;
 pub mod style;
 
 pub use crate::{
-    alignment::*, concat::*, disable::*, formating::*, highlight::*, indent::*, object::*,
-    panel::*, rotate::*, span::*, style::Style, table::*, width::*,
+    accessibility::*, alignment::*, color_support::*, concat::*, disable::*, error::*,
+    formating::*, group::*, height::*, highlight::*, indent::*, iter_table::*, layout::*, mask::*,
+    merge::*, metadata::*, object::*, panel::*, rotate::*, select::*, sort::*, span::*,
+    style::Style, table::*, width::*,
 };
 
+#[cfg(feature = "color")]
+pub use crate::color::*;
+
+/// Generators of arbitrary grids and checkers of layout invariants for property testing.
+#[cfg(feature = "testing")]
+pub use papergrid::testing;
+
 pub use tabled_derive::Tabled;
 
 // todo: change return type to impl Iterator<Cow<str
@@ -186,6 +213,21 @@ pub use tabled_derive::Tabled;
 /// ```text
 /// Self::headers().len() == self.fields().len()
 /// ```
+///
+/// Both must be equal to [Tabled::LENGTH], which is checked by debug assertions.
+/// A derive also checks at compile time that inlined fields have a known number of columns.
+///
+/// ```rust,compile_fail
+///   # use tabled::{Table, Tabled};
+///     #[derive(Tabled)]
+///     struct Release {
+///         version: &'static str,
+///         #[tabled(inline)]
+///         notes: Vec<&'static str>,
+///     }
+///
+///     Table::new([Release { version: "0.5.0", notes: vec![] }]);
+/// ```
 pub trait Tabled {
     /// A length of fields and headers,
     /// which must be the same.
@@ -197,6 +239,12 @@ pub trait Tabled {
     fn fields(&self) -> Vec<String>;
     /// Headers must return a list of column names.
     fn headers() -> Vec<String>;
+    /// Alignments returns a list of column alignments.
+    ///
+    /// [None] means a column uses a default alignment.
+    fn alignments() -> Vec<Option<Alignment>> {
+        vec![None; Self::LENGTH]
+    }
 }
 
 impl<T> Tabled for &T
@@ -211,26 +259,73 @@ where
     fn headers() -> Vec<String> {
         T::headers()
     }
+    fn alignments() -> Vec<Option<Alignment>> {
+        T::alignments()
+    }
+}
+
+/// Panics in debug builds if a [Tabled] implementation returns
+/// a number of headers or fields which differs from its [Tabled::LENGTH].
+///
+/// A type which length isn't known at compile time is not checked,
+/// as well as an empty list of fields which is returned for hidden enum variants.
+pub(crate) fn debug_assert_length<T: Tabled + ?Sized>(kind: &str, length: usize) {
+    debug_assert!(
+        T::LENGTH == 0 || length == 0 || length == T::LENGTH,
+        "Tabled implementation of `{}` returned {} {} while its LENGTH is {}",
+        std::any::type_name::<T>(),
+        length,
+        kind,
+        T::LENGTH,
+    );
 }
 
 macro_rules! tuple_table {
     ( $($name:ident)+ ) => {
         impl<$($name: Tabled),+> Tabled for ($($name,)+){
-            const LENGTH: usize = $($name::LENGTH+)+ 0;
+            const LENGTH: usize = {
+                // cells of a type which length isn't known at compile time
+                // would be misplaced if it's combined with other types
+                let lengths = [$($name::LENGTH),+];
+                let mut i = 0;
+                while i < lengths.len() {
+                    assert!(
+                        lengths.len() == 1 || lengths[i] != 0,
+                        "a tuple can't combine a type with an unknown number of columns (like a Vec) with other types"
+                    );
+                    i += 1;
+                }
+
+                $($name::LENGTH+)+ 0
+            };
 
             fn fields(&self) -> Vec<String> {
                 #![allow(non_snake_case)]
                 let ($($name,)+) = self;
                 let mut fields = Vec::new();
-                $(fields.append(&mut $name.fields());)+
+                $(
+                    let mut $name = $name.fields();
+                    debug_assert_length::<$name>("fields", $name.len());
+                    fields.append(&mut $name);
+                )+
                 fields
             }
 
             fn headers() -> Vec<String> {
                 let mut fields = Vec::new();
-                $(fields.append(&mut $name::headers());)+
+                $(
+                    let mut headers = $name::headers();
+                    debug_assert_length::<$name>("headers", headers.len());
+                    fields.append(&mut headers);
+                )+
                 fields
             }
+
+            fn alignments() -> Vec<Option<Alignment>> {
+                let mut alignments = Vec::new();
+                $(alignments.append(&mut $name::alignments());)+
+                alignments
+            }
         }
     };
 }
@@ -293,3 +388,90 @@ impl<T: fmt::Display, const N: usize> Tabled for [T; N] {
         (0..N).map(|i| format!("{}", i)).collect()
     }
 }
+
+/// A length of a slice isn't known at compile time, so its [Tabled::LENGTH] is 0
+/// and it has no headers.
+/// [Table] uses indexes of columns as a header in such case.
+///
+/// It can't be inlined by a derive, because its cells would be misplaced.
+impl<T: fmt::Display> Tabled for &[T] {
+    const LENGTH: usize = 0;
+
+    fn fields(&self) -> Vec<String> {
+        self.iter().map(|e| e.to_string()).collect()
+    }
+
+    fn headers() -> Vec<String> {
+        Vec::new()
+    }
+}
+
+/// A length of a vector isn't known at compile time, so its [Tabled::LENGTH] is 0
+/// and it has no headers.
+/// [Table] uses indexes of columns as a header in such case.
+///
+/// It can't be inlined by a derive, because its cells would be misplaced.
+impl<T: fmt::Display> Tabled for Vec<T> {
+    const LENGTH: usize = 0;
+
+    fn fields(&self) -> Vec<String> {
+        self.iter().map(|e| e.to_string()).collect()
+    }
+
+    fn headers() -> Vec<String> {
+        Vec::new()
+    }
+}
+
+/// [None] is rendered as empty cells.
+impl<T: Tabled> Tabled for Option<T> {
+    const LENGTH: usize = T::LENGTH;
+
+    fn fields(&self) -> Vec<String> {
+        match self {
+            Some(value) => value.fields(),
+            None => vec![String::new(); T::LENGTH],
+        }
+    }
+
+    fn headers() -> Vec<String> {
+        T::headers()
+    }
+
+    fn alignments() -> Vec<Option<Alignment>> {
+        T::alignments()
+    }
+}
+
+/// A first column shows whether it's `Ok` or `Err` with the error.
+/// Cells of an error are empty.
+impl<T: Tabled, E: fmt::Display> Tabled for Result<T, E> {
+    const LENGTH: usize = T::LENGTH + 1;
+
+    fn fields(&self) -> Vec<String> {
+        match self {
+            Ok(value) => {
+                let mut fields = vec![String::from("Ok")];
+                fields.extend(value.fields());
+                fields
+            }
+            Err(err) => {
+                let mut fields = vec![format!("Err: {}", err)];
+                fields.extend(vec![String::new(); T::LENGTH]);
+                fields
+            }
+        }
+    }
+
+    fn headers() -> Vec<String> {
+        let mut headers = vec![String::from("Result")];
+        headers.extend(T::headers());
+        headers
+    }
+
+    fn alignments() -> Vec<Option<Alignment>> {
+        let mut alignments = vec![None];
+        alignments.extend(T::alignments());
+        alignments
+    }
+}
@@ -0,0 +1,86 @@
+use papergrid::{string_width, AlignmentHorizontal, Entity, Grid};
+
+use crate::html::cell_spans;
+
+/// Renders a [Grid] as a GitHub flavored Markdown table.
+///
+/// A first row is rendered as a header,
+/// an alignment of a column is taken from its first cell after the header.
+/// Markdown has no spans so cells covered by a span are left empty.
+pub(crate) fn render(grid: &Grid) -> String {
+    let count_rows = grid.count_rows();
+    let count_columns = grid.count_columns();
+    if count_rows == 0 || count_columns == 0 {
+        return String::new();
+    }
+
+    let cells = collect_cells(grid);
+
+    let widths = (0..count_columns)
+        .map(|column| {
+            let width = cells.iter().map(|row| string_width(&row[column])).max();
+            width.unwrap_or(0).max(1)
+        })
+        .collect::<Vec<_>>();
+
+    let alignments = (0..count_columns).map(|column| {
+        let row = if count_rows > 1 { 1 } else { 0 };
+        &grid.style(&Entity::Cell(row, column)).alignment_h
+    });
+
+    let mut markdown = String::new();
+    for (row, cells) in cells.iter().enumerate() {
+        render_row(&mut markdown, cells, &widths);
+
+        if row == 0 {
+            for (width, alignment) in widths.iter().zip(alignments.clone()) {
+                markdown.push('|');
+                markdown.push_str(&alignment_marker(alignment, *width + 2));
+            }
+
+            markdown.push_str("|\n");
+        }
+    }
+
+    markdown
+}
+
+fn collect_cells(grid: &Grid) -> Vec<Vec<String>> {
+    let spans = cell_spans(grid);
+    (0..grid.count_rows())
+        .map(|row| {
+            (0..grid.count_columns())
+                .map(|column| match spans[row][column] {
+                    Some(_) => escape(grid.get_cell_content(row, column)),
+                    None => String::new(),
+                })
+                .collect()
+        })
+        .collect()
+}
+
+fn render_row(markdown: &mut String, cells: &[String], widths: &[usize]) {
+    for (cell, width) in cells.iter().zip(widths) {
+        markdown.push_str("| ");
+        markdown.push_str(cell);
+        markdown.push_str(&" ".repeat(width - string_width(cell) + 1));
+    }
+
+    markdown.push_str("|\n");
+}
+
+fn alignment_marker(alignment: &AlignmentHorizontal, width: usize) -> String {
+    match alignment {
+        AlignmentHorizontal::Left => format!(":{}", "-".repeat(width - 1)),
+        AlignmentHorizontal::Center => format!(":{}:", "-".repeat(width - 2)),
+        AlignmentHorizontal::Right => format!("{}:", "-".repeat(width - 1)),
+    }
+}
+
+// pipes would break a row and new lines are not allowed inside of a cell
+fn escape(text: &str) -> String {
+    text.lines()
+        .map(|line| line.replace('|', "\\|"))
+        .collect::<Vec<_>>()
+        .join("<br>")
+}
@@ -0,0 +1,136 @@
+#[allow(unused)]
+use crate::Table;
+use crate::{Column, Object, TableOption};
+use papergrid::{Entity, Grid, Settings};
+use std::ops::RangeInclusive;
+
+/// Mask hides contents of cells replacing their characters with `*`,
+/// which is handy for secrets printed in logs.
+///
+/// A few characters at the beginning and at the end can be kept visible.
+/// If kept characters would reveal a whole value it's masked entirely.
+/// New lines are preserved.
+///
+/// ```rust
+/// use tabled::{Mask, Style, Table};
+///
+/// let data = [("alice", "4556737586899855"), ("bob", "1234")];
+/// let table = Table::new(&data)
+///     .with(Style::psql())
+///     .with(Mask::column(1).keep_last(4));
+///
+/// assert_eq!(
+///     table.to_string(),
+///     concat!(
+///         " &str  |       &str       \n",
+///         "-------+------------------\n",
+///         " alice | ************9855 \n",
+///         "  bob  |       ****       \n",
+///     )
+/// );
+/// ```
+#[derive(Debug)]
+pub struct Mask<O> {
+    target: O,
+    keep_first: usize,
+    keep_last: usize,
+    symbol: char,
+    skip_header: bool,
+}
+
+impl<O> Mask<O>
+where
+    O: Object,
+{
+    /// Creates a mask of cells of a given [Object].
+    pub fn new(target: O) -> Self {
+        Self {
+            target,
+            keep_first: 0,
+            keep_last: 0,
+            symbol: '*',
+            skip_header: false,
+        }
+    }
+
+    /// Keeps a given number of first characters visible.
+    pub fn keep_first(mut self, count: usize) -> Self {
+        self.keep_first = count;
+        self
+    }
+
+    /// Keeps a given number of last characters visible.
+    pub fn keep_last(mut self, count: usize) -> Self {
+        self.keep_last = count;
+        self
+    }
+
+    /// Sets a character which is used instead of hidden ones.
+    pub fn symbol(mut self, symbol: char) -> Self {
+        self.symbol = symbol;
+        self
+    }
+}
+
+impl Mask<Column<RangeInclusive<usize>>> {
+    /// Creates a mask of a column by its index.
+    ///
+    /// A header of the column is left untouched, all of its rows.
+    pub fn column(index: usize) -> Self {
+        let mut mask = Self::new(Column(index..=index));
+        mask.skip_header = true;
+        mask
+    }
+}
+
+impl<O> TableOption for Mask<O>
+where
+    O: Object,
+{
+    fn change(&mut self, grid: &mut Grid) {
+        let first_row = if self.skip_header {
+            grid.count_header_rows()
+        } else {
+            0
+        };
+
+        for (row, column) in self.target.grid_cells(grid) {
+            if row < first_row || row >= grid.count_rows() || column >= grid.count_columns() {
+                continue;
+            }
+
+            let content = grid.get_cell_content(row, column);
+            let content = self.mask(content);
+            grid.set(&Entity::Cell(row, column), Settings::new().text(content));
+        }
+    }
+}
+
+impl<O> Mask<O> {
+    fn mask(&self, text: &str) -> String {
+        let length = text.chars().filter(|&c| c != '\n').count();
+        let (keep_first, keep_last) = if self.keep_first + self.keep_last < length {
+            (self.keep_first, self.keep_last)
+        } else {
+            (0, 0)
+        };
+
+        let mut index = 0;
+        text.chars()
+            .map(|c| {
+                if c == '\n' {
+                    return c;
+                }
+
+                let is_visible = index < keep_first || index >= length - keep_last;
+                index += 1;
+
+                if is_visible {
+                    c
+                } else {
+                    self.symbol
+                }
+            })
+            .collect()
+    }
+}
@@ -0,0 +1,162 @@
+#[allow(unused)]
+use crate::Table;
+use crate::{Object, TableOption};
+use papergrid::{Entity, Grid, Settings};
+use std::collections::BTreeMap;
+
+/// Merge combines consecutive cells with equal contents into one spanned cell,
+/// like a grouping in a report.
+///
+/// Only cells of a given [Object] are merged.
+/// Empty cells and cells which are already spanned are left untouched.
+///
+/// ```rust
+/// use tabled::{Column, Merge, Style, Table};
+///
+/// let data = [("Go", 2009), ("Go", 2012), ("Rust", 2015)];
+/// let table = Table::new(&data)
+///     .with(Style::psql())
+///     .with(Merge::vertical(Column(..1)));
+///
+/// assert_eq!(
+///     table.to_string(),
+///     concat!(
+///         " &str | i32  \n",
+///         "------+------\n",
+///         "  Go  | 2009 \n",
+///         "      | 2012 \n",
+///         " Rust | 2015 \n",
+///     )
+/// );
+/// ```
+#[derive(Debug)]
+pub struct Merge<O> {
+    target: O,
+    direction: Direction,
+    blank: bool,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Direction {
+    Vertical,
+    Horizontal,
+}
+
+impl<O> Merge<O>
+where
+    O: Object,
+{
+    /// Merges equal cells which follow each other in a column by a row span.
+    pub fn vertical(target: O) -> Self {
+        Self {
+            target,
+            direction: Direction::Vertical,
+            blank: false,
+        }
+    }
+
+    /// Merges equal cells which follow each other in a row by a column span.
+    pub fn horizontal(target: O) -> Self {
+        Self {
+            target,
+            direction: Direction::Horizontal,
+            blank: false,
+        }
+    }
+
+    /// Blanks duplicates instead of spanning a cell over them,
+    /// so borders between the cells are kept.
+    pub fn blank(mut self) -> Self {
+        self.blank = true;
+        self
+    }
+}
+
+impl<O> TableOption for Merge<O>
+where
+    O: Object,
+{
+    fn change(&mut self, grid: &mut Grid) {
+        let count_rows = grid.count_rows();
+        let count_columns = grid.count_columns();
+
+        // cells are grouped into lines which are columns or rows depending on a direction
+        let mut lines: BTreeMap<usize, Vec<usize>> = BTreeMap::new();
+        for (row, column) in self.target.grid_cells(grid) {
+            if row >= count_rows || column >= count_columns {
+                continue;
+            }
+
+            match self.direction {
+                Direction::Vertical => lines.entry(column).or_default().push(row),
+                Direction::Horizontal => lines.entry(row).or_default().push(column),
+            }
+        }
+
+        for (line, mut positions) in lines {
+            positions.sort_unstable();
+            positions.dedup();
+
+            let cell = |position: usize| match self.direction {
+                Direction::Vertical => (position, line),
+                Direction::Horizontal => (line, position),
+            };
+
+            let mut i = 0;
+            while i < positions.len() {
+                let (row, column) = cell(positions[i]);
+                let mut length = 1;
+                if is_mergeable(grid, row, column) {
+                    let content = grid.get_cell_content(row, column);
+                    while let Some(&next) = positions.get(i + length) {
+                        let (next_row, next_column) = cell(next);
+                        let is_next = next == positions[i] + length;
+                        if !is_next
+                            || !is_mergeable(grid, next_row, next_column)
+                            || grid.get_cell_content(next_row, next_column) != content
+                        {
+                            break;
+                        }
+
+                        length += 1;
+                    }
+                }
+
+                if length > 1 {
+                    self.merge(grid, (row, column), length);
+                }
+
+                i += length;
+            }
+        }
+    }
+}
+
+impl<O> Merge<O> {
+    fn merge(&self, grid: &mut Grid, (row, column): (usize, usize), length: usize) {
+        if self.blank {
+            for i in 1..length {
+                let cell = match self.direction {
+                    Direction::Vertical => Entity::Cell(row + i, column),
+                    Direction::Horizontal => Entity::Cell(row, column + i),
+                };
+
+                grid.set(&cell, Settings::new().text(""));
+            }
+
+            return;
+        }
+
+        let settings = match self.direction {
+            Direction::Vertical => Settings::new().row_span(length),
+            Direction::Horizontal => Settings::new().span(length),
+        };
+
+        grid.set(&Entity::Cell(row, column), settings);
+    }
+}
+
+fn is_mergeable(grid: &Grid, row: usize, column: usize) -> bool {
+    let style = grid.style(&Entity::Cell(row, column));
+    style.span == 1 && style.row_span == 1 && !grid.get_cell_content(row, column).is_empty()
+}
@@ -0,0 +1,72 @@
+use crate::CellOption;
+use papergrid::{Entity, Grid, Settings};
+
+/// Metadata attaches a set of attributes to particular cells.
+///
+/// Attributes are ignored while a table is rendered as a text,
+/// but they may be used by other renderers e.g. by a HTML one
+/// as `title`, `class` or `data-*` attributes.
+///
+/// ```rust,no_run
+///   # use tabled::{Metadata, Row, Column, Table, Modify};
+///   # let data: Vec<&'static str> = Vec::new();
+///     let table = Table::new(&data)
+///         .with(Modify::new(Row(..1)).with(Metadata::new().class("header")))
+///         .with(Modify::new(Column(..1)).with(Metadata::new().title("An identifier").data("type", "id")));
+/// ```
+#[derive(Debug, Default, Clone)]
+pub struct Metadata {
+    attributes: Vec<(String, String)>,
+    tags: Vec<String>,
+}
+
+impl Metadata {
+    /// Creates an empty [Metadata].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets a `title` attribute, which is commonly shown as a tooltip.
+    pub fn title<S: Into<String>>(self, title: S) -> Self {
+        self.attribute("title", title)
+    }
+
+    /// Sets a `class` attribute.
+    pub fn class<S: Into<String>>(self, class: S) -> Self {
+        self.attribute("class", class)
+    }
+
+    /// Sets a `data-*` attribute.
+    pub fn data<K: AsRef<str>, V: Into<String>>(self, key: K, value: V) -> Self {
+        self.attribute(format!("data-{}", key.as_ref()), value)
+    }
+
+    /// Sets an arbitrary attribute.
+    pub fn attribute<K: Into<String>, V: Into<String>>(mut self, key: K, value: V) -> Self {
+        self.attributes.push((key.into(), value.into()));
+        self
+    }
+
+    /// Marks cells by a tag so they can be referenced later by [crate::Tag].
+    pub fn tag<S: Into<String>>(mut self, tag: S) -> Self {
+        self.tags.push(tag.into());
+        self
+    }
+}
+
+impl CellOption for Metadata {
+    fn change_cell(&mut self, grid: &mut Grid, row: usize, column: usize) {
+        let settings = self
+            .attributes
+            .iter()
+            .fold(Settings::new(), |settings, (key, value)| {
+                settings.attribute(key, value)
+            });
+        let settings = self
+            .tags
+            .iter()
+            .fold(settings, |settings, tag| settings.tag(tag));
+
+        grid.set(&Entity::Cell(row, column), settings)
+    }
+}
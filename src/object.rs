@@ -1,4 +1,3 @@
-#[allow(unused)]
 use papergrid::Grid;
 use std::{
     collections::BTreeSet,
@@ -10,6 +9,14 @@ pub trait Object: Sized {
     /// Cells returns a set of cordinates of cells
     fn cells(&self, count_rows: usize, count_columns: usize) -> Vec<(usize, usize)>;
 
+    /// Returns a set of cordinates of cells on a given grid.
+    ///
+    /// By default it's [Object::cells] called with a size of the grid.
+    /// Objects which look up cells by their settings, like [Tag], override it.
+    fn grid_cells(&self, grid: &Grid) -> Vec<(usize, usize)> {
+        self.cells(grid.count_rows(), grid.count_columns())
+    }
+
     /// Combines cells.
     /// It doesn't repeat cells.
     fn and<O: Object>(self, rhs: O) -> Combination<Self, O> {
@@ -92,6 +99,60 @@ impl Object for Cell {
     }
 }
 
+/// Origin denotes a cell by its position at the time a [crate::Table] was built.
+///
+/// Unlike [Cell] it keeps pointing to the same cell
+/// after options like [crate::Panel] or [crate::Disable] shift cells.
+///
+/// ```rust
+///   # use tabled::{Alignment, Header, Modify, Origin, Table};
+///     let table = Table::new(&[1, 2, 3])
+///         .with(Header("Numbers"))
+///         .with(Modify::new(Origin(3, 0)).with(Alignment::right()));
+/// ```
+pub struct Origin(pub usize, pub usize);
+
+impl Object for Origin {
+    /// An origin can't be found without a grid, so there are no cells.
+    fn cells(&self, _: usize, _: usize) -> Vec<(usize, usize)> {
+        Vec::new()
+    }
+
+    fn grid_cells(&self, grid: &Grid) -> Vec<(usize, usize)> {
+        crate::table::find_origin(grid, (self.0, self.1))
+            .into_iter()
+            .collect()
+    }
+}
+
+/// Tag denotes cells marked by a tag via [crate::Metadata::tag].
+///
+/// Unlike [Cell] it keeps pointing to the same cells
+/// after rows or columns are inserted or removed.
+///
+/// ```rust
+///   # use tabled::{Alignment, Cell, Metadata, Modify, Table, Tag};
+///     let table = Table::new(&[1, 2, 3])
+///         .with(Modify::new(Cell(3, 0)).with(Metadata::new().tag("total")))
+///         .with(Modify::new(Tag("total")).with(Alignment::right()));
+/// ```
+pub struct Tag<S: AsRef<str>>(pub S);
+
+impl<S: AsRef<str>> Object for Tag<S> {
+    /// Tags can't be found without a grid, so there are no cells.
+    fn cells(&self, _: usize, _: usize) -> Vec<(usize, usize)> {
+        Vec::new()
+    }
+
+    fn grid_cells(&self, grid: &Grid) -> Vec<(usize, usize)> {
+        let tag = self.0.as_ref();
+        (0..grid.count_rows())
+            .flat_map(|row| (0..grid.count_columns()).map(move |column| (row, column)))
+            .filter(|&(row, column)| grid.get_tags(row, column).iter().any(|t| t == tag))
+            .collect()
+    }
+}
+
 /// Combinator is a transformation function
 type Combinator = fn(Vec<(usize, usize)>, Vec<(usize, usize)>) -> Vec<(usize, usize)>;
 
@@ -112,6 +173,12 @@ where
         let r = self.rhs.cells(count_rows, count_columns);
         (self.combinator)(l, r)
     }
+
+    fn grid_cells(&self, grid: &Grid) -> Vec<(usize, usize)> {
+        let l = self.lhs.grid_cells(grid);
+        let r = self.rhs.grid_cells(grid);
+        (self.combinator)(l, r)
+    }
 }
 
 /// Combines 2 sets of cells into one.
@@ -119,7 +186,7 @@ where
 /// Dublicates are removed from the output set.
 fn combine_cells(lhs: Vec<(usize, usize)>, rhs: Vec<(usize, usize)>) -> Vec<(usize, usize)> {
     lhs.into_iter()
-        .chain(rhs.into_iter())
+        .chain(rhs)
         .collect::<BTreeSet<_>>()
         .into_iter()
         .collect()
@@ -1,7 +1,9 @@
+use std::ops::RangeBounds;
+
 #[allow(unused)]
 use crate::Table;
-use crate::TableOption;
-use papergrid::{Entity, Grid, Settings};
+use crate::{object::bounds_to_usize, TableOption};
+use papergrid::{string_width, AlignmentHorizontal, AlignmentVertical, Entity, Grid, Settings};
 
 /// Panel allows to add a Row which has 1 continues Cell to a [Table].
 ///
@@ -9,28 +11,160 @@ use papergrid::{Entity, Grid, Settings};
 #[derive(Debug)]
 pub struct Panel<S: AsRef<str>>(pub S, pub usize);
 
+impl<S: AsRef<str>> Panel<S> {
+    /// Makes the panel cover only the given columns.
+    /// The rest of the row is left empty.
+    ///
+    /// Each panel is put on its own row,
+    /// so a few panels can be stacked one on top of another.
+    ///
+    /// ```rust
+    ///   # use tabled::{Panel, Style, Table};
+    ///     let table = Table::new([(0, 1, 2)])
+    ///         .with(Panel("panel", 0).columns(1..))
+    ///         .with(Style::modern());
+    ///
+    ///     assert_eq!(
+    ///         table.to_string(),
+    ///         concat!(
+    ///             "┌─────┬─────┬─────┐\n",
+    ///             "│     │panel      │\n",
+    ///             "├─────┼─────┼─────┤\n",
+    ///             "│ i32 │ i32 │ i32 │\n",
+    ///             "├─────┼─────┼─────┤\n",
+    ///             "│  0  │  1  │  2  │\n",
+    ///             "└─────┴─────┴─────┘\n",
+    ///         )
+    ///     );
+    /// ```
+    pub fn columns<R: RangeBounds<usize>>(self, columns: R) -> PanelRange<S, R> {
+        PanelRange {
+            text: self.0,
+            row: self.1,
+            columns,
+        }
+    }
+
+    /// Creates a panel which is added as a column spread over all rows.
+    /// Its text is rendered vertically, one char per line.
+    ///
+    /// ```rust
+    ///   # use tabled::{Panel, Style, Table};
+    ///     let table = Table::new([1, 2])
+    ///         .with(Panel::vertical(0, "ab"))
+    ///         .with(Style::modern());
+    ///
+    ///     assert_eq!(
+    ///         table.to_string(),
+    ///         concat!(
+    ///             "┌─┬─────┐\n",
+    ///             "│a│ i32 │\n",
+    ///             "│b┼─────┤\n",
+    ///             "│ │  1  │\n",
+    ///             "│ ┼─────┤\n",
+    ///             "│ │  2  │\n",
+    ///             "└─┴─────┘\n",
+    ///         )
+    ///     );
+    /// ```
+    pub fn vertical(column: usize, text: S) -> VerticalPanel<S> {
+        VerticalPanel { text, column }
+    }
+}
+
 impl<S: AsRef<str>> TableOption for Panel<S> {
     fn change(&mut self, grid: &mut Grid) {
-        let mut new_grid = Grid::new(grid.count_rows() + 1, grid.count_columns());
+        PanelRange {
+            text: self.0.as_ref(),
+            row: self.1,
+            columns: ..,
+        }
+        .change(grid)
+    }
+}
+
+/// PanelRange is a [Panel] which covers only a range of columns.
+///
+/// It's created by [Panel::columns].
+#[derive(Debug)]
+pub struct PanelRange<S: AsRef<str>, R: RangeBounds<usize>> {
+    text: S,
+    row: usize,
+    columns: R,
+}
+
+impl<S: AsRef<str>, R: RangeBounds<usize>> TableOption for PanelRange<S, R> {
+    fn change(&mut self, grid: &mut Grid) {
+        let count_columns = grid.count_columns();
+        let (start, end) = bounds_to_usize(
+            self.columns.start_bound(),
+            self.columns.end_bound(),
+            count_columns,
+        );
+        let end = std::cmp::min(end, count_columns);
+
+        grid.insert_row(self.row);
+
+        // a panel has no style of the columns it's spread over
+        // and the cells around it are left empty
+        for column in (0..count_columns).filter(|&column| column < start || column >= end) {
+            grid.set(&Entity::Cell(self.row, column), plain_cell());
+        }
+
+        if start < end {
+            grid.set(
+                &Entity::Cell(self.row, start),
+                plain_cell()
+                    .text(self.text.as_ref().to_owned())
+                    .span(end - start),
+            );
+        }
+
+        // as usual a first row is considered a header
+        grid.set_header_rows(1);
+    }
+}
+
+/// VerticalPanel is a [Panel] which is added as a column.
+///
+/// It's created by [Panel::vertical].
+#[derive(Debug)]
+pub struct VerticalPanel<S: AsRef<str>> {
+    text: S,
+    column: usize,
+}
+
+impl<S: AsRef<str>> TableOption for VerticalPanel<S> {
+    fn change(&mut self, grid: &mut Grid) {
+        grid.insert_column(self.column);
+
+        let text = self
+            .text
+            .as_ref()
+            .chars()
+            .filter(|c| *c != '\n')
+            .map(String::from)
+            .collect::<Vec<_>>()
+            .join("\n");
+
         for row in 0..grid.count_rows() {
-            for column in 0..grid.count_columns() {
-                let cell_settings = grid.get_settings(row, column).border_restriction(false);
-                if row >= self.1 {
-                    new_grid.set(&Entity::Cell(row + 1, column), cell_settings);
-                } else {
-                    new_grid.set(&Entity::Cell(row, column), cell_settings);
-                }
+            grid.set(&Entity::Cell(row, self.column), plain_cell());
+        }
+
+        // a fixed layout gets a column wide enough for the panel
+        if let Some(widths) = grid.get_fixed_widths() {
+            let mut widths = widths.to_vec();
+            if let Some(width) = widths.get_mut(self.column) {
+                *width = text.lines().map(string_width).max().unwrap_or(0);
             }
+
+            grid.set_fixed_widths(Some(widths));
         }
 
-        new_grid.set(
-            &Entity::Cell(self.1, 0),
-            Settings::new()
-                .text(self.0.as_ref().to_owned())
-                .span(new_grid.count_columns()),
+        grid.set(
+            &Entity::Cell(0, self.column),
+            plain_cell().text(text).row_span(grid.count_rows()),
         );
-
-        *grid = new_grid;
     }
 }
 
@@ -55,3 +189,78 @@ impl<S: AsRef<str>> TableOption for Footer<S> {
         Panel(self.0.as_ref(), grid.count_rows()).change(grid)
     }
 }
+
+/// EmptyMessage renders a [Panel] with a message in case a [Table] has no rows
+/// except header ones.
+///
+/// ```rust
+///   # use tabled::{EmptyMessage, Style, Table};
+///     let data: Vec<&'static str> = Vec::new();
+///     let table = Table::new(&data)
+///         .with(EmptyMessage("no results"))
+///         .with(Style::psql());
+///
+///     assert_eq!(table.to_string(), "    &str    \n------------\n no results \n");
+/// ```
+#[derive(Debug)]
+pub struct EmptyMessage<S: AsRef<str>>(pub S);
+
+impl<S: AsRef<str>> TableOption for EmptyMessage<S> {
+    fn change(&mut self, grid: &mut Grid) {
+        if grid.count_rows() > grid.count_header_rows() {
+            return;
+        }
+
+        if grid.count_columns() == 0 {
+            let mut new_grid = Grid::new(1, 1);
+            new_grid.set(
+                &Entity::Cell(0, 0),
+                Settings::new()
+                    .text(self.0.as_ref().to_owned())
+                    .indent(1, 1, 0, 0),
+            );
+
+            *grid = new_grid;
+            return;
+        }
+
+        let settings = header_like_settings(grid);
+        let header_rows = grid.count_header_rows();
+
+        let row = grid.count_rows();
+        Panel(self.0.as_ref(), row).change(grid);
+
+        grid.set(&Entity::Cell(row, 0), settings);
+        grid.set_header_rows(header_rows);
+    }
+}
+
+/// Returns settings which indent and align a cell the same way as a header is,
+/// so a message added to a table looks like a part of it.
+pub(crate) fn header_like_settings(grid: &Grid) -> Settings {
+    let style = grid.style(&Entity::Cell(0, 0));
+    Settings::new()
+        .indent(
+            style.indent.left,
+            style.indent.right,
+            style.indent.top,
+            style.indent.bottom,
+        )
+        .alignment(style.alignment_h)
+}
+
+/// Returns settings which reset a style of a cell to a default one,
+/// so a cell added to a grid doesn't inherit a style of a row or a column.
+pub(crate) fn plain_cell() -> Settings {
+    Settings::new()
+        .span(1)
+        .row_span(1)
+        .indent(0, 0, 0, 0)
+        .alignment(AlignmentHorizontal::Left)
+        .vertical_alignment(AlignmentVertical::Top)
+        .min_width(0)
+        .min_height(0)
+        .fill(' ')
+        .fill_left("")
+        .fill_right("")
+}
@@ -26,6 +26,7 @@ impl TableOption for Rotate {
                     }
                 }
 
+                new.copy_grid_settings(grid);
                 *grid = new;
             }
             Self::Right => {
@@ -39,6 +40,7 @@ impl TableOption for Rotate {
                     }
                 }
 
+                new.copy_grid_settings(grid);
                 *grid = new;
             }
             Self::Bottom => {
@@ -51,6 +53,7 @@ impl TableOption for Rotate {
                     }
                 }
 
+                new.copy_grid_settings(grid);
                 *grid = new;
             }
             Self::Top => Self::Bottom.change(grid),
@@ -0,0 +1,121 @@
+#[allow(unused)]
+use crate::Table;
+use crate::{span::remap_column_spans, TableOption};
+use papergrid::{Border, Entity, Grid, Settings};
+
+/// SelectColumns filters and orders columns of a [Table] by their header names.
+///
+/// Names are looked up in the last row of a header,
+/// so rows above it, like the ones added by [crate::Group], are skipped.
+///
+/// ```rust,no_run
+///   # use tabled::{Missing, SelectColumns, Table};
+///   # let data: Vec<&'static str> = Vec::new();
+///     let table = Table::new(&data)
+///         .with(SelectColumns::by_names(["name", "released"], Missing::Skip));
+/// ```
+#[derive(Debug)]
+pub struct SelectColumns {
+    names: Vec<String>,
+    missing: Missing,
+}
+
+/// Missing defines what [SelectColumns] does with a name which is not found in a header.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Missing {
+    /// The name is ignored.
+    Skip,
+    /// An empty column with the name as a header is created.
+    Empty,
+}
+
+impl SelectColumns {
+    /// Creates a [SelectColumns] from a list of header names in a desired order.
+    pub fn by_names<I, S>(names: I, missing: Missing) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        Self {
+            names: names.into_iter().map(|name| name.into()).collect(),
+            missing,
+        }
+    }
+}
+
+impl TableOption for SelectColumns {
+    fn change(&mut self, grid: &mut Grid) {
+        if grid.count_rows() == 0 {
+            return;
+        }
+
+        let names_row = grid.count_header_rows().saturating_sub(1);
+        let columns = self
+            .names
+            .iter()
+            .filter_map(|name| {
+                let column = (0..grid.count_columns())
+                    .find(|&column| grid.get_cell_content(names_row, column) == name);
+
+                match (column, self.missing) {
+                    (Some(column), _) => Some(Ok(column)),
+                    (None, Missing::Empty) => Some(Err(name)),
+                    (None, Missing::Skip) => None,
+                }
+            })
+            .collect::<Vec<_>>();
+
+        let count_columns = grid.count_columns();
+        let mut new_grid = Grid::new(grid.count_rows(), columns.len());
+        for row in 0..grid.count_rows() {
+            for (new_column, column) in columns.iter().enumerate() {
+                let cell_settings = match column {
+                    Ok(column) => grid.get_settings(row, *column),
+                    Err(name) => {
+                        // an empty column copies a style of the first column
+                        let text = if row == names_row { name.as_str() } else { "" };
+                        if count_columns > 0 {
+                            grid.get_settings(row, 0).text(text)
+                        } else {
+                            Settings::new().text(text)
+                        }
+                    }
+                };
+
+                // borders are kept in place so a frame is not broken by reordering
+                let cell_settings = if count_columns > 0 {
+                    let is_last = new_column + 1 == columns.len();
+                    let border = positional_border(grid, row, new_column, is_last);
+                    cell_settings.border(border)
+                } else {
+                    cell_settings
+                };
+
+                new_grid.set(
+                    &Entity::Cell(row, new_column),
+                    cell_settings.border_restriction(false),
+                );
+            }
+        }
+
+        let columns = columns.iter().map(|c| c.ok()).collect::<Vec<_>>();
+        remap_column_spans(grid, &mut new_grid, &columns);
+
+        new_grid.copy_grid_settings(grid);
+        new_grid.set_header_rows(grid.count_header_rows());
+        *grid = new_grid;
+    }
+}
+
+fn positional_border(grid: &mut Grid, row: usize, column: usize, is_last: bool) -> Border {
+    let last_column = grid.count_columns() - 1;
+    let mut border = grid.get_border(row, column.min(last_column));
+    if is_last {
+        let last = grid.get_border(row, last_column);
+        border.right = last.right;
+        border.right_top_corner = last.right_top_corner;
+        border.right_bottom_corner = last.right_bottom_corner;
+    }
+
+    border
+}
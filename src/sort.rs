@@ -0,0 +1,157 @@
+#[allow(unused)]
+use crate::Table;
+use crate::TableOption;
+use papergrid::{Entity, Grid};
+use std::{cmp::Ordering, fmt};
+
+/// Sort reorders rows of a [Table] keeping a header in place.
+///
+/// By a column values are compared as numbers if both of them are numbers,
+/// and as strings otherwise.
+/// The sort is stable, so equal rows keep their order.
+/// Borders are kept in place as well, so a style of a table is not broken.
+///
+/// A header is made of header rows of a table, by default it's a first row,
+/// so [crate::Header] is supposed to be added after sorting.
+///
+/// ```rust
+/// use tabled::{Sort, Style, Table};
+///
+/// let data = [("Go", 2009), ("C", 1972), ("Rust", 2010)];
+/// let table = Table::new(&data)
+///     .with(Style::psql())
+///     .with(Sort::by_column(1));
+///
+/// assert_eq!(
+///     table.to_string(),
+///     concat!(
+///         " &str | i32  \n",
+///         "------+------\n",
+///         "  C   | 1972 \n",
+///         "  Go  | 2009 \n",
+///         " Rust | 2010 \n",
+///     )
+/// );
+/// ```
+pub struct Sort {
+    order: Order,
+}
+
+enum Order {
+    Column { column: usize, desc: bool },
+    Custom(Comparator),
+}
+
+type Comparator = Box<dyn FnMut(&[&str], &[&str]) -> Ordering>;
+
+impl Sort {
+    /// Sorts rows by a column in an ascending order.
+    pub fn by_column(column: usize) -> Self {
+        Self {
+            order: Order::Column {
+                column,
+                desc: false,
+            },
+        }
+    }
+
+    /// Sorts rows by a column in a descending order.
+    pub fn by_column_desc(column: usize) -> Self {
+        Self {
+            order: Order::Column { column, desc: true },
+        }
+    }
+
+    /// Sorts rows by a comparator which gets a content of cells of rows.
+    ///
+    /// ```rust
+    /// use tabled::{Sort, Table};
+    ///
+    /// let data = ["ccc", "a", "bb"];
+    /// let table = Table::new(&data).with(Sort::with(|a, b| a[0].len().cmp(&b[0].len())));
+    /// ```
+    pub fn with<F>(f: F) -> Self
+    where
+        F: FnMut(&[&str], &[&str]) -> Ordering + 'static,
+    {
+        Self {
+            order: Order::Custom(Box::new(f)),
+        }
+    }
+}
+
+impl fmt::Debug for Sort {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.order {
+            Order::Column { column, desc } => f
+                .debug_struct("Sort")
+                .field("column", column)
+                .field("desc", desc)
+                .finish(),
+            Order::Custom(_) => f.debug_struct("Sort").finish_non_exhaustive(),
+        }
+    }
+}
+
+impl TableOption for Sort {
+    fn change(&mut self, grid: &mut Grid) {
+        let count_rows = grid.count_rows();
+        let count_columns = grid.count_columns();
+        let header_rows = grid.count_header_rows();
+        if count_rows <= header_rows + 1 {
+            return;
+        }
+
+        let rows = (0..count_rows)
+            .map(|row| {
+                (0..count_columns)
+                    .map(|column| grid.get_cell_content(row, column))
+                    .collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>();
+
+        let mut order = (header_rows..count_rows).collect::<Vec<_>>();
+        match &mut self.order {
+            Order::Column { column, desc } => {
+                if *column >= count_columns {
+                    return;
+                }
+
+                order.sort_by(|&a, &b| {
+                    let ordering = compare_values(rows[a][*column], rows[b][*column]);
+                    if *desc {
+                        ordering.reverse()
+                    } else {
+                        ordering
+                    }
+                });
+            }
+            Order::Custom(f) => order.sort_by(|&a, &b| f(&rows[a], &rows[b])),
+        }
+
+        let mut new_grid = Grid::new(count_rows, count_columns);
+        let rows = (0..header_rows).chain(order);
+        for (new_row, row) in rows.enumerate() {
+            for column in 0..count_columns {
+                // borders are kept in place so a style is not broken by reordering
+                let border = grid.get_border(new_row, column);
+                let settings = grid.get_settings(row, column).border(border);
+                new_grid.set(
+                    &Entity::Cell(new_row, column),
+                    settings.border_restriction(false),
+                );
+            }
+        }
+
+        new_grid.copy_grid_settings(grid);
+        new_grid.set_header_rows(header_rows);
+        *grid = new_grid;
+    }
+}
+
+fn compare_values(a: &str, b: &str) -> Ordering {
+    match (a.trim().parse::<f64>(), b.trim().parse::<f64>()) {
+        (Ok(a), Ok(b)) => a.partial_cmp(&b).unwrap_or(Ordering::Equal),
+        _ => a.cmp(b),
+    }
+}
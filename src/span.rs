@@ -1,9 +1,7 @@
 use crate::CellOption;
-use papergrid::{Entity, Grid, Settings};
+use papergrid::{Entity, Grid, Settings, SpanAlignment};
 
-pub use papergrid::{AlignmentHorizontal, AlignmentVertical};
-
-/// Span represent a horizontal/column span setting for any cell on a [crate::Table].
+/// Span represent a horizontal/column or a vertical/row span setting for any cell on a [crate::Table].
 ///
 /// ```rust,no_run
 ///   # use tabled::{Style, Span, Modify, Column, Table};
@@ -14,17 +12,177 @@ pub use papergrid::{AlignmentHorizontal, AlignmentVertical};
 #[derive(Debug)]
 pub struct Span {
     size: usize,
+    direction: Direction,
+}
+
+#[derive(Debug)]
+enum Direction {
+    Column,
+    Row,
 }
 
 impl Span {
     /// New constructs a horizontal/column [Span].
     pub fn column(size: usize) -> Self {
-        Self { size }
+        Self {
+            size,
+            direction: Direction::Column,
+        }
+    }
+
+    /// New constructs a vertical/row [Span].
+    ///
+    /// Cells which are covered by the span are not rendered.
+    /// A span which goes beyond the last row is cut.
+    ///
+    /// ```rust
+    /// use tabled::{Cell, Modify, Span, Style, Table};
+    ///
+    /// let data = [("A", 1), ("A", 2), ("B", 3)];
+    /// let table = Table::new(&data)
+    ///     .with(Style::ascii())
+    ///     .with(Modify::new(Cell(1, 0)).with(Span::row(2)));
+    ///
+    /// assert_eq!(
+    ///     table.to_string(),
+    ///     concat!(
+    ///         "+------+-----+\n",
+    ///         "| &str | i32 |\n",
+    ///         "+------+-----+\n",
+    ///         "|  A   |  1  |\n",
+    ///         "|      +-----+\n",
+    ///         "|      |  2  |\n",
+    ///         "+------+-----+\n",
+    ///         "|  B   |  3  |\n",
+    ///         "+------+-----+\n",
+    ///     )
+    /// );
+    /// ```
+    pub fn row(size: usize) -> Self {
+        Self {
+            size,
+            direction: Direction::Row,
+        }
     }
 }
 
 impl CellOption for Span {
     fn change_cell(&mut self, grid: &mut Grid, row: usize, column: usize) {
-        grid.set(&Entity::Cell(row, column), Settings::new().span(self.size));
+        let settings = match self.direction {
+            Direction::Column => Settings::new().span(self.size),
+            Direction::Row => Settings::new().row_span(self.size),
+        };
+
+        grid.set(&Entity::Cell(row, column), settings);
+    }
+}
+
+/// SpanAlign sets a region in which a content of a cell spanned over a few rows
+/// is vertically aligned.
+///
+/// A vertical [crate::Alignment] is applied across all the rows by default.
+///
+/// ```rust
+/// use tabled::{Alignment, Cell, Modify, Span, SpanAlign, Style, Table};
+///
+/// let data = [("A", "1\n2"), ("B", "3\n4")];
+/// let table = Table::new(&data)
+///     .with(Style::psql())
+///     .with(
+///         Modify::new(Cell(1, 0))
+///             .with(Span::row(2))
+///             .with(Alignment::bottom())
+///             .with(SpanAlign::FirstRow),
+///     );
+///
+/// assert_eq!(
+///     table.to_string(),
+///     concat!(
+///         " &str | &str \n",
+///         "------+------\n",
+///         "      |  1   \n",
+///         "  A   |  2   \n",
+///         "      |  3   \n",
+///         "      |  4   \n",
+///     )
+/// );
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SpanAlign {
+    /// A content is aligned across all the rows a cell is spanned over.
+    Merged,
+    /// A content is aligned within the first row a cell is spanned over.
+    FirstRow,
+}
+
+impl CellOption for SpanAlign {
+    fn change_cell(&mut self, grid: &mut Grid, row: usize, column: usize) {
+        let alignment = match self {
+            SpanAlign::Merged => SpanAlignment::Merged,
+            SpanAlign::FirstRow => SpanAlignment::FirstRow,
+        };
+
+        grid.set(
+            &Entity::Cell(row, column),
+            Settings::new().span_alignment(alignment),
+        );
+    }
+}
+
+/// Fixes column spans of a grid which was built from an original one by removing or reordering columns.
+///
+/// `columns` maps columns of a new grid to the original ones, `None` stands for a new column.
+/// A spanned cell is moved to the first of its columns which are left,
+/// and its span is reduced to the ones which still follow each other.
+pub(crate) fn remap_column_spans(grid: &Grid, new_grid: &mut Grid, columns: &[Option<usize>]) {
+    for row in 0..grid.count_rows() {
+        for column in 0..grid.count_columns() {
+            let span = grid.style(&Entity::Cell(row, column)).span;
+            if span <= 1 {
+                continue;
+            }
+
+            let covered = column..column + span;
+            let positions = columns
+                .iter()
+                .enumerate()
+                .filter(|(_, c)| c.is_some_and(|c| covered.contains(&c)))
+                .map(|(i, _)| i)
+                .collect::<Vec<_>>();
+
+            for &i in &positions {
+                new_grid.set(&Entity::Cell(row, i), Settings::new().text("").span(1));
+            }
+
+            // columns which still follow each other
+            let mut runs: Vec<(usize, usize)> = Vec::new();
+            for &i in &positions {
+                match runs.last_mut() {
+                    Some((start, length)) if *start + *length == i => *length += 1,
+                    _ => runs.push((i, 1)),
+                }
+            }
+
+            let main_run = runs
+                .iter()
+                .position(|&(start, length)| {
+                    (start..start + length).any(|i| columns[i] == Some(column))
+                })
+                .unwrap_or(0);
+
+            for (i, &(start, length)) in runs.iter().enumerate() {
+                let settings = if i == main_run {
+                    let border = new_grid.get_border(row, start);
+                    grid.get_settings(row, column).border(border)
+                } else {
+                    Settings::new()
+                };
+
+                new_grid.set(
+                    &Entity::Cell(row, start),
+                    settings.span(length).border_restriction(false),
+                );
+            }
+        }
     }
 }
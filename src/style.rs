@@ -5,7 +5,7 @@ use std::{borrow::Cow, marker::PhantomData};
 #[allow(unused)]
 use crate::Table;
 use crate::TableOption;
-use papergrid::{Border, Entity, Grid, Settings};
+use papergrid::{AlignmentHorizontal, Border, Entity, Grid, Settings};
 
 /// Style is represents a theme of a [Table].
 ///
@@ -207,6 +207,83 @@ impl Style {
         CustomStyle::new(Self::_RE_STRUCTURED_TEXT)
     }
 
+    /// Accessible style looks like the following table
+    ///
+    /// It uses only ASCII characters and a distinct header line,
+    /// so it's rendered correctly by screen readers and any terminal.
+    ///
+    /// ```text
+    ///     +----+--------------+---------------------------+
+    ///     | id | destribution |           link            |
+    ///     +====+==============+===========================+
+    ///     | 0  |    Fedora    |  https://getfedora.org/   |
+    ///     +----+--------------+---------------------------+
+    ///     | 2  |   OpenSUSE   | https://www.opensuse.org/ |
+    ///     +----+--------------+---------------------------+
+    ///     | 3  | Endeavouros  | https://endeavouros.com/  |
+    ///     +----+--------------+---------------------------+
+    /// ```
+    ///
+    /// See [crate::Accessible] to also get rid of colors.
+    pub const fn accessible() -> CustomStyle<On, On, On, On, On, On, On> {
+        CustomStyle::new(Self::_ACCESSIBLE)
+    }
+
+    /// Heavy frame style looks like the following table
+    ///
+    /// It has a heavy outer frame and a header line, other lines are light.
+    ///
+    /// Beware: It uses UTF8 characters.
+    ///
+    /// ```text
+    ///     ┏━━━━┯━━━━━━━━━━━━━━┯━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+    ///     ┃ id │ destribution │           link            ┃
+    ///     ┣━━━━┿━━━━━━━━━━━━━━┿━━━━━━━━━━━━━━━━━━━━━━━━━━━┫
+    ///     ┃ 0  │    Fedora    │  https://getfedora.org/   ┃
+    ///     ┠────┼──────────────┼───────────────────────────┨
+    ///     ┃ 2  │   OpenSUSE   │ https://www.opensuse.org/ ┃
+    ///     ┠────┼──────────────┼───────────────────────────┨
+    ///     ┃ 3  │ Endeavouros  │ https://endeavouros.com/  ┃
+    ///     ┗━━━━┷━━━━━━━━━━━━━━┷━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+    /// ```
+    pub const fn heavy_frame() -> CustomStyle<On, On, On, On, On, On, On> {
+        CustomStyle::new(Self::_HEAVY_FRAME)
+    }
+
+    /// Rounded style looks like the following table
+    ///
+    /// Beware: It uses UTF8 characters.
+    ///
+    /// ```text
+    ///     ╭────┬──────────────┬───────────────────────────╮
+    ///     │ id │ destribution │           link            │
+    ///     ├────┼──────────────┼───────────────────────────┤
+    ///     │ 0  │    Fedora    │  https://getfedora.org/   │
+    ///     │ 2  │   OpenSUSE   │ https://www.opensuse.org/ │
+    ///     │ 3  │ Endeavouros  │ https://endeavouros.com/  │
+    ///     ╰────┴──────────────┴───────────────────────────╯
+    /// ```
+    pub const fn rounded() -> CustomStyle<On, On, On, On, (), On, On> {
+        CustomStyle::new(Self::_ROUNDED)
+    }
+
+    /// Blank header style looks like the following table
+    ///
+    /// It's a [Style::blank] with a line under a header.
+    ///
+    /// Beware: It uses UTF8 characters.
+    ///
+    /// ```text
+    ///      id   destribution             link
+    ///     ──── ────────────── ───────────────────────────
+    ///      0       Fedora       https://getfedora.org/
+    ///      2      OpenSUSE     https://www.opensuse.org/
+    ///      3    Endeavouros    https://endeavouros.com/
+    /// ```
+    pub const fn blank_header() -> CustomStyle<(), (), (), (), (), On, On> {
+        CustomStyle::new(Self::_BLANK_HEADER)
+    }
+
     const _ASCII: StyleSettings = StyleSettings::new(
         Frame {
             bottom: Some(Line::bordered('-', '+', '+', '+')),
@@ -219,8 +296,35 @@ impl Style {
         Some('|'),
     );
 
+    const _ACCESSIBLE: StyleSettings = StyleSettings::new(
+        Frame {
+            bottom: Some(Line::bordered('-', '+', '+', '+')),
+            top: Some(Line::bordered('-', '+', '+', '+')),
+            left: Some('|'),
+            right: Some('|'),
+        },
+        Some(Line::bordered('=', '+', '+', '+')),
+        Some(Line::bordered('-', '+', '+', '+')),
+        Some('|'),
+    );
+
+    const _HEAVY_FRAME: StyleSettings = StyleSettings::new(
+        Frame {
+            left: Some('┃'),
+            right: Some('┃'),
+            bottom: Some(Line::bordered('━', '┷', '┗', '┛')),
+            top: Some(Line::bordered('━', '┯', '┏', '┓')),
+        },
+        Some(Line::bordered('━', '┿', '┣', '┫')),
+        Some(Line::bordered('─', '┼', '┠', '┨')),
+        Some('│'),
+    );
+
     const _BLANK: StyleSettings = StyleSettings::new(Frame::empty(), None, None, Some(' '));
 
+    const _BLANK_HEADER: StyleSettings =
+        StyleSettings::new(Frame::empty(), Some(Line::short('─', ' ')), None, Some(' '));
+
     const _PSQL: StyleSettings =
         StyleSettings::new(Frame::empty(), Some(Line::short('-', '+')), None, Some('|'));
 
@@ -260,6 +364,18 @@ impl Style {
         Some('║'),
     );
 
+    const _ROUNDED: StyleSettings = StyleSettings::new(
+        Frame {
+            left: Some('│'),
+            right: Some('│'),
+            bottom: Some(Line::bordered('─', '┴', '╰', '╯')),
+            top: Some(Line::bordered('─', '┬', '╭', '╮')),
+        },
+        Some(Line::bordered('─', '┼', '├', '┤')),
+        None,
+        Some('│'),
+    );
+
     const _DOTS: StyleSettings = StyleSettings::new(
         Frame {
             bottom: Some(Line::bordered('.', ':', ':', ':')),
@@ -291,6 +407,8 @@ pub struct StyleSettings {
     header_split_line: Option<Line>,
     split: Option<Line>,
     inner_split_char: Option<char>,
+    inner_split_separator: Option<&'static str>,
+    split_every: Option<usize>,
 }
 
 impl StyleSettings {
@@ -305,6 +423,8 @@ impl StyleSettings {
             split,
             header_split_line: header,
             inner_split_char: inner,
+            inner_split_separator: None,
+            split_every: None,
         }
     }
 }
@@ -366,10 +486,22 @@ impl TableOption for StyleSettings {
 
         let count_rows = grid.count_rows();
         let count_columns = grid.count_columns();
+        let header_rows = grid.count_header_rows();
         for row in 0..count_rows {
             for column in 0..count_columns {
                 let mut border = make_style(self, row, column, count_rows, count_columns);
-                make_style_header(&mut border, self, row, column, count_rows, count_columns);
+                make_style_header(
+                    &mut border,
+                    self,
+                    (row, column),
+                    (count_rows, count_columns),
+                    header_rows,
+                );
+
+                // lines which are kept are added back by [separate_every]
+                if self.split_every.is_some() {
+                    skip_split_lines(&mut border, row, (count_rows, header_rows));
+                }
 
                 grid.set(
                     &Entity::Cell(row, column),
@@ -377,6 +509,25 @@ impl TableOption for StyleSettings {
                 );
             }
         }
+
+        if let Some(step) = self.split_every {
+            let row = header_rows + step;
+            if step > 0 && row < count_rows {
+                let line = (0..count_columns)
+                    .map(|column| make_style(self, row, column, count_rows, count_columns))
+                    .collect::<Vec<_>>();
+
+                separate_every(grid, header_rows, step, &line);
+            }
+        }
+
+        grid.clear_inner_vertical_separator();
+        if let Some(separator) = self.inner_split_separator {
+            grid.set_inner_vertical_separator(separator);
+        }
+
+        // custom borders e.g. set by [crate::Highlight] are kept
+        grid.restore_border_overrides();
     }
 }
 
@@ -418,10 +569,10 @@ fn make_style(
         (true, true, false, true) => Border {
             top: style.frame.top.as_ref().map(|l| l.main),
             bottom: style.frame.bottom.as_ref().map(|l| l.main),
-            left: style.frame.left,
+            left: style.inner_split_char,
             left_top_corner: style.frame.top.as_ref().and_then(|l| l.intersection),
             left_bottom_corner: style.frame.bottom.as_ref().and_then(|l| l.intersection),
-            right: style.inner_split_char,
+            right: style.frame.right,
             right_top_corner: style.frame.top.as_ref().and_then(|l| l.right_corner),
             right_bottom_corner: style.frame.bottom.as_ref().and_then(|l| l.right_corner),
         },
@@ -429,7 +580,7 @@ fn make_style(
             top: style.frame.top.as_ref().map(|l| l.main),
             bottom: style.frame.bottom.as_ref().map(|l| l.main),
             left: style.inner_split_char,
-            left_top_corner: style.frame.top.as_ref().and_then(|l| l.left_corner),
+            left_top_corner: style.frame.top.as_ref().and_then(|l| l.intersection),
             left_bottom_corner: style.frame.bottom.as_ref().and_then(|l| l.intersection),
             right: style.inner_split_char,
             right_top_corner: style.frame.top.as_ref().and_then(|l| l.intersection),
@@ -560,19 +711,40 @@ fn make_style(
     }
 }
 
+/// Removes split lines between body rows.
+fn skip_split_lines(border: &mut Border, row: usize, (count_rows, header_rows): (usize, usize)) {
+    if row > header_rows {
+        border.top = None;
+        border.left_top_corner = None;
+        border.right_top_corner = None;
+    }
+
+    if row + 1 < count_rows && row + 1 > header_rows {
+        border.bottom = None;
+        border.left_bottom_corner = None;
+        border.right_bottom_corner = None;
+    }
+}
+
+/// Sets a header line under the last header row.
 fn make_style_header(
     border: &mut Border,
     style: &StyleSettings,
-    row: usize,
-    column: usize,
-    _count_rows: usize,
-    count_columns: usize,
+    (row, column): (usize, usize),
+    (count_rows, count_columns): (usize, usize),
+    header_rows: usize,
 ) {
+    if header_rows == 0 {
+        return;
+    }
+
     let is_first_column = column == 0;
     let is_last_column = column + 1 == count_columns;
+    let is_first_body_row = row == header_rows;
+    let is_last_header_row = row + 1 == header_rows && count_rows > header_rows;
 
     if style.header_split_line.is_some() {
-        if row == 1 {
+        if is_first_body_row {
             border.top = style.header_split_line.as_ref().map(|l| l.main);
 
             if is_last_column {
@@ -598,7 +770,7 @@ fn make_style_header(
             }
         }
 
-        if row == 0 {
+        if is_last_header_row {
             border.bottom = style.header_split_line.as_ref().map(|l| l.main);
 
             if is_last_column {
@@ -624,13 +796,13 @@ fn make_style_header(
             }
         }
     } else if count_columns > 1 {
-        if row == 1 {
+        if is_first_body_row {
             border.top = None;
             border.right_top_corner = None;
             border.left_top_corner = None;
         }
 
-        if row == 0 {
+        if row + 1 == header_rows {
             border.bottom = None;
             border.right_bottom_corner = None;
             border.left_bottom_corner = None;
@@ -657,20 +829,149 @@ fn make_style_header(
 /// );
 /// ```
 pub struct TopBorderText<'a> {
-    // todo: offset from which we start overriding border
-    // offset: usize,
     text: Cow<'a, str>,
+    alignment: AlignmentHorizontal,
 }
 
 impl<'a> TopBorderText<'a> {
     pub fn new<S: Into<Cow<'a, str>>>(text: S) -> Self {
-        Self { text: text.into() }
+        Self {
+            text: text.into(),
+            alignment: AlignmentHorizontal::Left,
+        }
+    }
+
+    /// Sets an alignment of the text on the border.
+    ///
+    /// The text is placed by its display width so wide characters don't shift the border.
+    ///
+    /// ```rust
+    /// use tabled::{style::TopBorderText, AlignmentHorizontal, Table};
+    /// let table = Table::new(["Hello World"])
+    ///     .with(TopBorderText::new("表格").alignment(AlignmentHorizontal::Right));
+    ///
+    /// assert_eq!(
+    ///     table.to_string(),
+    ///     "+----------表格\n\
+    ///      |    &str     |\n\
+    ///      +-------------+\n\
+    ///      | Hello World |\n\
+    ///      +-------------+\n"
+    /// );
+    /// ```
+    pub fn alignment(mut self, alignment: AlignmentHorizontal) -> Self {
+        self.alignment = alignment;
+        self
     }
 }
 
 impl<'a> TableOption for TopBorderText<'a> {
     fn change(&mut self, grid: &mut Grid) {
-        grid.override_split_line(0, self.text.as_ref())
+        grid.override_split_line_aligned(0, self.text.as_ref(), self.alignment)
+    }
+}
+
+/// SeparatorEvery adds a horizontal line after every N rows of a body.
+///
+/// The line repeats the one which splits a header and a body,
+/// so it must be applied after a [Style].
+/// If there's no such line nothing is changed.
+///
+/// # Example
+///
+/// ```rust
+/// use tabled::{style::SeparatorEvery, Style, Table};
+/// let table = Table::new([1, 2, 3, 4, 5])
+///     .with(Style::psql())
+///     .with(SeparatorEvery(2));
+///
+/// assert_eq!(
+///     table.to_string(),
+///     concat!(
+///         " i32 \n",
+///         "-----\n",
+///         "  1  \n",
+///         "  2  \n",
+///         "-----\n",
+///         "  3  \n",
+///         "  4  \n",
+///         "-----\n",
+///         "  5  \n",
+///     )
+/// );
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct SeparatorEvery(pub usize);
+
+impl TableOption for SeparatorEvery {
+    fn change(&mut self, grid: &mut Grid) {
+        let step = self.0;
+        let header_rows = grid.count_header_rows();
+        let first_row = header_rows;
+        if step == 0
+            || header_rows == 0
+            || grid.count_rows() <= first_row
+            || !grid.has_horizontal(first_row)
+        {
+            return;
+        }
+
+        let line = (0..grid.count_columns())
+            .map(|column| grid.get_border(first_row, column))
+            .collect::<Vec<_>>();
+
+        separate_every(grid, first_row, step, &line);
+    }
+}
+
+/// Sets a top border of every `step` row after the first one to a given line.
+///
+/// The line is taken from top borders of the given cells, one per column.
+fn separate_every(grid: &mut Grid, first_row: usize, step: usize, line: &[Border]) {
+    for row in (first_row + step..grid.count_rows()).step_by(step) {
+        for (column, line_border) in line.iter().enumerate() {
+            let border = Border {
+                top: line_border.top,
+                left_top_corner: line_border.left_top_corner,
+                right_top_corner: line_border.right_top_corner,
+                ..Default::default()
+            };
+
+            grid.set(
+                &Entity::Cell(row, column),
+                Settings::default().border(border).border_restriction(false),
+            );
+        }
+    }
+}
+
+/// A [BorderGlyph] renders a string in place of a border char.
+///
+/// It's available only with a `border-glyphs` feature on.
+///
+/// ```rust
+/// use tabled::{style::BorderGlyph, Style, Table};
+///
+/// // a combining accent makes a glyph of 2 code points but of 1 column
+/// let glyph = BorderGlyph::new('+', "+\u{301}").unwrap();
+/// let table = Table::new(["a"]).with(Style::ascii()).with(glyph);
+///
+/// assert_eq!(
+///     table.to_string(),
+///     "+\u{301}------+\u{301}\n\
+///      | &str |\n\
+///      +\u{301}------+\u{301}\n\
+///      |  a   |\n\
+///      +\u{301}------+\u{301}\n"
+/// );
+/// ```
+#[cfg(feature = "border-glyphs")]
+pub use papergrid::{BorderGlyph, BorderGlyphError};
+
+#[cfg(feature = "border-glyphs")]
+impl TableOption for BorderGlyph {
+    fn change(&mut self, grid: &mut Grid) {
+        grid.set_border_glyph(self.clone());
     }
 }
 
@@ -1235,12 +1536,71 @@ impl<T, B, L, R, IV, H> CustomStyle<T, B, L, R, On, IV, H> {
         self.inner.split = None;
         CustomStyle::new(self.inner)
     }
+
+    /// Keeps a horizontal split line only after every N rows of a body.
+    ///
+    /// A line under a header is not affected, 0 removes all the other lines.
+    /// It's the same as [SeparatorEvery] but with the split line of the style.
+    ///
+    /// ```rust
+    /// use tabled::{Style, Table};
+    ///
+    /// let table = Table::new([1, 2, 3, 4, 5]).with(Style::modern().lines_every(2));
+    ///
+    /// assert_eq!(
+    ///     table.to_string(),
+    ///     concat!(
+    ///         "┌─────┐\n",
+    ///         "│ i32 │\n",
+    ///         "├─────┤\n",
+    ///         "│  1  │\n",
+    ///         "│  2  │\n",
+    ///         "├─────┤\n",
+    ///         "│  3  │\n",
+    ///         "│  4  │\n",
+    ///         "├─────┤\n",
+    ///         "│  5  │\n",
+    ///         "└─────┘\n",
+    ///     )
+    /// );
+    /// ```
+    pub const fn lines_every(mut self, step: usize) -> Self {
+        self.inner.split_every = Some(step);
+        CustomStyle::new(self.inner)
+    }
 }
 
 impl<T, B, L, R, IH, H> CustomStyle<T, B, L, R, IH, On, H> {
+    /// Sets a string which is rendered in place of a vertical split line.
+    ///
+    /// The string may be wider than one character.
+    /// On horizontal lines its whitespace characters are replaced by a line character
+    /// and other characters by an intersection character.
+    ///
+    /// ```rust
+    /// use tabled::{Style, Table};
+    ///
+    /// let table = Table::new([("Hello", "World")])
+    ///     .with(Style::modern().vertical_separator(" │ "));
+    ///
+    /// assert_eq!(
+    ///     table.to_string(),
+    ///     "┌────────┬────────┐\n\
+    ///      │ &str   │  &str  │\n\
+    ///      ├────────┼────────┤\n\
+    ///      │ Hello  │  World │\n\
+    ///      └────────┴────────┘\n"
+    /// );
+    /// ```
+    pub const fn vertical_separator(mut self, separator: &'static str) -> Self {
+        self.inner.inner_split_separator = Some(separator);
+        CustomStyle::new(self.inner)
+    }
+
     /// Removes vertical split lines.
     pub const fn vertical_off(mut self) -> CustomStyle<T, B, L, R, IH, (), H> {
         self.inner.inner_split_char = None;
+        self.inner.inner_split_separator = None;
 
         if let Some(mut split) = self.inner.split {
             split.intersection = None;
@@ -1274,8 +1634,538 @@ impl<T, B, L, R, IH, IV> CustomStyle<T, B, L, R, IH, IV, On> {
     }
 }
 
+impl<T, B, L, R, IH, IV, H> CustomStyle<T, B, L, R, IH, IV, H> {
+    /// Checks the style for consistency.
+    ///
+    /// See [RawStyle::verify].
+    ///
+    /// ```rust
+    /// use tabled::Style;
+    ///
+    /// assert!(Style::modern().verify().is_empty());
+    /// ```
+    pub fn verify(&self) -> Vec<StyleIssue> {
+        RawStyle::from(CustomStyle::<T, B, L, R, IH, IV, H>::new(
+            self.inner.clone(),
+        ))
+        .verify()
+    }
+
+    /// Resolves issues found by [CustomStyle::verify].
+    ///
+    /// See [RawStyle::fix].
+    pub fn fix(self) -> Self {
+        Self::new(RawStyle::from(self).fix().settings())
+    }
+}
+
 impl<T, B, L, R, IH, IV, H> TableOption for CustomStyle<T, B, L, R, IH, IV, H> {
     fn change(&mut self, grid: &mut Grid) {
         self.inner.change(grid);
     }
 }
+
+/// RawStyle is a style where each character can be set independently.
+///
+/// Unlike [CustomStyle] it doesn't control a state,
+/// so a line is rendered only if its main character is set,
+/// and an intersection or a corner is rendered only if its line is on.
+///
+/// Any preset can be converted into it for tweaking.
+///
+/// ```rust
+/// use tabled::{style::RawStyle, Style, Table};
+///
+/// let style = RawStyle::from(Style::modern())
+///     .horizontal(None)
+///     .header_intersection(Some('┿'))
+///     .left_header_intersection(Some('┝'))
+///     .right_header_intersection(Some('┥'))
+///     .header(Some('━'));
+///
+/// let table = Table::new([("Hello", "World")]).with(style);
+///
+/// assert_eq!(
+///     table.to_string(),
+///     "┌───────┬───────┐\n\
+///      │ &str  │ &str  │\n\
+///      ┝━━━━━━━┿━━━━━━━┥\n\
+///      │ Hello │ World │\n\
+///      └───────┴───────┘\n"
+/// );
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct RawStyle {
+    top: Option<char>,
+    top_left_corner: Option<char>,
+    top_right_corner: Option<char>,
+    top_intersection: Option<char>,
+    bottom: Option<char>,
+    bottom_left_corner: Option<char>,
+    bottom_right_corner: Option<char>,
+    bottom_intersection: Option<char>,
+    left: Option<char>,
+    right: Option<char>,
+    horizontal: Option<char>,
+    left_intersection: Option<char>,
+    right_intersection: Option<char>,
+    inner_intersection: Option<char>,
+    vertical: Option<char>,
+    vertical_separator: Option<&'static str>,
+    lines_every: Option<usize>,
+    header: Option<char>,
+    left_header_intersection: Option<char>,
+    right_header_intersection: Option<char>,
+    header_intersection: Option<char>,
+}
+
+impl RawStyle {
+    /// Creates a style with no borders.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets a main character of a top border.
+    pub fn top(mut self, c: Option<char>) -> Self {
+        self.top = c;
+        self
+    }
+
+    /// Sets a main character of a bottom border.
+    pub fn bottom(mut self, c: Option<char>) -> Self {
+        self.bottom = c;
+        self
+    }
+
+    /// Sets a left border.
+    pub fn left(mut self, c: Option<char>) -> Self {
+        self.left = c;
+        self
+    }
+
+    /// Sets a right border.
+    pub fn right(mut self, c: Option<char>) -> Self {
+        self.right = c;
+        self
+    }
+
+    /// Sets a main character of horizontal split lines.
+    ///
+    /// Not including a line under a header.
+    pub fn horizontal(mut self, c: Option<char>) -> Self {
+        self.horizontal = c;
+        self
+    }
+
+    /// Sets vertical split lines.
+    pub fn vertical(mut self, c: Option<char>) -> Self {
+        self.vertical = c;
+        self
+    }
+
+    /// Sets a main character of a line under a header.
+    pub fn header(mut self, c: Option<char>) -> Self {
+        self.header = c;
+        self
+    }
+
+    /// Sets a top left corner.
+    pub fn top_left_corner(mut self, c: Option<char>) -> Self {
+        self.top_left_corner = c;
+        self
+    }
+
+    /// Sets a top right corner.
+    pub fn top_right_corner(mut self, c: Option<char>) -> Self {
+        self.top_right_corner = c;
+        self
+    }
+
+    /// Sets a bottom left corner.
+    pub fn bottom_left_corner(mut self, c: Option<char>) -> Self {
+        self.bottom_left_corner = c;
+        self
+    }
+
+    /// Sets a bottom right corner.
+    pub fn bottom_right_corner(mut self, c: Option<char>) -> Self {
+        self.bottom_right_corner = c;
+        self
+    }
+
+    /// Sets an intersection of a top border and vertical split lines.
+    pub fn top_intersection(mut self, c: Option<char>) -> Self {
+        self.top_intersection = c;
+        self
+    }
+
+    /// Sets an intersection of a bottom border and vertical split lines.
+    pub fn bottom_intersection(mut self, c: Option<char>) -> Self {
+        self.bottom_intersection = c;
+        self
+    }
+
+    /// Sets an intersection of a left border and horizontal split lines.
+    pub fn left_intersection(mut self, c: Option<char>) -> Self {
+        self.left_intersection = c;
+        self
+    }
+
+    /// Sets an intersection of a right border and horizontal split lines.
+    pub fn right_intersection(mut self, c: Option<char>) -> Self {
+        self.right_intersection = c;
+        self
+    }
+
+    /// Sets an intersection of horizontal and vertical split lines.
+    pub fn inner_intersection(mut self, c: Option<char>) -> Self {
+        self.inner_intersection = c;
+        self
+    }
+
+    /// Sets an intersection of a line under a header and vertical split lines.
+    pub fn header_intersection(mut self, c: Option<char>) -> Self {
+        self.header_intersection = c;
+        self
+    }
+
+    /// Sets an intersection of a left border and a line under a header.
+    pub fn left_header_intersection(mut self, c: Option<char>) -> Self {
+        self.left_header_intersection = c;
+        self
+    }
+
+    /// Sets an intersection of a right border and a line under a header.
+    pub fn right_header_intersection(mut self, c: Option<char>) -> Self {
+        self.right_header_intersection = c;
+        self
+    }
+
+    /// Sets a string which is rendered in place of vertical split lines.
+    ///
+    /// See [CustomStyle::vertical_separator].
+    pub fn vertical_separator(mut self, separator: Option<&'static str>) -> Self {
+        self.vertical_separator = separator;
+        self
+    }
+
+    /// Keeps a horizontal split line only after every N rows of a body.
+    ///
+    /// See [CustomStyle::lines_every].
+    pub fn lines_every(mut self, step: Option<usize>) -> Self {
+        self.lines_every = step;
+        self
+    }
+
+    /// Checks the style for consistency.
+    ///
+    /// It reports corners and intersections which are needed to draw the borders but not set,
+    /// ones which don't join box drawing borders around them,
+    /// and ones which are set but never drawn since a border they belong to is off.
+    /// An empty list means the style is consistent.
+    ///
+    /// ```rust
+    /// use tabled::style::{RawStyle, StyleIssue};
+    ///
+    /// let style = RawStyle::new().top(Some('─')).vertical(Some('│'));
+    ///
+    /// assert_eq!(
+    ///     style.verify(),
+    ///     vec![StyleIssue::Missing { name: "top_intersection", suggestion: '┬' }],
+    /// );
+    /// ```
+    pub fn verify(&self) -> Vec<StyleIssue> {
+        let mut issues = Vec::new();
+        self.clone().inspect(&mut issues);
+        issues
+    }
+
+    /// Resolves issues found by [RawStyle::verify].
+    ///
+    /// Missing characters are set to suggested ones and unused ones are removed.
+    pub fn fix(mut self) -> Self {
+        self.inspect(&mut Vec::new());
+        self
+    }
+
+    fn inspect(&mut self, issues: &mut Vec<StyleIssue>) {
+        let columns = [self.left, self.vertical, self.right];
+        let lines = [
+            (
+                self.top,
+                LinePosition::Top,
+                [
+                    ("top_left_corner", &mut self.top_left_corner),
+                    ("top_intersection", &mut self.top_intersection),
+                    ("top_right_corner", &mut self.top_right_corner),
+                ],
+            ),
+            (
+                self.header,
+                LinePosition::Inner,
+                [
+                    (
+                        "left_header_intersection",
+                        &mut self.left_header_intersection,
+                    ),
+                    ("header_intersection", &mut self.header_intersection),
+                    (
+                        "right_header_intersection",
+                        &mut self.right_header_intersection,
+                    ),
+                ],
+            ),
+            (
+                self.horizontal,
+                LinePosition::Inner,
+                [
+                    ("left_intersection", &mut self.left_intersection),
+                    ("inner_intersection", &mut self.inner_intersection),
+                    ("right_intersection", &mut self.right_intersection),
+                ],
+            ),
+            (
+                self.bottom,
+                LinePosition::Bottom,
+                [
+                    ("bottom_left_corner", &mut self.bottom_left_corner),
+                    ("bottom_intersection", &mut self.bottom_intersection),
+                    ("bottom_right_corner", &mut self.bottom_right_corner),
+                ],
+            ),
+        ];
+
+        for (main, position, chars) in lines {
+            for (index, (name, c)) in IntoIterator::into_iter(chars).enumerate() {
+                match (main, columns[index]) {
+                    (Some(horizontal), Some(vertical)) => match *c {
+                        None => {
+                            let suggestion =
+                                suggest_intersection(position, index, horizontal, vertical);
+                            issues.push(StyleIssue::Missing { name, suggestion });
+                            *c = Some(suggestion);
+                        }
+                        // a line char in place of a box drawing intersection breaks the line
+                        Some(current) if current == horizontal || current == vertical => {
+                            let suggestion =
+                                box_drawing_intersection(position, index, horizontal, vertical);
+                            if let Some(suggestion) = suggestion.filter(|&s| s != current) {
+                                issues.push(StyleIssue::Mismatched { name, suggestion });
+                                *c = Some(suggestion);
+                            }
+                        }
+                        Some(_) => {}
+                    },
+                    _ => {
+                        if c.is_some() {
+                            issues.push(StyleIssue::Unused { name });
+                            *c = None;
+                        }
+                    }
+                }
+            }
+        }
+
+        if self.vertical.is_none() && self.vertical_separator.is_some() {
+            issues.push(StyleIssue::Unused {
+                name: "vertical_separator",
+            });
+            self.vertical_separator = None;
+        }
+    }
+
+    fn settings(&self) -> StyleSettings {
+        let line = |main: Option<char>, intersection, left_corner, right_corner| {
+            main.map(|main| Line {
+                main,
+                intersection: self.vertical.and(intersection),
+                left_corner: self.left.and(left_corner),
+                right_corner: self.right.and(right_corner),
+            })
+        };
+
+        let frame = Frame {
+            top: line(
+                self.top,
+                self.top_intersection,
+                self.top_left_corner,
+                self.top_right_corner,
+            ),
+            bottom: line(
+                self.bottom,
+                self.bottom_intersection,
+                self.bottom_left_corner,
+                self.bottom_right_corner,
+            ),
+            left: self.left,
+            right: self.right,
+        };
+
+        let header = line(
+            self.header,
+            self.header_intersection,
+            self.left_header_intersection,
+            self.right_header_intersection,
+        );
+
+        let split = line(
+            self.horizontal,
+            self.inner_intersection,
+            self.left_intersection,
+            self.right_intersection,
+        );
+
+        let mut settings = StyleSettings::new(frame, header, split, self.vertical);
+        settings.inner_split_separator = self.vertical.and(self.vertical_separator);
+        settings.split_every = self.lines_every;
+        settings
+    }
+}
+
+impl<T, B, L, R, IH, IV, H> From<CustomStyle<T, B, L, R, IH, IV, H>> for RawStyle {
+    fn from(style: CustomStyle<T, B, L, R, IH, IV, H>) -> Self {
+        let style = style.inner;
+        let main = |line: &Option<Line>| line.as_ref().map(|l| l.main);
+        let lines = [
+            &style.frame.top,
+            &style.frame.bottom,
+            &style.split,
+            &style.header_split_line,
+        ];
+        let [top_main, bottom_main, split_main, header_main] = lines.map(main);
+        let [top, bottom, split, header] = lines.map(|line| line.clone().unwrap_or_default());
+
+        Self {
+            top: top_main,
+            top_left_corner: top.left_corner,
+            top_right_corner: top.right_corner,
+            top_intersection: top.intersection,
+            bottom: bottom_main,
+            bottom_left_corner: bottom.left_corner,
+            bottom_right_corner: bottom.right_corner,
+            bottom_intersection: bottom.intersection,
+            left: style.frame.left,
+            right: style.frame.right,
+            horizontal: split_main,
+            left_intersection: split.left_corner,
+            right_intersection: split.right_corner,
+            inner_intersection: split.intersection,
+            vertical: style.inner_split_char,
+            vertical_separator: style.inner_split_separator,
+            lines_every: style.split_every,
+            header: header_main,
+            left_header_intersection: header.left_corner,
+            right_header_intersection: header.right_corner,
+            header_intersection: header.intersection,
+        }
+    }
+}
+
+impl TableOption for RawStyle {
+    fn change(&mut self, grid: &mut Grid) {
+        self.settings().change(grid);
+    }
+}
+
+/// StyleIssue is an inconsistency of a style found by [RawStyle::verify].
+///
+/// A name of a character is a name of a [RawStyle] method which sets it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum StyleIssue {
+    /// A character is needed to draw borders but it's not set.
+    Missing {
+        /// A name of the character.
+        name: &'static str,
+        /// A character which fits the borders around.
+        suggestion: char,
+    },
+    /// A character doesn't join borders around,
+    /// e.g. a vertical split line character is used as an intersection with a top border.
+    Mismatched {
+        /// A name of the character.
+        name: &'static str,
+        /// A character which fits the borders around.
+        suggestion: char,
+    },
+    /// A character is set but never drawn since a border it belongs to is off.
+    Unused {
+        /// A name of the character.
+        name: &'static str,
+    },
+}
+
+impl std::fmt::Display for StyleIssue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Missing { name, suggestion } => {
+                write!(f, "{} is not set, {:?} would fit", name, suggestion)
+            }
+            Self::Mismatched { name, suggestion } => {
+                write!(
+                    f,
+                    "{} doesn't join borders, {:?} would fit",
+                    name, suggestion
+                )
+            }
+            Self::Unused { name } => write!(f, "{} is set but never drawn", name),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+enum LinePosition {
+    Top,
+    Inner,
+    Bottom,
+}
+
+// suggests a char for an intersection of a horizontal line and a left border,
+// a vertical split line or a right border (by an index)
+fn suggest_intersection(
+    position: LinePosition,
+    index: usize,
+    horizontal: char,
+    vertical: char,
+) -> char {
+    match box_drawing_intersection(position, index, horizontal, vertical) {
+        Some(c) => c,
+        None if horizontal.is_ascii_punctuation() && vertical.is_ascii_punctuation() => '+',
+        None => horizontal,
+    }
+}
+
+fn box_drawing_intersection(
+    position: LinePosition,
+    index: usize,
+    horizontal: char,
+    vertical: char,
+) -> Option<char> {
+    const BOX_DRAWINGS: [(char, char, [[char; 3]; 3]); 3] = [
+        (
+            '─',
+            '│',
+            [['┌', '┬', '┐'], ['├', '┼', '┤'], ['└', '┴', '┘']],
+        ),
+        (
+            '━',
+            '┃',
+            [['┏', '┳', '┓'], ['┣', '╋', '┫'], ['┗', '┻', '┛']],
+        ),
+        (
+            '═',
+            '║',
+            [['╔', '╦', '╗'], ['╠', '╬', '╣'], ['╚', '╩', '╝']],
+        ),
+    ];
+
+    let row = match position {
+        LinePosition::Top => 0,
+        LinePosition::Inner => 1,
+        LinePosition::Bottom => 2,
+    };
+
+    BOX_DRAWINGS
+        .iter()
+        .find(|(h, v, _)| *h == horizontal && *v == vertical)
+        .map(|(_, _, chars)| chars[row][index])
+}
@@ -1,13 +1,20 @@
-use std::{fmt, iter::FromIterator};
+use std::{any::Any, collections::HashMap, fmt, io, iter::FromIterator, sync::Arc};
 
-use papergrid::Grid;
+use papergrid::{Border, Entity, Grid, Settings};
 
-use crate::{builder::Builder, Object, Tabled};
+use crate::{builder::Builder, debug_assert_length, Alignment, Error, Object, TableLayout, Tabled};
 
 /// A trait which is responsilbe for configuration of a [Grid].
 pub trait TableOption {
     /// The function modifies a [Grid] object.
     fn change(&mut self, grid: &mut Grid);
+
+    /// Returns errors found by the last [TableOption::change], e.g. cells which are out of a grid.
+    ///
+    /// The errors are kept by [Table::with] and reported by [Table::try_build].
+    fn take_errors(&mut self) -> Vec<Error> {
+        Vec::new()
+    }
 }
 
 impl<T> TableOption for &mut T
@@ -17,6 +24,10 @@ where
     fn change(&mut self, grid: &mut Grid) {
         T::change(self, grid)
     }
+
+    fn take_errors(&mut self) -> Vec<Error> {
+        T::take_errors(self)
+    }
 }
 
 /// A trait for configuring a [Cell] a single cell.
@@ -50,8 +61,16 @@ pub trait CellOption {
 ///                 .with(Modify::new(Full).with(Alignment::left()));
 /// println!("{}", table);
 /// ```
+///
+/// A `Debug` output shows a structure of the table rather than a rendered one,
+/// which may help to debug a chain of options.
+#[derive(Debug)]
 pub struct Table {
     pub(crate) grid: Grid,
+    /// Metadata of rows by their original positions.
+    pub(crate) metas: HashMap<usize, Arc<dyn Any + Send + Sync>>,
+    /// Errors of options reported by [Table::try_build].
+    pub(crate) errors: Vec<Error>,
 }
 
 impl Table {
@@ -60,11 +79,419 @@ impl Table {
         Self::from_iter(iter)
     }
 
+    /// New creates a Table instance without a header,
+    /// so [Tabled::headers] are not used and a first row is a first record
+    /// which is not treated as a header.
+    ///
+    /// ```rust
+    /// use tabled::Table;
+    ///
+    /// let table = Table::new_without_header([("Rust", 2010), ("Go", 2009)]);
+    ///
+    /// assert_eq!(
+    ///     table.to_string(),
+    ///     "+------+------+\n\
+    ///      | Rust | 2010 |\n\
+    ///      +------+------+\n\
+    ///      |  Go  | 2009 |\n\
+    ///      +------+------+\n"
+    /// );
+    /// ```
+    pub fn new_without_header<T: Tabled>(iter: impl IntoIterator<Item = T>) -> Self {
+        from_records(iter, false)
+    }
+
+    /// Creates a two-column table of key-value pairs without a header.
+    ///
+    /// Keys are aligned to the right and values to the left.
+    ///
+    /// ```rust
+    /// use tabled::Table;
+    ///
+    /// let table = Table::kv([("name", "tabled"), ("version", "0.5.0")]);
+    ///
+    /// assert_eq!(
+    ///     table.to_string(),
+    ///     "+---------+--------+\n\
+    ///      |    name | tabled |\n\
+    ///      +---------+--------+\n\
+    ///      | version | 0.5.0  |\n\
+    ///      +---------+--------+\n"
+    /// );
+    /// ```
+    pub fn kv<K, V>(pairs: impl IntoIterator<Item = (K, V)>) -> Self
+    where
+        K: fmt::Display,
+        V: fmt::Display,
+    {
+        let rows = pairs
+            .into_iter()
+            .map(|(key, value)| vec![key.to_string(), value.to_string()])
+            .collect::<Vec<_>>();
+
+        Builder::from(rows)
+            .column_alignment(0, Alignment::right())
+            .column_alignment(1, Alignment::left())
+            .build()
+    }
+
+    /// Creates a two-column table out of a map with a `key` and `value` header.
+    ///
+    /// Rows are in the order of the map iteration,
+    /// so it's deterministic for [std::collections::BTreeMap].
+    /// [Table::from_map_sorted] can be used for maps with an arbitrary order.
+    ///
+    /// ```rust
+    /// use std::collections::BTreeMap;
+    /// use tabled::{Style, Table};
+    ///
+    /// let map = BTreeMap::from([("version", "0.5.0"), ("name", "tabled")]);
+    /// let table = Table::from_map(&map).with(Style::psql());
+    ///
+    /// assert_eq!(
+    ///     table.to_string(),
+    ///     concat!(
+    ///         "   key   | value  \n",
+    ///         "---------+--------\n",
+    ///         "  name   | tabled \n",
+    ///         " version | 0.5.0  \n",
+    ///     )
+    /// );
+    /// ```
+    pub fn from_map<K, V>(map: impl IntoIterator<Item = (K, V)>) -> Self
+    where
+        K: fmt::Display,
+        V: fmt::Display,
+    {
+        let rows = map
+            .into_iter()
+            .map(|(key, value)| vec![key.to_string(), value.to_string()])
+            .collect::<Vec<_>>();
+
+        Builder::from(rows).set_header(["key", "value"]).build()
+    }
+
+    /// Creates a two-column table out of a map like [Table::from_map],
+    /// but rows are sorted by keys.
+    ///
+    /// It's useful for a [std::collections::HashMap] which has an arbitrary order.
+    pub fn from_map_sorted<K, V>(map: impl IntoIterator<Item = (K, V)>) -> Self
+    where
+        K: fmt::Display + Ord,
+        V: fmt::Display,
+    {
+        let mut pairs = map.into_iter().collect::<Vec<_>>();
+        pairs.sort_by(|a, b| a.0.cmp(&b.0));
+
+        Self::from_map(pairs)
+    }
+
     /// Returns a table shape (count rows, count columns).
     pub fn shape(&self) -> (usize, usize) {
         (self.grid.count_rows(), self.grid.count_columns())
     }
 
+    /// Returns a current position of a cell by its position at the time the table was built.
+    ///
+    /// Options like [crate::Panel] or [crate::Disable] shift cells,
+    /// the function allows to locate a cell regardless of them.
+    /// It returns [None] if a cell was removed.
+    ///
+    /// ```rust
+    /// use tabled::{Header, Table};
+    ///
+    /// let table = Table::new(&["Hello", "World"]).with(Header("Greetings"));
+    ///
+    /// assert_eq!(table.map_coord(1, 0), Some((2, 0)));
+    /// ```
+    pub fn map_coord(&self, row: usize, column: usize) -> Option<(usize, usize)> {
+        find_origin(&self.grid, (row, column))
+    }
+
+    /// Returns a metadata of a row attached by [Builder::add_row_with_meta].
+    ///
+    /// A row is located by its original position,
+    /// so the metadata follows the row after options like [crate::Sort] or [crate::Panel].
+    /// It returns [None] if there's no metadata of a given type.
+    pub fn meta<M: Any>(&self, row: usize) -> Option<&M> {
+        if row >= self.grid.count_rows() {
+            return None;
+        }
+
+        let (origin_row, _) =
+            (0..self.grid.count_columns()).find_map(|column| self.grid.get_origin(row, column))?;
+
+        self.metas.get(&origin_row)?.downcast_ref()
+    }
+
+    /// Returns widths of columns on a rendered table.
+    ///
+    /// The widths can be applied to another table via [crate::UseWidths]
+    /// so tables of the same schema line up.
+    pub fn widths(&self) -> Vec<usize> {
+        self.grid.columns_widths()
+    }
+
+    /// Returns areas of cells on a rendered table.
+    ///
+    /// The layout corresponds to a string returned by `to_string()`
+    /// so it's supposed to be called after all options are applied.
+    pub fn layout(&self) -> TableLayout {
+        TableLayout::new(self.grid.layout())
+    }
+
+    /// Renders the table as an HTML `<table>`.
+    ///
+    /// It's a shortcut for a default [crate::html::Html] renderer.
+    pub fn to_html(&self) -> String {
+        crate::html::Html::new().render(self)
+    }
+
+    /// Renders the table into a [fmt::Write] without building a [String] of the whole table.
+    ///
+    /// ```rust
+    /// use tabled::{Style, Table};
+    ///
+    /// let mut buf = String::from("table:\n");
+    /// Table::new([1]).with(Style::psql()).fmt_into(&mut buf).unwrap();
+    ///
+    /// assert_eq!(buf, "table:\n i32 \n-----\n  1  \n");
+    /// ```
+    pub fn fmt_into<W: fmt::Write>(&self, writer: &mut W) -> fmt::Result {
+        self.grid.build_into(writer)
+    }
+
+    /// Renders the table into an [io::Write] without building a [String] of the whole table.
+    ///
+    /// The table is written in small parts,
+    /// so an unbuffered writer like a [std::fs::File] is better wrapped into an [io::BufWriter].
+    ///
+    /// ```rust
+    /// use tabled::{Style, Table};
+    ///
+    /// let mut buf = Vec::new();
+    /// Table::new([1]).with(Style::psql()).write_to(&mut buf).unwrap();
+    ///
+    /// assert_eq!(buf, b" i32 \n-----\n  1  \n");
+    /// ```
+    pub fn write_to<W: io::Write>(&self, writer: &mut W) -> io::Result<()> {
+        write!(writer, "{}", self.grid)
+    }
+
+    /// Renders the table as a GitHub flavored Markdown table.
+    ///
+    /// Unlike [crate::Style::github_markdown] it emits alignment markers of columns
+    /// which are taken from a first row after a header.
+    /// Pipes inside of cells are escaped.
+    ///
+    /// ```rust
+    /// use tabled::{Alignment, Column, Modify, Table};
+    ///
+    /// let table = Table::new([("Rust", 2010), ("C|C++", 1972)])
+    ///     .with(Modify::new(Column(..1)).with(Alignment::left()))
+    ///     .with(Modify::new(Column(1..)).with(Alignment::right()));
+    ///
+    /// assert_eq!(
+    ///     table.to_markdown(),
+    ///     concat!(
+    ///         "| &str   | i32  |\n",
+    ///         "|:-------|-----:|\n",
+    ///         "| Rust   | 2010 |\n",
+    ///         "| C\\|C++ | 1972 |\n",
+    ///     )
+    /// );
+    /// ```
+    pub fn to_markdown(&self) -> String {
+        crate::markdown::render(&self.grid)
+    }
+
+    /// Renders the table making whitespace visible.
+    ///
+    /// Spaces added by an indent or an alignment are shown as `·`,
+    /// and spaces used as borders, which work as margins, are shown as `░`.
+    /// It's supposed to be used in layout tests and bug reports.
+    ///
+    /// ```rust
+    /// use tabled::{Style, Table};
+    ///
+    /// let table = Table::new([("Hello", 1), ("World!", 22)]).with(Style::blank());
+    ///
+    /// assert_eq!(
+    ///     table.to_debug_string(),
+    ///     concat!(
+    ///         "··&str··░·i32·\n",
+    ///         "·Hello··░··1··\n",
+    ///         "·World!·░·22··\n",
+    ///     )
+    /// );
+    /// ```
+    pub fn to_debug_string(&self) -> String {
+        let mut grid = self.grid.clone();
+        for row in 0..grid.count_rows() {
+            for column in 0..grid.count_columns() {
+                let border = grid.get_border(row, column);
+                let border = Border {
+                    top: border.top.map(debug_border_char),
+                    bottom: border.bottom.map(debug_border_char),
+                    left: border.left.map(debug_border_char),
+                    right: border.right.map(debug_border_char),
+                    left_top_corner: border.left_top_corner.map(debug_border_char),
+                    right_top_corner: border.right_top_corner.map(debug_border_char),
+                    left_bottom_corner: border.left_bottom_corner.map(debug_border_char),
+                    right_bottom_corner: border.right_bottom_corner.map(debug_border_char),
+                };
+
+                grid.set(
+                    &Entity::Cell(row, column),
+                    Settings::new()
+                        .fill(DEBUG_FILL_CHAR)
+                        .border(border)
+                        .border_restriction(false),
+                );
+            }
+        }
+
+        let separator = grid
+            .get_inner_vertical_separator()
+            .map(|separator| separator.replace(' ', &DEBUG_MARGIN_CHAR.to_string()));
+        if let Some(separator) = separator {
+            grid.set_inner_vertical_separator(separator);
+        }
+
+        grid.to_string()
+    }
+
+    /// Builds a string representation of the table
+    /// checking that it was configured correctly.
+    ///
+    /// Unlike `to_string()` it returns an [Error] for a span which goes beyond
+    /// the last column or row instead of panicking,
+    /// as well as for cells out of the table which were targeted by [Modify]
+    /// and ignored.
+    /// Other settings are not checked as they are adjusted while rendering,
+    /// e.g. a fixed width smaller than an indent of a cell is widened.
+    ///
+    /// ```rust
+    /// use tabled::{Alignment, Cell, Error, Modify, Span, Table};
+    ///
+    /// let table = Table::new(&["Hello", "World"]).with(Modify::new(Cell(0, 0)).with(Span::column(2)));
+    ///
+    /// assert_eq!(
+    ///     table.try_build(),
+    ///     Err(Error::SpanOutOfRange { row: 0, column: 0, span: 2 })
+    /// );
+    ///
+    /// let table = Table::new(&["Hello", "World"]).with(Modify::new(Cell(5, 0)).with(Alignment::left()));
+    ///
+    /// assert_eq!(table.try_build(), Err(Error::OutOfRange { row: 5, column: 0 }));
+    /// ```
+    pub fn try_build(&self) -> Result<String, Error> {
+        if let Some(error) = self.errors.first() {
+            return Err(error.clone());
+        }
+
+        validate_spans(&self.grid)?;
+        Ok(self.grid.to_string())
+    }
+
+    /// Combines cells of 2 tables on the same positions via a function.
+    ///
+    /// It's supposed to be used with tables of the same shape,
+    /// otherwise only cells which are present in both tables are changed.
+    ///
+    /// ```rust
+    /// use tabled::{Style, Table};
+    ///
+    /// let before = Table::new(&[10, 20]);
+    /// let after = Table::new(&[12, 15]);
+    ///
+    /// let table = after
+    ///     .zip_with(&before, |a, b| match (a.parse::<i32>(), b.parse::<i32>()) {
+    ///         (Ok(a), Ok(b)) => format!("{} ({:+})", a, a - b),
+    ///         _ => a.to_owned(),
+    ///     })
+    ///     .with(Style::psql());
+    ///
+    /// assert_eq!(
+    ///     table.to_string(),
+    ///     concat!(
+    ///         "   i32   \n",
+    ///         "---------\n",
+    ///         " 12 (+2) \n",
+    ///         " 15 (-5) \n",
+    ///     )
+    /// );
+    /// ```
+    pub fn zip_with<F>(mut self, other: &Table, mut f: F) -> Self
+    where
+        F: FnMut(&str, &str) -> String,
+    {
+        let count_rows = std::cmp::min(self.grid.count_rows(), other.grid.count_rows());
+        let count_columns = std::cmp::min(self.grid.count_columns(), other.grid.count_columns());
+        for row in 0..count_rows {
+            for column in 0..count_columns {
+                let content = f(
+                    self.grid.get_cell_content(row, column),
+                    other.grid.get_cell_content(row, column),
+                );
+
+                self.grid
+                    .set(&Entity::Cell(row, column), Settings::new().text(content));
+            }
+        }
+
+        self
+    }
+
+    /// Splits the table into pages with a given number of rows after a header.
+    ///
+    /// Each page repeats the header rows and keeps a styling of the table,
+    /// so the pages can be printed one by one or piped to a pager.
+    /// Columns of all pages have the widths of the whole table.
+    ///
+    /// A table without rows after a header makes a single page.
+    ///
+    /// ```rust
+    /// use tabled::{Style, Table};
+    ///
+    /// let table = Table::new([1, 2, 3]).with(Style::psql());
+    /// let pages = table.paginate(2).map(|page| page.to_string()).collect::<Vec<_>>();
+    ///
+    /// assert_eq!(
+    ///     pages,
+    ///     [
+    ///         " i32 \n-----\n  1  \n  2  \n",
+    ///         " i32 \n-----\n  3  \n",
+    ///     ]
+    /// );
+    /// ```
+    pub fn paginate(&self, rows_per_page: usize) -> impl Iterator<Item = Table> {
+        let rows_per_page = std::cmp::max(rows_per_page, 1);
+        let grid = self.grid.clone();
+        let header_rows = grid.count_header_rows();
+        let count_rows = grid.count_rows().saturating_sub(header_rows);
+        // usize::div_ceil needs Rust 1.73
+        #[allow(clippy::manual_div_ceil)]
+        let count_pages = std::cmp::max((count_rows + rows_per_page - 1) / rows_per_page, 1);
+        let widths = grid.columns_widths();
+        let metas = self.metas.clone();
+
+        (0..count_pages).map(move |page| {
+            let start = header_rows + page * rows_per_page;
+            let end = std::cmp::min(start + rows_per_page, grid.count_rows());
+            let mut page = build_page(&grid, header_rows, start..end);
+            for (column, &width) in widths.iter().enumerate() {
+                page.set_column_min_width(column, width);
+            }
+
+            Table {
+                grid: page,
+                metas: metas.clone(),
+                errors: Vec::new(),
+            }
+        })
+    }
+
     /// With is a generic function which applies options to the [Table].
     ///
     /// It applies settings immediately.
@@ -73,16 +500,84 @@ impl Table {
         O: TableOption,
     {
         option.change(&mut self.grid);
+        self.errors.extend(option.take_errors());
         self
     }
 }
 
+/// Builds a grid out of header rows and a given range of rows.
+///
+/// Settings of the rows are kept,
+/// while borders are taken by a position in the page,
+/// so the last row of a page gets the bottom border of the table.
+pub(crate) fn build_page(grid: &Grid, header_rows: usize, rows: std::ops::Range<usize>) -> Grid {
+    let count_columns = grid.count_columns();
+    let last_row = grid.count_rows().saturating_sub(1);
+    let rows = (0..header_rows).chain(rows).collect::<Vec<_>>();
+    let columns = (0..count_columns).collect::<Vec<_>>();
+
+    let mut page = grid.extract_lines(&rows, &columns);
+    page.clear_split_grid();
+    let count_page_rows = page.count_rows();
+    for row in 0..count_page_rows {
+        for column in 0..count_columns {
+            let mut border = grid.get_border(row, column);
+            if row + 1 == count_page_rows {
+                let last = grid.get_border(last_row, column);
+                border.bottom = last.bottom;
+                border.left_bottom_corner = last.left_bottom_corner;
+                border.right_bottom_corner = last.right_bottom_corner;
+            }
+
+            page.set(
+                &Entity::Cell(row, column),
+                Settings::new().border(border).border_restriction(false),
+            );
+        }
+    }
+
+    page.set_header_rows(header_rows);
+    page
+}
+
+pub(crate) fn find_origin(grid: &Grid, origin: (usize, usize)) -> Option<(usize, usize)> {
+    (0..grid.count_rows())
+        .flat_map(|row| (0..grid.count_columns()).map(move |column| (row, column)))
+        .find(|&(row, column)| grid.get_origin(row, column) == Some(origin))
+}
+
 impl fmt::Display for Table {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(f, "{}", self.grid)
     }
 }
 
+/// A table is represented as a single cell of its rendered text,
+/// so tables can be nested into each other.
+///
+/// ```rust
+/// use tabled::Table;
+///
+/// let left = Table::new([1, 2]);
+/// let right = Table::new(["a"]);
+///
+/// println!("{}", Table::new([(left, right)]));
+/// ```
+impl Tabled for Table {
+    const LENGTH: usize = 1;
+
+    fn fields(&self) -> Vec<String> {
+        // a rendered table ends with a new line which is not a part of the content
+        let text = self.to_string();
+        let text = text.strip_suffix('\n').unwrap_or(&text);
+        vec![text.to_owned()]
+    }
+
+    fn headers() -> Vec<String> {
+        vec!["Table".to_owned()]
+    }
+}
+
 impl<D> FromIterator<D> for Table
 where
     D: Tabled,
@@ -91,16 +586,53 @@ where
     where
         T: IntoIterator<Item = D>,
     {
-        let rows = iter.into_iter().map(|t| t.fields());
-        Builder::from_iter(rows).set_header(D::headers()).build()
+        from_records(iter, true)
     }
 }
 
+fn from_records<T: Tabled>(iter: impl IntoIterator<Item = T>, with_header: bool) -> Table {
+    let rows = iter
+        .into_iter()
+        .map(|t| {
+            let fields = t.fields();
+            debug_assert_length::<T>("fields", fields.len());
+            fields
+        })
+        .collect::<Vec<_>>();
+    let count_columns = rows.iter().map(Vec::len).max().unwrap_or(0);
+    // rows are moved into a builder as they are, so fields are not copied
+    let mut builder = Builder::from(rows);
+    if with_header {
+        let mut headers = T::headers();
+        debug_assert_length::<T>("headers", headers.len());
+        // a type which length is not known at compile time has no headers
+        if headers.is_empty() {
+            headers = (0..count_columns).map(|i| i.to_string()).collect();
+        }
+
+        builder = builder.set_header(headers);
+    }
+
+    for (column, alignment) in T::alignments().into_iter().enumerate() {
+        if let Some(alignment) = alignment {
+            builder = builder.column_alignment(column, alignment);
+        }
+    }
+
+    let mut table = builder.build();
+    if !with_header {
+        table.grid.set_header_rows(0);
+    }
+
+    table
+}
+
 /// Modify structure provide an abstraction, to be able to apply
 /// a set of [CellOption]s to the same object.
 pub struct Modify<O> {
     obj: O,
     modifiers: Vec<Box<dyn CellOption>>,
+    rejected: Vec<(usize, usize)>,
 }
 
 impl<O> Modify<O>
@@ -112,6 +644,7 @@ where
         Self {
             obj,
             modifiers: Vec::new(),
+            rejected: Vec::new(),
         }
     }
 
@@ -134,13 +667,65 @@ where
     O: Object,
 {
     fn change(&mut self, grid: &mut Grid) {
-        let cells = self.obj.cells(grid.count_rows(), grid.count_columns());
+        let count_rows = grid.count_rows();
+        let count_columns = grid.count_columns();
+
+        // cells which are out of the grid are ignored and reported by [Table::try_build]
+        let (cells, rejected) = self
+            .obj
+            .grid_cells(grid)
+            .into_iter()
+            .partition::<Vec<_>, _>(|&(row, column)| row < count_rows && column < count_columns);
+        self.rejected = rejected;
+
         for func in &mut self.modifiers {
             for &(row, column) in &cells {
                 func.change_cell(grid, row, column)
             }
         }
     }
+
+    fn take_errors(&mut self) -> Vec<Error> {
+        std::mem::take(&mut self.rejected)
+            .into_iter()
+            .map(|(row, column)| Error::OutOfRange { row, column })
+            .collect()
+    }
+}
+
+const DEBUG_FILL_CHAR: char = '·';
+
+const DEBUG_MARGIN_CHAR: char = '░';
+
+fn debug_border_char(c: char) -> char {
+    if c == ' ' {
+        DEBUG_MARGIN_CHAR
+    } else {
+        c
+    }
+}
+
+fn validate_spans(grid: &Grid) -> Result<(), Error> {
+    for row in 0..grid.count_rows() {
+        let mut column = 0;
+        while column < grid.count_columns() {
+            let style = grid.style(&Entity::Cell(row, column));
+            let span = style.span;
+            if column + span > grid.count_columns() {
+                return Err(Error::SpanOutOfRange { row, column, span });
+            }
+
+            let span = style.row_span;
+            if row + span > grid.count_rows() {
+                return Err(Error::RowSpanOutOfRange { row, column, span });
+            }
+
+            // cells overlapped by a span are not checked
+            column += span.max(1);
+        }
+    }
+
+    Ok(())
 }
 
 /// A trait for [IntoIterator] whose Item type is bound to [Tabled].
@@ -2,8 +2,15 @@
 //!
 //! - [Truncate] cuts a cell content to limit width.
 //! - [Wrap] split the content via new lines in order to fit max width.
+//!
+//! Being used as a [TableOption] they limit a total width of a table instead.
+//! - [BudgetWidth] distributes a total width of a table across its columns.
+//! - [TotalWidth] shrinks columns of a table proportionally to fit a total width.
+//! - [TerminalWidth] makes a table fit a width of a terminal.
+
+use std::{borrow::Cow, collections::HashMap};
 
-use crate::CellOption;
+use crate::{CellOption, TableOption};
 use papergrid::{string_width, Entity, Grid, Settings};
 
 /// MaxWidth allows you to set a max width of an object on a [Grid],
@@ -11,6 +18,9 @@ use papergrid::{string_width, Entity, Grid, Settings};
 ///
 /// It is an abstract factory.
 ///
+/// Used via [crate::Modify] it limits a width of each cell.
+/// Used on a [crate::Table] directly it limits a width of the whole table, borders included.
+///
 /// ## Example
 ///
 /// ```
@@ -22,6 +32,29 @@ use papergrid::{string_width, Entity, Grid, Settings};
 ///     .with(Style::github_markdown())
 ///     .with(Modify::new(Full).with(MaxWidth::truncating(3).suffix("...")));
 /// ```
+///
+/// ```
+/// use tabled::{MaxWidth, Style, Table};
+///
+/// let data = [("Hello", "World"), ("!", "?")];
+///
+/// let table = Table::new(&data)
+///     .with(Style::ascii())
+///     .with(MaxWidth::truncating(13));
+///
+/// assert_eq!(
+///     table.to_string(),
+///     concat!(
+///         "+-----+-----+\n",
+///         "| &st | &st |\n",
+///         "+-----+-----+\n",
+///         "| Hel | Wor |\n",
+///         "+-----+-----+\n",
+///         "|  !  |  ?  |\n",
+///         "+-----+-----+\n",
+///     )
+/// );
+/// ```
 pub struct MaxWidth;
 
 impl MaxWidth {
@@ -52,12 +85,19 @@ impl MaxWidth {
 pub struct Truncate<S> {
     width: usize,
     suffix: S,
+    #[cfg(feature = "regex")]
+    keep: Option<regex::Regex>,
 }
 
 impl Truncate<&'static str> {
     /// Creates a [Truncate] object
     pub fn new(width: usize) -> Self {
-        Self { width, suffix: "" }
+        Self {
+            width,
+            suffix: "",
+            #[cfg(feature = "regex")]
+            keep: None,
+        }
     }
 }
 
@@ -68,8 +108,34 @@ impl<T> Truncate<T> {
         Truncate {
             width: self.width,
             suffix,
+            #[cfg(feature = "regex")]
+            keep: self.keep,
         }
     }
+
+    /// Sets a pattern of an important part of the content which must be kept
+    /// while truncating; the rest of the content is cut around it.
+    ///
+    /// A suffix, if set, is placed where the content was cut.
+    ///
+    /// It's available only with a `regex` feature on.
+    ///
+    /// ```
+    /// use regex::Regex;
+    /// use tabled::{Full, Modify, Style, Table, Truncate};
+    ///
+    /// let extension = Regex::new(r"\.[a-z]+$").unwrap();
+    /// let table = Table::new(&["a_long_file_name.txt"])
+    ///     .with(Style::psql())
+    ///     .with(Modify::new(Full).with(Truncate::new(10).suffix("..").keeping(extension)));
+    ///
+    /// assert_eq!(table.to_string(), "     &str     \n--------------\n a_long...txt \n");
+    /// ```
+    #[cfg(feature = "regex")]
+    pub fn keeping(mut self, regex: regex::Regex) -> Self {
+        self.keep = Some(regex);
+        self
+    }
 }
 
 impl<S> CellOption for Truncate<S>
@@ -78,6 +144,21 @@ where
 {
     fn change_cell(&mut self, grid: &mut Grid, row: usize, column: usize) {
         let content = grid.get_cell_content(row, column);
+
+        #[cfg(feature = "regex")]
+        if let Some(regex) = &self.keep {
+            if let Some(new_content) =
+                strip_keeping(content, self.width, regex, self.suffix.as_ref())
+            {
+                grid.set(
+                    &Entity::Cell(row, column),
+                    Settings::new().text(new_content),
+                );
+            }
+
+            return;
+        }
+
         let striped_content = strip(content, self.width);
         if striped_content.len() < content.len() {
             let new_content = format!("{}{}", striped_content, self.suffix.as_ref());
@@ -89,6 +170,77 @@ where
     }
 }
 
+/// Truncate limits a total width of a table.
+///
+/// The widest columns are shrunk first, and cells which don't fit
+/// their column anymore are truncated with a suffix counted in the width.
+/// Cells which are spanned over a few columns are not changed.
+impl<S> TableOption for Truncate<S>
+where
+    S: AsRef<str>,
+{
+    fn change(&mut self, grid: &mut Grid) {
+        let suffix = self.suffix.as_ref();
+        #[cfg(feature = "regex")]
+        let keep = self.keep.as_ref();
+
+        let change_line = |line: &str, width: usize| {
+            let suffix_width = string_width(suffix);
+            if suffix_width >= width {
+                return strip(line, width).into_owned();
+            }
+
+            let width = width - suffix_width;
+
+            #[cfg(feature = "regex")]
+            if let Some(regex) = keep {
+                return strip_keeping(line, width, regex, suffix)
+                    .unwrap_or_else(|| line.to_owned());
+            }
+
+            format!("{}{}", strip(line, width), suffix)
+        };
+
+        shrink_table(grid, self.width, Order::Widest, default_limits, change_line);
+    }
+}
+
+/// Truncates a string preserving a part which matches the regex.
+///
+/// The text before the matched part is cut first, and then the text after it.
+/// If the matched part itself doesn't fit it's truncated as an ordinary string.
+///
+/// Returns [None] if the string already fits the width.
+#[cfg(feature = "regex")]
+fn strip_keeping(s: &str, width: usize, regex: &regex::Regex, suffix: &str) -> Option<String> {
+    if string_width(s) <= width {
+        return None;
+    }
+
+    let found = match regex.find(s) {
+        Some(found) => found,
+        None => return Some(format!("{}{}", strip(s, width), suffix)),
+    };
+
+    let keep = found.as_str();
+    let keep_width = string_width(keep);
+    if keep_width >= width {
+        return Some(format!("{}{}", strip(keep, width), suffix));
+    }
+
+    let prefix = &s[..found.start()];
+    let postfix = &s[found.end()..];
+    let rest_width = width - keep_width;
+    let prefix_width = string_width(prefix);
+    if prefix_width > rest_width {
+        let prefix = strip(prefix, rest_width);
+        return Some(format!("{}{}{}", prefix, suffix, keep));
+    }
+
+    let postfix = strip(postfix, rest_width - prefix_width);
+    Some(format!("{}{}{}{}", prefix, keep, postfix, suffix))
+}
+
 /// Wrap wraps a string to a new line in case it exeeds the provided max boundry.
 /// Otherwise keeps the content of a cell untouched.
 ///
@@ -134,53 +286,195 @@ impl CellOption for Wrap {
         } else {
             split_keeping_words(content, self.width)
         };
-        grid.set(
-            &Entity::Cell(row, column),
-            Settings::new().text(wrapped_content),
-        )
+
+        // we don't touch a cell which content is left the same
+        if let Cow::Owned(wrapped_content) = wrapped_content {
+            grid.set(
+                &Entity::Cell(row, column),
+                Settings::new().text(wrapped_content),
+            )
+        }
     }
 }
 
-pub(crate) fn strip(s: &str, width: usize) -> String {
+/// Wrap limits a total width of a table.
+///
+/// The widest columns are shrunk first, and cells which don't fit
+/// their column anymore are wrapped.
+/// Cells which are spanned over a few columns are not changed.
+impl TableOption for Wrap {
+    fn change(&mut self, grid: &mut Grid) {
+        let keep_words = self.keep_words;
+        let change_line = |line: &str, width: usize| {
+            let wrapped = if keep_words {
+                split_keeping_words(line, width)
+            } else {
+                split(line, width)
+            };
+
+            wrapped.trim_end_matches('\n').to_owned()
+        };
+
+        shrink_table(grid, self.width, Order::Widest, default_limits, change_line);
+    }
+}
+
+/// Shrinks columns so a table would fit the width
+/// and changes lines of cells which don't fit their column anymore.
+///
+/// Limits of a column are given its index and width.
+fn shrink_table<L, F>(grid: &mut Grid, width: usize, order: Order, limits: L, change_line: F)
+where
+    L: Fn(usize, usize) -> ColumnLimits,
+    F: FnMut(&str, usize) -> String,
+{
+    let count_columns = grid.count_columns();
+    if grid.count_rows() == 0 || count_columns == 0 {
+        return;
+    }
+
+    let widths = grid.columns_widths();
+    let total_width = widths.iter().sum::<usize>() + borders_width(grid);
+    if total_width <= width {
+        return;
+    }
+
+    let available_width = width.saturating_sub(borders_width(grid));
+    let limits = (0..count_columns)
+        .map(|column| {
+            let mut limits = limits(column, widths[column]);
+            limits.min = std::cmp::max(limits.min, min_column_width(grid, column, widths[column]));
+            limits
+        })
+        .collect::<Vec<_>>();
+
+    let new_widths = distribute_width(&widths, &limits, available_width, order);
+    change_columns(grid, &widths, &new_widths, change_line);
+}
+
+/// Sets new widths of columns changing lines of cells which don't fit them.
+fn change_columns<F>(grid: &mut Grid, widths: &[usize], new_widths: &[usize], mut change_line: F)
+where
+    F: FnMut(&str, usize) -> String,
+{
+    for (column, &width) in new_widths.iter().enumerate() {
+        if width == widths[column] {
+            continue;
+        }
+
+        for row in 0..grid.count_rows() {
+            let style = grid.style(&Entity::Cell(row, column));
+            if style.span != 1 {
+                continue;
+            }
+
+            let indent = style.indent.left + style.indent.right;
+            let content_width = std::cmp::max(width.saturating_sub(indent), 1);
+            let content = grid.get_cell_content(row, column);
+            if string_width(content) <= content_width {
+                continue;
+            }
+
+            let new_content = content
+                .lines()
+                .map(|line| {
+                    if string_width(line) > content_width {
+                        change_line(line, content_width)
+                    } else {
+                        line.to_owned()
+                    }
+                })
+                .collect::<Vec<_>>()
+                .join("\n");
+
+            grid.set(
+                &Entity::Cell(row, column),
+                Settings::new().text(new_content),
+            );
+        }
+
+        if grid.get_column_min_width(column) > width {
+            grid.set_column_min_width(column, width);
+        }
+    }
+}
+
+/// Cuts a string to a given width.
+///
+/// A string is borrowed in case no changes were made.
+pub(crate) fn strip(s: &str, width: usize) -> Cow<'_, str> {
     #[cfg(not(feature = "color"))]
     {
-        s.chars().take(width).collect::<String>()
+        match s.char_indices().nth(width) {
+            Some((i, _)) => Cow::Borrowed(&s[..i]),
+            None => Cow::Borrowed(s),
+        }
     }
     #[cfg(feature = "color")]
     {
         let width = to_byte_length(s, width);
-        ansi_str::AnsiStr::ansi_cut(s, ..width)
+        if width == s.len() {
+            Cow::Borrowed(s)
+        } else {
+            Cow::Owned(ansi_str::AnsiStr::ansi_cut(s, ..width))
+        }
     }
 }
 
-pub(crate) fn split(s: &str, width: usize) -> String {
+/// Splits a string by new lines so each line would fit the width.
+///
+/// Existing lines are split independently.
+/// A string is borrowed in case no changes were made.
+pub(crate) fn split(s: &str, width: usize) -> Cow<'_, str> {
+    if s.split('\n').all(|line| is_fit_width(line, width)) {
+        return Cow::Borrowed(s);
+    }
+
     #[cfg(not(feature = "color"))]
     {
-        s.chars()
-            .enumerate()
-            .flat_map(|(i, c)| {
-                if i != 0 && i % width == 0 {
-                    Some('\n')
-                } else {
-                    None
+        let mut buf = String::with_capacity(s.len());
+        let mut line_length = 0;
+        for c in s.chars() {
+            if c == '\n' {
+                line_length = 0;
+            } else {
+                if line_length != 0 && line_length % width == 0 {
+                    buf.push('\n');
                 }
-                .into_iter()
-                .chain(std::iter::once(c))
-            })
-            .collect::<String>()
+
+                line_length += 1;
+            }
+
+            buf.push(c);
+        }
+
+        Cow::Owned(buf)
     }
     #[cfg(feature = "color")]
     {
         if width == 0 {
-            s.to_string()
+            Cow::Borrowed(s)
         } else {
-            chunks(s, width).join("\n")
+            let lines = ansi_str::AnsiStr::ansi_split(s, "\n")
+                .map(|line| chunks(&line, width).join("\n"))
+                .collect::<Vec<_>>();
+
+            Cow::Owned(lines.join("\n"))
         }
     }
 }
 
+/// Checks whether a string has not more chars than the width.
+fn is_fit_width(s: &str, width: usize) -> bool {
+    s.chars().nth(width).is_none()
+}
+
 #[cfg(not(feature = "color"))]
-fn split_keeping_words(s: &str, width: usize) -> String {
+pub(crate) fn split_keeping_words(s: &str, width: usize) -> Cow<'_, str> {
+    if is_fit_width(s, width) {
+        return Cow::Borrowed(s);
+    }
+
     let mut buf = String::new();
     let mut i = 0;
     for c in s.chars() {
@@ -228,13 +522,17 @@ fn split_keeping_words(s: &str, width: usize) -> String {
         i += 1;
     }
 
-    buf
+    Cow::Owned(buf)
 }
 
 #[cfg(feature = "color")]
-fn split_keeping_words(s: &str, width: usize) -> String {
+pub(crate) fn split_keeping_words(s: &str, width: usize) -> Cow<'_, str> {
     use ansi_str::AnsiStr;
 
+    if is_fit_width(s, width) {
+        return Cow::Borrowed(s);
+    }
+
     let mut buf = String::new();
     let mut s = s.to_string();
     while !s.is_empty() {
@@ -296,7 +594,7 @@ fn split_keeping_words(s: &str, width: usize) -> String {
         s = rhs;
     }
 
-    buf
+    Cow::Owned(buf)
 }
 
 #[cfg(feature = "color")]
@@ -320,10 +618,11 @@ fn chunks(s: &str, width: usize) -> Vec<String> {
     v
 }
 
-/// MinWidth changes a content in case if it's length is lower then the boundry.
+/// MinWidth sets a minimum width of a cell.
 ///
-/// It does anything in case if the content's length is bigger then the boundry.
-/// It doesn't include a [crate::Indent] settings.
+/// The width includes an [crate::Indent] of the cell,
+/// and it's honored by spanned cells as well.
+/// It doesn't change a content of a cell, a space is added as part of an alignment.
 ///
 /// ## Example
 ///
@@ -335,73 +634,560 @@ fn chunks(s: &str, width: usize) -> Vec<String> {
 /// let table = Table::new(&data)
 ///     .with(Style::github_markdown())
 ///     .with(Modify::new(Full).with(MinWidth::new(10)));
+///
+/// assert_eq!(
+///     table.to_string(),
+///     concat!(
+///         "|   &str   |\n",
+///         "|----------|\n",
+///         "|  Hello   |\n",
+///         "|  World   |\n",
+///         "|    !     |\n",
+///     )
+/// );
 /// ```
 pub struct MinWidth {
     size: usize,
-    fill: char,
+    fill: Option<char>,
 }
 
 impl MinWidth {
     /// Creates a new instance of MinWidth.
     pub fn new(size: usize) -> Self {
-        Self { size, fill: ' ' }
+        Self { size, fill: None }
     }
 
     /// Set's a fill character which will be used to fill the space
-    /// when increasing the length of the string to the set boundry.
+    /// when increasing the width of a cell to the set boundry.
+    ///
+    /// It's used for an indent of the cell as well.
     pub fn fill_with(mut self, c: char) -> Self {
-        self.fill = c;
+        self.fill = Some(c);
         self
     }
 }
 
 impl CellOption for MinWidth {
     fn change_cell(&mut self, grid: &mut Grid, row: usize, column: usize) {
-        let content = grid.get_cell_content(row, column);
-        let new_content = increase_width(content, self.size, self.fill);
-        grid.set(
-            &Entity::Cell(row, column),
-            Settings::new().text(new_content),
-        )
+        let mut settings = Settings::new().min_width(self.size);
+        if let Some(fill) = self.fill {
+            settings = settings.fill(fill);
+        }
+
+        grid.set(&Entity::Cell(row, column), settings);
     }
 }
 
-fn increase_width(s: &str, width: usize, fill_with: char) -> String {
-    let has_big_lines = s.lines().any(|line| string_width(line) < width);
-    if !has_big_lines {
-        return s.to_owned();
+/// Width is a factory of table-level width options.
+///
+/// ## Example
+///
+/// ```
+/// use tabled::{Constraint, Style, Table, Width};
+///
+/// let table = Table::new(&["Hello World"])
+///     .with(Style::ascii())
+///     .with(Width::budget(10).column(0, Constraint::Min(5)));
+///
+/// assert_eq!(
+///     table.to_string(),
+///     "+--------+\n\
+///      |  &str  |\n\
+///      +--------+\n\
+///      | Hello  |\n\
+///      | World  |\n\
+///      +--------+\n"
+/// );
+/// ```
+pub struct Width;
+
+impl Width {
+    /// Returns a [BudgetWidth] object.
+    pub fn budget(width: usize) -> BudgetWidth {
+        BudgetWidth::new(width)
     }
 
-    #[cfg(not(feature = "color"))]
-    {
-        s.lines()
-            .map(|line| {
-                let length = string_width(line);
-                if length < width {
-                    let remain = width - length;
-                    let mut new_line = String::with_capacity(width);
-                    new_line.push_str(line);
-                    new_line.extend(std::iter::repeat(fill_with).take(remain));
-                    std::borrow::Cow::Owned(new_line)
+    /// Returns a [TotalWidth] object.
+    pub fn total(width: usize) -> TotalWidth {
+        TotalWidth::new(width)
+    }
+
+    /// Returns a [TerminalWidth] object.
+    ///
+    /// It's available only with a `terminal` feature on.
+    #[cfg(feature = "terminal")]
+    pub fn fit_terminal() -> TerminalWidth {
+        TerminalWidth::new()
+    }
+}
+
+/// Constraint is a limit of a column width used by [BudgetWidth].
+///
+/// A width of a column includes an indent but not borders.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Constraint {
+    /// A minimum width of a column.
+    Min(usize),
+    /// A maximum width of a column.
+    Max(usize),
+    /// A weight of a column which defines a share of a width
+    /// a column gets or gives away compared to others.
+    ///
+    /// By default each column has a weight equal to 1.
+    /// A column with 0 weight keeps the width of its content.
+    Weight(usize),
+}
+
+/// BudgetWidth makes a [crate::Table] to have a given total width, borders included.
+///
+/// Each column starts from a width of its content.
+/// Then a lacking or an extra space is distributed across columns
+/// according to their weights and min/max [Constraint]s.
+/// A content which doesn't fit a column is wrapped.
+///
+/// Cells which are spanned over a few columns are not changed.
+#[derive(Debug)]
+pub struct BudgetWidth {
+    width: usize,
+    columns: HashMap<usize, ColumnLimits>,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct ColumnLimits {
+    min: usize,
+    max: usize,
+    weight: usize,
+    priority: usize,
+}
+
+impl Default for ColumnLimits {
+    fn default() -> Self {
+        Self {
+            min: 0,
+            max: usize::MAX,
+            weight: 1,
+            priority: 0,
+        }
+    }
+}
+
+fn default_limits(_: usize, _: usize) -> ColumnLimits {
+    ColumnLimits::default()
+}
+
+/// A way [distribute_width] picks a next column to change.
+#[derive(Debug, Clone, Copy)]
+enum Order {
+    /// A column with the least change relative to its weight.
+    Weighted,
+    /// The widest column, the leftmost one among equal.
+    Widest,
+}
+
+impl BudgetWidth {
+    /// Creates a [BudgetWidth] object with a given total width.
+    pub fn new(width: usize) -> Self {
+        Self {
+            width,
+            columns: HashMap::new(),
+        }
+    }
+
+    /// Sets a [Constraint] for a column.
+    pub fn column(mut self, column: usize, constraint: Constraint) -> Self {
+        let limits = self.columns.entry(column).or_default();
+        match constraint {
+            Constraint::Min(width) => limits.min = width,
+            Constraint::Max(width) => limits.max = width,
+            Constraint::Weight(weight) => limits.weight = weight,
+        }
+
+        self
+    }
+}
+
+impl TableOption for BudgetWidth {
+    fn change(&mut self, grid: &mut Grid) {
+        let count_columns = grid.count_columns();
+        if grid.count_rows() == 0 || count_columns == 0 {
+            return;
+        }
+
+        let available_width = self.width.saturating_sub(borders_width(grid));
+
+        let widths = (0..count_columns)
+            .map(|column| column_width(grid, column))
+            .collect::<Vec<_>>();
+
+        let limits = (0..count_columns)
+            .map(|column| {
+                let mut limits = self.columns.get(&column).copied().unwrap_or_default();
+                let min = min_column_width(grid, column, widths[column]);
+                limits.min = std::cmp::max(limits.min, min);
+                limits.max = std::cmp::max(limits.max, limits.min);
+                limits
+            })
+            .collect::<Vec<_>>();
+
+        let widths = distribute_width(&widths, &limits, available_width, Order::Weighted);
+
+        for (column, &width) in widths.iter().enumerate() {
+            set_column_width(grid, column, width);
+        }
+    }
+}
+
+/// TerminalWidth makes a [crate::Table] to have a width of a terminal, borders included.
+///
+/// The width is taken at the moment the option is applied.
+/// Columns are shrunk or expanded the same way [BudgetWidth] does it,
+/// so a content which doesn't fit a column is wrapped.
+///
+/// If the standard output is not a terminal a default width is used,
+/// and if there's none the table is not changed.
+///
+/// It's available only with a `terminal` feature on.
+///
+/// ## Example
+///
+/// ```
+/// use tabled::{Constraint, Table, Width};
+///
+/// let table = Table::new(&[("Hello", "World")])
+///     .with(Width::fit_terminal().column(0, Constraint::Min(10)).default_width(80));
+/// ```
+#[cfg(feature = "terminal")]
+#[derive(Debug)]
+pub struct TerminalWidth {
+    budget: BudgetWidth,
+    default_width: Option<usize>,
+}
+
+#[cfg(feature = "terminal")]
+impl TerminalWidth {
+    /// Creates a [TerminalWidth] object.
+    pub fn new() -> Self {
+        Self {
+            budget: BudgetWidth::new(0),
+            default_width: None,
+        }
+    }
+
+    /// Sets a [Constraint] for a column.
+    pub fn column(mut self, column: usize, constraint: Constraint) -> Self {
+        self.budget = self.budget.column(column, constraint);
+        self
+    }
+
+    /// Sets a width which is used if the standard output is not a terminal.
+    pub fn default_width(mut self, width: usize) -> Self {
+        self.default_width = Some(width);
+        self
+    }
+}
+
+#[cfg(feature = "terminal")]
+impl Default for TerminalWidth {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(feature = "terminal")]
+impl TableOption for TerminalWidth {
+    fn change(&mut self, grid: &mut Grid) {
+        let width = terminal_size::terminal_size()
+            .map(|(terminal_size::Width(width), _)| width as usize)
+            .or(self.default_width);
+
+        if let Some(width) = width {
+            self.budget.width = width;
+            self.budget.change(grid);
+        }
+    }
+}
+
+/// TotalWidth limits a total width of a [crate::Table], borders included.
+///
+/// Unlike [Wrap] and [Truncate] used on a table it doesn't shrink only the widest columns.
+/// A lacking space is taken from columns proportionally to their widths,
+/// starting from columns with the lowest priority.
+/// Only cells which don't fit their column anymore are wrapped or truncated.
+///
+/// A table which already fits the width is not changed.
+/// Cells which are spanned over a few columns are not changed.
+///
+/// ## Example
+///
+/// ```
+/// use tabled::{Style, Table, Width};
+///
+/// let data = [("Hello World", "!")];
+///
+/// let table = Table::new(&data)
+///     .with(Style::ascii())
+///     .with(Width::total(15).priority(1, 1));
+///
+/// assert_eq!(
+///     table.to_string(),
+///     concat!(
+///         "+------+------+\n",
+///         "| &str | &str |\n",
+///         "+------+------+\n",
+///         "| Hell |  !   |\n",
+///         "| o Wo |      |\n",
+///         "| rld  |      |\n",
+///         "+------+------+\n",
+///     )
+/// );
+/// ```
+#[derive(Debug)]
+pub struct TotalWidth {
+    width: usize,
+    priorities: HashMap<usize, usize>,
+    truncate: Option<String>,
+    keep_words: bool,
+}
+
+impl TotalWidth {
+    /// Creates a [TotalWidth] object with a given total width.
+    pub fn new(width: usize) -> Self {
+        Self {
+            width,
+            priorities: HashMap::new(),
+            truncate: None,
+            keep_words: false,
+        }
+    }
+
+    /// Sets a priority of a column.
+    ///
+    /// Columns with a higher priority are shrunk only
+    /// if columns with a lower one can't be shrunk anymore.
+    /// By default each column has a priority equal to 0.
+    pub fn priority(mut self, column: usize, priority: usize) -> Self {
+        self.priorities.insert(column, priority);
+        self
+    }
+
+    /// Truncates cells which don't fit their column instead of wrapping them.
+    pub fn truncate(self) -> Self {
+        self.suffix("")
+    }
+
+    /// Truncates cells which don't fit their column adding a suffix to them.
+    ///
+    /// The suffix is counted in the width.
+    pub fn suffix<S: Into<String>>(mut self, suffix: S) -> Self {
+        self.truncate = Some(suffix.into());
+        self
+    }
+
+    /// Wraps cells keeping words whole where it's possible.
+    pub fn keep_words(mut self) -> Self {
+        self.keep_words = true;
+        self
+    }
+}
+
+impl TableOption for TotalWidth {
+    fn change(&mut self, grid: &mut Grid) {
+        let priorities = &self.priorities;
+        // a lacking space is taken proportionally to widths of columns
+        let limits = |column, width| ColumnLimits {
+            weight: width,
+            priority: priorities.get(&column).copied().unwrap_or(0),
+            ..ColumnLimits::default()
+        };
+
+        let truncate = self.truncate.as_deref();
+        let keep_words = self.keep_words;
+        let change_line = |line: &str, width: usize| match truncate {
+            Some(suffix) => {
+                let suffix_width = string_width(suffix);
+                if suffix_width >= width {
+                    strip(line, width).into_owned()
                 } else {
-                    std::borrow::Cow::Borrowed(line)
+                    format!("{}{}", strip(line, width - suffix_width), suffix)
                 }
-            })
-            .collect::<String>()
+            }
+            None => {
+                let wrapped = if keep_words {
+                    split_keeping_words(line, width)
+                } else {
+                    split(line, width)
+                };
+
+                wrapped.trim_end_matches('\n').to_owned()
+            }
+        };
+
+        shrink_table(grid, self.width, Order::Weighted, limits, change_line);
     }
-    #[cfg(feature = "color")]
-    {
-        ansi_str::AnsiStr::ansi_split(s, "\n")
-            .map(|mut line| {
-                let length = string_width(&line);
-                if length < width {
-                    let remain = width - length;
-                    line.extend(std::iter::repeat(fill_with).take(remain));
-                    line
+}
+
+/// UseWidths sets widths of columns, e.g. ones taken from another table by [crate::Table::widths].
+///
+/// A content which doesn't fit a column is wrapped.
+/// Cells which are spanned over a few columns are not changed.
+///
+/// ## Example
+///
+/// ```
+/// use tabled::{Table, UseWidths};
+///
+/// let first = Table::new(&[("Hello", "World")]);
+/// let second = Table::new(&[("Hi", "!")]).with(UseWidths(first.widths()));
+///
+/// assert_eq!(first.widths(), second.widths());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UseWidths(pub Vec<usize>);
+
+impl TableOption for UseWidths {
+    fn change(&mut self, grid: &mut Grid) {
+        for (column, &width) in self.0.iter().enumerate().take(grid.count_columns()) {
+            set_column_width(grid, column, width);
+        }
+    }
+}
+
+fn set_column_width(grid: &mut Grid, column: usize, width: usize) {
+    for row in 0..grid.count_rows() {
+        let style = grid.style(&Entity::Cell(row, column));
+        if style.span != 1 {
+            continue;
+        }
+
+        let indent = style.indent.left + style.indent.right;
+        // a width must be at least 1 to be able to split a content
+        let content_width = std::cmp::max(width.saturating_sub(indent), 1);
+        let content = grid.get_cell_content(row, column);
+        if string_width(content) > content_width {
+            let wrapped_content = content
+                .lines()
+                .map(|line| split(line, content_width))
+                .collect::<Vec<_>>()
+                .join("\n");
+
+            grid.set(
+                &Entity::Cell(row, column),
+                Settings::new().text(wrapped_content),
+            );
+        }
+    }
+
+    grid.set_column_min_width(column, width);
+}
+
+fn borders_width(grid: &Grid) -> usize {
+    let count_columns = grid.count_columns();
+    (0..=count_columns)
+        .filter(|&column| grid.has_vertical(column))
+        .map(|column| {
+            let is_inner = column > 0 && column < count_columns;
+            match grid.get_inner_vertical_separator() {
+                Some(separator) if is_inner => string_width(separator),
+                _ => 1,
+            }
+        })
+        .sum()
+}
+
+fn column_width(grid: &Grid, column: usize) -> usize {
+    (0..grid.count_rows())
+        .filter(|&row| grid.style(&Entity::Cell(row, column)).span == 1)
+        .map(|row| {
+            let indent = &grid.style(&Entity::Cell(row, column)).indent;
+            string_width(grid.get_cell_content(row, column)) + indent.left + indent.right
+        })
+        .max()
+        .unwrap_or(0)
+}
+
+/// Returns a width a column can't be shrunk below.
+///
+/// A column must have a space for an indent and at least 1 character,
+/// unless it's already narrower.
+fn min_column_width(grid: &Grid, column: usize, width: usize) -> usize {
+    let indent = (0..grid.count_rows())
+        .map(|row| {
+            let indent = &grid.style(&Entity::Cell(row, column)).indent;
+            indent.left + indent.right
+        })
+        .max()
+        .unwrap_or(0);
+
+    std::cmp::min(indent + 1, width)
+}
+
+/// Changes widths by 1 at a time until they sum up to the total or reach their limits.
+///
+/// Columns with a lower priority are changed first,
+/// among them a next column is picked according to the [Order].
+/// Columns with 0 weight are not changed.
+fn distribute_width(
+    widths: &[usize],
+    limits: &[ColumnLimits],
+    total: usize,
+    order: Order,
+) -> Vec<usize> {
+    let mut widths = widths
+        .iter()
+        .zip(limits)
+        .map(|(&width, limits)| width.clamp(limits.min, limits.max))
+        .collect::<Vec<_>>();
+
+    let current: usize = widths.iter().sum();
+    let is_growing = total > current;
+    let mut diff = if is_growing {
+        total - current
+    } else {
+        current - total
+    };
+
+    let mut changes = vec![0; widths.len()];
+    while diff > 0 {
+        let changeable = (0..widths.len())
+            .filter(|&i| limits[i].weight > 0)
+            .filter(|&i| {
+                if is_growing {
+                    widths[i] < limits[i].max
                 } else {
-                    line
+                    widths[i] > limits[i].min
                 }
             })
-            .collect::<String>()
+            .collect::<Vec<_>>();
+
+        let priority = match changeable.iter().map(|&i| limits[i].priority).min() {
+            Some(priority) => priority,
+            None => break,
+        };
+
+        let candidates = changeable
+            .into_iter()
+            .filter(|&i| limits[i].priority == priority);
+        let next = match order {
+            Order::Weighted => candidates.min_by(|&a, &b| {
+                (changes[a] * limits[b].weight).cmp(&(changes[b] * limits[a].weight))
+            }),
+            Order::Widest => candidates.max_by_key(|&i| (widths[i], std::cmp::Reverse(i))),
+        };
+
+        let i = match next {
+            Some(i) => i,
+            None => break,
+        };
+
+        if is_growing {
+            widths[i] += 1;
+        } else {
+            widths[i] -= 1;
+        }
+
+        changes[i] += 1;
+        diff -= 1;
     }
+
+    widths
 }
@@ -0,0 +1,107 @@
+/// A case style which is used by `#[tabled(rename_all = "...")]`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CaseStyle {
+    Lower,
+    Upper,
+    Pascal,
+    Camel,
+    Snake,
+    ScreamingSnake,
+    Kebab,
+    ScreamingKebab,
+    Title,
+}
+
+impl CaseStyle {
+    pub fn parse(name: &str) -> Self {
+        match name {
+            "lowercase" => Self::Lower,
+            "UPPERCASE" => Self::Upper,
+            "PascalCase" => Self::Pascal,
+            "camelCase" => Self::Camel,
+            "snake_case" => Self::Snake,
+            "SCREAMING_SNAKE_CASE" => Self::ScreamingSnake,
+            "kebab-case" => Self::Kebab,
+            "SCREAMING-KEBAB-CASE" => Self::ScreamingKebab,
+            "Title Case" => Self::Title,
+            _ => panic!(
+                "unexpected rename_all value {:?}; expected one of \"lowercase\", \"UPPERCASE\", \
+                 \"PascalCase\", \"camelCase\", \"snake_case\", \"SCREAMING_SNAKE_CASE\", \
+                 \"kebab-case\", \"SCREAMING-KEBAB-CASE\", \"Title Case\"",
+                name
+            ),
+        }
+    }
+
+    pub fn apply(&self, name: &str) -> String {
+        let name = name.trim_start_matches("r#");
+        let words = split_words(name);
+        match self {
+            Self::Lower => name.to_lowercase(),
+            Self::Upper => name.to_uppercase(),
+            Self::Pascal => words.iter().map(|w| capitalize(w)).collect(),
+            Self::Camel => words
+                .iter()
+                .enumerate()
+                .map(|(i, w)| {
+                    if i == 0 {
+                        w.to_lowercase()
+                    } else {
+                        capitalize(w)
+                    }
+                })
+                .collect(),
+            Self::Snake => words.join("_").to_lowercase(),
+            Self::ScreamingSnake => words.join("_").to_uppercase(),
+            Self::Kebab => words.join("-").to_lowercase(),
+            Self::ScreamingKebab => words.join("-").to_uppercase(),
+            Self::Title => words
+                .iter()
+                .map(|w| capitalize(w))
+                .collect::<Vec<_>>()
+                .join(" "),
+        }
+    }
+}
+
+// splits an identifier by underscores and by lowercase to uppercase transitions,
+// so both `field_name` and `VariantName` are handled
+fn split_words(name: &str) -> Vec<String> {
+    let mut words = Vec::new();
+    let mut word = String::new();
+    let mut prev_lowercase = false;
+    for c in name.chars() {
+        if c == '_' {
+            if !word.is_empty() {
+                words.push(std::mem::take(&mut word));
+            }
+
+            prev_lowercase = false;
+            continue;
+        }
+
+        if c.is_uppercase() && prev_lowercase && !word.is_empty() {
+            words.push(std::mem::take(&mut word));
+        }
+
+        prev_lowercase = c.is_lowercase() || c.is_numeric();
+        word.push(c);
+    }
+
+    if !word.is_empty() {
+        words.push(word);
+    }
+
+    words
+}
+
+fn capitalize(word: &str) -> String {
+    let mut chars = word.chars();
+    match chars.next() {
+        Some(first) => first
+            .to_uppercase()
+            .chain(chars.flat_map(char::to_lowercase))
+            .collect(),
+        None => String::new(),
+    }
+}
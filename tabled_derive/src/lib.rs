@@ -1,5 +1,8 @@
 extern crate proc_macro;
 
+mod casing;
+
+use casing::CaseStyle;
 use proc_macro2::TokenStream;
 use quote::*;
 use std::str;
@@ -8,7 +11,7 @@ use syn::{
     Ident, Index, Lit, Meta, NestedMeta, Type, Variant,
 };
 
-#[proc_macro_derive(Tabled, attributes(header, field))]
+#[proc_macro_derive(Tabled, attributes(header, field, tabled))]
 pub fn tabled(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
     let input = parse_macro_input!(input as DeriveInput);
     let ast = impl_tabled(&input);
@@ -16,16 +19,30 @@ pub fn tabled(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
 }
 
 fn impl_tabled(ast: &DeriveInput) -> TokenStream {
-    let length = get_tabled_length(ast).unwrap();
-    let info = collect_info(ast).unwrap();
+    let rename_all = look_for_rename_all(&ast.attrs);
+    let display_types = look_for_display_types(&ast.attrs);
+    let (length, info) = match (&ast.data, look_for_kind(&ast.attrs)) {
+        (Data::Enum(data), Some(kind)) => {
+            collect_info_enum_kind(data, &kind, rename_all, &display_types)
+        }
+        _ => (
+            get_tabled_length(ast).unwrap(),
+            collect_info(ast, rename_all, &display_types).unwrap(),
+        ),
+    };
     let fields = info.values;
     let headers = info.headers;
+    let alignments = info.alignments;
 
     let name = &ast.ident;
+    let length_checks = inline_length_checks(ast);
     let (impl_generics, ty_generics, where_clause) = ast.generics.split_for_impl();
     let expanded = quote! {
         impl #impl_generics Tabled for #name #ty_generics #where_clause {
-            const LENGTH: usize = #length;
+            const LENGTH: usize = {
+                #(#length_checks)*
+                #length
+            };
 
             fn fields(&self) -> Vec<String> {
                 #fields
@@ -34,12 +51,56 @@ fn impl_tabled(ast: &DeriveInput) -> TokenStream {
             fn headers() -> Vec<String> {
                 #headers
             }
+
+            fn alignments() -> Vec<Option<::tabled::Alignment>> {
+                #alignments
+            }
         }
     };
 
     expanded
 }
 
+/// Builds const assertions which fail a compilation
+/// if an inlined field has a type which length isn't known at compile time,
+/// because its cells would be misplaced.
+fn inline_length_checks(ast: &DeriveInput) -> Vec<TokenStream> {
+    let fields: Vec<(usize, &Field)> = match &ast.data {
+        Data::Struct(data) => data.fields.iter().enumerate().collect(),
+        Data::Enum(data) => data
+            .variants
+            .iter()
+            .filter(|variant| {
+                let attributes = Attributes::parse(&variant.attrs);
+                !attributes.is_ignored() && attributes.inline
+            })
+            .flat_map(|variant| variant.fields.iter().enumerate())
+            .collect(),
+        Data::Union(_) => Vec::new(),
+    };
+
+    fields
+        .into_iter()
+        .filter(|(_, field)| {
+            let attributes = Attributes::parse(&field.attrs);
+            !attributes.is_ignored() && attributes.inline
+        })
+        .map(|(i, field)| {
+            let field_type = &field.ty;
+            let field_name = field
+                .ident
+                .as_ref()
+                .map_or_else(|| i.to_string(), |ident| ident.to_string());
+            let message = format!(
+                "a field `{}` of `{}` can't be inlined, because a number of its columns isn't known at compile time",
+                field_name, ast.ident,
+            );
+
+            quote!(assert!(<#field_type as Tabled>::LENGTH != 0, #message);)
+        })
+        .collect()
+}
+
 fn get_tabled_length(ast: &DeriveInput) -> Result<TokenStream, String> {
     match &ast.data {
         Data::Struct(data) => Ok(get_fields_length(&data.fields)),
@@ -101,16 +162,24 @@ fn get_enum_variant_length(enum_ast: &DataEnum) -> impl Iterator<Item = TokenStr
         })
 }
 
-fn collect_info(ast: &DeriveInput) -> Result<Impl, String> {
+fn collect_info(
+    ast: &DeriveInput,
+    rename_all: Option<CaseStyle>,
+    display_types: &DisplayTypes,
+) -> Result<Impl, String> {
     match &ast.data {
-        Data::Struct(data) => collect_info_struct(data),
-        Data::Enum(data) => collect_info_enum(data),
+        Data::Struct(data) => collect_info_struct(data, rename_all, display_types),
+        Data::Enum(data) => collect_info_enum(data, rename_all, display_types),
         Data::Union(_) => Err("Union type isn't supported".to_owned()),
     }
 }
 
-fn collect_info_struct(ast: &DataStruct) -> Result<Impl, String> {
-    info_from_fields(&ast.fields, field_var_name, "")
+fn collect_info_struct(
+    ast: &DataStruct,
+    rename_all: Option<CaseStyle>,
+    display_types: &DisplayTypes,
+) -> Result<Impl, String> {
+    info_from_fields(&ast.fields, field_var_name, "", rename_all, display_types)
 }
 
 // todo: refactoring. instead of using a lambda + prefix
@@ -120,6 +189,8 @@ fn info_from_fields(
     fields: &Fields,
     field_name: impl Fn(usize, &Field) -> TokenStream,
     header_prefix: &str,
+    rename_all: Option<CaseStyle>,
+    display_types: &DisplayTypes,
 ) -> Result<Impl, String> {
     let fields = fields.into_iter().enumerate().map(|(i, field)| {
         let attributes = Attributes::parse(&field.attrs);
@@ -128,18 +199,24 @@ fn info_from_fields(
 
     let mut headers = Vec::new();
     let mut values = Vec::new();
+    let mut alignments = Vec::new();
 
     for (i, field, attributes) in fields {
         if attributes.is_ignored() {
             continue;
         }
 
-        let header = field_headers(field, i, &attributes, header_prefix);
+        let header = field_headers(field, i, &attributes, header_prefix, rename_all);
 
         headers.push(header);
 
+        let alignment = field_alignments(field, &attributes);
+
+        alignments.push(alignment);
+
         let field_name = field_name(i, field);
-        let value = get_field_fields(field_name, &attributes);
+        let display_with = display_function(field, &attributes, display_types);
+        let value = get_field_fields(field_name, &attributes, display_with);
 
         values.push(value);
     }
@@ -156,7 +233,40 @@ fn info_from_fields(
         out
     });
 
-    Ok(Impl { headers, values })
+    let alignments = quote!({
+        let mut out = Vec::new();
+        #(out.extend(#alignments);)*
+        out
+    });
+
+    Ok(Impl {
+        headers,
+        values,
+        alignments,
+    })
+}
+
+fn field_alignments(field: &Field, attributes: &Attributes) -> TokenStream {
+    if attributes.inline {
+        let field_type = &field.ty;
+        return quote! { <#field_type as Tabled>::alignments() };
+    }
+
+    let alignment = alignment_token(attributes);
+    quote!(vec![#alignment])
+}
+
+fn alignment_token(attributes: &Attributes) -> TokenStream {
+    match attributes.align.as_deref() {
+        Some("left") => quote!(Some(::tabled::Alignment::left())),
+        Some("center") => quote!(Some(::tabled::Alignment::center_horizontal())),
+        Some("right") => quote!(Some(::tabled::Alignment::right())),
+        Some(align) => panic!(
+            "unexpected alignment {:?}; expected one of \"left\", \"center\", \"right\"",
+            align
+        ),
+        None => quote!(None),
+    }
 }
 
 fn field_headers(
@@ -164,12 +274,13 @@ fn field_headers(
     index: usize,
     attributes: &Attributes,
     prefix: &str,
+    rename_all: Option<CaseStyle>,
 ) -> TokenStream {
     if attributes.inline {
         return get_type_headers(&field.ty, &attributes.inline_prefix, "");
     }
 
-    let header_name = field_header_name(field, attributes, index);
+    let header_name = field_header_name(field, attributes, index, rename_all);
     if !prefix.is_empty() {
         quote!(vec![format!("{}{}", #prefix, #header_name)])
     } else {
@@ -177,8 +288,13 @@ fn field_headers(
     }
 }
 
-fn collect_info_enum(ast: &DataEnum) -> Result<Impl, String> {
+fn collect_info_enum(
+    ast: &DataEnum,
+    rename_all: Option<CaseStyle>,
+    display_types: &DisplayTypes,
+) -> Result<Impl, String> {
     let mut headers_list = Vec::new();
+    let mut alignments_list = Vec::new();
     let mut variants = Vec::new();
     for variant in &ast.variants {
         let attributes = Attributes::parse(&variant.attrs);
@@ -186,9 +302,10 @@ fn collect_info_enum(ast: &DataEnum) -> Result<Impl, String> {
             continue;
         }
 
-        let info = info_from_variant(variant, &attributes)?;
+        let info = info_from_variant(variant, &attributes, rename_all, display_types)?;
         variants.push((variant, info.values));
         headers_list.push(info.headers);
+        alignments_list.push(info.alignments);
     }
 
     let variant_sizes = get_enum_variant_length(ast);
@@ -201,15 +318,122 @@ fn collect_info_enum(ast: &DataEnum) -> Result<Impl, String> {
         .concat()
     };
 
-    Ok(Impl { headers, values })
+    let alignments = quote! {
+        vec![
+            #(#alignments_list,)*
+        ]
+        .concat()
+    };
+
+    Ok(Impl {
+        headers,
+        values,
+        alignments,
+    })
+}
+
+/// Builds an enum table with a column of variant names
+/// followed by a union of fields of all variants.
+fn collect_info_enum_kind(
+    ast: &DataEnum,
+    kind: &str,
+    rename_all: Option<CaseStyle>,
+    display_types: &DisplayTypes,
+) -> (TokenStream, Impl) {
+    let mut columns: Vec<(String, TokenStream)> = Vec::new();
+    let mut branches = Vec::new();
+    for variant in &ast.variants {
+        let attributes = Attributes::parse(&variant.attrs);
+        if attributes.is_ignored() {
+            continue;
+        }
+
+        let variant_name = variant_name(variant, &attributes, rename_all);
+        let mut values = Vec::new();
+        for (i, field) in variant.fields.iter().enumerate() {
+            let attributes = Attributes::parse(&field.attrs);
+            if attributes.is_ignored() {
+                continue;
+            }
+
+            if attributes.inline {
+                panic!("inline fields aren't supported by a kind mode of an enum");
+            }
+
+            let header = field_header_name(field, &attributes, i, rename_all);
+            let column = match columns.iter().position(|(name, _)| *name == header) {
+                Some(column) => column,
+                None => {
+                    columns.push((header, alignment_token(&attributes)));
+                    columns.len() - 1
+                }
+            };
+
+            // the first column is taken by a variant name
+            let column = column + 1;
+            let display_with = display_function(field, &attributes, display_types);
+            let field = variant_var_name(i, field);
+            let value = match display_with {
+                Some(func) => use_function_for(field, func),
+                None => quote!(format!("{}", #field)),
+            };
+
+            values.push(quote!(out[#column] = #value;));
+        }
+
+        let branch = match_variant(variant);
+        branches.push(quote! {
+            Self::#branch => {
+                out[0] = String::from(#variant_name);
+                #(#values)*
+            },
+        });
+    }
+
+    let length = 1 + columns.len();
+    let headers = columns.iter().map(|(name, _)| name);
+    let alignments = columns.iter().map(|(_, alignment)| alignment);
+
+    let headers = quote!(vec![String::from(#kind), #(String::from(#headers),)*]);
+    let alignments = quote!(vec![None, #(#alignments,)*]);
+    let values = quote! {
+        let mut out = vec![String::new(); #length];
+
+        #[allow(unused_variables)]
+        match &self {
+            #(#branches)*
+            _ => return vec![], // variant is hidden so we return an empty vector
+        };
+
+        out
+    };
+
+    let info = Impl {
+        headers,
+        values,
+        alignments,
+    };
+
+    (quote!(#length), info)
 }
 
-fn info_from_variant(variant: &Variant, attributes: &Attributes) -> Result<Impl, String> {
+fn info_from_variant(
+    variant: &Variant,
+    attributes: &Attributes,
+    rename_all: Option<CaseStyle>,
+    display_types: &DisplayTypes,
+) -> Result<Impl, String> {
     if attributes.inline {
-        return info_from_fields(&variant.fields, variant_var_name, &attributes.inline_prefix);
+        return info_from_fields(
+            &variant.fields,
+            variant_var_name,
+            &attributes.inline_prefix,
+            rename_all,
+            display_types,
+        );
     }
 
-    let variant_name = variant_name(variant, attributes);
+    let variant_name = variant_name(variant, attributes, rename_all);
     let value = "+";
 
     // we need exactly string because of it must be inlined as string
@@ -217,12 +441,20 @@ fn info_from_variant(variant: &Variant, attributes: &Attributes) -> Result<Impl,
     // we need exactly string because of it must be inlined as string
     let values = quote! {vec![#value.to_string()]};
 
-    Ok(Impl { headers, values })
+    let alignment = alignment_token(attributes);
+    let alignments = quote! {vec![#alignment]};
+
+    Ok(Impl {
+        headers,
+        values,
+        alignments,
+    })
 }
 
 struct Impl {
     headers: TokenStream,
     values: TokenStream,
+    alignments: TokenStream,
 }
 
 fn get_type_headers(field_type: &Type, inline_prefix: &str, prefix: &str) -> TokenStream {
@@ -237,12 +469,16 @@ fn get_type_headers(field_type: &Type, inline_prefix: &str, prefix: &str) -> Tok
     }
 }
 
-fn get_field_fields(field: TokenStream, attr: &Attributes) -> TokenStream {
+fn get_field_fields(
+    field: TokenStream,
+    attr: &Attributes,
+    display_with: Option<&str>,
+) -> TokenStream {
     if attr.inline {
         return quote! { #field.fields() };
     }
 
-    if let Some(func) = &attr.display_with {
+    if let Some(func) = display_with {
         let func_call = use_function_for(field, func);
         return quote!(vec![#func_call]);
     }
@@ -250,6 +486,19 @@ fn get_field_fields(field: TokenStream, attr: &Attributes) -> TokenStream {
     quote!(vec![format!("{}", #field)])
 }
 
+/// Returns a function which is used to display a field.
+/// A `display_with` attribute of a field overrides `display_types` of a type.
+fn display_function<'a>(
+    field: &Field,
+    attributes: &'a Attributes,
+    display_types: &'a DisplayTypes,
+) -> Option<&'a str> {
+    attributes
+        .display_with
+        .as_deref()
+        .or_else(|| display_types.find(&field.ty))
+}
+
 fn use_function_for(field: TokenStream, function: &str) -> TokenStream {
     let path: syn::Result<syn::ExprPath> = syn::parse_str(function);
     match path {
@@ -368,18 +617,33 @@ fn match_variant(v: &Variant) -> TokenStream {
     token
 }
 
-fn variant_name(variant: &Variant, attributes: &Attributes) -> String {
-    attributes
-        .name
-        .clone()
-        .unwrap_or_else(|| variant.ident.to_string())
+fn variant_name(
+    variant: &Variant,
+    attributes: &Attributes,
+    rename_all: Option<CaseStyle>,
+) -> String {
+    attributes.name.clone().unwrap_or_else(|| {
+        let name = variant.ident.to_string();
+        match rename_all {
+            Some(case) => case.apply(&name),
+            None => name,
+        }
+    })
 }
 
-fn field_header_name(f: &Field, attr: &Attributes, index: usize) -> String {
+fn field_header_name(
+    f: &Field,
+    attr: &Attributes,
+    index: usize,
+    rename_all: Option<CaseStyle>,
+) -> String {
     match &attr.name {
         Some(name) => name.to_string(),
         None => match f.ident.as_ref() {
-            Some(name) => name.to_string(),
+            Some(name) => match rename_all {
+                Some(case) => case.apply(&name.to_string()),
+                None => name.to_string(),
+            },
             None => format!("{}", index),
         },
     }
@@ -393,6 +657,7 @@ struct Attributes {
     inline_prefix: String,
     name: Option<String>,
     display_with: Option<String>,
+    align: Option<String>,
 }
 
 impl Attributes {
@@ -402,8 +667,10 @@ impl Attributes {
         let inline_prefix = look_for_inline_prefix(attrs);
         let display_with = check_display_with_func(attrs);
         let override_header_name = override_header_name(attrs);
+        let align = look_for_alignment(attrs);
 
         Self {
+            align,
             display_with,
             hidden: is_ignored,
             inline: should_be_inlined,
@@ -417,32 +684,69 @@ impl Attributes {
     }
 }
 
+/// Functions set by `#[tabled(display_types(bool = "fmt_bool"))]`
+/// which are used to display all fields of given types.
+struct DisplayTypes(Vec<(String, String)>);
+
+impl DisplayTypes {
+    fn find(&self, field_type: &Type) -> Option<&str> {
+        let field_type = field_type.to_token_stream().to_string();
+        self.0
+            .iter()
+            .find(|(display_type, _)| *display_type == field_type)
+            .map(|(_, func)| func.as_str())
+    }
+}
+
+fn look_for_display_types(attrs: &[Attribute]) -> DisplayTypes {
+    let types = find_name_attribute(attrs, "tabled", "display_types", look_up_nested_meta_pairs);
+    DisplayTypes(types.unwrap_or_default())
+}
+
+fn look_for_rename_all(attrs: &[Attribute]) -> Option<CaseStyle> {
+    find_name_attribute(attrs, "tabled", "rename_all", look_up_nested_meta_str)
+        .map(|name| CaseStyle::parse(&name))
+}
+
+fn look_for_kind(attrs: &[Attribute]) -> Option<String> {
+    find_name_attribute(attrs, "tabled", "kind", look_up_nested_meta_str).or_else(|| {
+        find_name_attribute(attrs, "tabled", "kind", look_up_nested_meta_bool)
+            .filter(|&kind| kind)
+            .map(|_| String::from("kind"))
+    })
+}
+
 fn override_header_name(attrs: &[Attribute]) -> Option<String> {
     find_name_attribute(attrs, "header", "name", look_up_nested_meta_str)
         .or_else(|| find_name_attribute(attrs, "header", "name", look_up_nested_meta_flag_str))
 }
 
 fn check_display_with_func(attrs: &[Attribute]) -> Option<String> {
-    find_name_attribute(attrs, "field", "display_with", look_up_nested_meta_str)
+    ["tabled", "field"].iter().find_map(|method| {
+        find_name_attribute(attrs, method, "display_with", look_up_nested_meta_str)
+    })
+}
+
+fn look_for_alignment(attrs: &[Attribute]) -> Option<String> {
+    find_name_attribute(attrs, "tabled", "align", look_up_nested_meta_str)
 }
 
 fn should_be_inlined(attrs: &[Attribute]) -> bool {
-    let inline_attr = find_name_attribute(attrs, "header", "inline", look_up_nested_meta_bool)
-        .or_else(|| find_name_attribute(attrs, "field", "inline", look_up_nested_meta_bool))
-        .or_else(|| {
-            find_name_attribute(attrs, "header", "inline", look_up_nested_flag_str_in_attr)
+    let inline_attr = ["tabled", "header", "field"].iter().find_map(|method| {
+        find_name_attribute(attrs, method, "inline", look_up_nested_meta_bool).or_else(|| {
+            find_name_attribute(attrs, method, "inline", look_up_nested_flag_str_in_attr)
                 .map(|_| true)
         })
-        .or_else(|| {
-            find_name_attribute(attrs, "field", "inline", look_up_nested_flag_str_in_attr)
-                .map(|_| true)
-        });
+    });
     inline_attr == Some(true)
 }
 
 fn look_for_inline_prefix(attrs: &[Attribute]) -> String {
-    find_name_attribute(attrs, "header", "inline", look_up_nested_flag_str_in_attr)
-        .or_else(|| find_name_attribute(attrs, "field", "inline", look_up_nested_flag_str_in_attr))
+    ["tabled", "header", "field"]
+        .iter()
+        .find_map(|method| {
+            find_name_attribute(attrs, method, "inline", look_up_nested_flag_str_in_attr)
+        })
         .unwrap_or_else(|| "".to_owned())
 }
 
@@ -505,6 +809,30 @@ fn look_up_nested_meta_str(meta: &NestedMeta, name: &str) -> Result<Option<Strin
     }
 }
 
+fn look_up_nested_meta_pairs(
+    meta: &NestedMeta,
+    name: &str,
+) -> Result<Option<Vec<(String, String)>>, String> {
+    let list = match meta {
+        NestedMeta::Meta(Meta::List(list)) if list.path.is_ident(name) => list,
+        _ => return Ok(None),
+    };
+
+    list.nested
+        .iter()
+        .map(|nested| match nested {
+            NestedMeta::Meta(Meta::NameValue(value)) => match check_str_literal(&value.lit)? {
+                Some(func) => Ok((value.path.to_token_stream().to_string(), func)),
+                None => Err("A function should be a string literal".to_string()),
+            },
+            _ => Err(
+                "Expected pairs of a type and a function like `bool = \"fmt_bool\"`".to_string(),
+            ),
+        })
+        .collect::<Result<Vec<_>, _>>()
+        .map(Some)
+}
+
 fn look_up_nested_meta_flag_str(meta: &NestedMeta, _: &str) -> Result<Option<String>, String> {
     match meta {
         NestedMeta::Lit(lit) => check_str_literal(lit),
@@ -1,5 +1,5 @@
 use crate::util::create_vector;
-use tabled::{Alignment, Column, Full, Head, Indent, Modify, Row, Style, Table};
+use tabled::{Alignment, CenterRounding, Column, Full, Head, Indent, Modify, Row, Style, Table};
 
 mod util;
 
@@ -123,3 +123,101 @@ fn alignment_doesnt_change_indent() {
 
     assert_eq!(table, expected);
 }
+
+#[test]
+fn horizontal_and_vertical_alignments_dont_reset_each_other() {
+    let mut data = create_vector::<1, 2>();
+    data[0][2] = String::from("multi\nline\ntext");
+
+    let expected = concat!(
+        " N | column 0 | column 1 \n",
+        "---+----------+----------\n",
+        "   |          | multi    \n",
+        "   |          | line     \n",
+        " 0 |      0-0 | text     \n",
+    );
+
+    let table = Table::new(&data)
+        .with(Style::psql())
+        .with(Modify::new(Row(1..)).with(Alignment::bottom()))
+        .with(Modify::new(Row(1..)).with(Alignment::right()))
+        .with(Modify::new(Column(2..)).with(Alignment::left()))
+        .to_string();
+
+    assert_eq!(table, expected);
+
+    let table = Table::new(&data)
+        .with(Style::psql())
+        .with(Modify::new(Row(1..)).with(Alignment::right()))
+        .with(Modify::new(Row(1..)).with(Alignment::bottom()))
+        .with(Modify::new(Column(2..)).with(Alignment::left()))
+        .to_string();
+
+    assert_eq!(table, expected);
+}
+
+#[test]
+fn combined_alignment() {
+    let mut data = create_vector::<1, 2>();
+    data[0][2] = String::from("multi\nline\ntext");
+
+    let table = Table::new(&data)
+        .with(Style::psql())
+        .with(Modify::new(Row(1..)).with(Alignment::bottom_right()))
+        .to_string();
+
+    let expected = concat!(
+        " N | column 0 | column 1 \n",
+        "---+----------+----------\n",
+        "   |          |    multi \n",
+        "   |          |     line \n",
+        " 0 |      0-0 |     text \n",
+    );
+
+    assert_eq!(table, expected);
+
+    let table = Table::new(&data)
+        .with(Style::psql())
+        .with(Modify::new(Row(1..)).with(Alignment::center()))
+        .with(Modify::new(Column(..1)).with(Alignment::top_left()))
+        .to_string();
+
+    let expected = concat!(
+        " N | column 0 | column 1 \n",
+        "---+----------+----------\n",
+        " 0 |          |  multi   \n",
+        "   |   0-0    |   line   \n",
+        "   |          |   text   \n",
+    );
+
+    assert_eq!(table, expected);
+}
+
+#[test]
+fn center_rounding() {
+    let table = |rounding| {
+        Table::new(["a", "bb", "c", "d"])
+            .with(Style::psql())
+            .with(Modify::new(Full).with(Alignment::center_horizontal()))
+            .with(rounding)
+            .to_string()
+    };
+
+    let expected = concat!(" &str \n", "------\n", "  a   \n", "  bb  \n", "  c   \n", "  d   \n",);
+    assert_eq!(table(CenterRounding::Right), expected);
+
+    let expected = concat!(" &str \n", "------\n", "   a  \n", "  bb  \n", "   c  \n", "   d  \n",);
+    assert_eq!(table(CenterRounding::Left), expected);
+}
+
+#[test]
+fn center_rounding_alternate() {
+    let table = Table::new(["a\nb\nc", "dd"])
+        .with(Style::psql())
+        .with(Modify::new(Full).with(Alignment::center_horizontal()))
+        .with(CenterRounding::Alternate)
+        .to_string();
+
+    let expected = concat!(" &str \n", "------\n", "  a   \n", "   b  \n", "  c   \n", "  dd  \n",);
+    assert_eq!(table, expected);
+}
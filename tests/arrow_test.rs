@@ -0,0 +1,56 @@
+#[cfg(feature = "arrow")]
+use std::sync::Arc;
+
+#[cfg(feature = "arrow")]
+use arrow_array::{ArrayRef, BooleanArray, Float64Array, RecordBatch, StringArray};
+#[cfg(feature = "arrow")]
+use tabled::{builder::Builder, Style};
+
+#[cfg(feature = "arrow")]
+#[test]
+fn record_batch_alignment_by_type() {
+    let batch = RecordBatch::try_from_iter([
+        (
+            "name",
+            Arc::new(StringArray::from(vec![Some("x"), None])) as ArrayRef,
+        ),
+        (
+            "value",
+            Arc::new(Float64Array::from(vec![2.5, 100.5])) as ArrayRef,
+        ),
+        (
+            "exact",
+            Arc::new(BooleanArray::from(vec![false, true])) as ArrayRef,
+        ),
+    ])
+    .unwrap();
+
+    let table = Builder::from_record_batch(&batch, 10)
+        .unwrap()
+        .build()
+        .with(Style::psql())
+        .to_string();
+
+    let expected = concat!(
+        " name | value | exact \n",
+        "------+-------+-------\n",
+        " x    |   2.5 | false \n",
+        "      | 100.5 | true  \n",
+    );
+
+    assert_eq!(table, expected);
+}
+
+#[cfg(feature = "arrow")]
+#[test]
+fn record_batch_max_rows() {
+    let batch = RecordBatch::try_from_iter([(
+        "value",
+        Arc::new(Float64Array::from(vec![1.0, 2.0, 3.0])) as ArrayRef,
+    )])
+    .unwrap();
+
+    let table = Builder::from_record_batch(&batch, 0).unwrap().build();
+
+    assert_eq!(table.shape(), (1, 1));
+}
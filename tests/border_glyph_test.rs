@@ -0,0 +1,67 @@
+#![cfg(feature = "border-glyphs")]
+
+use crate::util::create_vector;
+use tabled::{
+    style::{BorderGlyph, BorderGlyphError},
+    Disable, Style, Table,
+};
+
+mod util;
+
+#[test]
+fn border_glyph() {
+    let data = create_vector::<1, 1>();
+    let table = Table::new(&data)
+        .with(Style::psql())
+        .with(BorderGlyph::new('|', "¦").unwrap())
+        .to_string();
+
+    let expected = concat!(" N ¦ column 0 \n", "---+----------\n", " 0 ¦   0-0    \n",);
+
+    assert_eq!(table, expected);
+}
+
+#[test]
+fn border_glyph_in_split_line() {
+    let data = create_vector::<1, 1>();
+    let table = Table::new(&data)
+        .with(Style::psql())
+        .with(BorderGlyph::new('-', "=").unwrap())
+        .with(BorderGlyph::new('+', "+\u{301}").unwrap())
+        .to_string();
+
+    let expected = concat!(
+        " N | column 0 \n",
+        "===+\u{301}==========\n",
+        " 0 |   0-0    \n",
+    );
+
+    assert_eq!(table, expected);
+}
+
+#[test]
+fn border_glyph_is_kept_by_structural_options() {
+    let data = create_vector::<1, 2>();
+    let table = Table::new(&data)
+        .with(Style::psql())
+        .with(BorderGlyph::new('|', "¦").unwrap())
+        .with(Disable::Row(1..))
+        .to_string();
+
+    let expected = concat!(" N ¦ column 0 ¦ column 1 \n", "---+----------+----------\n",);
+
+    assert_eq!(table, expected);
+}
+
+#[test]
+fn border_glyph_width_is_validated() {
+    assert_eq!(
+        BorderGlyph::new('|', "||"),
+        Err(BorderGlyphError {
+            c: '|',
+            width: 2,
+            expected: 1,
+        })
+    );
+    assert!(BorderGlyph::new('|', "").is_err());
+}
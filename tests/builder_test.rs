@@ -1,6 +1,8 @@
-use std::iter::FromIterator;
+use std::{collections::HashMap, iter::FromIterator};
 
-use tabled::builder::Builder;
+use tabled::{
+    builder::Builder, Alignment, Disable, Error, Full, Header, Modify, Sort, Style, Table, Tabled,
+};
 
 #[test]
 fn builder_add_row() {
@@ -206,6 +208,86 @@ fn builder_from_vector() {
     assert_eq!(table, expected);
 }
 
+#[test]
+fn builder_from_maps() {
+    let data = vec![
+        HashMap::from([
+            ("name".to_string(), "Fedora".to_string()),
+            ("id".to_string(), "0".to_string()),
+        ]),
+        HashMap::from([("name".to_string(), "OpenSUSE".to_string())]),
+        HashMap::from([
+            ("id".to_string(), "2".to_string()),
+            ("link".to_string(), "https://endeavouros.com/".to_string()),
+        ]),
+        HashMap::new(),
+    ];
+
+    let table = Builder::from_maps(data).build().to_string();
+
+    let expected = "+----+--------------------------+----------+\n\
+                         | id |           link           |   name   |\n\
+                         +----+--------------------------+----------+\n\
+                         | 0  |                          |  Fedora  |\n\
+                         +----+--------------------------+----------+\n\
+                         |    |                          | OpenSUSE |\n\
+                         +----+--------------------------+----------+\n\
+                         | 2  | https://endeavouros.com/ |          |\n\
+                         +----+--------------------------+----------+\n\
+                         |    |                          |          |\n\
+                         +----+--------------------------+----------+\n";
+
+    assert_eq!(table, expected);
+}
+
+#[test]
+fn builder_from_empty_maps() {
+    let table = Builder::from_maps(Vec::new()).build().to_string();
+    assert_eq!(table, "");
+}
+
+#[test]
+fn builder_column_alignment() {
+    let table = Builder::default()
+        .set_header(["name", "value"])
+        .add_row(["weight", "0.443"])
+        .add_row(["hi", "1"])
+        .column_alignment(0, Alignment::left())
+        .column_alignment(1, Alignment::right())
+        .column_alignment(5, Alignment::right())
+        .build()
+        .to_string();
+
+    let expected = "+--------+-------+\n\
+                    | name   | value |\n\
+                    +--------+-------+\n\
+                    | weight | 0.443 |\n\
+                    +--------+-------+\n\
+                    | hi     |     1 |\n\
+                    +--------+-------+\n";
+
+    assert_eq!(table, expected);
+}
+
+#[test]
+fn builder_column_alignment_can_be_overridden() {
+    let table = Builder::default()
+        .set_header(["name", "value"])
+        .add_row(["hi", "1"])
+        .column_alignment(1, Alignment::right())
+        .build()
+        .with(Modify::new(Full).with(Alignment::left()))
+        .to_string();
+
+    let expected = "+------+-------+\n\
+                    | name | value |\n\
+                    +------+-------+\n\
+                    | hi   | 1     |\n\
+                    +------+-------+\n";
+
+    assert_eq!(table, expected);
+}
+
 #[quickcheck_macros::quickcheck]
 #[ignore = "Quickcheck tests are a bit slow, so we don't run them all the time"]
 fn qc_table_is_consistent(data: Vec<Vec<isize>>) -> bool {
@@ -223,3 +305,193 @@ fn qc_table_is_consistent(data: Vec<Vec<isize>>) -> bool {
         .all(|line_width| line_width == lines[0].len());
     lines_has_the_same_length
 }
+
+#[derive(Tabled)]
+struct Distribution {
+    name: &'static str,
+    #[header("is active")]
+    is_active: bool,
+}
+
+#[test]
+fn builder_expect_header() {
+    let builder = Builder::default()
+        .set_header(["name", "is active"])
+        .add_row(["Manjaro", "true"])
+        .expect_header::<Distribution>();
+    assert!(builder.is_ok());
+
+    let builder = Builder::default()
+        .set_header(["name", "is_active"])
+        .expect_header::<Distribution>();
+    assert_eq!(
+        builder.unwrap_err(),
+        Error::HeaderMismatch {
+            expected: vec!["name".to_owned(), "is active".to_owned()],
+            found: vec!["name".to_owned(), "is_active".to_owned()],
+        }
+    );
+}
+
+#[test]
+fn builder_expect_header_checks_first_row_if_header_is_not_set() {
+    let builder = Builder::default()
+        .add_row(["name", "is active"])
+        .add_row(["Manjaro", "true"])
+        .expect_header::<Distribution>();
+    assert!(builder.is_ok());
+
+    let builder = Builder::default().expect_header::<Distribution>();
+    assert_eq!(
+        builder.unwrap_err(),
+        Error::HeaderMismatch {
+            expected: vec!["name".to_owned(), "is active".to_owned()],
+            found: vec![],
+        }
+    );
+}
+
+#[test]
+fn builder_add_record() {
+    let table = Builder::default()
+        .set_header(Distribution::headers())
+        .add_record(Distribution {
+            name: "Manjaro",
+            is_active: true,
+        })
+        .build()
+        .to_string();
+
+    let expected = Table::new([Distribution {
+        name: "Manjaro",
+        is_active: true,
+    }])
+    .to_string();
+
+    assert_eq!(table, expected);
+}
+
+#[test]
+fn builder_remove_record() {
+    let table = Builder::default()
+        .set_header(["a", "b"])
+        .add_row(["1", "2"])
+        .add_row(["3", "4"])
+        .remove_record(1)
+        .remove_record(10)
+        .build()
+        .to_string();
+
+    let expected = concat!(
+        "+---+---+\n",
+        "| a | b |\n",
+        "+---+---+\n",
+        "| 1 | 2 |\n",
+        "+---+---+\n",
+    );
+
+    assert_eq!(table, expected);
+}
+
+#[test]
+fn builder_remove_record_shrinks_columns() {
+    let table = Builder::default()
+        .add_row(["1"])
+        .add_row(["2", "3"])
+        .set_default_text("x")
+        .remove_record(1)
+        .build()
+        .to_string();
+
+    assert_eq!(table, "+---+\n| 1 |\n+---+\n");
+}
+
+#[test]
+fn builder_from_table() {
+    let data = [
+        Distribution {
+            name: "Manjaro",
+            is_active: true,
+        },
+        Distribution {
+            name: "Debian",
+            is_active: true,
+        },
+    ];
+
+    let table = Table::new(&data[..1]);
+    let table = Builder::from(table).add_record(&data[1]).build();
+
+    assert_eq!(table.to_string(), Table::new(&data).to_string());
+}
+
+#[test]
+fn builder_add_header_row() {
+    let table = Builder::default()
+        .set_header(["distance", "time"])
+        .add_header_row(["km", "h"])
+        .add_row(["100", "1.5"])
+        .add_row(["20"])
+        .set_default_text("-")
+        .build()
+        .with(Style::modern())
+        .to_string();
+
+    let expected = concat!(
+        "┌──────────┬──────┐\n",
+        "│ distance │ time │\n",
+        "├──────────┼──────┤\n",
+        "│    km    │  h   │\n",
+        "├──────────┼──────┤\n",
+        "│   100    │ 1.5  │\n",
+        "├──────────┼──────┤\n",
+        "│    20    │  -   │\n",
+        "└──────────┴──────┘\n",
+    );
+
+    assert_eq!(table, expected);
+}
+
+#[test]
+fn builder_add_header_row_is_kept_as_header() {
+    let table = Builder::default()
+        .add_header_row(["distance", "time"])
+        .add_header_row(["km", "h"])
+        .add_row(["100", "1.5"])
+        .add_row(["20", "0.5"])
+        .build()
+        .with(Disable::Row(2..3))
+        .with(Style::psql());
+
+    let expected = concat!(
+        " distance | time \n",
+        "    km    |  h   \n",
+        "----------+------\n",
+        "    20    | 0.5  \n",
+    );
+
+    assert_eq!(table.to_string(), expected);
+
+    let table = Builder::from(table).build().with(Style::psql());
+    assert_eq!(table.to_string(), expected);
+}
+
+#[test]
+fn builder_row_meta() {
+    let table = Builder::default()
+        .set_header(["name", "released"])
+        .add_row_with_meta(["Rust", "2015"], 15_usize)
+        .add_row(["Go", "2012"])
+        .add_row_with_meta(["C", "1972"], 72_usize)
+        .remove_record(1)
+        .build()
+        .with(Sort::by_column(1))
+        .with(Header("languages"));
+
+    assert_eq!(table.meta::<usize>(0), None);
+    assert_eq!(table.meta::<usize>(1), None);
+    assert_eq!(table.meta::<usize>(2), Some(&72));
+    assert_eq!(table.meta::<usize>(3), Some(&15));
+    assert_eq!(table.meta::<String>(3), None);
+    assert_eq!(table.meta::<usize>(4), None);
+}
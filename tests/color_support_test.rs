@@ -0,0 +1,71 @@
+use tabled::{ColorSupport, Table};
+
+#[test]
+fn color_support_basic() {
+    let data = [
+        "\u{1b}[38;2;255;0;0mred\u{1b}[39m",
+        "\u{1b}[1;48;5;28;38;5;15mbold\u{1b}[0m",
+        "\u{1b}[31mplain\u{1b}[39m",
+    ];
+    let expected = [
+        "\u{1b}[91mred\u{1b}[39m",
+        "\u{1b}[1;42;97mbold\u{1b}[0m",
+        "\u{1b}[31mplain\u{1b}[39m",
+    ];
+
+    let table = Table::new(data).with(ColorSupport::Basic).to_string();
+
+    assert_eq!(table, Table::new(expected).to_string());
+}
+
+#[test]
+fn color_support_ansi256() {
+    let data = [
+        "\u{1b}[38;2;255;0;0mred\u{1b}[39m",
+        "\u{1b}[48;5;208mfixed\u{1b}[49m",
+    ];
+    let expected = [
+        "\u{1b}[38;5;196mred\u{1b}[39m",
+        "\u{1b}[48;5;208mfixed\u{1b}[49m",
+    ];
+
+    let table = Table::new(data).with(ColorSupport::Ansi256).to_string();
+
+    assert_eq!(table, Table::new(expected).to_string());
+}
+
+#[test]
+fn color_support_drops_underline_color() {
+    let data = [
+        "\u{1b}[58;2;0;0;255mtext\u{1b}[59m",
+        "\u{1b}[4;58;5;4mtext\u{1b}[0m",
+    ];
+    let expected = ["text\u{1b}[59m", "\u{1b}[4mtext\u{1b}[0m"];
+
+    let table = Table::new(data).with(ColorSupport::Basic).to_string();
+
+    assert_eq!(table, Table::new(expected).to_string());
+}
+
+#[test]
+fn color_support_none() {
+    let data = [
+        "\u{1b}[38;2;255;0;0mred\u{1b}[39m",
+        "\u{1b}]8;;https://example.com\u{1b}\\link\u{1b}]8;;\u{1b}\\",
+        "\u{1b}[1mbold\u{1b}[22m\nline",
+    ];
+    let expected = ["red", "link", "bold\nline"];
+
+    let table = Table::new(data).with(ColorSupport::None).to_string();
+
+    assert_eq!(table, Table::new(expected).to_string());
+}
+
+#[test]
+fn color_support_truecolor() {
+    let data = ["\u{1b}[38;2;1;2;3mtext\u{1b}[39m"];
+
+    let table = Table::new(data).with(ColorSupport::TrueColor).to_string();
+
+    assert_eq!(table, Table::new(data).to_string());
+}
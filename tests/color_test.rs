@@ -0,0 +1,135 @@
+#[cfg(feature = "color")]
+use tabled::{Color, ColorSupport, Colors, Column, Gradient, Modify, Row, Style, Table};
+
+#[cfg(feature = "color")]
+#[test]
+fn color_downgrade_test() {
+    let color = Color::rgb(12, 200, 90);
+
+    assert_eq!(color.downgrade(ColorSupport::TrueColor), color);
+    assert_eq!(color.downgrade(ColorSupport::Ansi256), Color::fixed(42));
+    assert_eq!(color.downgrade(ColorSupport::Basic), Color::basic(10));
+
+    assert_eq!(
+        Color::rgb(128, 128, 128).downgrade(ColorSupport::Ansi256),
+        Color::fixed(244)
+    );
+    assert_eq!(
+        Color::fixed(196).downgrade(ColorSupport::Basic),
+        Color::basic(9)
+    );
+    assert_eq!(
+        Color::fixed(16).downgrade(ColorSupport::Basic),
+        Color::BLACK
+    );
+    assert_eq!(Color::RED.downgrade(ColorSupport::Ansi256), Color::RED);
+}
+
+#[cfg(feature = "color")]
+#[test]
+fn colorize_test() {
+    assert_eq!(Color::GREEN.colorize("text"), "\u{1b}[32mtext\u{1b}[39m");
+    assert_eq!(
+        Color::basic(12).colorize("text"),
+        "\u{1b}[94mtext\u{1b}[39m"
+    );
+    assert_eq!(
+        Color::fixed(208).colorize("text"),
+        "\u{1b}[38;5;208mtext\u{1b}[39m"
+    );
+    assert_eq!(
+        Color::Basic(255).colorize("text"),
+        "\u{1b}[97mtext\u{1b}[39m"
+    );
+    assert_eq!(Color::Basic(18).to_rgb(), Color::GREEN.to_rgb());
+    assert_eq!(
+        Color::rgb(12, 200, 90).colorize("multi\nline"),
+        "\u{1b}[38;2;12;200;90mmulti\u{1b}[39m\n\u{1b}[38;2;12;200;90mline\u{1b}[39m"
+    );
+}
+
+#[cfg(feature = "color")]
+#[test]
+fn colorize_background_test() {
+    assert_eq!(
+        Color::fg(Color::RED)
+            .bg(Color::BLACK)
+            .colorize("multi\nline"),
+        concat!(
+            "\u{1b}[31m\u{1b}[40mmulti\u{1b}[39m\u{1b}[49m\n",
+            "\u{1b}[31m\u{1b}[40mline\u{1b}[39m\u{1b}[49m",
+        )
+    );
+    assert_eq!(
+        Color::basic(12).bg().colorize("text"),
+        "\u{1b}[104mtext\u{1b}[49m"
+    );
+    assert_eq!(
+        Color::rgb(12, 200, 90).bg().colorize("text"),
+        "\u{1b}[48;2;12;200;90mtext\u{1b}[49m"
+    );
+    assert_eq!(Colors::new().colorize("text"), "text");
+}
+
+#[cfg(feature = "color")]
+#[test]
+fn colors_row_test() {
+    let data = ["Hello", "World"];
+    let table = Table::new(data)
+        .with(Style::psql())
+        .with(Modify::new(Row(..1)).with(Color::fg(Color::RED).bg(Color::BLACK)))
+        .to_string();
+
+    let expected = concat!(
+        " \u{1b}[31m\u{1b}[40m&str\u{1b}[39m\u{1b}[49m  \n",
+        "-------\n",
+        " Hello \n",
+        " World \n",
+    );
+
+    assert_eq!(table, expected);
+}
+
+#[cfg(feature = "color")]
+#[test]
+fn color_column_test() {
+    let data = ["Hello", "World"];
+    let table = Table::new(data)
+        .with(Style::psql())
+        .with(Modify::new(Column(..)).with(Color::BLUE))
+        .to_string();
+
+    let expected = concat!(
+        " \u{1b}[34m&str\u{1b}[39m  \n",
+        "-------\n",
+        " \u{1b}[34mHello\u{1b}[39m \n",
+        " \u{1b}[34mWorld\u{1b}[39m \n",
+    );
+
+    assert_eq!(table, expected);
+}
+
+#[cfg(feature = "color")]
+#[test]
+fn gradient_test() {
+    std::env::set_var("COLORTERM", "truecolor");
+
+    let data = [("a", 10), ("b", 5), ("c", 0)];
+    let table = Table::new(data)
+        .with(Style::psql())
+        .with(
+            Modify::new(Column(..))
+                .with(Gradient::new(Color::rgb(0, 0, 0), Color::rgb(200, 100, 0))),
+        )
+        .to_string();
+
+    let expected = concat!(
+        " &str | i32 \n",
+        "------+-----\n",
+        "  a   | \u{1b}[38;2;200;100;0m10\u{1b}[39m  \n",
+        "  b   |  \u{1b}[38;2;100;50;0m5\u{1b}[39m  \n",
+        "  c   |  \u{1b}[38;2;0;0;0m0\u{1b}[39m  \n",
+    );
+
+    assert_eq!(table, expected);
+}
@@ -1,4 +1,4 @@
-use tabled::{Concat, Style, Table};
+use tabled::{Alignment, Concat, ConcatStyle, Style, Table};
 use util::create_vector;
 
 mod util;
@@ -180,3 +180,125 @@ fn table_join_vertical_with_not_default_empty_string() {
 
     assert_eq!(expected, table3.to_string());
 }
+
+#[test]
+fn table_join_vertical_keep_right_style() {
+    let data1 = create_vector::<2, 2>();
+    let data2 = create_vector::<1, 2>();
+    let table1 = Table::new(&data1).with(Style::psql());
+    let table2 = Table::new(&data2).with(Style::ascii());
+    let table3 = table1.with(Concat::vertical(table2).style(ConcatStyle::KeepRight));
+
+    let expected = concat!(
+        "+---+----------+----------+\n",
+        "| N | column 0 | column 1 |\n",
+        "+---+----------+----------+\n",
+        "| 0 |   0-0    |   0-1    |\n",
+        "+---+----------+----------+\n",
+        "| 1 |   1-0    |   1-1    |\n",
+        "+---+----------+----------+\n",
+        "| N | column 0 | column 1 |\n",
+        "+---+----------+----------+\n",
+        "| 0 |   0-0    |   0-1    |\n",
+        "+---+----------+----------+\n",
+    );
+
+    assert_eq!(table3.to_string(), expected);
+}
+
+#[test]
+fn table_join_horizontal_keep_left_style() {
+    let data1 = create_vector::<2, 2>();
+    let data2 = create_vector::<2, 1>();
+    let table1 = Table::new(&data1).with(Style::ascii());
+    let table2 = Table::new(&data2).with(Style::psql());
+    let table3 = table1.with(Concat::horizontal(table2).style(ConcatStyle::KeepLeft));
+
+    let expected = concat!(
+        "+---+----------+----------+---+----------+\n",
+        "| N | column 0 | column 1 | N | column 0 |\n",
+        "+---+----------+----------+---+----------+\n",
+        "| 0 |   0-0    |   0-1    | 0 |   0-0    |\n",
+        "+---+----------+----------+---+----------+\n",
+        "| 1 |   1-0    |   1-1    | 1 |   1-0    |\n",
+        "+---+----------+----------+---+----------+\n",
+    );
+
+    assert_eq!(table3.to_string(), expected);
+}
+
+#[test]
+fn table_join_horizontal_restyle() {
+    let data1 = create_vector::<1, 1>();
+    let data2 = create_vector::<1, 1>();
+    let table1 = Table::new(&data1).with(Style::ascii());
+    let table2 = Table::new(&data2).with(Style::psql());
+    let table3 = table1
+        .with(Concat::horizontal(table2).style(ConcatStyle::restyle(Style::github_markdown())));
+
+    let expected = concat!(
+        "| N | column 0 | N | column 0 |\n",
+        "|---+----------+---+----------|\n",
+        "| 0 |   0-0    | 0 |   0-0    |\n",
+    );
+
+    assert_eq!(table3.to_string(), expected);
+}
+
+#[test]
+fn table_join_vertical_different_size_aligned() {
+    let data1 = create_vector::<1, 1>();
+    let data2 = create_vector::<1, 3>();
+
+    let table = |alignment| {
+        Table::new(&data1)
+            .with(Style::psql())
+            .with(Concat::vertical(Table::new(&data2).with(Style::psql())).alignment(alignment))
+            .to_string()
+    };
+
+    let expected = concat!(
+        "                   N     | column 0 \n",
+        "               ----------+----------\n",
+        "                   0     |   0-0    \n",
+        " N | column 0 | column 1 | column 2 \n",
+        "---+----------+----------+----------\n",
+        " 0 |   0-0    |   0-1    |   0-2    \n",
+    );
+
+    assert_eq!(expected, table(Alignment::right()));
+
+    let expected = concat!(
+        "        N     | column 0            \n",
+        "    ----------+----------           \n",
+        "        0     |   0-0               \n",
+        " N | column 0 | column 1 | column 2 \n",
+        "---+----------+----------+----------\n",
+        " 0 |   0-0    |   0-1    |   0-2    \n",
+    );
+
+    assert_eq!(expected, table(Alignment::center_horizontal()));
+}
+
+#[test]
+fn table_join_horizontal_different_size_aligned() {
+    let data1 = create_vector::<3, 1>();
+    let data2 = create_vector::<1, 1>();
+
+    let table3 = Table::new(&data1).with(Style::psql()).with(
+        Concat::horizontal(Table::new(&data2).with(Style::psql()))
+            .alignment(Alignment::center_vertical())
+            .default_cell("-"),
+    );
+
+    let expected = concat!(
+        " N | column 0 -   -         \n",
+        "---+----------              \n",
+        " 0 |   0-0     N | column 0 \n",
+        "              ---+----------\n",
+        " 1 |   1-0     0 |   0-0    \n",
+        " 2 |   2-0    -   -         \n",
+    );
+
+    assert_eq!(expected, table3.to_string());
+}
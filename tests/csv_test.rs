@@ -0,0 +1,120 @@
+#[cfg(feature = "csv")]
+use tabled::{
+    builder::{Builder, CsvOptions},
+    Error, Style,
+};
+
+#[cfg(feature = "csv")]
+#[test]
+fn csv_with_header() {
+    let data = "name,released\nDebian,1993\n\"Arch, Linux\",2002\n";
+    let table = Builder::from_csv(data.as_bytes())
+        .unwrap()
+        .build()
+        .with(Style::github_markdown())
+        .to_string();
+
+    let expected = concat!(
+        "|    name     | released |\n",
+        "|-------------+----------|\n",
+        "|   Debian    |   1993   |\n",
+        "| Arch, Linux |   2002   |\n",
+    );
+
+    assert_eq!(table, expected);
+}
+
+#[cfg(feature = "csv")]
+#[test]
+fn tsv_with_different_length_of_records() {
+    let data = "name\treleased\nDebian\t1993\tstable\nArch\n";
+    let table = Builder::from_tsv(data.as_bytes())
+        .unwrap()
+        .build()
+        .with(Style::github_markdown())
+        .to_string();
+
+    let expected = concat!(
+        "|  name  | released |        |\n",
+        "|--------+----------+--------|\n",
+        "| Debian |   1993   | stable |\n",
+        "|  Arch  |          |        |\n",
+    );
+
+    assert_eq!(table, expected);
+}
+
+#[cfg(feature = "csv")]
+#[test]
+fn csv_without_quoting() {
+    let data = "a;\"b\"\n";
+    let table = CsvOptions::new()
+        .delimiter(b';')
+        .quoting(false)
+        .has_header(false)
+        .read(data.as_bytes())
+        .unwrap()
+        .build()
+        .with(Style::github_markdown())
+        .to_string();
+
+    let expected = "| a | \"b\" |\n";
+
+    assert_eq!(table, expected);
+}
+
+#[cfg(feature = "csv")]
+#[test]
+fn csv_detect_header() {
+    let data = "name,released\nDebian,1993\nArch,2002\n";
+    let table = CsvOptions::new()
+        .detect_header()
+        .read(data.as_bytes())
+        .unwrap()
+        .build()
+        .with(Style::github_markdown())
+        .to_string();
+
+    let expected = concat!(
+        "|  name  | released |\n",
+        "|--------+----------|\n",
+        "| Debian |   1993   |\n",
+        "|  Arch  |   2002   |\n",
+    );
+
+    assert_eq!(table, expected);
+}
+
+#[cfg(feature = "csv")]
+#[test]
+fn csv_detect_no_header() {
+    let data = "Debian,1993,stable\nArch,2002,rolling\nGentoo,2000,rolling\n";
+    let table = CsvOptions::new()
+        .detect_header()
+        .read(data.as_bytes())
+        .unwrap()
+        .build();
+
+    assert_eq!(table.shape(), (3, 3));
+    assert_eq!(
+        table.to_string(),
+        [
+            ["Debian", "1993", "stable"],
+            ["Arch", "2002", "rolling"],
+            ["Gentoo", "2000", "rolling"],
+        ]
+        .iter()
+        .collect::<Builder>()
+        .build()
+        .to_string()
+    );
+}
+
+#[cfg(feature = "csv")]
+#[test]
+fn csv_invalid_data() {
+    let data: &[u8] = b"name\n\xff\xfe\n";
+    let result = Builder::from_csv(data);
+
+    assert!(matches!(result, Err(Error::Csv(_))));
+}
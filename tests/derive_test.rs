@@ -159,6 +159,24 @@ mod enum_ {
         assert_eq!(Domain::LENGTH, 4);
     }
 
+    #[test]
+    fn align_variant() {
+        #[allow(dead_code)]
+        #[derive(Tabled)]
+        enum Domain {
+            #[tabled(align = "center")]
+            Security,
+            #[header(inline)]
+            Embeded(#[tabled(align = "right")] u8),
+            Unknown,
+        }
+
+        assert_eq!(
+            "[Some(Horizontal(Center)), Some(Horizontal(Right)), None]",
+            format!("{:?}", Domain::alignments())
+        );
+    }
+
     #[test]
     fn diverse_enum() {
         #[allow(dead_code)]
@@ -372,6 +390,139 @@ mod enum_ {
         );
         assert_eq!(vec!["".to_owned(), "+".to_owned(),], Fact::Unknown.fields());
     }
+
+    #[test]
+    fn kind() {
+        #[allow(dead_code)]
+        #[derive(Tabled)]
+        #[tabled(kind = "type")]
+        enum Shape {
+            Circle {
+                radius: u8,
+            },
+            #[header("Rect")]
+            Rectangle {
+                width: u8,
+                #[tabled(align = "right")]
+                height: u8,
+            },
+            Square {
+                width: u8,
+                #[header(hidden)]
+                id: u8,
+            },
+            Point,
+            #[header(hidden)]
+            Hidden,
+        }
+
+        assert_eq!(Shape::LENGTH, 4);
+        assert_eq!(vec!["type", "radius", "width", "height"], Shape::headers());
+        assert_eq!(
+            "[None, None, None, Some(Horizontal(Right))]",
+            format!("{:?}", Shape::alignments())
+        );
+        assert_eq!(
+            vec!["Circle", "1", "", ""],
+            Shape::Circle { radius: 1 }.fields()
+        );
+        assert_eq!(
+            vec!["Rect", "", "2", "3"],
+            Shape::Rectangle {
+                width: 2,
+                height: 3
+            }
+            .fields()
+        );
+        assert_eq!(
+            vec!["Square", "", "4", ""],
+            Shape::Square { width: 4, id: 5 }.fields()
+        );
+        assert_eq!(vec!["Point", "", "", ""], Shape::Point.fields());
+        assert!(Shape::Hidden.fields().is_empty());
+    }
+
+    #[test]
+    fn kind_with_default_name() {
+        #[allow(dead_code)]
+        #[derive(Tabled)]
+        #[tabled(kind)]
+        enum Event {
+            Message(&'static str),
+            Exit(#[field(display_with = "code")] i32),
+        }
+
+        fn code(code: &i32) -> String {
+            format!("code {}", code)
+        }
+
+        assert_eq!(vec!["kind", "0"], Event::headers());
+        assert_eq!(vec!["Message", "hello"], Event::Message("hello").fields());
+        assert_eq!(vec!["Exit", "code 1"], Event::Exit(1).fields());
+    }
+
+    #[test]
+    fn rename_all() {
+        #[allow(dead_code)]
+        #[derive(Tabled)]
+        #[tabled(rename_all = "kebab-case")]
+        enum Status {
+            InProgress,
+            #[header("DONE")]
+            Done,
+            #[header(inline)]
+            FailedWith {
+                error_code: u8,
+            },
+        }
+
+        assert_eq!(vec!["in-progress", "DONE", "error-code"], Status::headers());
+        assert_eq!(
+            vec!["", "", "3"],
+            Status::FailedWith { error_code: 3 }.fields()
+        );
+    }
+
+    #[test]
+    fn display_types() {
+        fn fmt_bool(value: &bool) -> String {
+            if *value { "yes" } else { "no" }.to_string()
+        }
+
+        #[derive(Tabled)]
+        #[tabled(display_types(bool = "fmt_bool"))]
+        enum Status {
+            #[header(inline)]
+            Done { ok: bool },
+            #[header(inline("failed::"))]
+            Failed { retried: bool, code: u8 },
+        }
+
+        assert_eq!(vec!["yes", "", ""], Status::Done { ok: true }.fields());
+        assert_eq!(
+            vec!["", "no", "3"],
+            Status::Failed {
+                retried: false,
+                code: 3
+            }
+            .fields()
+        );
+    }
+
+    #[test]
+    fn display_types_in_kind_mode() {
+        fn fmt_bool(value: &bool) -> String {
+            if *value { "yes" } else { "no" }.to_string()
+        }
+
+        #[derive(Tabled)]
+        #[tabled(kind, display_types(bool = "fmt_bool"))]
+        enum Status {
+            Done { ok: bool },
+        }
+
+        assert_eq!(vec!["Done", "yes"], Status::Done { ok: true }.fields());
+    }
 }
 
 mod unit {
@@ -553,6 +704,74 @@ mod structure {
     }
 
     #[allow(dead_code)]
+    #[test]
+    fn tabled_inline_with_nested_prefix() {
+        #[derive(Tabled)]
+        struct Person {
+            name: &'static str,
+            #[tabled(inline("ed_"))]
+            ed: Education,
+        }
+
+        #[derive(Tabled)]
+        struct Education {
+            uni: &'static str,
+            #[tabled(inline)]
+            address: Address,
+            #[tabled(inline("years."))]
+            years: (u16, u16),
+        }
+
+        #[derive(Tabled)]
+        struct Address {
+            city: &'static str,
+        }
+
+        let p = Person {
+            name: "Maxim",
+            ed: Education {
+                uni: "BNTU",
+                address: Address { city: "Minsk" },
+                years: (2015, 2019),
+            },
+        };
+
+        assert_eq!(vec!["Maxim", "BNTU", "Minsk", "2015", "2019"], p.fields());
+        assert_eq!(
+            vec!["name", "ed_uni", "ed_city", "ed_years.u16", "ed_years.u16"],
+            Person::headers()
+        );
+        assert_eq!(Person::LENGTH, 5);
+    }
+
+    #[test]
+    fn rename_all() {
+        #[derive(Tabled)]
+        #[tabled(rename_all = "UPPERCASE")]
+        struct Upper {
+            first_name: &'static str,
+            #[header("Age")]
+            age: u8,
+        }
+
+        #[derive(Tabled)]
+        #[tabled(rename_all = "Title Case")]
+        struct Title {
+            first_name: &'static str,
+            r#type: u8,
+        }
+
+        #[derive(Tabled)]
+        #[tabled(rename_all = "camelCase")]
+        struct Camel {
+            first_name: &'static str,
+        }
+
+        assert_eq!(vec!["FIRST_NAME", "Age"], Upper::headers());
+        assert_eq!(vec!["First Name", "Type"], Title::headers());
+        assert_eq!(vec!["firstName"], Camel::headers());
+    }
+
     #[test]
     fn display_with() {
         fn display_option(o: &Option<&'static str>) -> String {
@@ -578,6 +797,31 @@ mod structure {
         assert_eq!(vec!["f1".to_owned(), "f2".to_owned()], St::headers());
     }
 
+    #[test]
+    fn tabled_display_with() {
+        fn join(v: &[u8]) -> String {
+            v.iter()
+                .map(|n| n.to_string())
+                .collect::<Vec<_>>()
+                .join(",")
+        }
+
+        #[derive(Tabled)]
+        struct St {
+            f1: u8,
+            #[tabled(display_with = "join")]
+            f2: Vec<u8>,
+        }
+
+        let st = St {
+            f1: 0,
+            f2: vec![1, 2, 3],
+        };
+
+        assert_eq!(vec!["0".to_owned(), "1,2,3".to_owned()], st.fields());
+        assert_eq!(vec!["f1".to_owned(), "f2".to_owned()], St::headers());
+    }
+
     #[allow(dead_code)]
     #[test]
     fn display_with_self_static_method() {
@@ -605,6 +849,137 @@ mod structure {
         assert_eq!(vec!["0".to_owned(), "some v2".to_owned()], st.fields());
         assert_eq!(vec!["f1".to_owned(), "f2".to_owned()], St::headers());
     }
+
+    #[test]
+    fn align() {
+        #[derive(Tabled)]
+        struct St {
+            #[tabled(align = "left")]
+            f1: u8,
+            f2: u8,
+            #[tabled(align = "right")]
+            f3: u8,
+        }
+
+        assert_eq!(
+            "[Some(Horizontal(Left)), None, Some(Horizontal(Right))]",
+            format!("{:?}", St::alignments())
+        );
+    }
+
+    #[test]
+    fn align_inline() {
+        #[derive(Tabled)]
+        struct Inner {
+            #[tabled(align = "center")]
+            f1: u8,
+        }
+
+        #[derive(Tabled)]
+        struct St {
+            #[tabled(align = "right")]
+            f1: u8,
+            #[header(inline)]
+            f2: Inner,
+        }
+
+        assert_eq!(
+            "[Some(Horizontal(Right)), Some(Horizontal(Center))]",
+            format!("{:?}", St::alignments())
+        );
+    }
+
+    #[test]
+    fn align_is_applied_to_table() {
+        #[derive(Tabled)]
+        struct St {
+            #[tabled(align = "left")]
+            name: &'static str,
+            #[tabled(align = "right")]
+            value: u32,
+        }
+
+        let table = tabled::Table::new([
+            St {
+                name: "weight",
+                value: 443,
+            },
+            St {
+                name: "id",
+                value: 1,
+            },
+        ])
+        .to_string();
+
+        let expected = "+--------+-------+\n\
+                        | name   | value |\n\
+                        +--------+-------+\n\
+                        | weight |   443 |\n\
+                        +--------+-------+\n\
+                        | id     |     1 |\n\
+                        +--------+-------+\n";
+
+        assert_eq!(table, expected);
+    }
+
+    #[test]
+    fn display_types() {
+        fn fmt_bool(value: &bool) -> String {
+            if *value { "yes" } else { "no" }.to_string()
+        }
+
+        fn fmt_f64(value: &f64) -> String {
+            format!("{:.2}", value)
+        }
+
+        #[derive(Tabled)]
+        #[tabled(display_types(bool = "fmt_bool", f64 = "fmt_f64"))]
+        struct St {
+            active: bool,
+            visible: bool,
+            ratio: f64,
+            #[tabled(display_with = "fmt_ratio")]
+            total: f64,
+            count: f32,
+        }
+
+        fn fmt_ratio(value: &f64) -> String {
+            format!("{}%", value * 100.0)
+        }
+
+        let st = St {
+            active: true,
+            visible: false,
+            ratio: 0.12345,
+            total: 0.5,
+            count: 1.5,
+        };
+
+        assert_eq!(vec!["yes", "no", "0.12", "50%", "1.5"], st.fields());
+    }
+
+    #[test]
+    fn display_types_with_path() {
+        #[derive(Tabled)]
+        #[tabled(display_types(std::string::String = "Self::quote"))]
+        struct St {
+            name: std::string::String,
+            value: u8,
+        }
+
+        impl St {
+            fn quote(value: &str) -> String {
+                format!("{:?}", value)
+            }
+        }
+
+        let st = St {
+            name: String::from("tabled"),
+            value: 1,
+        };
+
+        assert_eq!(vec!["\"tabled\"", "1"], st.fields());
+    }
 }
 
 #[test]
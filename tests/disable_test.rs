@@ -1,5 +1,5 @@
 use crate::util::create_vector;
-use tabled::{Alignment, Disable, Full, Modify, Style, Table};
+use tabled::{Alignment, Cell, Disable, Full, Modify, Span, Style, Table};
 
 mod util;
 
@@ -105,3 +105,160 @@ fn disable_all_table_via_columns() {
 
     assert_eq!(table, "");
 }
+
+#[test]
+fn disable_column_under_span() {
+    let data = create_vector::<2, 3>();
+    let table = Table::new(&data)
+        .with(Modify::new(Cell(1, 1)).with(Span::column(3)))
+        .with(Disable::Column(1..2))
+        .with(Style::psql())
+        .to_string();
+
+    let expected = concat!(
+        " N | column 1 | column 2 \n",
+        "---+----------+----------\n",
+        " 0 |         0-0         \n",
+        " 1 |   1-1    |   1-2    \n",
+    );
+
+    assert_eq!(table, expected);
+}
+
+#[test]
+fn disable_columns_by_name() {
+    let data = create_vector::<3, 3>();
+    let table = Table::new(&data)
+        .with(Disable::column_by_name(["N", "column 1", "unknown"]))
+        .with(Style::psql())
+        .to_string();
+
+    let expected = concat!(
+        " column 0 | column 2 \n",
+        "----------+----------\n",
+        "   0-0    |   0-2    \n",
+        "   1-0    |   1-2    \n",
+        "   2-0    |   2-2    \n",
+    );
+
+    assert_eq!(table, expected);
+}
+
+#[test]
+fn disable_columns_by_unknown_name() {
+    let data = create_vector::<1, 2>();
+    let table = Table::new(&data)
+        .with(Disable::column_by_name(["unknown"]))
+        .with(Style::psql())
+        .to_string();
+
+    let expected = concat!(
+        " N | column 0 | column 1 \n",
+        "---+----------+----------\n",
+        " 0 |   0-0    |   0-1    \n",
+    );
+
+    assert_eq!(table, expected);
+}
+
+#[test]
+fn disable_columns_by_range_of_names() {
+    let data = create_vector::<2, 3>();
+    let table = Table::new(&data)
+        .with(Disable::column_range_by_name("column 0", "column 1"))
+        .with(Style::psql())
+        .to_string();
+
+    let expected = concat!(
+        " N | column 2 \n",
+        "---+----------\n",
+        " 0 |   0-2    \n",
+        " 1 |   1-2    \n",
+    );
+
+    assert_eq!(table, expected);
+
+    let table = Table::new(&data)
+        .with(Disable::column_range_by_name("column 1", "column 0"))
+        .with(Style::psql())
+        .to_string();
+
+    let expected = concat!(
+        " N | column 0 | column 1 | column 2 \n",
+        "---+----------+----------+----------\n",
+        " 0 |   0-0    |   0-1    |   0-2    \n",
+        " 1 |   1-0    |   1-1    |   1-2    \n",
+    );
+
+    assert_eq!(table, expected);
+}
+
+#[test]
+fn disable_column_by_name_under_span() {
+    let data = create_vector::<2, 3>();
+    let table = Table::new(&data)
+        .with(Modify::new(Cell(1, 1)).with(Span::column(3)))
+        .with(Disable::column_by_name(["column 0"]))
+        .with(Style::psql())
+        .to_string();
+
+    let expected = concat!(
+        " N | column 1 | column 2 \n",
+        "---+----------+----------\n",
+        " 0 |         0-0         \n",
+        " 1 |   1-1    |   1-2    \n",
+    );
+
+    assert_eq!(table, expected);
+}
+
+#[test]
+fn disable_columns_by_range_of_names_across_span() {
+    let data = create_vector::<2, 3>();
+    let table = Table::new(&data)
+        .with(Modify::new(Cell(1, 0)).with(Span::column(3)))
+        .with(Disable::column_range_by_name("column 0", "column 1"))
+        .with(Style::psql())
+        .to_string();
+
+    let expected = concat!(
+        " N | column 2 \n",
+        "---+----------\n",
+        " 0 |   0-2    \n",
+        " 1 |   1-2    \n",
+    );
+
+    assert_eq!(table, expected);
+}
+
+#[test]
+fn disable_rows_under_span_beyond_table() {
+    let data = create_vector::<2, 2>();
+    let table = Table::new(&data)
+        .with(Modify::new(Cell(2, 0)).with(Span::row(2)))
+        .with(Disable::Row(2..))
+        .with(Style::psql())
+        .to_string();
+
+    let expected = concat!(
+        " N | column 0 | column 1 \n",
+        "---+----------+----------\n",
+        " 0 |   0-0    |   0-1    \n",
+    );
+
+    assert_eq!(table, expected);
+}
+
+#[test]
+fn disable_columns_under_span_beyond_table() {
+    let data = create_vector::<2, 2>();
+    let table = Table::new(&data)
+        .with(Modify::new(Cell(1, 2)).with(Span::column(2)))
+        .with(Disable::Column(1..))
+        .with(Style::psql())
+        .to_string();
+
+    let expected = concat!(" N \n", "---\n", " 0 \n", " 1 \n",);
+
+    assert_eq!(table, expected);
+}
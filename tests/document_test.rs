@@ -0,0 +1,85 @@
+#[cfg(any(feature = "toml", feature = "serde_yaml"))]
+use tabled::{builder::Builder, Style};
+
+#[cfg(feature = "toml")]
+#[test]
+fn toml_table() {
+    let value: toml::Value = toml::from_str(
+        r#"
+        name = "tabled"
+        keywords = ["table", "print"]
+
+        [dependencies]
+        papergrid = "0.1"
+        "#,
+    )
+    .unwrap();
+
+    let table = Builder::from_toml(&value)
+        .build()
+        .with(Style::psql())
+        .to_string();
+
+    let expected = concat!(
+        "     key      |       value        \n",
+        "--------------+--------------------\n",
+        " dependencies | papergrid = \"0.1\"  \n",
+        "   keywords   | [\"table\", \"print\"] \n",
+        "     name     |       tabled       \n",
+    );
+
+    assert_eq!(table, expected);
+}
+
+#[cfg(feature = "toml")]
+#[test]
+fn toml_array() {
+    let value: toml::Value = toml::from_str("values = [1, 2]").unwrap();
+    let table = Builder::from_toml(&value["values"])
+        .build()
+        .with(Style::psql())
+        .to_string();
+
+    assert_eq!(table, " value \n-------\n   1   \n   2   \n");
+}
+
+#[cfg(feature = "serde_yaml")]
+#[test]
+fn yaml_records() {
+    let value: serde_yaml::Value = serde_yaml::from_str(
+        "
+        - name: alpha
+          port: 8080
+        - name: beta
+          tags: [a, b]
+        ",
+    )
+    .unwrap();
+
+    let table = Builder::from_yaml(&value)
+        .build()
+        .with(Style::psql())
+        .to_string();
+
+    let expected = concat!(
+        " name  | port | tags \n",
+        "-------+------+------\n",
+        " alpha | 8080 |      \n",
+        " beta  |      | - a  \n",
+        "       |      | - b  \n",
+    );
+
+    assert_eq!(table, expected);
+}
+
+#[cfg(feature = "serde_yaml")]
+#[test]
+fn yaml_scalar() {
+    let value: serde_yaml::Value = serde_yaml::from_str("~").unwrap();
+    let table = Builder::from_yaml(&value)
+        .build()
+        .with(Style::psql())
+        .to_string();
+
+    assert_eq!(table, " value \n-------\n       \n");
+}
@@ -0,0 +1,70 @@
+use tabled::{ext, grid::Grid, Cell, Column, Format, Modify, Row, Span, Style, Table};
+
+#[test]
+fn ext_cells_are_bounded() {
+    let table = Table::new([("a", "b")]).with(ext::option(|grid: &mut Grid| {
+        assert_eq!(ext::cells(grid, &Column(1..10)), vec![(0, 1), (1, 1)]);
+        assert_eq!(ext::cells(grid, &Cell(5, 0)), vec![]);
+    }));
+
+    assert_eq!(table.shape(), (2, 2));
+}
+
+#[test]
+fn ext_for_each_cell() {
+    let table = Table::new([("a", "b"), ("c", "d")])
+        .with(Style::psql())
+        .with(ext::option(|grid: &mut Grid| {
+            ext::for_each_cell(grid, &Row(1..), |mut cell| {
+                let (row, column) = cell.position();
+                let text = format!("{}{}{}", cell.text(), row, column);
+                cell.set_text(text);
+            })
+        }))
+        .to_string();
+
+    let expected = concat!(
+        " &str | &str \n",
+        "------+------\n",
+        " a10  | b11  \n",
+        " c20  | d21  \n",
+    );
+
+    assert_eq!(table, expected);
+}
+
+#[test]
+fn ext_reshape_transpose() {
+    let table = Table::new([("a", "b"), ("c", "d")])
+        .with(ext::option(|grid: &mut Grid| {
+            let shape = (grid.count_columns(), grid.count_rows());
+            ext::reshape(grid, shape, |row, column| Some((column, row)));
+        }))
+        .with(Style::psql())
+        .to_string();
+
+    let expected = concat!(" &str | a | c \n", "------+---+---\n", " &str | b | d \n",);
+
+    assert_eq!(table, expected);
+}
+
+#[test]
+fn ext_column_name_under_span() {
+    let table = Table::new([("a", "b", "c")])
+        .with(
+            Modify::new(Cell(0, 0))
+                .with(Format(|_| "wide".to_owned()))
+                .with(Span::column(2)),
+        )
+        .with(ext::option(|grid: &mut Grid| {
+            assert_eq!(ext::column_name(grid, 0), "wide");
+            assert_eq!(ext::column_name(grid, 1), "wide");
+            assert_eq!(ext::column_name(grid, 2), "&str");
+            assert_eq!(ext::column_name(grid, 3), "");
+
+            grid.set_header_rows(0);
+            assert_eq!(ext::column_name(grid, 0), "");
+        }));
+
+    assert_eq!(table.shape(), (2, 3));
+}
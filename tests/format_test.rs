@@ -1,7 +1,7 @@
 use crate::util::create_vector;
 use tabled::{
-    multiline, Alignment, Cell, Column, Format, FormatFrom, FormatWithIndex, Full, Head, Indent,
-    Modify, Object, Row, Style, Table,
+    multiline, Alignment, Cell, Column, ControlChars, Format, FormatFrom, FormatWithIndex,
+    FormatWithName, Full, Group, Head, Indent, Modify, Newlines, Object, Row, Style, Table,
 };
 
 mod util;
@@ -323,6 +323,49 @@ mod color {
     }
 }
 
+#[test]
+fn format_with_name() {
+    let data = create_vector::<2, 2>();
+    let table = Table::new(&data)
+        .with(Style::psql())
+        .with(Modify::new(Full).with(FormatWithName::new(|name, value| {
+            format!("{}:{}", name.len(), value)
+        })))
+        .to_string();
+
+    let expected = concat!(
+        " 1:N | 8:column 0 | 8:column 1 \n",
+        "-----+------------+------------\n",
+        " 1:0 |   8:0-0    |   8:0-1    \n",
+        " 1:1 |   8:1-0    |   8:1-1    \n",
+    );
+
+    assert_eq!(table, expected);
+}
+
+#[test]
+fn format_with_name_uses_last_header_row() {
+    let data = create_vector::<1, 2>();
+    let table = Table::new(&data)
+        .with(Group::new("group", 1..3))
+        .with(Style::psql())
+        .with(
+            Modify::new(Row(2..)).with(FormatWithName::new(|name, value| {
+                format!("{}={}", name, value)
+            })),
+        )
+        .to_string();
+
+    let expected = concat!(
+        "     |            group            \n",
+        "  N  |   column 0   |   column 1   \n",
+        "-----+--------------+--------------\n",
+        " N=0 | column 0=0-0 | column 1=0-1 \n",
+    );
+
+    assert_eq!(table, expected);
+}
+
 #[test]
 fn format_doesnt_change_indent() {
     let data = create_vector::<3, 3>();
@@ -346,3 +389,69 @@ fn format_doesnt_change_indent() {
 
     assert_eq!(table, expected);
 }
+
+#[test]
+fn newlines_escape_and_render_test() {
+    let data = [("log", "line 1\nline 2\r\nline 3")];
+    let table = Table::new(data)
+        .with(Style::psql())
+        .with(Modify::new(Row(1..)).with(Newlines::Escape))
+        .to_string();
+
+    let expected = concat!(
+        " &str |           &str           \n",
+        "------+--------------------------\n",
+        " log  | line 1\\nline 2\\r\\nline 3 \n",
+    );
+
+    assert_eq!(table, expected);
+
+    let table = Table::new(data)
+        .with(Style::psql())
+        .with(Modify::new(Row(1..)).with(Newlines::Escape))
+        .with(Modify::new(Row(1..)).with(Newlines::Render))
+        .to_string();
+
+    let expected = concat!(
+        " &str |  &str  \n",
+        "------+--------\n",
+        " log  | line 1 \n",
+        "      | line 2 \n",
+        "      | line 3 \n",
+    );
+
+    assert_eq!(table, expected);
+}
+
+#[test]
+fn control_chars_test() {
+    let data = [("\x1b[31m", "a\r\nb\u{7f}\u{85}")];
+
+    let table = Table::new(data)
+        .with(Style::psql())
+        .with(Modify::new(Row(1..)).with(ControlChars::Pictures))
+        .to_string();
+
+    let expected = concat!(
+        " &str  |  &str  \n",
+        "-------+--------\n",
+        " ␛[31m |   a␍   \n",
+        "       | b␡\\x85 \n",
+    );
+
+    assert_eq!(table, expected);
+
+    let table = Table::new(data)
+        .with(Style::psql())
+        .with(Modify::new(Row(1..)).with(ControlChars::Escape))
+        .to_string();
+
+    let expected = concat!(
+        "   &str   |   &str    \n",
+        "----------+-----------\n",
+        " \\x1B[31m |   a\\x0D   \n",
+        "          | b\\x7F\\x85 \n",
+    );
+
+    assert_eq!(table, expected);
+}
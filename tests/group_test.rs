@@ -0,0 +1,102 @@
+use tabled::{Disable, Group, Missing, SelectColumns, Style, Table, Tabled};
+
+#[test]
+fn group_row() {
+    let data = [
+        ("Rust", 2010, 2015, "Graydon Hoare"),
+        ("Go", 2009, 2012, "Rob Pike"),
+    ];
+    let table = Table::new(data)
+        .with(Group::new("released", 1..3).and("author", 3..))
+        .with(Group::new("language", ..))
+        .with(Style::ascii())
+        .to_string();
+
+    let expected = concat!(
+        "+------+------+------+---------------+\n",
+        "|              language              |\n",
+        "+------+------+------+---------------+\n",
+        "|      |  released   |    author     |\n",
+        "+------+------+------+---------------+\n",
+        "| &str | i32  | i32  |     &str      |\n",
+        "+------+------+------+---------------+\n",
+        "| Rust | 2010 | 2015 | Graydon Hoare |\n",
+        "+------+------+------+---------------+\n",
+        "|  Go  | 2009 | 2012 |   Rob Pike    |\n",
+        "+------+------+------+---------------+\n",
+    );
+
+    assert_eq!(table, expected);
+}
+
+#[test]
+fn group_with_removed_columns() {
+    let data = [
+        ("Rust", 2010, 2015, "Graydon Hoare"),
+        ("Go", 2009, 2012, "Rob Pike"),
+    ];
+    let table = Table::new(data)
+        .with(Group::new("released", 1..3).and("author", 3..))
+        .with(Disable::Column(1..2))
+        .with(Style::ascii())
+        .to_string();
+
+    let expected = concat!(
+        "+------+----------+---------------+\n",
+        "|      | released |    author     |\n",
+        "+------+----------+---------------+\n",
+        "| &str |   i32    |     &str      |\n",
+        "+------+----------+---------------+\n",
+        "| Rust |   2015   | Graydon Hoare |\n",
+        "+------+----------+---------------+\n",
+        "|  Go  |   2012   |   Rob Pike    |\n",
+        "+------+----------+---------------+\n",
+    );
+
+    assert_eq!(table, expected);
+}
+
+#[test]
+fn group_with_reordered_columns() {
+    #[derive(Tabled)]
+    struct Language {
+        name: &'static str,
+        first: usize,
+        stable: usize,
+    }
+
+    let data = [
+        Language {
+            name: "Rust",
+            first: 2010,
+            stable: 2015,
+        },
+        Language {
+            name: "Go",
+            first: 2009,
+            stable: 2012,
+        },
+    ];
+    let table = Table::new(data)
+        .with(Group::new("released", 1..3))
+        .with(SelectColumns::by_names(
+            ["first", "name", "stable"],
+            Missing::Skip,
+        ))
+        .with(Style::ascii())
+        .to_string();
+
+    let expected = concat!(
+        "+----------+------+--------+\n",
+        "| released |      |        |\n",
+        "+----------+------+--------+\n",
+        "|  first   | name | stable |\n",
+        "+----------+------+--------+\n",
+        "|   2010   | Rust |  2015  |\n",
+        "+----------+------+--------+\n",
+        "|   2009   |  Go  |  2012  |\n",
+        "+----------+------+--------+\n",
+    );
+
+    assert_eq!(table, expected);
+}
@@ -0,0 +1,304 @@
+use crate::util::create_vector;
+use tabled::{
+    Alignment, Cell, Ellipsis, Full, Height, HeightStrategy, Indent, LineSpacing, MaxHeight,
+    MinHeight, Modify, Row, Span, Style, Table,
+};
+
+mod util;
+
+#[test]
+fn max_height() {
+    let mut data = create_vector::<2, 2>();
+    data[0][1] = String::from("0-0\n0-0\n0-0");
+    data[1][2] = String::from("1-1\n1-1");
+
+    let table = Table::new(&data)
+        .with(Style::psql())
+        .with(Modify::new(Row(1..)).with(MaxHeight::new(2)))
+        .to_string();
+
+    let expected = concat!(
+        " N | column 0 | column 1 \n",
+        "---+----------+----------\n",
+        " 0 |   0-0    |   0-1    \n",
+        "   |   0-0    |          \n",
+        " 1 |   1-0    |   1-1    \n",
+        "   |          |   1-1    \n",
+    );
+
+    assert_eq!(table, expected);
+}
+
+#[test]
+fn max_height_ellipsis() {
+    let mut data = create_vector::<1, 2>();
+    data[0][1] = String::from("0-0\n0-0\n0-0");
+    data[0][2] = String::from("0-1\n0-1\n0-1");
+
+    let table = Table::new(&data)
+        .with(Style::psql())
+        .with(Modify::new(Row(1..)).with(MaxHeight::new(2).ellipsis(Ellipsis::Line("…".into()))))
+        .with(Modify::new(Full).with(MaxHeight::new(1).ellipsis(Ellipsis::Suffix("…".into()))))
+        .to_string();
+
+    let expected = concat!(
+        " N | column 0 | column 1 \n",
+        "---+----------+----------\n",
+        " 0 |   0-0…   |   0-1…   \n",
+    );
+
+    assert_eq!(table, expected);
+
+    let table = Table::new(&data)
+        .with(Style::psql())
+        .with(Modify::new(Row(1..)).with(MaxHeight::new(2).ellipsis(Ellipsis::Line("…".into()))))
+        .to_string();
+
+    let expected = concat!(
+        " N | column 0 | column 1 \n",
+        "---+----------+----------\n",
+        " 0 |   0-0    |   0-1    \n",
+        "   |    …     |    …     \n",
+    );
+
+    assert_eq!(table, expected);
+}
+
+#[test]
+fn max_height_zero() {
+    let data = create_vector::<1, 1>();
+    let table = Table::new(&data)
+        .with(Style::psql())
+        .with(Modify::new(Row(1..)).with(MaxHeight::new(0).ellipsis(Ellipsis::Suffix("…".into()))))
+        .to_string();
+
+    let expected = concat!(" N | column 0 \n", "---+----------\n", "   |          \n",);
+
+    assert_eq!(table, expected);
+}
+
+#[test]
+fn max_height_strategy() {
+    let table = |strategy| {
+        Table::new(["1\n\n2\n3\n\n4"])
+            .with(Style::psql())
+            .with(
+                Modify::new(Row(1..)).with(
+                    MaxHeight::new(3)
+                        .strategy(strategy)
+                        .ellipsis(Ellipsis::Suffix("~".into())),
+                ),
+            )
+            .to_string()
+    };
+
+    assert_eq!(
+        table(HeightStrategy::ClipBottom),
+        " &str \n------\n  1   \n      \n  2~  \n"
+    );
+    assert_eq!(
+        table(HeightStrategy::ClipTop),
+        " &str \n------\n  3~  \n      \n  4   \n"
+    );
+    assert_eq!(
+        table(HeightStrategy::Scroll(2)),
+        " &str \n------\n  2~  \n  3   \n  ~   \n"
+    );
+    assert_eq!(
+        table(HeightStrategy::Scroll(10)),
+        table(HeightStrategy::ClipTop)
+    );
+    assert_eq!(
+        table(HeightStrategy::Squeeze),
+        " &str \n------\n  1   \n  2   \n  3~  \n"
+    );
+}
+
+#[test]
+fn min_height() {
+    let data = create_vector::<2, 2>();
+    let table = Table::new(&data)
+        .with(Style::psql())
+        .with(Modify::new(Cell(1, 1)).with(MinHeight::new(3)))
+        .with(Modify::new(Full).with(Alignment::bottom()))
+        .to_string();
+
+    let expected = concat!(
+        " N | column 0 | column 1 \n",
+        "---+----------+----------\n",
+        "   |          |          \n",
+        "   |          |          \n",
+        " 0 |   0-0    |   0-1    \n",
+        " 1 |   1-0    |   1-1    \n",
+    );
+
+    assert_eq!(table, expected);
+}
+
+#[test]
+fn min_height_includes_indent() {
+    let data = create_vector::<1, 1>();
+    let table = Table::new(&data)
+        .with(Style::psql())
+        .with(Modify::new(Row(1..)).with(Indent::new(1, 1, 1, 0)))
+        .with(Modify::new(Row(1..)).with(MinHeight::new(2)))
+        .to_string();
+
+    let expected = concat!(
+        " N | column 0 \n",
+        "---+----------\n",
+        "   |          \n",
+        " 0 |   0-0    \n",
+    );
+
+    assert_eq!(table, expected);
+}
+
+#[test]
+fn min_height_spanned_cell() {
+    let data = create_vector::<2, 1>();
+    let table = Table::new(&data)
+        .with(Style::ascii())
+        .with(Modify::new(Cell(1, 0)).with(Span::row(2)))
+        .with(Modify::new(Cell(1, 0)).with(MinHeight::new(5)))
+        .to_string();
+
+    let expected = concat!(
+        "+---+----------+\n",
+        "| N | column 0 |\n",
+        "+---+----------+\n",
+        "| 0 |   0-0    |\n",
+        "|   +----------+\n",
+        "|   |   1-0    |\n",
+        "|   |          |\n",
+        "|   |          |\n",
+        "+---+----------+\n",
+    );
+
+    assert_eq!(table, expected);
+}
+
+#[test]
+fn height_limit() {
+    let data = create_vector::<4, 1>();
+
+    let table = Table::new(&data)
+        .with(Style::ascii())
+        .with(Height::limit(9))
+        .to_string();
+
+    let expected = concat!(
+        "+----+----------+\n",
+        "| N  | column 0 |\n",
+        "+----+----------+\n",
+        "| 0  |   0-0    |\n",
+        "+----+----------+\n",
+        "| 1  |   1-0    |\n",
+        "+----+----------+\n",
+        "| … 2 more rows |\n",
+        "+----+----------+\n",
+    );
+
+    assert_eq!(table, expected);
+
+    let mut data = data;
+    data[3][1] = String::from("3-0\n3-0");
+
+    let table = Table::new(&data)
+        .with(Style::psql())
+        .with(Height::limit(6))
+        .to_string();
+
+    let expected = concat!(
+        " N | column 0 \n",
+        "---+----------\n",
+        " 0 |   0-0    \n",
+        " 1 |   1-0    \n",
+        " 2 |   2-0    \n",
+        " … 1 more row \n",
+    );
+
+    assert_eq!(table, expected);
+}
+
+#[test]
+fn height_limit_fit() {
+    let data = create_vector::<4, 1>();
+    let table = Table::new(&data).with(Style::psql());
+    let expected = table.to_string();
+
+    assert_eq!(table.with(Height::limit(6)).to_string(), expected);
+}
+
+#[test]
+fn height_limit_keeps_header() {
+    let data = create_vector::<2, 1>();
+    let table = Table::new(&data)
+        .with(Style::psql())
+        .with(Height::limit(3))
+        .to_string();
+
+    let expected = concat!(
+        " N  | column 0 \n",
+        "----+----------\n",
+        " … 2 more rows \n",
+    );
+
+    assert_eq!(table, expected);
+}
+
+#[test]
+fn height_limit_below_header() {
+    let data = create_vector::<2, 1>();
+
+    let table = Table::new(&data)
+        .with(Style::psql())
+        .with(Height::limit(2))
+        .to_string();
+
+    assert_eq!(table, " … 2 more rows \n");
+
+    let table = Table::new(&data)
+        .with(Style::ascii())
+        .with(Height::limit(2))
+        .to_string();
+
+    assert_eq!(table, "");
+
+    let table = Table::new(&data)
+        .with(Style::psql())
+        .with(Height::limit(0))
+        .to_string();
+
+    assert_eq!(table, "");
+}
+
+#[test]
+fn height_limit_without_rows() {
+    let table = Table::new(Vec::<&str>::new());
+    let expected = table.to_string();
+
+    assert_eq!(table.with(Height::limit(1)).to_string(), expected);
+}
+
+#[test]
+fn height_limit_keeps_line_spacing() {
+    let data = create_vector::<4, 1>();
+
+    let table = Table::new(&data)
+        .with(Style::psql())
+        .with(LineSpacing(1))
+        .with(Height::limit(6))
+        .to_string();
+
+    let expected = concat!(
+        " N  | column 0 \n",
+        "----+----------\n",
+        " 0  |   0-0    \n",
+        "    |          \n",
+        " … 3 more rows \n",
+        "               \n",
+    );
+
+    assert_eq!(table, expected);
+}
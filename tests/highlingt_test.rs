@@ -1,5 +1,7 @@
 use crate::util::create_vector;
-use tabled::{Border, Highlight, Style, Table};
+use tabled::{
+    Border, Cell, ClearHighlight, Column, Highlight, Indent, Modify, Object, Row, Style, Table,
+};
 
 mod util;
 
@@ -173,3 +175,148 @@ fn highlingt_frame() {
 
     assert_eq!(table, expected);
 }
+
+#[test]
+fn highlingt_is_kept_after_style_change() {
+    let data = create_vector::<2, 2>();
+    let table = Table::new(&data)
+        .with(Style::modern())
+        .with(Highlight::cell(
+            1,
+            1,
+            Border::full('*', '*', '*', '*', '*', '*', '*', '*'),
+        ))
+        .with(Style::ascii())
+        .to_string();
+
+    let expected = concat!(
+        "+---+----------+----------+\n",
+        "| N | column 0 | column 1 |\n",
+        "+---************----------+\n",
+        "| 0 *   0-0    *   0-1    |\n",
+        "+---************----------+\n",
+        "| 1 |   1-0    |   1-1    |\n",
+        "+---+----------+----------+\n",
+    );
+
+    assert_eq!(table, expected);
+}
+
+#[test]
+fn highlingt_is_removed_by_style_change_after_clear() {
+    let data = create_vector::<2, 2>();
+    let table = Table::new(&data)
+        .with(Style::modern())
+        .with(Highlight::cell(
+            1,
+            1,
+            Border::full('*', '*', '*', '*', '*', '*', '*', '*'),
+        ))
+        .with(ClearHighlight)
+        .with(Style::ascii())
+        .to_string();
+
+    let expected = concat!(
+        "+---+----------+----------+\n",
+        "| N | column 0 | column 1 |\n",
+        "+---+----------+----------+\n",
+        "| 0 |   0-0    |   0-1    |\n",
+        "+---+----------+----------+\n",
+        "| 1 |   1-0    |   1-1    |\n",
+        "+---+----------+----------+\n",
+    );
+
+    assert_eq!(table, expected);
+}
+
+#[test]
+fn highlingt_fill_column() {
+    let data = create_vector::<2, 2>();
+    let table = Table::new(&data)
+        .with(Style::ascii())
+        .with(Highlight::fill(Column(1..2), '#'))
+        .to_string();
+
+    let expected = concat!(
+        "+---+----------+----------+\n",
+        "| N |#column 0#| column 1 |\n",
+        "+---+----------+----------+\n",
+        "| 0 |###0-0####|   0-1    |\n",
+        "+---+----------+----------+\n",
+        "| 1 |###1-0####|   1-1    |\n",
+        "+---+----------+----------+\n",
+    );
+
+    assert_eq!(table, expected);
+}
+
+#[test]
+fn highlingt_fill_with_padding() {
+    let data = create_vector::<1, 1>();
+    let table = Table::new(&data)
+        .with(Style::ascii())
+        .with(Modify::new(Cell(1, 1)).with(Indent::new(1, 1, 1, 1)))
+        .with(Highlight::fill(Cell(1, 1), '~'))
+        .to_string();
+
+    let expected = concat!(
+        "+---+----------+\n",
+        "| N | column 0 |\n",
+        "+---+----------+\n",
+        "| 0 |~~~~~~~~~~|\n",
+        "|   |~~~0-0~~~~|\n",
+        "|   |~~~~~~~~~~|\n",
+        "+---+----------+\n",
+    );
+
+    assert_eq!(table, expected);
+}
+
+#[test]
+fn highlingt_object() {
+    let data = create_vector::<3, 3>();
+    let table = Table::new(&data)
+        .with(Style::modern())
+        .with(Highlight::object(
+            Row(1..3).and(Column(3..4)),
+            Border::full('*', '*', '*', '*', '#', '#', '#', '#'),
+        ))
+        .to_string();
+
+    let expected = concat!(
+        "┌───┬──────────┬──────────#**********#\n",
+        "│ N │ column 0 │ column 1 * column 2 *\n",
+        "#*************************#──────────*\n",
+        "* 0 │   0-0    │   0-1    │   0-2    *\n",
+        "*───┼──────────┼──────────┼──────────*\n",
+        "* 1 │   1-0    │   1-1    │   1-2    *\n",
+        "#*************************#──────────*\n",
+        "│ 2 │   2-0    │   2-1    *   2-2    *\n",
+        "└───┴──────────┴──────────#**********#\n",
+    );
+
+    assert_eq!(table, expected);
+}
+
+#[test]
+fn highlingt_object_is_kept_after_style_change() {
+    let data = create_vector::<2, 2>();
+    let table = Table::new(&data)
+        .with(Highlight::object(
+            Cell(1, 1).and(Cell(2, 2)),
+            Border::full('*', '*', '*', '*', '#', '#', '#', '#'),
+        ))
+        .with(Style::psql())
+        .to_string();
+
+    let expected = concat!(
+        " N | column 0 | column 1  \n",
+        "---#**********#---------- \n",
+        " 0 *   0-0    *   0-1     \n",
+        "   #**********#**********#\n",
+        " 1 |   1-0    *   1-1    *\n",
+        "              #**********#\n",
+    );
+
+    assert_eq!(table, expected);
+}
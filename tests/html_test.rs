@@ -0,0 +1,107 @@
+use crate::util::create_vector;
+use tabled::{
+    builder::Builder,
+    html::{Html, HtmlAlignment},
+    Alignment, Cell, Modify, Span, Table,
+};
+
+mod util;
+
+#[test]
+fn html_table() {
+    let data = create_vector::<2, 1>();
+    let html = Table::new(&data)
+        .with(Modify::new(Cell(1, 1)).with(Alignment::left()))
+        .with(Modify::new(Cell(2, 1)).with(Alignment::bottom()))
+        .to_html();
+
+    let expected = concat!(
+        "<table>\n",
+        "  <thead>\n",
+        "    <tr>",
+        "<th style=\"text-align: center; vertical-align: top\">N</th>",
+        "<th style=\"text-align: center; vertical-align: top\">column 0</th>",
+        "</tr>\n",
+        "  </thead>\n",
+        "  <tbody>\n",
+        "    <tr>",
+        "<td style=\"text-align: center; vertical-align: top\">0</td>",
+        "<td style=\"text-align: left; vertical-align: top\">0-0</td>",
+        "</tr>\n",
+        "    <tr>",
+        "<td style=\"text-align: center; vertical-align: top\">1</td>",
+        "<td style=\"text-align: center; vertical-align: bottom\">1-0</td>",
+        "</tr>\n",
+        "  </tbody>\n",
+        "</table>\n",
+    );
+
+    assert_eq!(html, expected);
+}
+
+#[test]
+fn html_spans() {
+    let data = create_vector::<3, 2>();
+    let table = Table::new(&data)
+        .with(Modify::new(Cell(0, 0)).with(Span::column(3)))
+        .with(
+            Modify::new(Cell(1, 1))
+                .with(Span::row(2))
+                .with(Span::column(2)),
+        );
+    let html = Html::new().alignment(HtmlAlignment::None).render(&table);
+
+    let expected = concat!(
+        "<table>\n",
+        "  <thead>\n",
+        "    <tr><th colspan=\"3\">N</th></tr>\n",
+        "  </thead>\n",
+        "  <tbody>\n",
+        "    <tr><td>0</td><td colspan=\"2\" rowspan=\"2\">0-0</td></tr>\n",
+        "    <tr><td>1</td></tr>\n",
+        "    <tr><td>2</td><td>2-0</td><td>2-1</td></tr>\n",
+        "  </tbody>\n",
+        "</table>\n",
+    );
+
+    assert_eq!(html, expected);
+}
+
+#[test]
+fn html_escape() {
+    let table = Table::new(["a & b\n<c>"]);
+
+    let html = Html::new().alignment(HtmlAlignment::None).render(&table);
+    assert!(html.contains("<td>a &amp; b<br>&lt;c&gt;</td>"));
+
+    let html = Html::new()
+        .alignment(HtmlAlignment::None)
+        .escape(false)
+        .render(&table);
+    assert!(html.contains("<td>a & b<br><c></td>"));
+}
+
+#[test]
+fn html_multiple_header_rows() {
+    let table = Builder::default()
+        .add_header_row(["distance"])
+        .add_header_row(["km"])
+        .add_row(["100"])
+        .build();
+
+    let html = Html::new().alignment(HtmlAlignment::None).render(&table);
+
+    let expected = concat!(
+        "<table>\n",
+        "  <thead>\n",
+        "    <tr><th>distance</th></tr>\n",
+        "    <tr><th>km</th></tr>\n",
+        "  </thead>\n",
+        "  <tbody>\n",
+        "    <tr><td>100</td></tr>\n",
+        "  </tbody>\n",
+        "</table>\n",
+    );
+
+    assert_eq!(html, expected);
+}
@@ -1,5 +1,5 @@
 use crate::util::create_vector;
-use tabled::{Alignment, Full, Indent, Modify, Row, Style, Table};
+use tabled::{Alignment, Fill, Full, Indent, LineSpacing, Modify, Row, Style, Table};
 
 mod util;
 
@@ -83,3 +83,111 @@ fn indent_multiline_with_vertical_alignment() {
 
     assert_eq!(table, expected);
 }
+
+#[test]
+fn indent_partial_keeps_other_sides() {
+    let data = create_vector::<1, 2>();
+    let table = Table::new(&data)
+        .with(Style::psql())
+        .with(Modify::new(Full).with(Alignment::left()))
+        .with(Modify::new(Row(1..)).with(Indent::default().left(3)))
+        .with(Modify::new(Row(1..)).with(Indent::default().bottom(1)))
+        .to_string();
+
+    let expected = concat!(
+        " N   | column 0 | column 1 \n",
+        "-----+----------+----------\n",
+        "   0 |   0-0    |   0-1    \n",
+        "     |          |          \n",
+    );
+
+    assert_eq!(table, expected);
+}
+
+#[test]
+fn line_spacing() {
+    let data = create_vector::<2, 2>();
+    let table = Table::new(&data)
+        .with(Style::psql())
+        .with(LineSpacing(1))
+        .to_string();
+
+    let expected = concat!(
+        " N | column 0 | column 1 \n",
+        "---+----------+----------\n",
+        " 0 |   0-0    |   0-1    \n",
+        "   |          |          \n",
+        " 1 |   1-0    |   1-1    \n",
+        "   |          |          \n",
+    );
+
+    assert_eq!(table, expected);
+}
+
+#[test]
+fn line_spacing_with_vertical_alignment() {
+    let mut data = create_vector::<2, 2>();
+    data[0][1] = String::from("0-0\n0-0");
+
+    let table = Table::new(&data)
+        .with(Style::ascii())
+        .with(Modify::new(Full).with(Alignment::center_vertical()))
+        .with(LineSpacing(2))
+        .to_string();
+
+    let expected = concat!(
+        "+---+----------+----------+\n",
+        "| N | column 0 | column 1 |\n",
+        "+---+----------+----------+\n",
+        "|   |          |          |\n",
+        "| 0 |   0-0    |   0-1    |\n",
+        "|   |   0-0    |          |\n",
+        "|   |          |          |\n",
+        "+---+----------+----------+\n",
+        "|   |          |          |\n",
+        "| 1 |   1-0    |   1-1    |\n",
+        "|   |          |          |\n",
+        "+---+----------+----------+\n",
+    );
+
+    assert_eq!(table, expected);
+}
+
+#[test]
+fn fill_both_sides() {
+    let table = Table::new(["a", "bbb"])
+        .with(Style::ascii())
+        .with(Modify::new(Row(1..)).with(Fill::new("*")))
+        .to_string();
+
+    let expected = concat!(
+        "+------+\n",
+        "| &str |\n",
+        "+------+\n",
+        "|**a***|\n",
+        "+------+\n",
+        "|*bbb**|\n",
+        "+------+\n",
+    );
+
+    assert_eq!(table, expected);
+}
+
+#[test]
+fn fill_empty_pattern_resets_it() {
+    let table = Table::new(["a"])
+        .with(Style::ascii())
+        .with(Modify::new(Full).with(Fill::new("*")))
+        .with(Modify::new(Full).with(Fill::default().right("")))
+        .to_string();
+
+    let expected = concat!(
+        "+------+\n",
+        "|*&str |\n",
+        "+------+\n",
+        "|**a   |\n",
+        "+------+\n",
+    );
+
+    assert_eq!(table, expected);
+}
@@ -0,0 +1,75 @@
+use tabled::{IterTable, Style, Table};
+
+fn render<T: tabled::Tabled>(table: IterTable<impl Iterator<Item = T>>) -> String {
+    let mut output = Vec::new();
+    table.write_to(&mut output).unwrap();
+    String::from_utf8(output).unwrap()
+}
+
+#[test]
+fn iter_table_matches_table() {
+    let data = [("a", 1), ("bb", 22), ("ccc", 333)];
+
+    let output = render(IterTable::new(data).with(Style::ascii()));
+    assert_eq!(output, Table::new(data).with(Style::ascii()).to_string());
+
+    let output = render(IterTable::new(data).with(Style::modern()));
+    assert_eq!(output, Table::new(data).with(Style::modern()).to_string());
+
+    let output = render(IterTable::new(data).with(Style::psql()));
+    assert_eq!(output, Table::new(data).with(Style::psql()).to_string());
+}
+
+#[test]
+fn iter_table_empty() {
+    let data = Vec::<(i32, i32)>::new();
+
+    let output = render(IterTable::new(data.clone()).with(Style::modern()));
+    let expected = Table::new(data).with(Style::modern()).to_string();
+
+    assert_eq!(output, expected);
+}
+
+#[test]
+fn iter_table_wraps_rows_after_sample() {
+    let data = ["a", "b", "longer"];
+
+    let output = render(IterTable::new(data).sample(2));
+
+    let expected = concat!(
+        "+------+\n",
+        "| &str |\n",
+        "+------+\n",
+        "|  a   |\n",
+        "+------+\n",
+        "|  b   |\n",
+        "+------+\n",
+        "| long |\n",
+        "|  er  |\n",
+        "+------+\n",
+    );
+
+    assert_eq!(output, expected);
+}
+
+#[test]
+fn iter_table_fixed_widths() {
+    let data = ["hello", "world"];
+
+    let output = render(IterTable::new(data).widths(vec![5]));
+
+    let expected = concat!(
+        "+-----+\n",
+        "| &st |\n",
+        "|  r  |\n",
+        "+-----+\n",
+        "| hel |\n",
+        "| lo  |\n",
+        "+-----+\n",
+        "| wor |\n",
+        "| ld  |\n",
+        "+-----+\n",
+    );
+
+    assert_eq!(output, expected);
+}
@@ -0,0 +1,156 @@
+use crate::util::create_vector;
+use tabled::{Concat, Layout, Modify, Panel, Row, Span, Style, Table};
+
+mod util;
+
+#[test]
+fn layout_cell_at() {
+    let data = create_vector::<2, 2>();
+    let table = Table::new(&data).with(Style::psql());
+
+    //  N | column 0 | column 1
+    // ---+----------+----------
+    //  0 |   0-0    |   0-1
+    //  1 |   1-0    |   1-1
+    let layout = table.layout();
+
+    assert_eq!(layout.cells().len(), 9);
+    assert_eq!(layout.cell_at(0, 0), Some((0, 0)));
+    assert_eq!(layout.cell_at(3, 0), None);
+    assert_eq!(layout.cell_at(5, 0), Some((0, 1)));
+    assert_eq!(layout.cell_at(5, 1), None);
+    assert_eq!(layout.cell_at(24, 3), Some((2, 2)));
+    assert_eq!(layout.cell_at(25, 3), None);
+    assert_eq!(layout.cell_at(24, 4), None);
+}
+
+#[test]
+fn layout_with_span() {
+    let data = create_vector::<2, 2>();
+    let table = Table::new(&data)
+        .with(Style::ascii())
+        .with(Modify::new(Row(1..2)).with(Span::column(3)));
+
+    // +---+----------+----------+
+    // | N | column 0 | column 1 |
+    // +---+----------+----------+
+    // |            0            |
+    // +---+----------+----------+
+    // | 1 |   1-0    |   1-1    |
+    // +---+----------+----------+
+    let layout = table.layout();
+    let cell = layout.cell(1, 0).unwrap();
+
+    assert_eq!((cell.x, cell.y, cell.width, cell.height), (1, 3, 25, 1));
+    assert!(layout.cell(1, 1).is_none());
+    assert_eq!(layout.cell_at(20, 3), Some((1, 0)));
+    assert_eq!(layout.cell_at(20, 5), Some((2, 2)));
+}
+
+#[test]
+fn layout_fixed() {
+    let data = [("Hello World", 1), ("Hi", 22)];
+
+    let table = Table::new(data)
+        .with(Style::psql())
+        .with(Layout::fixed(vec![9, 3]))
+        .to_string();
+
+    let expected = concat!(
+        "  &str   | i \n",
+        "---------+---\n",
+        " Hello W | 1 \n",
+        "   Hi    | 2 \n",
+    );
+
+    assert_eq!(table, expected);
+
+    let table = Table::new(data)
+        .with(Style::psql())
+        .with(Layout::fixed(vec![9, 5]).keep_words())
+        .to_string();
+
+    let expected = concat!(
+        "  &str   | i32 \n",
+        "---------+-----\n",
+        "  Hello  |  1  \n",
+        "  World  |     \n",
+        "   Hi    | 22  \n",
+    );
+
+    assert_eq!(table, expected);
+}
+
+#[test]
+fn layout_fixed_short_widths() {
+    let table = Table::new([("a", "b")])
+        .with(Style::ascii())
+        .with(Layout::fixed(vec![5]))
+        .to_string();
+
+    let expected = concat!(
+        "+-----+------+\n",
+        "| &st | &str |\n",
+        "+-----+------+\n",
+        "|  a  |  b   |\n",
+        "+-----+------+\n",
+    );
+
+    assert_eq!(table, expected);
+}
+
+#[test]
+fn layout_fixed_width_below_padding() {
+    let table = Table::new([("a", "b")])
+        .with(Style::ascii())
+        .with(Layout::fixed(vec![1, 0]))
+        .to_string();
+
+    let expected = concat!(
+        "+--+--+\n",
+        "|  |  |\n",
+        "+--+--+\n",
+        "|  |  |\n",
+        "+--+--+\n",
+    );
+
+    assert_eq!(table, expected);
+}
+
+#[test]
+fn layout_fixed_with_vertical_panel() {
+    let table = Table::new([("a", "b")])
+        .with(Style::ascii())
+        .with(Layout::fixed(vec![5, 5]))
+        .with(Panel::vertical(0, "ab"))
+        .to_string();
+
+    let expected = concat!(
+        "+ -----+-----+\n",
+        "|a &st | &st |\n",
+        "|b-----+-----+\n",
+        "|   a  |  b  |\n",
+        "+ -----+-----+\n",
+    );
+
+    assert_eq!(table, expected);
+}
+
+#[test]
+fn layout_fixed_with_concat() {
+    let table = Table::new([("a", "b")])
+        .with(Style::ascii())
+        .with(Layout::fixed(vec![5, 5]))
+        .with(Concat::horizontal(Table::new(["123"])))
+        .to_string();
+
+    let expected = concat!(
+        "+-----+-----+------+\n",
+        "| &st | &st | &str |\n",
+        "+-----+-----+------+\n",
+        "|  a  |  b  | 123  |\n",
+        "+-----+-----+------+\n",
+    );
+
+    assert_eq!(table, expected);
+}
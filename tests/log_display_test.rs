@@ -0,0 +1,42 @@
+use tabled::{display::LogDisplay, Style, Table};
+
+#[test]
+fn log_display_prefix() {
+    let table = Table::new([(1, 2)]).with(Style::ascii());
+
+    let log = LogDisplay::new(&table).prefix("[table] ").to_string();
+
+    let expected = concat!(
+        "[table] +-----+-----+\n",
+        "[table] | i32 | i32 |\n",
+        "[table] +-----+-----+\n",
+        "[table] |  1  |  2  |\n",
+        "[table] +-----+-----+\n",
+    );
+
+    assert_eq!(log, expected);
+}
+
+#[test]
+fn log_display_single_line_quotes_values() {
+    let table = Table::new([("", "a=b"), ("multi\nline", "x")]);
+
+    let log = LogDisplay::new(&table)
+        .prefix("rows: ")
+        .single_line()
+        .to_string();
+
+    assert_eq!(
+        log,
+        r#"rows: &str="" &str="a=b"; &str="multi\nline" &str=x"#
+    );
+}
+
+#[test]
+fn log_display_single_line_empty_table() {
+    let table = Table::new(Vec::<(i32, i32)>::new());
+
+    let log = LogDisplay::new(&table).single_line().to_string();
+
+    assert_eq!(log, "");
+}
@@ -0,0 +1,55 @@
+use crate::util::create_vector;
+use tabled::{Alignment, Cell, Column, Modify, Span, Table};
+
+mod util;
+
+#[test]
+fn markdown_table() {
+    let data = create_vector::<2, 2>();
+    let markdown = Table::new(&data)
+        .with(Modify::new(Column(1..2)).with(Alignment::left()))
+        .with(Modify::new(Column(2..)).with(Alignment::right()))
+        .to_markdown();
+
+    let expected = concat!(
+        "| N | column 0 | column 1 |\n",
+        "|:-:|:---------|---------:|\n",
+        "| 0 | 0-0      | 0-1      |\n",
+        "| 1 | 1-0      | 1-1      |\n",
+    );
+
+    assert_eq!(markdown, expected);
+}
+
+#[test]
+fn markdown_spans_are_left_empty() {
+    let data = create_vector::<2, 2>();
+    let markdown = Table::new(&data)
+        .with(Modify::new(Cell(1, 0)).with(Span::column(2)))
+        .with(Modify::new(Cell(1, 2)).with(Span::row(2)))
+        .to_markdown();
+
+    let expected = concat!(
+        "| N | column 0 | column 1 |\n",
+        "|:-:|:--------:|:--------:|\n",
+        "| 0 |          | 0-1      |\n",
+        "| 1 | 1-0      |          |\n",
+    );
+
+    assert_eq!(markdown, expected);
+}
+
+#[test]
+fn markdown_escape() {
+    let markdown = Table::new(["a|b\nc"]).to_markdown();
+
+    let expected = concat!("| &str      |\n", "|:---------:|\n", "| a\\|b<br>c |\n",);
+
+    assert_eq!(markdown, expected);
+}
+
+#[test]
+fn markdown_empty() {
+    let markdown = Table::new(Vec::<String>::new()).to_markdown();
+    assert_eq!(markdown, "| String |\n|:------:|\n");
+}
@@ -0,0 +1,76 @@
+use tabled::{builder::Builder, Cell, Mask, Object, Row, Style, Table};
+
+#[test]
+fn mask_column() {
+    let data = [("alice", "s3cr3t-token"), ("bob", "hunter2")];
+    let table = Table::new(data)
+        .with(Style::psql())
+        .with(Mask::column(1).keep_first(2).keep_last(2))
+        .to_string();
+
+    let expected = concat!(
+        " &str  |     &str     \n",
+        "-------+--------------\n",
+        " alice | s3********en \n",
+        "  bob  |   hu***r2    \n",
+    );
+
+    assert_eq!(table, expected);
+}
+
+#[test]
+fn mask_short_value_entirely() {
+    let data = [("key", "abc"), ("other", "")];
+    let table = Table::new(data)
+        .with(Style::psql())
+        .with(Mask::column(1).keep_first(1).keep_last(2))
+        .to_string();
+
+    let expected = concat!(
+        " &str  | &str \n",
+        "-------+------\n",
+        "  key  | ***  \n",
+        " other |      \n",
+    );
+
+    assert_eq!(table, expected);
+}
+
+#[test]
+fn mask_object_with_symbol() {
+    let data = [("first line\nsecond line", 1)];
+    let table = Table::new(data)
+        .with(Style::psql())
+        .with(Mask::new(Row(1..).and(Cell(0, 1))).symbol('#').keep_last(3))
+        .to_string();
+
+    let expected = concat!(
+        "    &str     | ### \n",
+        "-------------+-----\n",
+        " ##########  |  #  \n",
+        " ########ine |     \n",
+    );
+
+    assert_eq!(table, expected);
+}
+
+#[test]
+fn mask_column_keeps_multirow_header() {
+    let table = Builder::default()
+        .add_header_row(["user", "token"])
+        .add_header_row(["name", "secret"])
+        .add_row(["alice", "s3cr3t"])
+        .build()
+        .with(Style::psql())
+        .with(Mask::column(1))
+        .to_string();
+
+    let expected = concat!(
+        " user  | token  \n",
+        " name  | secret \n",
+        "-------+--------\n",
+        " alice | ****** \n",
+    );
+
+    assert_eq!(table, expected);
+}
@@ -0,0 +1,70 @@
+use tabled::{Column, Full, Merge, Object, Row, Style, Table};
+
+#[test]
+fn merge_vertical() {
+    let data = [("A", 1), ("A", 2), ("B", 3), ("A", 4)];
+    let table = Table::new(data)
+        .with(Style::ascii())
+        .with(Merge::vertical(Column(..1)))
+        .to_string();
+
+    let expected = concat!(
+        "+------+-----+\n",
+        "| &str | i32 |\n",
+        "+------+-----+\n",
+        "|  A   |  1  |\n",
+        "|      +-----+\n",
+        "|      |  2  |\n",
+        "+------+-----+\n",
+        "|  B   |  3  |\n",
+        "+------+-----+\n",
+        "|  A   |  4  |\n",
+        "+------+-----+\n",
+    );
+
+    assert_eq!(table, expected);
+}
+
+#[test]
+fn merge_vertical_blank() {
+    let data = [("A", 1), ("A", 2), ("B", 3)];
+    let table = Table::new(data)
+        .with(Style::ascii())
+        .with(Merge::vertical(Column(..1)).blank())
+        .to_string();
+
+    let expected = concat!(
+        "+------+-----+\n",
+        "| &str | i32 |\n",
+        "+------+-----+\n",
+        "|  A   |  1  |\n",
+        "+------+-----+\n",
+        "|      |  2  |\n",
+        "+------+-----+\n",
+        "|  B   |  3  |\n",
+        "+------+-----+\n",
+    );
+
+    assert_eq!(table, expected);
+}
+
+#[test]
+fn merge_horizontal() {
+    let data = [("A", "A", "B"), ("C", "", "")];
+    let table = Table::new(data)
+        .with(Style::ascii())
+        .with(Merge::horizontal(Full.not(Row(..1))))
+        .to_string();
+
+    let expected = concat!(
+        "+------+------+------+\n",
+        "| &str | &str | &str |\n",
+        "+------+------+------+\n",
+        "|      A      |  B   |\n",
+        "+------+------+------+\n",
+        "|  C   |      |      |\n",
+        "+------+------+------+\n",
+    );
+
+    assert_eq!(table, expected);
+}
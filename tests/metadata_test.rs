@@ -0,0 +1,111 @@
+use crate::util::create_vector;
+use tabled::{
+    Alignment, Cell, Column, Disable, Format, Full, Header, Metadata, Modify, Object, Row, Style,
+    Table, Tag,
+};
+
+mod util;
+
+#[test]
+fn metadata_doesnt_affect_rendering() {
+    let data = create_vector::<2, 2>();
+    let table = Table::new(&data)
+        .with(Style::psql())
+        .with(Modify::new(Row(..1)).with(Metadata::new().class("header")))
+        .with(Modify::new(Column(1..2)).with(Metadata::new().title("first").data("index", "0")))
+        .with(Modify::new(Full).with(Metadata::new().attribute("id", "cell")))
+        .to_string();
+
+    let expected = concat!(
+        " N | column 0 | column 1 \n",
+        "---+----------+----------\n",
+        " 0 |   0-0    |   0-1    \n",
+        " 1 |   1-0    |   1-1    \n",
+    );
+
+    assert_eq!(table, expected);
+}
+
+#[test]
+fn tag_follows_cell_after_structural_changes() {
+    let data = create_vector::<2, 2>();
+    let table = Table::new(&data)
+        .with(Modify::new(Cell(2, 2)).with(Metadata::new().tag("total")))
+        .with(Disable::Column(..1))
+        .with(Header("Report"))
+        .with(Modify::new(Tag("total")).with(Format(|s| format!("[{}]", s))))
+        .with(Style::psql())
+        .to_string();
+
+    let expected = concat!(
+        "Report               \n",
+        "----------+----------\n",
+        " column 0 | column 1 \n",
+        "   0-0    |   0-1    \n",
+        "   1-0    |  [1-1]   \n",
+    );
+
+    assert_eq!(table, expected);
+}
+
+#[test]
+fn tag_can_be_combined() {
+    let data = create_vector::<2, 2>();
+    let table = Table::new(&data)
+        .with(Modify::new(Row(1..)).with(Metadata::new().tag("body")))
+        .with(Modify::new(Cell(1, 1)).with(Metadata::new().tag("first")))
+        .with(Modify::new(Tag("body").not(Tag("first"))).with(Alignment::right()))
+        .with(Style::psql())
+        .to_string();
+
+    let expected = concat!(
+        " N | column 0 | column 1 \n",
+        "---+----------+----------\n",
+        " 0 |   0-0    |      0-1 \n",
+        " 1 |      1-0 |      1-1 \n",
+    );
+
+    assert_eq!(table, expected);
+}
+
+#[test]
+fn unknown_tag_doesnt_change_table() {
+    let data = create_vector::<1, 1>();
+    let table = Table::new(&data)
+        .with(Modify::new(Tag("unknown")).with(Format(|s| format!("[{}]", s))))
+        .with(Style::psql())
+        .to_string();
+
+    let expected = concat!(" N | column 0 \n", "---+----------\n", " 0 |   0-0    \n",);
+
+    assert_eq!(table, expected);
+}
+
+#[test]
+fn tag_can_be_combined_with_custom_object() {
+    struct LastColumn;
+
+    impl Object for LastColumn {
+        fn cells(&self, count_rows: usize, count_columns: usize) -> Vec<(usize, usize)> {
+            (0..count_rows)
+                .map(|row| (row, count_columns - 1))
+                .collect()
+        }
+    }
+
+    let data = create_vector::<2, 2>();
+    let table = Table::new(&data)
+        .with(Modify::new(Row(1..)).with(Metadata::new().tag("body")))
+        .with(Modify::new(Tag("body").not(LastColumn)).with(Alignment::right()))
+        .with(Style::psql())
+        .to_string();
+
+    let expected = concat!(
+        " N | column 0 | column 1 \n",
+        "---+----------+----------\n",
+        " 0 |      0-0 |   0-1    \n",
+        " 1 |      1-0 |   1-1    \n",
+    );
+
+    assert_eq!(table, expected);
+}
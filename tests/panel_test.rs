@@ -1,7 +1,7 @@
 use crate::util::create_vector;
 use tabled::{
-    Alignment, Border, Cell, Footer, Full, Header, Highlight, Modify, Object, Panel, Row, Style,
-    Table,
+    builder::Builder, Alignment, Border, Cell, EmptyMessage, Footer, Full, Header, Highlight,
+    Modify, Object, Panel, Row, Style, Table,
 };
 
 mod util;
@@ -248,3 +248,185 @@ fn panel_in_single_column() {
 
     assert_eq!(table, expected);
 }
+
+#[test]
+fn empty_message() {
+    let table = Table::new(create_vector::<0, 2>())
+        .with(EmptyMessage("no results"))
+        .with(Style::ascii())
+        .to_string();
+
+    let expected = concat!(
+        "+---+----------+----------+\n",
+        "| N | column 0 | column 1 |\n",
+        "+---+----------+----------+\n",
+        "|       no results        |\n",
+        "+---+----------+----------+\n",
+    );
+
+    assert_eq!(table, expected);
+}
+
+#[test]
+fn empty_message_is_not_rendered_when_there_are_rows() {
+    let table = Table::new(create_vector::<1, 2>())
+        .with(Style::psql())
+        .with(EmptyMessage("no results"))
+        .to_string();
+
+    let expected = concat!(
+        " N | column 0 | column 1 \n",
+        "---+----------+----------\n",
+        " 0 |   0-0    |   0-1    \n",
+    );
+
+    assert_eq!(table, expected);
+}
+
+#[test]
+fn empty_message_without_header() {
+    let table = Builder::default()
+        .build()
+        .with(EmptyMessage("no results"))
+        .with(Style::ascii())
+        .to_string();
+
+    let expected = concat!("+------------+\n", "| no results |\n", "+------------+\n",);
+
+    assert_eq!(table, expected);
+}
+
+#[test]
+fn panel_over_column_range() {
+    let table = Table::new(create_vector::<2, 3>())
+        .with(Panel("Linux", 0).columns(1..=2))
+        .with(Style::modern())
+        .to_string();
+
+    let expected = concat!(
+        "┌───┬──────────┬──────────┬──────────┐\n",
+        "│   │Linux                │          │\n",
+        "├───┼──────────┼──────────┼──────────┤\n",
+        "│ N │ column 0 │ column 1 │ column 2 │\n",
+        "├───┼──────────┼──────────┼──────────┤\n",
+        "│ 0 │   0-0    │   0-1    │   0-2    │\n",
+        "├───┼──────────┼──────────┼──────────┤\n",
+        "│ 1 │   1-0    │   1-1    │   1-2    │\n",
+        "└───┴──────────┴──────────┴──────────┘\n",
+    );
+
+    assert_eq!(table, expected);
+}
+
+#[test]
+fn panel_over_column_range_out_of_table() {
+    let table = Table::new(create_vector::<1, 1>())
+        .with(Panel("Linux Distributions", 1).columns(1..10))
+        .with(Style::psql())
+        .to_string();
+
+    let expected = concat!(
+        " N |     column 0      \n",
+        "---+-------------------\n",
+        "   |Linux Distributions\n",
+        " 0 |        0-0        \n",
+    );
+
+    assert_eq!(table, expected);
+}
+
+#[test]
+fn stacked_panels() {
+    let table = Table::new(create_vector::<1, 3>())
+        .with(Panel("Linux", 0).columns(..2))
+        .with(Panel("Distributions", 0).columns(2..))
+        .with(Panel("Total", 0))
+        .with(Style::modern())
+        .to_string();
+
+    let expected = concat!(
+        "┌───┬──────────┬──────────┬──────────┐\n",
+        "│Total                               │\n",
+        "├───┼──────────┼──────────┼──────────┤\n",
+        "│   │          │Distributions        │\n",
+        "├───┼──────────┼──────────┼──────────┤\n",
+        "│Linux         │          │          │\n",
+        "├───┼──────────┼──────────┼──────────┤\n",
+        "│ N │ column 0 │ column 1 │ column 2 │\n",
+        "├───┼──────────┼──────────┼──────────┤\n",
+        "│ 0 │   0-0    │   0-1    │   0-2    │\n",
+        "└───┴──────────┴──────────┴──────────┘\n",
+    );
+
+    assert_eq!(table, expected);
+}
+
+#[test]
+fn vertical_panel() {
+    let table = Table::new(create_vector::<3, 2>())
+        .with(Panel::vertical(0, "Linux"))
+        .with(Style::psql())
+        .to_string();
+
+    let expected = concat!(
+        "L| N | column 0 | column 1 \n",
+        "i+---+----------+----------\n",
+        "n| 0 |   0-0    |   0-1    \n",
+        "u| 1 |   1-0    |   1-1    \n",
+        "x| 2 |   2-0    |   2-1    \n",
+    );
+
+    assert_eq!(table, expected);
+}
+
+#[test]
+fn vertical_panel_in_the_middle() {
+    let table = Table::new(create_vector::<2, 2>())
+        .with(Panel::vertical(2, "OS"))
+        .with(Style::modern())
+        .to_string();
+
+    let expected = concat!(
+        "┌───┬──────────┬─┬──────────┐\n",
+        "│ N │ column 0 │O│ column 1 │\n",
+        "├───┼──────────┼S┼──────────┤\n",
+        "│ 0 │   0-0    │ │   0-1    │\n",
+        "├───┼──────────┼ ┼──────────┤\n",
+        "│ 1 │   1-0    │ │   1-1    │\n",
+        "└───┴──────────┴─┴──────────┘\n",
+    );
+
+    assert_eq!(table, expected);
+}
+
+#[test]
+fn empty_message_is_not_rendered_without_header() {
+    let table = Table::new_without_header(create_vector::<1, 2>())
+        .with(EmptyMessage("no results"))
+        .with(Style::psql())
+        .to_string();
+
+    let expected = " 0 | 0-0 | 0-1 \n";
+
+    assert_eq!(table, expected);
+}
+
+#[test]
+fn empty_message_under_multiline_header() {
+    let table = Builder::default()
+        .add_header_row(["a", "b"])
+        .add_header_row(["c", "d"])
+        .build()
+        .with(EmptyMessage("no results"))
+        .with(Style::psql())
+        .to_string();
+
+    let expected = concat!(
+        "  a   |  b  \n",
+        "  c   |  d  \n",
+        "------+-----\n",
+        " no results \n",
+    );
+
+    assert_eq!(table, expected);
+}
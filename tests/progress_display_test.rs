@@ -0,0 +1,27 @@
+use tabled::{display::ProgressDisplay, Full, MinWidth, Modify, Style, Table};
+
+#[test]
+fn progress_display_keeps_frame() {
+    let table = Table::new([("a", 1)])
+        .with(Style::modern())
+        .with(Modify::new(Full).with(MinWidth::new(8)));
+    let progress = ProgressDisplay::new(&table);
+
+    let header = concat!(
+        "┌────────┬────────┐\n",
+        "│  &str  │  i32   │\n",
+        "├────────┼────────┤\n",
+    );
+
+    assert_eq!(progress.header(), header);
+    assert_eq!(progress.row(["a.txt", "10"]), "│ a.txt  │   10   │");
+    assert_eq!(progress.row(["long_file.txt", "99"]), "│ long_f │   99   │");
+}
+
+#[test]
+fn progress_display_missing_fields_are_empty() {
+    let table = Table::new([("a", "b", "c")]).with(Style::psql());
+    let progress = ProgressDisplay::new(&table);
+
+    assert_eq!(progress.row(["x"]), "  x   |      |      ");
+}
@@ -0,0 +1,57 @@
+use crate::util::create_vector;
+use tabled::{Missing, SelectColumns, Style, Table};
+
+mod util;
+
+#[test]
+fn select_columns_by_names() {
+    let data = create_vector::<2, 3>();
+    let table = Table::new(&data)
+        .with(Style::psql())
+        .with(SelectColumns::by_names(
+            ["column 2", "N", "column 5", "column 0"],
+            Missing::Skip,
+        ))
+        .to_string();
+
+    let expected = concat!(
+        " column 2 | N | column 0 \n",
+        "----------+---+----------\n",
+        "   0-2    | 0 |   0-0    \n",
+        "   1-2    | 1 |   1-0    \n",
+    );
+
+    assert_eq!(table, expected);
+}
+
+#[test]
+fn select_columns_by_names_with_missing_empty() {
+    let data = create_vector::<2, 3>();
+    let table = Table::new(&data)
+        .with(Style::psql())
+        .with(SelectColumns::by_names(
+            ["column 1", "column 5", "N"],
+            Missing::Empty,
+        ))
+        .to_string();
+
+    let expected = concat!(
+        " column 1 | column 5 | N \n",
+        "----------+----------+---\n",
+        "   0-1    |          | 0 \n",
+        "   1-1    |          | 1 \n",
+    );
+
+    assert_eq!(table, expected);
+}
+
+#[test]
+fn select_columns_all_missing() {
+    let data = create_vector::<2, 3>();
+    let table = Table::new(&data)
+        .with(Style::psql())
+        .with(SelectColumns::by_names(["column 5"], Missing::Skip))
+        .to_string();
+
+    assert_eq!(table, "");
+}
@@ -0,0 +1,98 @@
+use tabled::{builder::Builder, Footer, Header, Sort, Style, Table};
+
+#[test]
+fn sort_by_column() {
+    let data = [("Go", 2009), ("C", 1972), ("Rust", 2010), ("C++", 1985)];
+    let table = Table::new(data)
+        .with(Sort::by_column(0))
+        .with(Style::modern())
+        .to_string();
+
+    let expected = concat!(
+        "┌──────┬──────┐\n",
+        "│ &str │ i32  │\n",
+        "├──────┼──────┤\n",
+        "│  C   │ 1972 │\n",
+        "├──────┼──────┤\n",
+        "│ C++  │ 1985 │\n",
+        "├──────┼──────┤\n",
+        "│  Go  │ 2009 │\n",
+        "├──────┼──────┤\n",
+        "│ Rust │ 2010 │\n",
+        "└──────┴──────┘\n",
+    );
+
+    assert_eq!(table, expected);
+}
+
+#[test]
+fn sort_by_column_desc_numbers() {
+    let data = [("a", 2), ("b", 10), ("c", -1)];
+    let table = Table::new(data)
+        .with(Style::psql())
+        .with(Sort::by_column_desc(1))
+        .to_string();
+
+    let expected = concat!(
+        " &str | i32 \n",
+        "------+-----\n",
+        "  b   | 10  \n",
+        "  a   |  2  \n",
+        "  c   | -1  \n",
+    );
+
+    assert_eq!(table, expected);
+}
+
+#[test]
+fn sort_with_comparator_is_stable() {
+    let data = ["bb", "a", "cc", "d"];
+    let table = Table::new(data)
+        .with(Sort::with(|a, b| a[0].len().cmp(&b[0].len())))
+        .with(Style::psql())
+        .to_string();
+
+    let expected = concat!(" &str \n", "------\n", "  a   \n", "  d   \n", "  bb  \n", "  cc  \n",);
+
+    assert_eq!(table, expected);
+}
+
+#[test]
+fn sort_keeps_header_rows_and_panels() {
+    let table = Builder::default()
+        .add_header_row(["distance"])
+        .add_header_row(["km"])
+        .add_row(["20"])
+        .add_row(["100"])
+        .add_row(["3"])
+        .build()
+        .with(Sort::with(|a, b| b[0].cmp(a[0])))
+        .with(Header("Trips"))
+        .with(Footer("Total"))
+        .with(Style::psql())
+        .to_string();
+
+    let expected = concat!(
+        "Trips     \n",
+        "----------\n",
+        " distance \n",
+        "    km    \n",
+        "    3     \n",
+        "    20    \n",
+        "   100    \n",
+        "Total     \n",
+    );
+
+    assert_eq!(table, expected);
+}
+
+#[test]
+fn sort_by_missing_column() {
+    let data = [("b", 1), ("a", 2)];
+    let table = Table::new(data);
+    let expected = table.to_string();
+
+    let table = table.with(Sort::by_column(10)).to_string();
+
+    assert_eq!(table, expected);
+}
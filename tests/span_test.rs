@@ -1,5 +1,5 @@
 use crate::util::create_vector;
-use tabled::{Alignment, Cell, Column, Full, Indent, Modify, Span, Style, Table};
+use tabled::{Alignment, Cell, Column, Error, Full, Indent, Modify, Span, SpanAlign, Style, Table};
 
 mod util;
 
@@ -282,6 +282,63 @@ fn span_column_exceeds_boundries_test() {
 }
 
 #[test]
+fn span_column_exceeds_boundries_try_build_test() {
+    let data = create_vector::<3, 3>();
+    let table = Table::new(&data).with(Modify::new(Column(..1)).with(Span::column(100)));
+
+    assert_eq!(
+        table.try_build(),
+        Err(Error::SpanOutOfRange {
+            row: 0,
+            column: 0,
+            span: 100
+        })
+    );
+}
+
+#[test]
+fn span_row_exceeds_boundries_try_build_test() {
+    let data = create_vector::<3, 3>();
+    let table = Table::new(&data).with(Modify::new(Cell(2, 1)).with(Span::row(3)));
+
+    assert_eq!(
+        table.try_build(),
+        Err(Error::RowSpanOutOfRange {
+            row: 2,
+            column: 1,
+            span: 3
+        })
+    );
+}
+
+#[test]
+fn modify_out_of_table_try_build_test() {
+    let data = create_vector::<3, 3>();
+    let table = Table::new(&data)
+        .with(Modify::new(Cell(1, 1)).with(Alignment::left()))
+        .with(Modify::new(Cell(10, 1)).with(Alignment::left()));
+
+    assert_eq!(
+        table.try_build(),
+        Err(Error::OutOfRange { row: 10, column: 1 })
+    );
+}
+
+#[test]
+fn span_try_build_test() {
+    let data = create_vector::<3, 3>();
+    let table = Table::new(&data)
+        .with(Style::psql())
+        .with(Modify::new(Column(1..2)).with(Span::column(3)));
+
+    assert_eq!(table.try_build(), Ok(table.to_string()));
+}
+
+#[test]
+#[cfg_attr(
+    feature = "width-assertions",
+    ignore = "the layout is known to have lines of different width"
+)]
 fn span_cell_exceeds_boundries_test() {
     // these tests shows that exiding boundries causes invalid behaiviour
     //
@@ -431,3 +488,144 @@ fn spaned_columns_with_colision() {
 
     assert_eq!(table, expected);
 }
+
+#[test]
+fn span_row_test() {
+    let data = create_vector::<3, 2>();
+    let table = Table::new(&data)
+        .with(Style::ascii())
+        .with(Modify::new(Cell(1, 1)).with(Span::row(2)))
+        .to_string();
+
+    let expected = concat!(
+        "+---+----------+----------+\n",
+        "| N | column 0 | column 1 |\n",
+        "+---+----------+----------+\n",
+        "| 0 |   0-0    |   0-1    |\n",
+        "+---+          +----------+\n",
+        "| 1 |          |   1-1    |\n",
+        "+---+----------+----------+\n",
+        "| 2 |   2-0    |   2-1    |\n",
+        "+---+----------+----------+\n",
+    );
+
+    assert_eq!(table, expected);
+}
+
+#[test]
+fn span_row_without_split_lines_test() {
+    let data = create_vector::<3, 2>();
+    let table = Table::new(&data)
+        .with(Style::psql())
+        .with(Modify::new(Cell(1, 2)).with(Span::row(3)))
+        .with(Modify::new(Cell(1, 2)).with(|_: &str| "a\nb\nc\nd".to_string()))
+        .to_string();
+
+    let expected = concat!(
+        " N | column 0 | column 1 \n",
+        "---+----------+----------\n",
+        " 0 |   0-0    |    a     \n",
+        " 1 |   1-0    |    b     \n",
+        " 2 |   2-0    |    c     \n",
+        "   |          |    d     \n",
+    );
+
+    assert_eq!(table, expected);
+}
+
+#[test]
+fn span_row_and_column_test() {
+    let data = create_vector::<3, 2>();
+    let table = Table::new(&data)
+        .with(Style::modern())
+        .with(
+            Modify::new(Cell(1, 1))
+                .with(Span::row(3))
+                .with(Span::column(2))
+                .with(Alignment::center_vertical()),
+        )
+        .to_string();
+
+    let expected = concat!(
+        "┌───┬──────────┬──────────┐\n",
+        "│ N │ column 0 │ column 1 │\n",
+        "├───┼──────────┼──────────┤\n",
+        "│ 0 │                     │\n",
+        "├───┼                     │\n",
+        "│ 1 │         0-0         │\n",
+        "├───┼                     │\n",
+        "│ 2 │                     │\n",
+        "└───┴──────────┴──────────┘\n",
+    );
+
+    assert_eq!(table, expected);
+}
+
+#[test]
+fn span_row_vertical_alignment_test() {
+    let data = [("A", "1\n2"), ("B", "3\n4"), ("C", "5")];
+    let table = |alignment, span_alignment| {
+        Table::new(data)
+            .with(Style::ascii())
+            .with(
+                Modify::new(Cell(1, 0))
+                    .with(Span::row(3))
+                    .with(alignment)
+                    .with(span_alignment),
+            )
+            .to_string()
+    };
+
+    assert_eq!(
+        table(Alignment::top(), SpanAlign::Merged),
+        table(Alignment::top(), SpanAlign::FirstRow)
+    );
+    assert_eq!(
+        table(Alignment::center_vertical(), SpanAlign::Merged),
+        concat!(
+            "+------+------+\n",
+            "| &str | &str |\n",
+            "+------+------+\n",
+            "|      |  1   |\n",
+            "|      |  2   |\n",
+            "|      +------+\n",
+            "|  A   |  3   |\n",
+            "|      |  4   |\n",
+            "|      +------+\n",
+            "|      |  5   |\n",
+            "+------+------+\n",
+        )
+    );
+    assert_eq!(
+        table(Alignment::bottom(), SpanAlign::Merged),
+        concat!(
+            "+------+------+\n",
+            "| &str | &str |\n",
+            "+------+------+\n",
+            "|      |  1   |\n",
+            "|      |  2   |\n",
+            "|      +------+\n",
+            "|      |  3   |\n",
+            "|      |  4   |\n",
+            "|      +------+\n",
+            "|  A   |  5   |\n",
+            "+------+------+\n",
+        )
+    );
+    assert_eq!(
+        table(Alignment::bottom(), SpanAlign::FirstRow),
+        concat!(
+            "+------+------+\n",
+            "| &str | &str |\n",
+            "+------+------+\n",
+            "|      |  1   |\n",
+            "|  A   |  2   |\n",
+            "|      +------+\n",
+            "|      |  3   |\n",
+            "|      |  4   |\n",
+            "|      +------+\n",
+            "|      |  5   |\n",
+            "+------+------+\n",
+        )
+    );
+}
@@ -0,0 +1,50 @@
+#[cfg(feature = "rusqlite")]
+use rusqlite::Connection;
+#[cfg(feature = "rusqlite")]
+use tabled::{builder::Builder, Style};
+
+#[cfg(feature = "rusqlite")]
+#[test]
+fn sqlite_rows_value_types() {
+    let conn = Connection::open_in_memory().unwrap();
+    conn.execute_batch(
+        "CREATE TABLE t (id INTEGER, price REAL, data BLOB, note TEXT);
+         INSERT INTO t VALUES (1, 2.5, x'0aff', 'a'), (2, NULL, NULL, NULL);",
+    )
+    .unwrap();
+
+    let mut stmt = conn.prepare("SELECT * FROM t").unwrap();
+    let table = Builder::from_sqlite_rows(stmt.query([]).unwrap(), "-")
+        .unwrap()
+        .build()
+        .with(Style::psql())
+        .to_string();
+
+    let expected = concat!(
+        " id | price |  data   | note \n",
+        "----+-------+---------+------\n",
+        " 1  |  2.5  | x'0aff' |  a   \n",
+        " 2  |   -   |    -    |  -   \n",
+    );
+
+    assert_eq!(table, expected);
+}
+
+#[cfg(feature = "rusqlite")]
+#[test]
+fn sqlite_rows_empty_result_keeps_header() {
+    let conn = Connection::open_in_memory().unwrap();
+    conn.execute_batch("CREATE TABLE t (id INTEGER, name TEXT);")
+        .unwrap();
+
+    let mut stmt = conn.prepare("SELECT id, name AS title FROM t").unwrap();
+    let table = Builder::from_sqlite_rows(stmt.query([]).unwrap(), "")
+        .unwrap()
+        .build()
+        .with(Style::psql())
+        .to_string();
+
+    let expected = " id | title \n";
+
+    assert_eq!(table, expected);
+}
@@ -1,6 +1,9 @@
 use crate::util::create_vector;
-use tabled::style::TopBorderText;
-use tabled::{Full, Indent, Modify, Style, Table, TableIteratorExt};
+use tabled::style::{RawStyle, SeparatorEvery, StyleIssue, TopBorderText};
+use tabled::{
+    Accessible, AlignmentHorizontal, Disable, Full, Header, Indent, Modify, Style, Table,
+    TableIteratorExt,
+};
 
 mod util;
 
@@ -1164,3 +1167,477 @@ fn custom_style_test() {
         )
     );
 }
+
+#[test]
+fn accessible_style() {
+    let data = create_vector::<2, 2>();
+    let table = Table::new(&data).with(Style::accessible()).to_string();
+
+    let expected = concat!(
+        "+---+----------+----------+\n",
+        "| N | column 0 | column 1 |\n",
+        "+===+==========+==========+\n",
+        "| 0 |   0-0    |   0-1    |\n",
+        "+---+----------+----------+\n",
+        "| 1 |   1-0    |   1-1    |\n",
+        "+---+----------+----------+\n",
+    );
+
+    assert_eq!(table, expected);
+}
+
+#[test]
+fn accessible_option_replaces_box_drawing_chars() {
+    let data = create_vector::<2, 2>();
+    let table = Table::new(&data)
+        .with(Style::modern())
+        .with(Accessible::new())
+        .to_string();
+
+    let expected = concat!(
+        "+---+----------+----------+\n",
+        "| N | column 0 | column 1 |\n",
+        "+---+----------+----------+\n",
+        "| 0 |   0-0    |   0-1    |\n",
+        "+---+----------+----------+\n",
+        "| 1 |   1-0    |   1-1    |\n",
+        "+---+----------+----------+\n",
+    );
+
+    assert_eq!(table, expected);
+}
+
+#[test]
+fn accessible_option_strips_ansi_sequences() {
+    let data = [
+        "\u{1b}[31mred\u{1b}[0m",
+        "\u{1b}]8;;https://example.com\u{7}link\u{1b}]8;;\u{7}",
+    ];
+    let table = Table::new(data)
+        .with(Style::psql())
+        .with(Accessible::new())
+        .to_string();
+
+    let expected = concat!(" &str \n", "------\n", " red  \n", " link \n");
+
+    assert_eq!(table, expected);
+}
+
+#[test]
+fn style_vertical_separator() {
+    let data = create_vector::<2, 2>();
+    let table = Table::new(&data)
+        .with(Style::psql().vertical_separator(" | "))
+        .with(Modify::new(Full).with(Indent::new(0, 0, 0, 0)))
+        .to_string();
+
+    let expected = concat!(
+        "N | column 0 | column 1\n",
+        "--+----------+---------\n",
+        "0 |   0-0    |   0-1   \n",
+        "1 |   1-0    |   1-1   \n",
+    );
+
+    assert_eq!(table, expected);
+}
+
+#[test]
+fn style_vertical_separator_is_kept_by_structural_options() {
+    let data = create_vector::<2, 2>();
+    let table = Table::new(&data)
+        .with(Style::psql().vertical_separator(" | "))
+        .with(Header("header"))
+        .with(Disable::Row(2..))
+        .to_string();
+
+    let expected = concat!(
+        "header                       \n",
+        " N  |  column 0  |  column 1 \n",
+        "----+------------+-----------\n",
+    );
+
+    assert_eq!(table, expected);
+}
+
+#[test]
+fn style_vertical_separator_is_reset_by_style() {
+    let data = create_vector::<1, 1>();
+    let table = Table::new(&data)
+        .with(Style::psql().vertical_separator(" | "))
+        .with(Style::psql())
+        .to_string();
+
+    let expected = concat!(" N | column 0 \n", "---+----------\n", " 0 |   0-0    \n");
+
+    assert_eq!(table, expected);
+}
+
+#[test]
+fn heavy_frame_style() {
+    let data = create_vector::<2, 2>();
+    let table = Table::new(&data).with(Style::heavy_frame()).to_string();
+
+    let expected = concat!(
+        "┏━━━┯━━━━━━━━━━┯━━━━━━━━━━┓\n",
+        "┃ N │ column 0 │ column 1 ┃\n",
+        "┣━━━┿━━━━━━━━━━┿━━━━━━━━━━┫\n",
+        "┃ 0 │   0-0    │   0-1    ┃\n",
+        "┠───┼──────────┼──────────┨\n",
+        "┃ 1 │   1-0    │   1-1    ┃\n",
+        "┗━━━┷━━━━━━━━━━┷━━━━━━━━━━┛\n",
+    );
+
+    assert_eq!(table, expected);
+}
+
+#[test]
+fn heavy_frame_style_single_row() {
+    let data = create_vector::<0, 2>();
+    let table = Table::new(&data).with(Style::heavy_frame()).to_string();
+
+    let expected = concat!(
+        "┏━━━┯━━━━━━━━━━┯━━━━━━━━━━┓\n",
+        "┃ N │ column 0 │ column 1 ┃\n",
+        "┗━━━┷━━━━━━━━━━┷━━━━━━━━━━┛\n",
+    );
+
+    assert_eq!(table, expected);
+}
+
+#[test]
+fn rounded_style() {
+    let data = create_vector::<3, 2>();
+    let table = Table::new(&data).with(Style::rounded()).to_string();
+
+    let expected = concat!(
+        "╭───┬──────────┬──────────╮\n",
+        "│ N │ column 0 │ column 1 │\n",
+        "├───┼──────────┼──────────┤\n",
+        "│ 0 │   0-0    │   0-1    │\n",
+        "│ 1 │   1-0    │   1-1    │\n",
+        "│ 2 │   2-0    │   2-1    │\n",
+        "╰───┴──────────┴──────────╯\n",
+    );
+
+    assert_eq!(table, expected);
+}
+
+#[test]
+fn blank_header_style() {
+    let data = create_vector::<3, 2>();
+    let table = Table::new(&data).with(Style::blank_header()).to_string();
+
+    let expected = concat!(
+        " N   column 0   column 1 \n",
+        "─── ────────── ──────────\n",
+        " 0     0-0        0-1    \n",
+        " 1     1-0        1-1    \n",
+        " 2     2-0        2-1    \n",
+    );
+
+    assert_eq!(table, expected);
+}
+
+#[test]
+fn separator_every() {
+    let data = create_vector::<5, 2>();
+    let table = Table::new(&data)
+        .with(Style::modern().horizontal_off())
+        .with(SeparatorEvery(2))
+        .to_string();
+
+    let expected = concat!(
+        "┌───┬──────────┬──────────┐\n",
+        "│ N │ column 0 │ column 1 │\n",
+        "├───┼──────────┼──────────┤\n",
+        "│ 0 │   0-0    │   0-1    │\n",
+        "│ 1 │   1-0    │   1-1    │\n",
+        "├───┼──────────┼──────────┤\n",
+        "│ 2 │   2-0    │   2-1    │\n",
+        "│ 3 │   3-0    │   3-1    │\n",
+        "├───┼──────────┼──────────┤\n",
+        "│ 4 │   4-0    │   4-1    │\n",
+        "└───┴──────────┴──────────┘\n",
+    );
+
+    assert_eq!(table, expected);
+}
+
+#[test]
+fn separator_every_is_not_added_after_last_row() {
+    let data = create_vector::<4, 1>();
+    let table = Table::new(&data)
+        .with(Style::psql())
+        .with(SeparatorEvery(2))
+        .to_string();
+
+    let expected = concat!(
+        " N | column 0 \n",
+        "---+----------\n",
+        " 0 |   0-0    \n",
+        " 1 |   1-0    \n",
+        "---+----------\n",
+        " 2 |   2-0    \n",
+        " 3 |   3-0    \n",
+    );
+
+    assert_eq!(table, expected);
+}
+
+#[test]
+fn separator_every_without_header_line() {
+    let data = create_vector::<4, 1>();
+    let expected = Table::new(&data).with(Style::blank()).to_string();
+
+    let table = Table::new(&data)
+        .with(Style::blank())
+        .with(SeparatorEvery(1))
+        .to_string();
+    assert_eq!(table, expected);
+
+    let expected = Table::new(&data).with(Style::psql()).to_string();
+
+    let table = Table::new(&data)
+        .with(Style::psql())
+        .with(SeparatorEvery(0))
+        .to_string();
+    assert_eq!(table, expected);
+}
+
+#[test]
+fn separator_every_is_reset_by_style() {
+    let data = create_vector::<4, 1>();
+    let table = Table::new(&data)
+        .with(Style::psql())
+        .with(SeparatorEvery(1))
+        .with(Style::psql())
+        .to_string();
+
+    assert_eq!(table, Table::new(&data).with(Style::psql()).to_string());
+}
+
+#[test]
+fn raw_style_from_presets() {
+    fn assert_raw<S>(style: S)
+    where
+        S: tabled::TableOption + Clone + Into<RawStyle>,
+    {
+        let data = create_vector::<3, 3>();
+        let expected = Table::new(&data).with(style.clone()).to_string();
+        let table = Table::new(&data).with(style.into()).to_string();
+        assert_eq!(table, expected);
+    }
+
+    assert_raw(Style::empty());
+    assert_raw(Style::blank());
+    assert_raw(Style::ascii());
+    assert_raw(Style::dots());
+    assert_raw(Style::psql());
+    assert_raw(Style::github_markdown());
+    assert_raw(Style::modern());
+    assert_raw(Style::extended());
+    assert_raw(Style::re_structured_text());
+    assert_raw(Style::accessible());
+    assert_raw(Style::heavy_frame());
+    assert_raw(Style::rounded());
+    assert_raw(Style::blank_header());
+    assert_raw(Style::modern().vertical_separator(" │ "));
+    assert_raw(Style::modern().lines_every(2));
+}
+
+#[test]
+fn raw_style_independent_chars() {
+    let data = create_vector::<2, 2>();
+    let style = RawStyle::from(Style::ascii())
+        .left_intersection(Some('>'))
+        .right_intersection(Some('<'))
+        .left_header_intersection(Some('#'))
+        .header_intersection(Some('*'))
+        .top_intersection(None)
+        .bottom(None);
+
+    let table = Table::new(&data).with(style).to_string();
+
+    let expected = concat!(
+        "+--- ---------- ----------+\n",
+        "| N | column 0 | column 1 |\n",
+        "#---*----------*----------+\n",
+        "| 0 |   0-0    |   0-1    |\n",
+        ">---+----------+----------<\n",
+        "| 1 |   1-0    |   1-1    |\n",
+    );
+
+    assert_eq!(table, expected);
+}
+
+#[test]
+fn lines_every() {
+    let data = create_vector::<5, 1>();
+    let table = Table::new(&data)
+        .with(Style::ascii().lines_every(3))
+        .to_string();
+
+    let expected = concat!(
+        "+---+----------+\n",
+        "| N | column 0 |\n",
+        "+---+----------+\n",
+        "| 0 |   0-0    |\n",
+        "| 1 |   1-0    |\n",
+        "| 2 |   2-0    |\n",
+        "+---+----------+\n",
+        "| 3 |   3-0    |\n",
+        "| 4 |   4-0    |\n",
+        "+---+----------+\n",
+    );
+
+    assert_eq!(table, expected);
+}
+
+#[test]
+fn lines_every_zero() {
+    let data = create_vector::<3, 1>();
+    let table = Table::new(&data)
+        .with(Style::ascii().lines_every(0))
+        .to_string();
+
+    let expected = Table::new(&data)
+        .with(Style::ascii().horizontal_off())
+        .to_string();
+
+    assert_eq!(table, expected);
+}
+
+#[test]
+fn lines_every_matches_separator_every() {
+    let data = create_vector::<5, 2>();
+    let table = Table::new(&data)
+        .with(Style::ascii().lines_every(2))
+        .to_string();
+
+    let expected = Table::new(&data)
+        .with(Style::ascii().horizontal_off())
+        .with(SeparatorEvery(2))
+        .to_string();
+
+    assert_eq!(table, expected);
+}
+
+#[test]
+fn presets_are_consistent() {
+    assert_eq!(Style::empty().verify(), vec![]);
+    assert_eq!(Style::blank().verify(), vec![]);
+    assert_eq!(Style::ascii().verify(), vec![]);
+    assert_eq!(Style::dots().verify(), vec![]);
+    assert_eq!(Style::psql().verify(), vec![]);
+    assert_eq!(Style::github_markdown().verify(), vec![]);
+    assert_eq!(Style::modern().verify(), vec![]);
+    assert_eq!(Style::extended().verify(), vec![]);
+    assert_eq!(Style::re_structured_text().verify(), vec![]);
+    assert_eq!(Style::accessible().verify(), vec![]);
+    assert_eq!(Style::heavy_frame().verify(), vec![]);
+    assert_eq!(Style::rounded().verify(), vec![]);
+    assert_eq!(Style::blank_header().verify(), vec![]);
+}
+
+#[test]
+fn verify_raw_style() {
+    let style = RawStyle::new()
+        .top(Some('-'))
+        .bottom(Some('━'))
+        .left(Some('|'))
+        .right(Some('┃'))
+        .vertical(Some('│'))
+        .bottom_left_corner(Some('+'))
+        .left_intersection(Some('+'));
+
+    assert_eq!(
+        style.verify(),
+        vec![
+            StyleIssue::Missing {
+                name: "top_left_corner",
+                suggestion: '+'
+            },
+            StyleIssue::Missing {
+                name: "top_intersection",
+                suggestion: '-'
+            },
+            StyleIssue::Missing {
+                name: "top_right_corner",
+                suggestion: '-'
+            },
+            StyleIssue::Unused {
+                name: "left_intersection"
+            },
+            StyleIssue::Missing {
+                name: "bottom_intersection",
+                suggestion: '━'
+            },
+            StyleIssue::Missing {
+                name: "bottom_right_corner",
+                suggestion: '┛'
+            },
+        ]
+    );
+
+    assert_eq!(style.fix().verify(), vec![]);
+}
+
+#[test]
+fn fix_custom_style() {
+    let data = create_vector::<1, 1>();
+    let style = Style::empty().top('─').bottom('─').left('│').vertical('│');
+
+    assert_eq!(
+        style.verify(),
+        vec![
+            StyleIssue::Mismatched {
+                name: "top_left_corner",
+                suggestion: '┌'
+            },
+            StyleIssue::Mismatched {
+                name: "top_intersection",
+                suggestion: '┬'
+            },
+            StyleIssue::Mismatched {
+                name: "bottom_left_corner",
+                suggestion: '└'
+            },
+            StyleIssue::Mismatched {
+                name: "bottom_intersection",
+                suggestion: '┴'
+            },
+        ]
+    );
+
+    let table = Table::new(&data).with(style.fix()).to_string();
+
+    let expected = concat!(
+        "┌───┬──────────\n",
+        "│ N │ column 0 \n",
+        "│ 0 │   0-0    \n",
+        "└───┴──────────\n",
+    );
+
+    assert_eq!(table, expected);
+}
+
+#[test]
+fn top_border_override_with_wide_chars_test() {
+    let data = create_vector::<2, 2>();
+    let table = Table::new(&data)
+        .with(Style::ascii())
+        .with(TopBorderText::new("表格").alignment(AlignmentHorizontal::Center))
+        .to_string();
+
+    let expected = concat!(
+        "+---+------表格+----------+\n",
+        "| N | column 0 | column 1 |\n",
+        "+---+----------+----------+\n",
+        "| 0 |   0-0    |   0-1    |\n",
+        "+---+----------+----------+\n",
+        "| 1 |   1-0    |   1-1    |\n",
+        "+---+----------+----------+\n",
+    );
+
+    assert_eq!(table, expected);
+}
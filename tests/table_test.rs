@@ -1,8 +1,11 @@
 use std::{
-    collections::{BTreeMap, BTreeSet},
+    collections::{BTreeMap, BTreeSet, HashMap},
     iter::FromIterator,
 };
-use tabled::{Style, Table, TableIteratorExt, Tabled};
+use tabled::{
+    Alignment, Cell, Disable, Format, Header, Indent, Layout, LineSpacing, Modify, Origin, Rotate,
+    Style, Table, TableIteratorExt, Tabled,
+};
 
 use crate::util::create_vector;
 
@@ -296,6 +299,54 @@ mod default_types {
 
         assert_eq!(table, expected);
     }
+
+    #[test]
+    fn table_vec() {
+        let data = vec![vec![0, 1], vec![2, 3, 4]];
+        let expected = "+---+---+---+\n\
+                             | 0 | 1 | 2 |\n\
+                             +---+---+---+\n\
+                             | 0 | 1 |   |\n\
+                             +---+---+---+\n\
+                             | 2 | 3 | 4 |\n\
+                             +---+---+---+\n";
+
+        assert_eq!(Table::new(&data).to_string(), expected);
+
+        let slices = data.iter().map(Vec::as_slice);
+        assert_eq!(Table::new(slices).to_string(), expected);
+    }
+}
+
+#[test]
+fn table_option_and_result_elements() {
+    #[derive(Tabled)]
+    struct St {
+        f1: u8,
+        f2: &'static str,
+    }
+
+    let data = vec![Some(St { f1: 0, f2: "0" }), None];
+    let expected = "+----+----+\n\
+                         | f1 | f2 |\n\
+                         +----+----+\n\
+                         | 0  | 0  |\n\
+                         +----+----+\n\
+                         |    |    |\n\
+                         +----+----+\n";
+
+    assert_eq!(Table::new(data).to_string(), expected);
+
+    let data: Vec<Result<St, String>> = vec![Ok(St { f1: 0, f2: "0" }), Err("no data".into())];
+    let expected = "+--------------+----+----+\n\
+                         |    Result    | f1 | f2 |\n\
+                         +--------------+----+----+\n\
+                         |      Ok      | 0  | 0  |\n\
+                         +--------------+----+----+\n\
+                         | Err: no data |    |    |\n\
+                         +--------------+----+----+\n";
+
+    assert_eq!(Table::new(data).to_string(), expected);
 }
 
 #[test]
@@ -338,6 +389,35 @@ fn table_empty_vector_structures() {
     assert_eq!(table, expected);
 }
 
+#[test]
+fn table_without_header() {
+    #[derive(Tabled)]
+    struct St {
+        f1: u8,
+        f2: &'static str,
+    }
+
+    let st = vec![St { f1: 0, f2: "zero" }, St { f1: 1, f2: "one" }];
+    let expected = "+---+------+\n\
+                         | 0 | zero |\n\
+                         +---+------+\n\
+                         | 1 | one  |\n\
+                         +---+------+\n";
+
+    let table = Table::new_without_header(st).to_string();
+
+    assert_eq!(table, expected);
+}
+
+#[test]
+fn table_without_header_empty() {
+    let data: Vec<(u8, u8)> = Vec::new();
+    let table = Table::new_without_header(data);
+
+    assert_eq!(table.shape(), (0, 0));
+    assert_eq!(table.to_string(), "");
+}
+
 #[test]
 fn table_option() {
     #[derive(Tabled)]
@@ -592,6 +672,33 @@ fn table_btreemap() {
     assert_eq!(table, expected);
 }
 
+#[test]
+fn table_from_map() {
+    let map = HashMap::from([("b", 2), ("c", 3), ("a", 1)]);
+    let expected = "+-----+-------+\n\
+                         | key | value |\n\
+                         +-----+-------+\n\
+                         |  a  |   1   |\n\
+                         +-----+-------+\n\
+                         |  b  |   2   |\n\
+                         +-----+-------+\n\
+                         |  c  |   3   |\n\
+                         +-----+-------+\n";
+
+    assert_eq!(Table::from_map_sorted(&map).to_string(), expected);
+
+    let map = map.into_iter().collect::<BTreeMap<_, _>>();
+    assert_eq!(Table::from_map(&map).to_string(), expected);
+
+    let map: HashMap<u8, u8> = HashMap::new();
+    assert_eq!(
+        Table::from_map(map).to_string(),
+        "+-----+-------+\n\
+         | key | value |\n\
+         +-----+-------+\n"
+    );
+}
+
 #[test]
 fn table_btreeset() {
     #[derive(Tabled, PartialEq, Eq, PartialOrd, Ord)]
@@ -837,3 +944,324 @@ fn table_emojie_utf8_style() {
 
     assert_eq!(table, expected);
 }
+
+#[test]
+fn modify_cells_out_of_the_table_are_ignored() {
+    let data = create_vector::<1, 1>();
+    let table = Table::new(&data)
+        .with(Style::psql())
+        .with(
+            Modify::new(Cell(10, 10))
+                .with(Alignment::left())
+                .with(|s: &str| format!("{}!", s)),
+        )
+        .to_string();
+
+    let expected = concat!(" N | column 0 \n", "---+----------\n", " 0 |   0-0    \n",);
+
+    assert_eq!(table, expected);
+}
+
+#[test]
+fn table_zip_with() {
+    let data1 = create_vector::<2, 2>();
+    let data2 = create_vector::<2, 2>();
+    let table1 = Table::new(&data1);
+    let table2 = Table::new(&data2);
+
+    let table = table1
+        .zip_with(&table2, |a, b| format!("{}={}", a, b))
+        .with(Style::psql())
+        .to_string();
+
+    let expected = concat!(
+        " N=N | column 0=column 0 | column 1=column 1 \n",
+        "-----+-------------------+-------------------\n",
+        " 0=0 |      0-0=0-0      |      0-1=0-1      \n",
+        " 1=1 |      1-0=1-0      |      1-1=1-1      \n",
+    );
+
+    assert_eq!(table, expected);
+}
+
+#[test]
+fn table_zip_with_different_shapes() {
+    let data1 = create_vector::<2, 2>();
+    let data2 = create_vector::<1, 1>();
+    let table1 = Table::new(&data1);
+    let table2 = Table::new(&data2);
+
+    let table = table1
+        .zip_with(&table2, |a, b| format!("{}|{}", a, b))
+        .with(Style::psql())
+        .to_string();
+
+    let expected = concat!(
+        " N|N | column 0|column 0 | column 1 \n",
+        "-----+-------------------+----------\n",
+        " 0|0 |      0-0|0-0      |   0-1    \n",
+        "  1  |        1-0        |   1-1    \n",
+    );
+
+    assert_eq!(table, expected);
+}
+
+#[test]
+fn table_map_coord() {
+    let data = create_vector::<2, 2>();
+    let table = Table::new(&data);
+    assert_eq!(table.map_coord(1, 1), Some((1, 1)));
+    assert_eq!(table.map_coord(3, 0), None);
+
+    let table = table.with(Header("Title")).with(Disable::Column(..1));
+    assert_eq!(table.map_coord(1, 1), Some((2, 0)));
+    assert_eq!(table.map_coord(2, 2), Some((3, 1)));
+    assert_eq!(table.map_coord(1, 0), None);
+
+    let table = table.with(Rotate::Left);
+    assert_eq!(table.map_coord(1, 1), Some((1, 2)));
+}
+
+#[test]
+fn table_modify_by_origin() {
+    let data = create_vector::<2, 2>();
+    let table = Table::new(&data)
+        .with(Header("Title"))
+        .with(Modify::new(Origin(1, 1)).with(Format(|s| format!("[{}]", s))))
+        .with(Style::psql())
+        .to_string();
+
+    let expected = concat!(
+        "Title                    \n",
+        "---+----------+----------\n",
+        " N | column 0 | column 1 \n",
+        " 0 |  [0-0]   |   0-1    \n",
+        " 1 |   1-0    |   1-1    \n",
+    );
+
+    assert_eq!(table, expected);
+}
+
+#[test]
+fn table_of_tables() {
+    let left = Table::new([1, 2]).with(Style::psql());
+    let right = Table::new(["a"]).with(Style::psql());
+
+    let table = Table::new([(left, right)]).to_string();
+
+    let expected = "+-------+--------+\n\
+                    | Table | Table  |\n\
+                    +-------+--------+\n\
+                    |  i32  |  &str  |\n\
+                    | ----- | ------ |\n\
+                    |   1   |   a    |\n\
+                    |   2   |        |\n\
+                    +-------+--------+\n";
+
+    assert_eq!(table, expected);
+}
+
+#[test]
+fn nested_table_fields_have_no_trailing_new_line() {
+    let table = Table::new([1]).with(Style::psql());
+    assert_eq!(table.fields(), vec![" i32 \n-----\n  1  "]);
+    assert_eq!(Table::headers(), vec!["Table"]);
+}
+
+#[test]
+fn table_kv() {
+    let table =
+        Table::kv([("name", "tabled"), ("version", "0.5.0"), ("license", "MIT")]).to_string();
+
+    let expected = "+---------+--------+\n\
+                    |    name | tabled |\n\
+                    +---------+--------+\n\
+                    | version | 0.5.0  |\n\
+                    +---------+--------+\n\
+                    | license | MIT    |\n\
+                    +---------+--------+\n";
+
+    assert_eq!(table, expected);
+}
+
+#[test]
+fn table_kv_empty() {
+    let table = Table::kv(Vec::<(String, String)>::new());
+    assert_eq!(table.to_string(), "");
+}
+
+#[test]
+fn table_debug_string() {
+    let data = create_vector::<2, 1>();
+    let table = Table::new(&data)
+        .with(Style::ascii())
+        .with(Modify::new(Cell(1, 1)).with(Indent::new(1, 1, 1, 0)))
+        .with(Modify::new(Cell(2, 1)).with(Alignment::left()));
+
+    let expected = concat!(
+        "+---+----------+\n",
+        "|·N·|·column 0·|\n",
+        "+---+----------+\n",
+        "|·0·|··········|\n",
+        "|···|···0-0····|\n",
+        "+---+----------+\n",
+        "|·1·|·1-0······|\n",
+        "+---+----------+\n",
+    );
+
+    assert_eq!(table.to_debug_string(), expected);
+}
+
+#[test]
+fn table_debug_string_doesnt_change_table() {
+    let data = create_vector::<2, 1>();
+    let table = Table::new(&data).with(Style::re_structured_text());
+    let expected = table.to_string();
+
+    assert_eq!(
+        table.to_debug_string(),
+        concat!(
+            "===░==========\n",
+            "·N·░·column 0·\n",
+            "===░==========\n",
+            "·0·░···0-0····\n",
+            "·1·░···1-0····\n",
+            "===░==========\n",
+        )
+    );
+    assert_eq!(table.to_string(), expected);
+}
+
+#[test]
+fn paginate_repeats_header_and_keeps_frame() {
+    let table = Table::new(["a", "b", "long cell"]).with(Style::modern());
+
+    let pages = table
+        .paginate(2)
+        .map(|page| page.to_string())
+        .collect::<Vec<_>>();
+
+    assert_eq!(
+        pages,
+        [
+            concat!(
+                "┌───────────┐\n",
+                "│   &str    │\n",
+                "├───────────┤\n",
+                "│     a     │\n",
+                "├───────────┤\n",
+                "│     b     │\n",
+                "└───────────┘\n",
+            ),
+            concat!(
+                "┌───────────┐\n",
+                "│   &str    │\n",
+                "├───────────┤\n",
+                "│ long cell │\n",
+                "└───────────┘\n",
+            ),
+        ]
+    );
+}
+
+#[test]
+fn paginate_table_without_rows() {
+    let table = Table::new(Vec::<i32>::new());
+
+    let pages = table
+        .paginate(10)
+        .map(|page| page.to_string())
+        .collect::<Vec<_>>();
+
+    assert_eq!(pages, [table.to_string()]);
+}
+
+#[test]
+fn debug_shows_grid_structure() {
+    let table = Table::new([1]).with(Style::psql());
+
+    let debug = format!("{:?}", table);
+
+    assert!(debug.starts_with("Table { grid: Grid { size: (2, 1), header_rows: 1"));
+    assert!(!debug.contains("-----"));
+}
+
+#[test]
+fn write_to_and_fmt_into_match_display() {
+    let table = Table::new([("Hello", 1), ("World", 2)]).with(Style::modern());
+
+    let mut bytes = Vec::new();
+    table.write_to(&mut bytes).unwrap();
+
+    let mut buf = String::new();
+    table.fmt_into(&mut buf).unwrap();
+
+    assert_eq!(String::from_utf8(bytes).unwrap(), table.to_string());
+    assert_eq!(buf, table.to_string());
+}
+
+#[test]
+fn write_to_returns_io_error() {
+    struct Failing;
+
+    impl std::io::Write for Failing {
+        fn write(&mut self, _: &[u8]) -> std::io::Result<usize> {
+            Err(std::io::Error::other("closed"))
+        }
+
+        fn flush(&mut self) -> std::io::Result<()> {
+            Ok(())
+        }
+    }
+
+    let result = Table::new([1]).write_to(&mut Failing);
+
+    assert_eq!(result.unwrap_err().to_string(), "closed");
+}
+
+struct Inconsistent;
+
+impl Tabled for Inconsistent {
+    const LENGTH: usize = 1;
+
+    fn fields(&self) -> Vec<String> {
+        vec![String::from("1"), String::from("2")]
+    }
+
+    fn headers() -> Vec<String> {
+        vec![String::from("a"), String::from("b")]
+    }
+}
+
+#[test]
+#[should_panic(expected = "Tabled implementation of `table_test::Inconsistent` returned 2 fields")]
+fn inconsistent_length_panics() {
+    Table::new([Inconsistent]);
+}
+
+#[test]
+#[should_panic(expected = "Tabled implementation of `table_test::Inconsistent` returned 2 fields")]
+fn inconsistent_length_in_tuple_panics() {
+    Table::new([(1, Inconsistent)]);
+}
+
+#[test]
+fn paginate_keeps_settings_of_rows_and_columns() {
+    let table = Table::new(["a", "b", "c"])
+        .with(Style::psql())
+        .with(LineSpacing(1))
+        .with(Layout::fixed(vec![6]));
+
+    let pages = table
+        .paginate(2)
+        .map(|page| page.to_string())
+        .collect::<Vec<_>>();
+
+    assert_eq!(
+        pages,
+        [
+            concat!(" &str \n", "------\n", "  a   \n", "      \n", "  b   \n", "      \n",),
+            concat!(" &str \n", "------\n", "  c   \n", "      \n"),
+        ]
+    );
+}
@@ -30,7 +30,8 @@ impl<const N: usize> IndexMut<usize> for Obj<N> {
 }
 
 impl<const N: usize> Tabled for Obj<N> {
-    const LENGTH: usize = N;
+    // an index column goes first
+    const LENGTH: usize = N + 1;
 
     fn fields(&self) -> Vec<String> {
         self.data.clone()
@@ -1,6 +1,9 @@
 use crate::util::create_vector;
+#[cfg(feature = "regex")]
+use regex::Regex;
 use tabled::{
-    Alignment, Cell, Column, Full, MaxWidth, MinWidth, Modify, Object, Row, Style, Table,
+    Alignment, Cell, Column, Constraint, Full, Indent, MaxWidth, MinWidth, Modify, Object, Row,
+    Span, Style, Table, UseWidths, Width,
 };
 
 mod util;
@@ -425,11 +428,11 @@ fn min_width() {
         .to_string();
 
     let expected = concat!(
-        "|      N       |   column 0   |   column 1   |   column 2   |\n",
-        "|--------------+--------------+--------------+--------------|\n",
-        "|      0       |     0-0      |     0-1      |     0-2      |\n",
-        "|      1       |     1-0      |     1-1      |     1-2      |\n",
-        "|      2       |     2-0      |     2-1      |     2-2      |\n",
+        "|     N      |  column 0  |  column 1  |  column 2  |\n",
+        "|------------+------------+------------+------------|\n",
+        "|     0      |    0-0     |    0-1     |    0-2     |\n",
+        "|     1      |    1-0     |    1-1     |    1-2     |\n",
+        "|     2      |    2-0     |    2-1     |    2-2     |\n",
     );
 
     assert_eq!(table, expected);
@@ -444,11 +447,11 @@ fn min_width_with_filler() {
         .to_string();
 
     let expected = concat!(
-        "| N........... | column 0.... | column 1.... | column 2.... |\n",
-        "|--------------+--------------+--------------+--------------|\n",
-        "|      0       |     0-0      |     0-1      |     0-2      |\n",
-        "|      1       |     1-0      |     1-1      |     1-2      |\n",
-        "|      2       |     2-0      |     2-1      |     2-2      |\n",
+        "|.....N......|..column 0..|..column 1..|..column 2..|\n",
+        "|------------+------------+------------+------------|\n",
+        "|     0      |    0-0     |    0-1     |    0-2     |\n",
+        "|     1      |    1-0     |    1-1     |    1-2     |\n",
+        "|     2      |    2-0     |    2-1     |    2-2     |\n",
     );
 
     assert_eq!(table, expected);
@@ -463,11 +466,11 @@ fn min_width_one_column() {
         .to_string();
 
     let expected = concat!(
-        "|   N   | column 0 | column 1 | column 2 |\n",
-        "|-------+----------+----------+----------|\n",
-        "|   0   |   0-0    |   0-1    |   0-2    |\n",
-        "|   1   |   1-0    |   1-1    |   1-2    |\n",
-        "|   2   |   2-0    |   2-1    |   2-2    |\n",
+        "|  N  | column 0 | column 1 | column 2 |\n",
+        "|-----+----------+----------+----------|\n",
+        "|  0  |   0-0    |   0-1    |   0-2    |\n",
+        "|  1  |   1-0    |   1-1    |   1-2    |\n",
+        "|  2  |   2-0    |   2-1    |   2-2    |\n",
     );
 
     assert_eq!(table, expected);
@@ -496,11 +499,11 @@ fn min_with_max_width() {
         .to_string();
 
     let expected = concat!(
-        "|  N  | col | col | col |\n",
-        "|-----+-----+-----+-----|\n",
-        "|  0  | 0-0 | 0-1 | 0-2 |\n",
-        "|  1  | 1-0 | 1-1 | 1-2 |\n",
-        "|  2  | 2-0 | 2-1 | 2-2 |\n",
+        "| N | col | col | col |\n",
+        "|---+-----+-----+-----|\n",
+        "| 0 | 0-0 | 0-1 | 0-2 |\n",
+        "| 1 | 1-0 | 1-1 | 1-2 |\n",
+        "| 2 | 2-0 | 2-1 | 2-2 |\n",
     );
 
     assert_eq!(table, expected);
@@ -516,11 +519,11 @@ fn min_with_max_width_truncate_suffix() {
         .to_string();
 
     let expected = concat!(
-        "|  N  | col... | col... | col... |\n",
-        "|-----+--------+--------+--------|\n",
-        "|  0  |  0-0   |  0-1   |  0-2   |\n",
-        "|  1  |  1-0   |  1-1   |  1-2   |\n",
-        "|  2  |  2-0   |  2-1   |  2-2   |\n",
+        "| N | col... | col... | col... |\n",
+        "|---+--------+--------+--------|\n",
+        "| 0 |  0-0   |  0-1   |  0-2   |\n",
+        "| 1 |  1-0   |  1-1   |  1-2   |\n",
+        "| 2 |  2-0   |  2-1   |  2-2   |\n",
     );
 
     assert_eq!(table, expected);
@@ -538,11 +541,11 @@ fn min_width_color() {
     ];
 
     let expected = concat!(
-        "|   String   |\n",
-        "|------------|\n",
-        "|    \u{1b}[31masd\u{1b}[0m     |\n",
-        "|    \u{1b}[34mzxc\u{1b}[0m     |\n",
-        "|   \u{1b}[32m\u{1b}[40masdasd\u{1b}[0m\u{1b}[0m   |\n",
+        "|  String  |\n",
+        "|----------|\n",
+        "|   \u{1b}[31masd\u{1b}[0m    |\n",
+        "|   \u{1b}[34mzxc\u{1b}[0m    |\n",
+        "|  \u{1b}[32m\u{1b}[40masdasd\u{1b}[0m\u{1b}[0m  |\n",
     );
 
     let table = Table::new(data)
@@ -573,3 +576,419 @@ fn min_width_color_with_smaller_then_width() {
         Table::new(data).to_string()
     );
 }
+
+#[cfg(feature = "regex")]
+#[test]
+fn max_width_truncate_keeping() {
+    let data = ["a_long_file_name.txt", "main.rs", "no_extension_at_all"];
+    let table = Table::new(data)
+        .with(Style::github_markdown())
+        .with(
+            Modify::new(Row(1..)).with(
+                MaxWidth::truncating(10)
+                    .suffix("..")
+                    .keeping(Regex::new(r"\.[a-z]+$").unwrap()),
+            ),
+        )
+        .to_string();
+
+    let expected = concat!(
+        "|     &str     |\n",
+        "|--------------|\n",
+        "| a_long...txt |\n",
+        "|   main.rs    |\n",
+        "| no_extensi.. |\n",
+    );
+
+    assert_eq!(table, expected);
+}
+
+#[cfg(feature = "regex")]
+#[test]
+fn max_width_truncate_keeping_a_middle_part() {
+    let data = ["id-1b4e28ba-2fa1-suffix", "id-1b4e28ba"];
+    let table = Table::new(data)
+        .with(Style::github_markdown())
+        .with(
+            Modify::new(Row(1..))
+                .with(MaxWidth::truncating(14).keeping(Regex::new(r"[0-9a-f]{8}-").unwrap())),
+        )
+        .to_string();
+
+    let expected = concat!(
+        "|      &str      |\n",
+        "|----------------|\n",
+        "| id-1b4e28ba-2f |\n",
+        "|  id-1b4e28ba   |\n",
+    );
+
+    assert_eq!(table, expected);
+
+    let table = Table::new(data)
+        .with(Style::github_markdown())
+        .with(
+            Modify::new(Row(1..))
+                .with(MaxWidth::truncating(10).keeping(Regex::new(r"[0-9a-f]{8}-").unwrap())),
+        )
+        .to_string();
+
+    let expected = concat!(
+        "|    &str    |\n",
+        "|------------|\n",
+        "| i1b4e28ba- |\n",
+        "| id-1b4e28b |\n",
+    );
+
+    assert_eq!(table, expected);
+}
+
+#[test]
+fn budget_width_grows_columns_equally() {
+    let data = create_vector::<1, 2>();
+    let table = Table::new(&data)
+        .with(Style::ascii())
+        .with(Width::budget(35))
+        .to_string();
+
+    let expected = concat!(
+        "+------+-------------+------------+\n",
+        "|  N   |  column 0   |  column 1  |\n",
+        "+------+-------------+------------+\n",
+        "|  0   |     0-0     |    0-1     |\n",
+        "+------+-------------+------------+\n",
+    );
+
+    assert_eq!(table, expected);
+}
+
+#[test]
+fn budget_width_respects_weights() {
+    let data = create_vector::<1, 2>();
+    let table = Table::new(&data)
+        .with(Style::ascii())
+        .with(
+            Width::budget(36)
+                .column(0, Constraint::Weight(0))
+                .column(2, Constraint::Weight(2)),
+        )
+        .to_string();
+
+    let expected = concat!(
+        "+---+-------------+----------------+\n",
+        "| N |  column 0   |    column 1    |\n",
+        "+---+-------------+----------------+\n",
+        "| 0 |     0-0     |      0-1       |\n",
+        "+---+-------------+----------------+\n",
+    );
+
+    assert_eq!(table, expected);
+}
+
+#[test]
+fn budget_width_shrinks_columns() {
+    let data = create_vector::<1, 2>();
+    let table = Table::new(&data)
+        .with(Style::ascii())
+        .with(Width::budget(20).column(1, Constraint::Min(8)))
+        .to_string();
+
+    let expected = concat!(
+        "+---+--------+-----+\n",
+        "| N | column | col |\n",
+        "|   |   0    | umn |\n",
+        "|   |        |  1  |\n",
+        "+---+--------+-----+\n",
+        "| 0 |  0-0   | 0-1 |\n",
+        "+---+--------+-----+\n",
+    );
+
+    assert_eq!(table, expected);
+}
+
+#[test]
+fn budget_width_respects_max() {
+    let data = create_vector::<1, 2>();
+    let table = Table::new(&data)
+        .with(Style::ascii())
+        .with(Width::budget(40).column(1, Constraint::Max(10)))
+        .to_string();
+
+    let expected = concat!(
+        "+----------+----------+----------------+\n",
+        "|    N     | column 0 |    column 1    |\n",
+        "+----------+----------+----------------+\n",
+        "|    0     |   0-0    |      0-1       |\n",
+        "+----------+----------+----------------+\n",
+    );
+
+    assert_eq!(table, expected);
+}
+
+#[test]
+fn use_widths_of_another_table() {
+    let first = Table::new([("Hello World", 1)]).with(Style::psql());
+    let second = Table::new([("Hi", 123)])
+        .with(Style::psql())
+        .with(UseWidths(first.widths()));
+
+    assert_eq!(first.widths(), vec![13, 5]);
+    assert_eq!(second.widths(), vec![13, 5]);
+
+    let expected = concat!(
+        "    &str     | i32 \n",
+        "-------------+-----\n",
+        "     Hi      | 123 \n",
+    );
+
+    assert_eq!(second.to_string(), expected);
+}
+
+#[test]
+fn use_widths_wraps_content() {
+    let table = Table::new([("Hello World", 1)])
+        .with(Style::psql())
+        .with(UseWidths(vec![7, 5]))
+        .to_string();
+
+    let expected = concat!(
+        " &str  | i32 \n",
+        "-------+-----\n",
+        " Hello |  1  \n",
+        " Worl  |     \n",
+        "   d   |     \n",
+    );
+
+    assert_eq!(table, expected);
+}
+
+#[test]
+fn min_width_keeps_lines() {
+    let table = Table::new(["a\nbc"])
+        .with(Style::github_markdown())
+        .with(Modify::new(Full).with(MinWidth::new(5).fill_with('.')))
+        .to_string();
+
+    assert_eq!(
+        table,
+        "|.&str.|\n\
+         |------|\n\
+         |..a...|\n\
+         |..bc..|\n"
+    );
+}
+
+#[test]
+fn max_width_wrapping_splits_lines_independently() {
+    let table = Table::new(["abc\nde"])
+        .with(Style::github_markdown())
+        .with(
+            Modify::new(Full)
+                .with(MaxWidth::wrapping(2))
+                .with(Alignment::left()),
+        )
+        .to_string();
+
+    assert_eq!(
+        table,
+        "| &s |\n\
+         | tr |\n\
+         |----|\n\
+         | ab |\n\
+         | c  |\n\
+         | de |\n"
+    );
+}
+
+#[test]
+fn budget_width_counts_vertical_separator() {
+    let data = create_vector::<1, 2>();
+    let table = Table::new(&data)
+        .with(Style::psql().vertical_separator(" | "))
+        .with(Width::budget(20))
+        .to_string();
+
+    assert!(table
+        .lines()
+        .all(|line| papergrid::string_width(line) == 20));
+}
+
+#[test]
+fn min_width_includes_indent() {
+    let table = Table::new(["a"])
+        .with(Style::github_markdown())
+        .with(Modify::new(Full).with(Indent::new(2, 2, 0, 0)))
+        .with(Modify::new(Full).with(MinWidth::new(8)))
+        .to_string();
+
+    assert_eq!(
+        table,
+        concat!("|  &str  |\n", "|--------|\n", "|   a    |\n",)
+    );
+}
+
+#[test]
+fn min_width_spanned_cell() {
+    let data = create_vector::<1, 1>();
+    let table = Table::new(&data)
+        .with(Style::ascii())
+        .with(Modify::new(Cell(0, 0)).with(Span::column(2)))
+        .with(Modify::new(Cell(0, 0)).with(MinWidth::new(20)))
+        .to_string();
+
+    let expected = concat!(
+        "+---------+----------+\n",
+        "|         N          |\n",
+        "+---------+----------+\n",
+        "|    0    |   0-0    |\n",
+        "+---------+----------+\n",
+    );
+
+    assert_eq!(table, expected);
+}
+
+#[test]
+fn max_width_truncating_table() {
+    let data = [("id", "A long description of a row")];
+    let table = Table::new(data)
+        .with(Style::ascii())
+        .with(MaxWidth::truncating(20).suffix(".."))
+        .to_string();
+
+    let expected = concat!(
+        "+------+-----------+\n",
+        "| &str |   &str    |\n",
+        "+------+-----------+\n",
+        "|  id  | A long .. |\n",
+        "+------+-----------+\n",
+    );
+
+    assert_eq!(table, expected);
+    assert!(table.lines().all(|line| line.chars().count() <= 20));
+}
+
+#[test]
+fn max_width_wrapping_table() {
+    let data = [("id", "A long description")];
+    let table = Table::new(data)
+        .with(Style::ascii())
+        .with(MaxWidth::wrapping(20))
+        .to_string();
+
+    let expected = concat!(
+        "+------+-----------+\n",
+        "| &str |   &str    |\n",
+        "+------+-----------+\n",
+        "|  id  | A long de |\n",
+        "|      | scription |\n",
+        "+------+-----------+\n",
+    );
+
+    assert_eq!(table, expected);
+}
+
+#[test]
+fn max_width_table_which_fits_is_not_changed() {
+    let data = create_vector::<3, 3>();
+    let table = Table::new(&data).with(Style::ascii());
+    let expected = table.to_string();
+
+    let table = table.with(MaxWidth::wrapping(1000)).to_string();
+
+    assert_eq!(table, expected);
+}
+
+#[test]
+fn max_width_wrapping_table_keeping_words() {
+    let data = [("A long description", "A long description")];
+    let table = Table::new(data)
+        .with(Style::ascii())
+        .with(MaxWidth::wrapping(25).keep_words())
+        .to_string();
+
+    let expected = concat!(
+        "+-----------+-----------+\n",
+        "|   &str    |   &str    |\n",
+        "+-----------+-----------+\n",
+        "|  A long   |  A long   |\n",
+        "| descripti | descripti |\n",
+        "|    on     |    on     |\n",
+        "+-----------+-----------+\n",
+    );
+
+    assert_eq!(table, expected);
+}
+
+#[test]
+fn total_width_shrinks_columns_proportionally() {
+    let data = [("a", "Hello World", "Hello World Hello World")];
+    let table = Table::new(data)
+        .with(Style::ascii())
+        .with(Width::total(30))
+        .to_string();
+
+    let expected = concat!(
+        "+---+--------+---------------+\n",
+        "| & |  &str  |     &str      |\n",
+        "| s |        |               |\n",
+        "| t |        |               |\n",
+        "| r |        |               |\n",
+        "+---+--------+---------------+\n",
+        "| a | Hello  | Hello World H |\n",
+        "|   | World  |  ello World   |\n",
+        "+---+--------+---------------+\n",
+    );
+
+    assert_eq!(table, expected);
+}
+
+#[test]
+fn total_width_truncates() {
+    let data = [("a", "Hello World", "Hello World Hello World")];
+    let table = Table::new(data)
+        .with(Style::ascii())
+        .with(Width::total(30).priority(0, 1).suffix(".."))
+        .to_string();
+
+    let expected = concat!(
+        "+------+-------+-------------+\n",
+        "| &str | &str  |    &str     |\n",
+        "+------+-------+-------------+\n",
+        "|  a   | Hel.. | Hello Wor.. |\n",
+        "+------+-------+-------------+\n",
+    );
+
+    assert_eq!(table, expected);
+}
+
+#[test]
+fn total_width_doesnt_change_table_which_fits() {
+    let data = create_vector::<1, 2>();
+    let table = Table::new(&data).with(Style::ascii());
+    let expected = table.to_string();
+
+    let table = table.with(Width::total(100)).to_string();
+
+    assert_eq!(table, expected);
+}
+
+#[cfg(feature = "terminal")]
+#[test]
+fn fit_terminal_uses_default_width_without_terminal() {
+    if terminal_size::terminal_size().is_some() {
+        return;
+    }
+
+    let data = create_vector::<1, 2>();
+    let table = Table::new(&data)
+        .with(Style::ascii())
+        .with(Width::fit_terminal().default_width(20))
+        .to_string();
+
+    let expected = Table::new(&data)
+        .with(Style::ascii())
+        .with(Width::budget(20))
+        .to_string();
+
+    assert_eq!(table, expected);
+    assert!(table.lines().all(|line| line.chars().count() == 20));
+}